@@ -1,7 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use serde::{Deserialize, Serialize};
 
-use fixnum::{fixnum, ops::*, FixedPoint};
+use fixnum::{fixnum, FixedPoint};
 
 #[cfg(feature = "i64")]
 type F64p9 = FixedPoint<i64, typenum::U9>;
@@ -0,0 +1,17 @@
+use std::env;
+
+// Layouts backed by a primitive integer, sharing the `impl_fixed_point!` machinery.
+const PRIMITIVE_LAYOUTS: &[&str] = &["i16", "i32", "i64", "i128", "u64", "u128"];
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(primitive_layout)");
+
+    let enabled = PRIMITIVE_LAYOUTS.iter().any(|layout| {
+        let feature = format!("CARGO_FEATURE_{}", layout.to_uppercase());
+        env::var_os(feature).is_some()
+    });
+
+    if enabled {
+        println!("cargo::rustc-cfg=primitive_layout");
+    }
+}
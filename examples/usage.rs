@@ -43,5 +43,8 @@ fn main() -> Result<(), ArithmeticError> {
     let half = price.rmul(fp!(0.5), RoundMode::Ceil)?;
     assert_eq!(half, fp!(2.125));
 
+    let total = amount.0.saturating_add(Fp::MAX);
+    assert_eq!(total, Fp::MAX);
+
     Ok(())
 }
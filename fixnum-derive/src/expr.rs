@@ -0,0 +1,87 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    BinOp, Error, Expr, Ident, Result, Token, UnOp,
+};
+
+/// `fixnum_expr!(mode, expr)`.
+pub(crate) struct Input {
+    mode: Expr,
+    expr: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let mode = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let expr = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { mode, expr })
+    }
+}
+
+pub(crate) fn expand(input: &Input) -> Result<TokenStream> {
+    // Isn't visible to operands, because of the mixed-site hygiene.
+    let mode_var = Ident::new("mode", Span::mixed_site());
+    let mode = &input.mode;
+    let expr = rewrite(&input.expr, &mode_var)?;
+
+    Ok(quote! {{
+        let #mode_var: ::fixnum::ops::RoundMode = #mode;
+        (|| -> ::core::result::Result<_, ::fixnum::ArithmeticError> {
+            ::core::result::Result::Ok(#expr)
+        })()
+    }})
+}
+
+/// Replaces arithmetic operators with checked methods, other expressions are operands.
+fn rewrite(expr: &Expr, mode: &Ident) -> Result<TokenStream> {
+    match expr {
+        Expr::Binary(binary) => {
+            let lhs = rewrite(&binary.left, mode)?;
+            let rhs = rewrite(&binary.right, mode)?;
+            let span = binary.op.span();
+
+            Ok(match binary.op {
+                BinOp::Add(_) => {
+                    quote_spanned!(span=> ::fixnum::ops::CheckedAdd::cadd(#lhs, #rhs)?)
+                }
+                BinOp::Sub(_) => {
+                    quote_spanned!(span=> ::fixnum::ops::CheckedSub::csub(#lhs, #rhs)?)
+                }
+                BinOp::Mul(_) => {
+                    quote_spanned!(span=> ::fixnum::ops::RoundingMul::rmul(#lhs, #rhs, #mode)?)
+                }
+                BinOp::Div(_) => {
+                    quote_spanned!(span=> ::fixnum::ops::RoundingDiv::rdiv(#lhs, #rhs, #mode)?)
+                }
+                BinOp::Rem(_) => {
+                    quote_spanned!(span=> ::fixnum::ops::CheckedRem::crem(#lhs, #rhs)?)
+                }
+                _ => {
+                    return Err(Error::new(
+                        span,
+                        "unsupported operator, expected `+`, `-`, `*`, `/` or `%`",
+                    ))
+                }
+            })
+        }
+        // Negative literals like `-2` are operands.
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) && !is_literal(&unary.expr) => {
+            let operand = rewrite(&unary.expr, mode)?;
+            Ok(quote_spanned!(unary.op.span()=> (#operand).cneg()?))
+        }
+        Expr::Paren(paren) => {
+            let inner = rewrite(&paren.expr, mode)?;
+            Ok(quote!((#inner)))
+        }
+        Expr::Group(group) => rewrite(&group.expr, mode),
+        _ => Ok(quote!(#expr)),
+    }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Lit(_))
+}
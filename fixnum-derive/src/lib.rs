@@ -0,0 +1,209 @@
+//! Procedural macros for [`fixnum`](https://docs.rs/fixnum), use them through the `derive` feature.
+
+#![warn(rust_2018_idioms, unreachable_pub, missing_docs)]
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr, Result, Type};
+
+mod expr;
+
+/// Forwards operations of a tuple-struct newtype to the wrapped `FixedPoint`.
+///
+/// See `fixnum::FixedPointOps` for details.
+#[proc_macro_derive(FixedPointOps, attributes(fixnum))]
+pub fn derive_fixed_point_ops(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Rewrites an arithmetic expression into checked operations.
+///
+/// See `fixnum::fixnum_expr` for details.
+#[proc_macro]
+pub fn fixnum_expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as expr::Input);
+    expr::expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let inner = inner_type(input)?;
+    let serde = serde_module(input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let ops = quote! {
+        impl #impl_generics ::fixnum::ops::CheckedAdd for #name #ty_generics #where_clause {
+            type Output = Self;
+            type Error = ::fixnum::ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> ::core::result::Result<Self, ::fixnum::ArithmeticError> {
+                ::fixnum::ops::CheckedAdd::cadd(self.0, rhs.0).map(Self)
+            }
+        }
+
+        impl #impl_generics ::fixnum::ops::CheckedSub for #name #ty_generics #where_clause {
+            type Output = Self;
+            type Error = ::fixnum::ArithmeticError;
+
+            #[inline]
+            fn csub(self, rhs: Self) -> ::core::result::Result<Self, ::fixnum::ArithmeticError> {
+                ::fixnum::ops::CheckedSub::csub(self.0, rhs.0).map(Self)
+            }
+        }
+
+        impl #impl_generics ::fixnum::ops::RoundingMul<#inner> for #name #ty_generics #where_clause {
+            type Output = Self;
+            type Error = ::fixnum::ArithmeticError;
+
+            #[inline]
+            fn rmul(
+                self,
+                rhs: #inner,
+                mode: ::fixnum::ops::RoundMode,
+            ) -> ::core::result::Result<Self, ::fixnum::ArithmeticError> {
+                ::fixnum::ops::RoundingMul::rmul(self.0, rhs, mode).map(Self)
+            }
+        }
+
+        impl #impl_generics ::fixnum::ops::RoundingDiv<#inner> for #name #ty_generics #where_clause {
+            type Output = Self;
+            type Error = ::fixnum::ArithmeticError;
+
+            #[inline]
+            fn rdiv(
+                self,
+                rhs: #inner,
+                mode: ::fixnum::ops::RoundMode,
+            ) -> ::core::result::Result<Self, ::fixnum::ArithmeticError> {
+                ::fixnum::ops::RoundingDiv::rdiv(self.0, rhs, mode).map(Self)
+            }
+        }
+
+        impl #impl_generics ::fixnum::ops::RoundingDiv for #name #ty_generics #where_clause {
+            type Output = #inner;
+            type Error = ::fixnum::ArithmeticError;
+
+            #[inline]
+            fn rdiv(
+                self,
+                rhs: Self,
+                mode: ::fixnum::ops::RoundMode,
+            ) -> ::core::result::Result<#inner, ::fixnum::ArithmeticError> {
+                ::fixnum::ops::RoundingDiv::rdiv(self.0, rhs.0, mode)
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = <#inner as ::core::str::FromStr>::Err;
+
+            #[inline]
+            fn from_str(str: &str) -> ::core::result::Result<Self, Self::Err> {
+                <#inner as ::core::str::FromStr>::from_str(str).map(Self)
+            }
+        }
+    };
+
+    let mut de_generics = input.generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    let serde = serde.map(|module| {
+        let (serialize, deserialize) = match module {
+            Some(module) => {
+                let module = syn::Ident::new(&module.value(), module.span());
+                (
+                    quote!(::fixnum::serde::#module::serialize(&self.0, serializer)),
+                    quote!(::fixnum::serde::#module::deserialize(deserializer)),
+                )
+            }
+            None => (
+                quote!(::fixnum::_priv::serde::Serialize::serialize(&self.0, serializer)),
+                quote!(<#inner as ::fixnum::_priv::serde::Deserialize<'de>>::deserialize(
+                    deserializer
+                )),
+            ),
+        };
+
+        quote! {
+            impl #impl_generics ::fixnum::_priv::serde::Serialize for #name #ty_generics #where_clause {
+                #[inline]
+                fn serialize<S: ::fixnum::_priv::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    #serialize
+                }
+            }
+
+            impl #de_impl_generics ::fixnum::_priv::serde::Deserialize<'de> for #name #ty_generics #where_clause {
+                #[inline]
+                fn deserialize<D: ::fixnum::_priv::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    #deserialize.map(Self)
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #ops
+        #serde
+    })
+}
+
+fn inner_type(input: &DeriveInput) -> Result<&Type> {
+    if let Data::Struct(data) = &input.data {
+        if let Fields::Unnamed(fields) = &data.fields {
+            if fields.unnamed.len() == 1 {
+                return Ok(&fields.unnamed[0].ty);
+            }
+        }
+    }
+
+    Err(Error::new(
+        Span::call_site(),
+        "`FixedPointOps` supports only tuple structs with a single field",
+    ))
+}
+
+/// Parses `#[fixnum(serde)]` and `#[fixnum(serde = "module")]`.
+fn serde_module(input: &DeriveInput) -> Result<Option<Option<LitStr>>> {
+    let mut serde = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("fixnum"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("serde") {
+                return Err(meta.error("unsupported attribute, expected `serde`"));
+            }
+
+            serde = Some(if meta.input.peek(syn::Token![=]) {
+                Some(meta.value()?.parse()?)
+            } else {
+                None
+            });
+
+            Ok(())
+        })?;
+    }
+
+    Ok(serde)
+}
@@ -0,0 +1,174 @@
+use core::iter::FromIterator;
+
+#[cfg(any(feature = "i128", feature = "u128"))]
+use crate::i256::I256;
+use crate::ArithmeticError;
+use crate::{FixedPoint, Precision};
+
+use self::private::Accumulate;
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// Sums values exactly in a wider integer, checking overflow only in [`finish`].
+///
+/// The sum is kept in `i128` for 64-bit and smaller layouts and in 256 bits for 128-bit ones,
+/// so it can't overflow in practice and intermediate spikes beyond the layout (or below zero
+/// for unsigned layouts) don't fail the result.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{Accumulator, FixedPoint, typenum::U9};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let mut acc = Accumulator::new();
+/// acc.add(Amount::MAX);
+/// acc.add("1.5".parse()?);
+/// assert!(acc.finish().is_err());
+///
+/// acc.sub(Amount::MAX);
+/// assert_eq!(acc.finish()?, "1.5".parse()?);
+///
+/// let values: [Amount; 3] = ["0.1".parse()?, "0.2".parse()?, "0.3".parse()?];
+/// let acc: Accumulator<Amount> = values.into_iter().collect();
+/// assert_eq!(acc.finish()?, "0.6".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// [`finish`]: Accumulator::finish
+pub struct Accumulator<F: Accumulate> {
+    sum: F::Sum,
+}
+
+impl<F: Accumulate> Accumulator<F> {
+    /// Creates an empty accumulator.
+    #[inline]
+    pub fn new() -> Self {
+        Self { sum: F::ZERO_SUM }
+    }
+
+    /// Adds the value without overflow checks.
+    #[inline]
+    pub fn add(&mut self, value: F) {
+        self.sum = F::add(self.sum, value);
+    }
+
+    /// Subtracts the value without overflow checks.
+    #[inline]
+    pub fn sub(&mut self, value: F) {
+        self.sum = F::sub(self.sum, value);
+    }
+
+    /// Returns the sum, `Err` if it doesn't fit in the layout.
+    #[inline]
+    pub fn finish(&self) -> Result<F> {
+        F::finish(self.sum)
+    }
+}
+
+impl<F: Accumulate> Default for Accumulator<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Accumulate> Clone for Accumulator<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Accumulate> Copy for Accumulator<F> {}
+
+impl<F: Accumulate> Extend<F> for Accumulator<F> {
+    #[inline]
+    fn extend<It: IntoIterator<Item = F>>(&mut self, iter: It) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+impl<'a, F: Accumulate + Copy + 'a> Extend<&'a F> for Accumulator<F> {
+    #[inline]
+    fn extend<It: IntoIterator<Item = &'a F>>(&mut self, iter: It) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<F: Accumulate> FromIterator<F> for Accumulator<F> {
+    #[inline]
+    fn from_iter<It: IntoIterator<Item = F>>(iter: It) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+impl<'a, F: Accumulate + Copy + 'a> FromIterator<&'a F> for Accumulator<F> {
+    #[inline]
+    fn from_iter<It: IntoIterator<Item = &'a F>>(iter: It) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to use `Accumulator` in generic code.
+    #[allow(unreachable_pub)]
+    pub trait Accumulate: Sized {
+        type Sum: Copy;
+
+        const ZERO_SUM: Self::Sum;
+
+        fn add(sum: Self::Sum, value: Self) -> Self::Sum;
+        fn sub(sum: Self::Sum, value: Self) -> Self::Sum;
+        fn finish(sum: Self::Sum) -> Result<Self>;
+    }
+}
+
+macro_rules! impl_accumulate {
+    ($layout:tt, $sum:tt, $zero:expr) => {
+        impl<P: Precision> Accumulate for FixedPoint<$layout, P> {
+            type Sum = $sum;
+
+            const ZERO_SUM: $sum = $zero;
+
+            #[inline]
+            fn add(sum: $sum, value: Self) -> $sum {
+                sum + $sum::from(value.inner)
+            }
+
+            #[inline]
+            fn sub(sum: $sum, value: Self) -> $sum {
+                sum - $sum::from(value.inner)
+            }
+
+            #[inline]
+            fn finish(sum: $sum) -> Result<Self> {
+                $layout::try_from(sum)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_accumulate!(i16, i128, 0);
+#[cfg(feature = "i32")]
+impl_accumulate!(i32, i128, 0);
+#[cfg(feature = "i64")]
+impl_accumulate!(i64, i128, 0);
+#[cfg(feature = "i128")]
+impl_accumulate!(i128, I256, I256::from_i128(0));
+#[cfg(feature = "u64")]
+impl_accumulate!(u64, i128, 0);
+#[cfg(feature = "u128")]
+impl_accumulate!(u128, I256, I256::from_i128(0));
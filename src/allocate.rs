@@ -0,0 +1,175 @@
+use core::iter::FusedIterator;
+
+#[cfg(feature = "i128")]
+use crate::i256::I256;
+#[cfg(feature = "u128")]
+use crate::i256::U256;
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::{ArithmeticError, FixedPoint, Precision};
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// An iterator over parts of an amount, see [`FixedPoint::split`] and [`FixedPoint::allocate`].
+///
+/// Parts sum exactly to the original amount: the remainder is distributed by `EPSILON`
+/// to the first parts having non-zero ratios.
+#[derive(Clone)]
+pub struct Allocation<'a, I, P> {
+    amount: I,
+    // `None` means equal parts.
+    ratios: Option<&'a [FixedPoint<I, P>]>,
+    total: I,
+    len: usize,
+    index: usize,
+    leftover: usize,
+}
+
+macro_rules! impl_allocate {
+    ($layout:tt, $promotion:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Splits the amount into `n` equal parts, which sum exactly to the amount.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2};
+            ///
+            /// type Amount = FixedPoint<i64, U2>;
+            ///
+            /// let a: Amount = "100".parse()?;
+            /// let parts: Vec<String> = a.split(3)?.map(|part| part.to_string()).collect();
+            /// assert_eq!(parts, ["33.34", "33.33", "33.33"]);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn split(self, n: usize) -> Result<Allocation<'static, $layout, P>> {
+                if n == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let total = $layout::try_from(n).map_err(|_| ArithmeticError::Overflow)?;
+
+                Ok(Allocation {
+                    amount: self.inner,
+                    ratios: None,
+                    total,
+                    len: n,
+                    index: 0,
+                    leftover: (self.inner % total).unsigned_abs() as usize,
+                })
+            }
+
+            /// Splits the amount proportionally to non-negative `ratios`,
+            /// parts sum exactly to the amount.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2};
+            ///
+            /// type Amount = FixedPoint<i64, U2>;
+            ///
+            /// let a: Amount = "0.05".parse()?;
+            /// let ratios: [Amount; 2] = ["0.3".parse()?, "0.7".parse()?];
+            /// let parts: Vec<String> = a.allocate(&ratios)?.map(|part| part.to_string()).collect();
+            /// assert_eq!(parts, ["0.02", "0.03"]);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            ///
+            /// Returns `Err(DomainViolation)` for negative ratios and `Err(DivisionByZero)`
+            /// if all ratios are zero.
+            pub fn allocate(self, ratios: &[Self]) -> Result<Allocation<'_, $layout, P>> {
+                let mut total: $layout = 0;
+
+                for ratio in ratios {
+                    if ratio.inner.is_negative() {
+                        return Err(ArithmeticError::DomainViolation);
+                    }
+
+                    total = total
+                        .checked_add(ratio.inner)
+                        .ok_or(ArithmeticError::Overflow)?;
+                }
+
+                if total == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let mut allocation = Allocation {
+                    amount: self.inner,
+                    ratios: Some(ratios),
+                    total,
+                    len: ratios.len(),
+                    index: 0,
+                    leftover: 0,
+                };
+
+                // `|part| <= |amount|` and all parts have the same sign, thus no overflow.
+                let allocated = allocation
+                    .clone()
+                    .fold(0, |sum: $layout, part| sum + part.inner);
+                allocation.leftover = (self.inner - allocated).unsigned_abs() as usize;
+
+                Ok(allocation)
+            }
+        }
+
+        impl<P: Precision> Iterator for Allocation<'_, $layout, P> {
+            type Item = FixedPoint<$layout, P>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.index == self.len {
+                    return None;
+                }
+
+                let ratio = self.ratios.map_or(1, |ratios| ratios[self.index].inner);
+                self.index += 1;
+
+                let part = if self.ratios.is_none() {
+                    self.amount / self.total
+                } else {
+                    let part = $promotion::from(self.amount) * $promotion::from(ratio)
+                        / $promotion::from(self.total);
+                    // `ratio <= total`, thus `|part| <= |amount|`.
+                    $layout::try_from(part).unwrap()
+                };
+
+                let part = if self.leftover > 0 && ratio != 0 {
+                    self.leftover -= 1;
+                    part + self.amount.signum()
+                } else {
+                    part
+                };
+
+                Some(FixedPoint::from_bits(part))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.len - self.index;
+                (len, Some(len))
+            }
+        }
+
+        impl<P: Precision> ExactSizeIterator for Allocation<'_, $layout, P> {}
+
+        impl<P: Precision> FusedIterator for Allocation<'_, $layout, P> {}
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_allocate!(i16, i32);
+#[cfg(feature = "i32")]
+impl_allocate!(i32, i64);
+#[cfg(feature = "i64")]
+impl_allocate!(i64, i128);
+#[cfg(feature = "i128")]
+impl_allocate!(i128, I256);
+#[cfg(feature = "u64")]
+impl_allocate!(u64, u128);
+#[cfg(feature = "u128")]
+impl_allocate!(u128, U256);
@@ -0,0 +1,366 @@
+//! A currency-tagged wrapper over [`FixedPoint`].
+//!
+//! [`Amount<C, I, P>`][Amount] behaves like `FixedPoint<I, P>`, but amounts of different
+//! currencies `C` can't be mixed by mistake:
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{amount::Amount, ops::*, typenum::U9, FixedPoint};
+//!
+//! enum Usd {}
+//! enum Eur {}
+//!
+//! type Price = FixedPoint<i64, U9>;
+//! type Usds = Amount<Usd, i64, U9>;
+//! type Euros = Amount<Eur, i64, U9>;
+//!
+//! let a: Usds = "1.5".parse()?;
+//! let b: Usds = "2.25".parse()?;
+//! assert_eq!(a.cadd(b)?, "3.75".parse()?);
+//!
+//! let rate: Price = "0.9".parse()?;
+//! let c = Euros::new(a.value().rmul(rate, RoundMode::Nearest)?);
+//! assert_eq!(c, "1.35".parse()?);
+//!
+//! // Doesn't compile: `CheckedAdd<Euros>` isn't implemented for `Usds`.
+//! // a.cadd(c)?;
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+//!
+//! Currency markers are arbitrary types, e.g. empty enums, no values of them are created.
+
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData, str::FromStr};
+
+use crate::{ops::*, FixedPoint};
+
+/// An amount of the currency `C`. See the [module level documentation](self).
+#[repr(transparent)]
+pub struct Amount<C, I, P> {
+    value: FixedPoint<I, P>,
+    // `fn() -> C` makes `Amount` `Send` and `Sync` regardless of `C`.
+    currency: PhantomData<fn() -> C>,
+}
+
+impl<C, I, P> Amount<C, I, P> {
+    /// Tags the value with the currency.
+    #[inline]
+    pub const fn new(value: FixedPoint<I, P>) -> Self {
+        Self {
+            value,
+            currency: PhantomData,
+        }
+    }
+
+    /// Returns the untagged value.
+    #[inline]
+    pub fn value(self) -> FixedPoint<I, P> {
+        self.value
+    }
+
+    /// Returns the reference to the untagged value.
+    #[inline]
+    pub const fn as_value(&self) -> &FixedPoint<I, P> {
+        &self.value
+    }
+
+    // Inherent methods take precedence over the deprecated ones of `Checked*` and `RoundingMul`,
+    // so method calls aren't ambiguous when both traits are in scope, e.g. by `ops::*`.
+
+    /// The same as [`SaturatingAdd::saturating_add`].
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self
+    where
+        FixedPoint<I, P>: SaturatingAdd<Output = FixedPoint<I, P>>,
+    {
+        SaturatingAdd::saturating_add(self, rhs)
+    }
+
+    /// The same as [`SaturatingSub::saturating_sub`].
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self
+    where
+        FixedPoint<I, P>: SaturatingSub<Output = FixedPoint<I, P>>,
+    {
+        SaturatingSub::saturating_sub(self, rhs)
+    }
+
+    /// The same as [`SaturatingMul::saturating_mul`].
+    #[inline]
+    pub fn saturating_mul(self, rhs: I) -> Self
+    where
+        FixedPoint<I, P>: SaturatingMul<I, Output = FixedPoint<I, P>>,
+    {
+        SaturatingMul::saturating_mul(self, rhs)
+    }
+
+    /// The same as [`SaturatingRMul::saturating_rmul`].
+    #[inline]
+    pub fn saturating_rmul(self, rhs: FixedPoint<I, P>, mode: RoundMode) -> Self
+    where
+        FixedPoint<I, P>: SaturatingRMul<Output = FixedPoint<I, P>>,
+    {
+        SaturatingRMul::saturating_rmul(self, rhs, mode)
+    }
+}
+
+impl<C, I: Clone, P> Clone for Amount<C, I, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(FixedPoint::from_bits(self.value.inner.clone()))
+    }
+}
+
+impl<C, I: Copy, P: Copy> Copy for Amount<C, I, P> {}
+
+impl<C, I: Default, P> Default for Amount<C, I, P> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(FixedPoint::from_bits(I::default()))
+    }
+}
+
+impl<C, I: PartialEq, P> PartialEq for Amount<C, I, P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value.inner == other.value.inner
+    }
+}
+
+impl<C, I: Eq, P> Eq for Amount<C, I, P> {}
+
+impl<C, I: PartialOrd, P> PartialOrd for Amount<C, I, P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.inner.partial_cmp(&other.value.inner)
+    }
+}
+
+impl<C, I: Ord, P> Ord for Amount<C, I, P> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.inner.cmp(&other.value.inner)
+    }
+}
+
+impl<C, I: hash::Hash, P> hash::Hash for Amount<C, I, P> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.value.inner.hash(state);
+    }
+}
+
+impl<C, I, P> fmt::Debug for Amount<C, I, P>
+where
+    FixedPoint<I, P>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<C, I, P> fmt::Display for Amount<C, I, P>
+where
+    FixedPoint<I, P>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<C, I, P> FromStr for Amount<C, I, P>
+where
+    FixedPoint<I, P>: FromStr,
+{
+    type Err = <FixedPoint<I, P> as FromStr>::Err;
+
+    #[inline]
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        str.parse().map(Self::new)
+    }
+}
+
+impl<C, I, P> Zero for Amount<C, I, P>
+where
+    FixedPoint<I, P>: Zero,
+{
+    const ZERO: Self = Self::new(<FixedPoint<I, P> as Zero>::ZERO);
+}
+
+impl<C, I, P> Bounded for Amount<C, I, P>
+where
+    FixedPoint<I, P>: Bounded,
+{
+    const MIN: Self = Self::new(<FixedPoint<I, P> as Bounded>::MIN);
+    const MAX: Self = Self::new(<FixedPoint<I, P> as Bounded>::MAX);
+}
+
+impl<C, I, P> CheckedAdd for Amount<C, I, P>
+where
+    FixedPoint<I, P>: CheckedAdd<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+    type Error = <FixedPoint<I, P> as CheckedAdd>::Error;
+
+    #[inline]
+    fn cadd(self, rhs: Self) -> Result<Self, Self::Error> {
+        self.value.cadd(rhs.value).map(Self::new)
+    }
+}
+
+impl<C, I, P> SaturatingAdd for Amount<C, I, P>
+where
+    FixedPoint<I, P>: SaturatingAdd<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self {
+        Self::new(self.value.saturating_add(rhs.value))
+    }
+}
+
+impl<C, I, P> CheckedSub for Amount<C, I, P>
+where
+    FixedPoint<I, P>: CheckedSub<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+    type Error = <FixedPoint<I, P> as CheckedSub>::Error;
+
+    #[inline]
+    fn csub(self, rhs: Self) -> Result<Self, Self::Error> {
+        self.value.csub(rhs.value).map(Self::new)
+    }
+}
+
+impl<C, I, P> SaturatingSub for Amount<C, I, P>
+where
+    FixedPoint<I, P>: SaturatingSub<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn saturating_sub(self, rhs: Self) -> Self {
+        Self::new(self.value.saturating_sub(rhs.value))
+    }
+}
+
+/// Multiplication by an integer, e.g. a quantity.
+impl<C, I, P> CheckedMul<I> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: CheckedMul<I, Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+    type Error = <FixedPoint<I, P> as CheckedMul<I>>::Error;
+
+    #[inline]
+    fn cmul(self, rhs: I) -> Result<Self, Self::Error> {
+        self.value.cmul(rhs).map(Self::new)
+    }
+}
+
+/// Multiplication by an integer, e.g. a quantity.
+impl<C, I, P> SaturatingMul<I> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: SaturatingMul<I, Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn saturating_mul(self, rhs: I) -> Self {
+        Self::new(self.value.saturating_mul(rhs))
+    }
+}
+
+/// Multiplication by a dimensionless number, e.g. a fee rate.
+impl<C, I, P> RoundingMul<FixedPoint<I, P>> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: RoundingMul<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+    type Error = <FixedPoint<I, P> as RoundingMul>::Error;
+
+    #[inline]
+    fn rmul(self, rhs: FixedPoint<I, P>, mode: RoundMode) -> Result<Self, Self::Error> {
+        self.value.rmul(rhs, mode).map(Self::new)
+    }
+}
+
+/// Multiplication by a dimensionless number, e.g. a fee rate.
+impl<C, I, P> SaturatingRMul<FixedPoint<I, P>> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: SaturatingRMul<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn saturating_rmul(self, rhs: FixedPoint<I, P>, mode: RoundMode) -> Self {
+        Self::new(self.value.saturating_rmul(rhs, mode))
+    }
+}
+
+/// Division by a dimensionless number.
+impl<C, I, P> RoundingDiv<FixedPoint<I, P>> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: RoundingDiv<Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+    type Error = <FixedPoint<I, P> as RoundingDiv>::Error;
+
+    #[inline]
+    fn rdiv(self, rhs: FixedPoint<I, P>, mode: RoundMode) -> Result<Self, Self::Error> {
+        self.value.rdiv(rhs, mode).map(Self::new)
+    }
+}
+
+/// Division by an integer, e.g. a quantity.
+impl<C, I, P> RoundingDiv<I> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: RoundingDiv<I, Output = FixedPoint<I, P>>,
+{
+    type Output = Self;
+    type Error = <FixedPoint<I, P> as RoundingDiv<I>>::Error;
+
+    #[inline]
+    fn rdiv(self, rhs: I, mode: RoundMode) -> Result<Self, Self::Error> {
+        self.value.rdiv(rhs, mode).map(Self::new)
+    }
+}
+
+/// Division of amounts of the same currency, the result is dimensionless.
+impl<C, I, P> RoundingDiv for Amount<C, I, P>
+where
+    FixedPoint<I, P>: RoundingDiv<Output = FixedPoint<I, P>>,
+{
+    type Output = FixedPoint<I, P>;
+    type Error = <FixedPoint<I, P> as RoundingDiv>::Error;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<FixedPoint<I, P>, Self::Error> {
+        self.value.rdiv(rhs.value, mode)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C, I, P> serde::Serialize for Amount<C, I, P>
+where
+    FixedPoint<I, P>: serde::Serialize,
+{
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C, I, P> serde::Deserialize<'de> for Amount<C, I, P>
+where
+    FixedPoint<I, P>: serde::Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FixedPoint::deserialize(deserializer).map(Self::new)
+    }
+}
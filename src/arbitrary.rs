@@ -0,0 +1,55 @@
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::{FixedPoint, Precision};
+
+// Out of 10 values, 2 are boundary ones.
+const BOUNDARY_WEIGHT: u8 = 2;
+const REGULAR_WEIGHT: u8 = 8;
+
+macro_rules! impl_arbitrary {
+    ($layout:tt) => {
+        /// Generates any values, biased toward `MIN`, `MAX`, `±EPSILON`, `±1` and zero.
+        #[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+        impl<'a, P: Precision> Arbitrary<'a> for FixedPoint<$layout, P> {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                if !u.ratio(BOUNDARY_WEIGHT, BOUNDARY_WEIGHT + REGULAR_WEIGHT)? {
+                    return u.arbitrary().map(Self::from_bits);
+                }
+
+                // Zero goes first to be chosen on exhausted input.
+                let boundaries = [Self::ZERO, Self::MIN, Self::MAX]
+                    .into_iter()
+                    .chain(
+                        [Self::EPSILON, Self::ONE]
+                            .into_iter()
+                            // Skipped for unsigned layouts.
+                            .flat_map(|value| [Some(value), value.cneg().ok()])
+                            .flatten(),
+                    )
+                    .collect::<Vec<_>>();
+
+                u.choose(&boundaries).copied()
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                size_hint::and(
+                    <u8 as Arbitrary>::size_hint(depth),
+                    <$layout as Arbitrary>::size_hint(depth),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_arbitrary!(i16);
+#[cfg(feature = "i32")]
+impl_arbitrary!(i32);
+#[cfg(feature = "i64")]
+impl_arbitrary!(i64);
+#[cfg(feature = "i128")]
+impl_arbitrary!(i128);
+#[cfg(feature = "u64")]
+impl_arbitrary!(u64);
+#[cfg(feature = "u128")]
+impl_arbitrary!(u128);
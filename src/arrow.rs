@@ -0,0 +1,82 @@
+//! Conversions between `FixedPoint<i128, P>` and Arrow's `Decimal128`.
+//!
+//! Values of [`Decimal128Array`] are `i128` mantissas sharing the array's scale,
+//! so arrays with the scale equal to the precision are converted without rescaling.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{arrow, typenum::U9, FixedPoint};
+//!
+//! type Price = FixedPoint<i128, U9>;
+//!
+//! let prices: Vec<Price> = vec!["1.25".parse()?, "-0.5".parse()?];
+//! let array = arrow::to_array(&prices);
+//! assert_eq!((array.precision(), array.scale()), (38, 9));
+//!
+//! let decoded = arrow::from_array::<U9>(&array).collect::<Result<Vec<_>, _>>()?;
+//! assert_eq!(decoded, [Some(prices[0]), Some(prices[1])]);
+//! # Ok(()) }
+//! ```
+
+use arrow_array::{
+    types::{Decimal128Type, DecimalType},
+    Decimal128Array,
+};
+
+use crate::{ConvertError, FixedPoint, Precision};
+
+/// Builds an array with the maximum precision and the scale equal to `P`.
+pub fn to_array<P: Precision>(values: &[FixedPoint<i128, P>]) -> Decimal128Array {
+    to_nullable_array(values.iter().copied().map(Some))
+}
+
+/// Builds an array with nulls, the maximum precision and the scale equal to `P`.
+pub fn to_nullable_array<P: Precision>(
+    values: impl IntoIterator<Item = Option<FixedPoint<i128, P>>>,
+) -> Decimal128Array {
+    values
+        .into_iter()
+        .map(|value| value.map(FixedPoint::into_bits))
+        .collect::<Decimal128Array>()
+        // `10^PRECISION` fits in `i128`, thus `PRECISION <= 38`.
+        .with_precision_and_scale(Decimal128Type::MAX_PRECISION, P::I8)
+        .unwrap()
+}
+
+/// Converts a value of an array with the provided `scale`.
+///
+/// Fails if the value can't be represented without rounding or overflows.
+pub fn from_value<P: Precision>(
+    value: i128,
+    scale: i8,
+) -> Result<FixedPoint<i128, P>, ConvertError> {
+    let excess = i32::from(scale) - P::I32;
+
+    if excess <= 0 {
+        return FixedPoint::<i128, P>::from_decimal(value, -i32::from(scale));
+    }
+
+    let divisor = 10i128
+        .checked_pow(excess as u32)
+        .ok_or(ConvertError::new("requested precision is too high"))?;
+
+    if value % divisor != 0 {
+        return Err(ConvertError::new("requested precision is too high"));
+    }
+
+    Ok(FixedPoint::<i128, P>::from_bits(value / divisor))
+}
+
+/// Converts values of an array, nulls are `None`, see [`from_value`].
+pub fn from_array<P: Precision>(
+    array: &Decimal128Array,
+) -> impl Iterator<Item = Result<Option<FixedPoint<i128, P>>, ConvertError>> + '_ {
+    let scale = array.scale();
+    let is_exact = i32::from(scale) == P::I32;
+
+    array.iter().map(move |value| match value {
+        Some(value) if is_exact => Ok(Some(FixedPoint::from_bits(value))),
+        Some(value) => from_value(value, scale).map(Some),
+        None => Ok(None),
+    })
+}
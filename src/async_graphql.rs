@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, TypeName, Value};
+
+use crate::{FixedPoint, Precision};
+
+macro_rules! impl_async_graphql {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "async-graphql")))]
+        impl<P: Precision + Send + Sync> TypeName for FixedPoint<$layout, P> {
+            /// Returns `FixedPoint_<layout>_<precision>`, e.g. `FixedPoint_i64_9`.
+            fn type_name() -> Cow<'static, str> {
+                Cow::Owned(format!(
+                    concat!("FixedPoint_", stringify!($layout), "_{}"),
+                    Self::PRECISION
+                ))
+            }
+        }
+
+        /// Accepts strings and numbers, excess fractional digits are rounded like `FromStr` does.
+        /// Outputs strings to keep all digits.
+        #[Scalar(name_type)]
+        impl<P: Precision + Send + Sync> ScalarType for FixedPoint<$layout, P> {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                match &value {
+                    Value::String(s) => s.parse().map_err(InputValueError::custom),
+                    // `Number` is printed in the shortest form keeping the value, e.g. `1e-7`,
+                    // so it's parsed without intermediate floats.
+                    Value::Number(n) => n.to_string().parse().map_err(InputValueError::custom),
+                    _ => Err(InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                matches!(value, Value::String(_) | Value::Number(_))
+            }
+
+            fn to_value(&self) -> Value {
+                Value::String(self.to_string())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_async_graphql!(i16);
+#[cfg(feature = "i32")]
+impl_async_graphql!(i32);
+#[cfg(feature = "i64")]
+impl_async_graphql!(i64);
+#[cfg(feature = "i128")]
+impl_async_graphql!(i128);
+#[cfg(feature = "u64")]
+impl_async_graphql!(u64);
+#[cfg(feature = "u128")]
+impl_async_graphql!(u128);
@@ -0,0 +1,226 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+use crate::{ArithmeticError, FixedPoint, Precision};
+
+use self::private::AtomicLayout;
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// A `FixedPoint` which can be safely shared between threads, e.g. for lock-free position counters.
+///
+/// Available for `i16`, `i32`, `i64` and `u64` layouts on targets supporting atomics of
+/// the same width. `i128` and `u128` layouts aren't supported, because 128-bit atomics
+/// aren't stable.
+///
+/// Methods take [`Ordering`] arguments with the same meaning as the ones of
+/// [`AtomicI64`](core::sync::atomic::AtomicI64).
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::sync::atomic::Ordering::SeqCst;
+/// use fixnum::{ArithmeticError, AtomicFixedPoint, FixedPoint, typenum::U9};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let position = AtomicFixedPoint::new("1.5".parse::<Amount>()?);
+/// assert_eq!(position.fetch_add_checked("2.5".parse()?, SeqCst)?, "1.5".parse()?);
+/// assert_eq!(position.load(SeqCst), "4".parse()?);
+///
+/// let result = position.fetch_add_checked(Amount::MAX, SeqCst);
+/// assert_eq!(result, Err(ArithmeticError::Overflow));
+/// assert_eq!(position.load(SeqCst), "4".parse()?);
+///
+/// position.fetch_max("10".parse()?, SeqCst);
+/// assert_eq!(position.into_inner(), "10".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[repr(transparent)]
+pub struct AtomicFixedPoint<I: AtomicLayout, P> {
+    inner: I::Atomic,
+    _marker: PhantomData<P>,
+}
+
+impl<I: AtomicLayout, P> AtomicFixedPoint<I, P> {
+    /// Creates a new atomic value.
+    #[inline]
+    pub fn new(value: FixedPoint<I, P>) -> Self {
+        Self {
+            inner: value.inner.to_atomic(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    #[inline]
+    pub fn into_inner(self) -> FixedPoint<I, P> {
+        FixedPoint::from_bits(I::from_atomic(self.inner))
+    }
+}
+
+/// Returns the ordering of the load in a CAS loop like `fetch_update` requires.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+mod private {
+    /// A layout having an atomic counterpart.
+    #[allow(unreachable_pub)]
+    pub trait AtomicLayout: Sized {
+        type Atomic;
+
+        fn to_atomic(self) -> Self::Atomic;
+        fn from_atomic(atomic: Self::Atomic) -> Self;
+    }
+}
+
+macro_rules! impl_atomic {
+    ($layout:tt, $atomic:ident) => {
+        impl AtomicLayout for $layout {
+            type Atomic = core::sync::atomic::$atomic;
+
+            #[inline]
+            fn to_atomic(self) -> Self::Atomic {
+                Self::Atomic::new(self)
+            }
+
+            #[inline]
+            fn from_atomic(atomic: Self::Atomic) -> Self {
+                atomic.into_inner()
+            }
+        }
+
+        impl<P: Precision> AtomicFixedPoint<$layout, P> {
+            /// Loads the value.
+            #[inline]
+            pub fn load(&self, order: Ordering) -> FixedPoint<$layout, P> {
+                FixedPoint::from_bits(self.inner.load(order))
+            }
+
+            /// Stores the value.
+            #[inline]
+            pub fn store(&self, value: FixedPoint<$layout, P>, order: Ordering) {
+                self.inner.store(value.inner, order);
+            }
+
+            /// Stores the value, returning the previous one.
+            #[inline]
+            pub fn swap(
+                &self,
+                value: FixedPoint<$layout, P>,
+                order: Ordering,
+            ) -> FixedPoint<$layout, P> {
+                FixedPoint::from_bits(self.inner.swap(value.inner, order))
+            }
+
+            /// Stores `new` if the current value is `current`.
+            /// Returns the previous value, `Ok` if it's been replaced.
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: FixedPoint<$layout, P>,
+                new: FixedPoint<$layout, P>,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<FixedPoint<$layout, P>, FixedPoint<$layout, P>> {
+                self.inner
+                    .compare_exchange(current.inner, new.inner, success, failure)
+                    .map(FixedPoint::from_bits)
+                    .map_err(FixedPoint::from_bits)
+            }
+
+            /// Adds to the current value, returning the previous one.
+            /// Returns `Err` and keeps the value unchanged on overflow.
+            ///
+            /// Implemented by a CAS loop, so it can be retried under contention.
+            #[inline]
+            pub fn fetch_add_checked(
+                &self,
+                value: FixedPoint<$layout, P>,
+                order: Ordering,
+            ) -> Result<FixedPoint<$layout, P>> {
+                self.inner
+                    .fetch_update(order, load_ordering(order), |inner| {
+                        inner.checked_add(value.inner)
+                    })
+                    .map(FixedPoint::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Subtracts from the current value, returning the previous one.
+            /// Returns `Err` and keeps the value unchanged on overflow.
+            ///
+            /// Implemented by a CAS loop, so it can be retried under contention.
+            #[inline]
+            pub fn fetch_sub_checked(
+                &self,
+                value: FixedPoint<$layout, P>,
+                order: Ordering,
+            ) -> Result<FixedPoint<$layout, P>> {
+                self.inner
+                    .fetch_update(order, load_ordering(order), |inner| {
+                        inner.checked_sub(value.inner)
+                    })
+                    .map(FixedPoint::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Stores the maximum of the current value and `value`, returning the previous one.
+            #[inline]
+            pub fn fetch_max(
+                &self,
+                value: FixedPoint<$layout, P>,
+                order: Ordering,
+            ) -> FixedPoint<$layout, P> {
+                FixedPoint::from_bits(self.inner.fetch_max(value.inner, order))
+            }
+
+            /// Stores the minimum of the current value and `value`, returning the previous one.
+            #[inline]
+            pub fn fetch_min(
+                &self,
+                value: FixedPoint<$layout, P>,
+                order: Ordering,
+            ) -> FixedPoint<$layout, P> {
+                FixedPoint::from_bits(self.inner.fetch_min(value.inner, order))
+            }
+        }
+
+        impl<P: Precision> Default for AtomicFixedPoint<$layout, P> {
+            #[inline]
+            fn default() -> Self {
+                Self::new(FixedPoint::from_bits(0))
+            }
+        }
+
+        impl<P: Precision> From<FixedPoint<$layout, P>> for AtomicFixedPoint<$layout, P> {
+            #[inline]
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<P: Precision> fmt::Debug for AtomicFixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "i16", target_has_atomic = "16"))]
+impl_atomic!(i16, AtomicI16);
+#[cfg(all(feature = "i32", target_has_atomic = "32"))]
+impl_atomic!(i32, AtomicI32);
+#[cfg(all(feature = "i64", target_has_atomic = "64"))]
+impl_atomic!(i64, AtomicI64);
+#[cfg(all(feature = "u64", target_has_atomic = "64"))]
+impl_atomic!(u64, AtomicU64);
@@ -0,0 +1,324 @@
+use core::fmt;
+
+#[cfg(any(feature = "i128", feature = "u128"))]
+use crate::i256::I256;
+use crate::ops::{One, RoundMode, Zero};
+use crate::ArithmeticError;
+use crate::{ops::RoundingDiv, FixedPoint, Precision};
+
+use self::private::Average;
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// An exponential moving average: `ema = ema + alpha * (value - ema)`.
+///
+/// Every update is calculated exactly in a wider integer and [rounded][RoundMode] once,
+/// the first value initializes the average.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{Ema, FixedPoint, typenum::U9, ops::RoundMode::*};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let mut ema = Ema::<Price>::new("0.25".parse()?)?;
+/// assert_eq!(ema.value(), None);
+/// assert_eq!(ema.update("10".parse()?, Nearest)?, "10".parse()?);
+/// assert_eq!(ema.update("14".parse()?, Nearest)?, "11".parse()?);
+/// assert_eq!(ema.update("12".parse()?, Nearest)?, "11.25".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Ema<F> {
+    alpha: F,
+    value: Option<F>,
+}
+
+impl<F: Average> Ema<F> {
+    /// Creates an empty average with the smoothing factor `alpha`.
+    ///
+    /// Returns [`ArithmeticError::DomainViolation`] unless `0 < alpha <= 1`.
+    #[inline]
+    pub fn new(alpha: F) -> Result<Self> {
+        if !F::is_valid_alpha(alpha) {
+            return Err(ArithmeticError::DomainViolation);
+        }
+
+        Ok(Self { alpha, value: None })
+    }
+
+    /// Returns the smoothing factor.
+    #[inline]
+    pub fn alpha(&self) -> F {
+        self.alpha
+    }
+
+    /// Returns the current average, `None` if no values have been seen.
+    #[inline]
+    pub fn value(&self) -> Option<F> {
+        self.value
+    }
+
+    /// Adds the value and returns the updated average.
+    #[inline]
+    pub fn update(&mut self, value: F, mode: RoundMode) -> Result<F> {
+        let value = match self.value {
+            Some(prev) => F::ema(prev, value, self.alpha, mode)?,
+            None => value,
+        };
+
+        self.value = Some(value);
+        Ok(value)
+    }
+}
+
+/// An arithmetic mean of all seen values.
+///
+/// The sum is kept exactly in a wider integer (see [`Accumulator`]), so the mean is rounded
+/// only once and doesn't drift however many values are added.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{RunningMean, FixedPoint, typenum::U9, ops::RoundMode::*};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let mut mean = RunningMean::<Price>::new();
+/// mean.add(Price::MAX)?;
+/// mean.add(Price::MAX)?;
+/// assert_eq!(mean.mean(Nearest)?, Price::MAX);
+///
+/// mean.add("1".parse()?)?;
+/// assert_eq!(mean.count(), 3);
+/// assert_eq!(mean.mean(Nearest)?, "6148914691.569850538".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// [`Accumulator`]: crate::Accumulator
+pub struct RunningMean<F: Average> {
+    sum: F::Sum,
+    count: u64,
+}
+
+impl<F: Average> RunningMean<F> {
+    /// Creates an empty mean.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sum: <F::Sum as Zero>::ZERO,
+            count: 0,
+        }
+    }
+
+    /// Adds the value, the state is unchanged on error.
+    #[inline]
+    pub fn add(&mut self, value: F) -> Result<()> {
+        let sum = F::add(self.sum, value)?;
+        self.count = self.count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+        self.sum = sum;
+        Ok(())
+    }
+
+    /// Returns the number of added values.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean, [`ArithmeticError::DivisionByZero`] if no values have been added.
+    #[inline]
+    pub fn mean(&self, mode: RoundMode) -> Result<F> {
+        F::div(self.sum, F::count(self.count), mode)
+    }
+}
+
+/// A volume-weighted average price: `sum(price * volume) / sum(volume)`.
+///
+/// Both sums are kept exactly in a wider integer, the result is rounded once.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{Vwap, FixedPoint, typenum::U9, ops::RoundMode::*};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let mut vwap = Vwap::<Price>::new();
+/// vwap.add("100".parse()?, "1".parse()?)?;
+/// vwap.add("101".parse()?, "3".parse()?)?;
+/// assert_eq!(vwap.volume()?, "4".parse()?);
+/// assert_eq!(vwap.vwap(Nearest)?, "100.75".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub struct Vwap<F: Average> {
+    notional: F::Sum,
+    volume: F::Sum,
+}
+
+impl<F: Average> Vwap<F> {
+    /// Creates an empty average.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            notional: <F::Sum as Zero>::ZERO,
+            volume: <F::Sum as Zero>::ZERO,
+        }
+    }
+
+    /// Adds a trade, the state is unchanged on error.
+    #[inline]
+    pub fn add(&mut self, price: F, volume: F) -> Result<()> {
+        let notional = F::add_product(self.notional, price, volume)?;
+        self.volume = F::add(self.volume, volume)?;
+        self.notional = notional;
+        Ok(())
+    }
+
+    /// Returns the total volume, `Err` if it doesn't fit in the layout.
+    #[inline]
+    pub fn volume(&self) -> Result<F> {
+        F::div(self.volume, <F::Sum as One>::ONE, RoundMode::Floor)
+    }
+
+    /// Returns the average price, [`ArithmeticError::DivisionByZero`] if the total volume is zero.
+    #[inline]
+    pub fn vwap(&self, mode: RoundMode) -> Result<F> {
+        F::div(self.notional, self.volume, mode)
+    }
+}
+
+impl<F: Average> Clone for RunningMean<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Average> Copy for RunningMean<F> {}
+
+impl<F: Average> fmt::Debug for RunningMean<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningMean")
+            .field("sum", &self.sum)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<F: Average> Default for RunningMean<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Average> Clone for Vwap<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Average> Copy for Vwap<F> {}
+
+impl<F: Average> fmt::Debug for Vwap<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vwap")
+            .field("notional", &self.notional)
+            .field("volume", &self.volume)
+            .finish()
+    }
+}
+
+impl<F: Average> Default for Vwap<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to use averages in generic code.
+    #[allow(unreachable_pub)]
+    pub trait Average: Copy {
+        type Sum: Copy + fmt::Debug + Zero + One;
+
+        fn is_valid_alpha(alpha: Self) -> bool;
+        fn ema(prev: Self, value: Self, alpha: Self, mode: RoundMode) -> Result<Self>;
+        fn count(count: u64) -> Self::Sum;
+        fn add(sum: Self::Sum, value: Self) -> Result<Self::Sum>;
+        fn add_product(sum: Self::Sum, a: Self, b: Self) -> Result<Self::Sum>;
+        fn div(numerator: Self::Sum, denominator: Self::Sum, mode: RoundMode) -> Result<Self>;
+    }
+}
+
+macro_rules! impl_average {
+    ($layout:tt, $sum:tt) => {
+        impl<P: Precision> Average for FixedPoint<$layout, P> {
+            type Sum = $sum;
+
+            #[inline]
+            fn is_valid_alpha(alpha: Self) -> bool {
+                alpha.inner > 0 && alpha.inner <= Self::COEF
+            }
+
+            #[inline]
+            fn ema(prev: Self, value: Self, alpha: Self, mode: RoundMode) -> Result<Self> {
+                // `(value * alpha + prev * (1 - alpha)) / 1`, it never overflows the layout.
+                let sum = Self::add_product($sum::ZERO, value, alpha)?;
+                let sum = Self::add_product(sum, prev, Self::from_bits(Self::COEF - alpha.inner))?;
+                Self::div(sum, $sum::from(Self::COEF), mode)
+            }
+
+            #[inline]
+            fn count(count: u64) -> $sum {
+                $sum::from(count as i128)
+            }
+
+            #[inline]
+            fn add(sum: $sum, value: Self) -> Result<$sum> {
+                sum.checked_add($sum::from(value.inner))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn add_product(sum: $sum, a: Self, b: Self) -> Result<$sum> {
+                $sum::from(a.inner)
+                    .checked_mul($sum::from(b.inner))
+                    .and_then(|product| sum.checked_add(product))
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn div(numerator: $sum, denominator: $sum, mode: RoundMode) -> Result<Self> {
+                let result = numerator.rdiv(denominator, mode)?;
+                $layout::try_from(result)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_average!(i16, i128);
+#[cfg(feature = "i32")]
+impl_average!(i32, i128);
+#[cfg(feature = "i64")]
+impl_average!(i64, i128);
+#[cfg(feature = "i128")]
+impl_average!(i128, I256);
+#[cfg(feature = "u64")]
+impl_average!(u64, i128);
+#[cfg(feature = "u128")]
+impl_average!(u128, I256);
@@ -0,0 +1,85 @@
+use core::convert::TryFrom;
+
+use bigdecimal::{num_bigint::BigInt, BigDecimal, RoundingMode};
+
+use crate::{ops::RoundMode, ConvertError, FixedPoint, Precision};
+
+// No layout has more integral digits, checked before rounding to avoid huge intermediate values.
+const MAX_INTEGRAL_DIGITS: i64 = 39;
+
+fn rounding_mode(mode: RoundMode) -> RoundingMode {
+    match mode {
+        RoundMode::Ceil => RoundingMode::Ceiling,
+        RoundMode::Floor => RoundingMode::Floor,
+        RoundMode::Nearest => RoundingMode::HalfUp,
+        RoundMode::HalfEven => RoundingMode::HalfEven,
+    }
+}
+
+macro_rules! impl_bigdecimal {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Converts from [`BigDecimal`][bigdecimal::BigDecimal] rounding extra fractional
+            /// digits according to `mode`.
+            /// Fails if the value doesn't fit.
+            pub fn from_big_decimal(
+                value: &BigDecimal,
+                mode: RoundMode,
+            ) -> Result<Self, ConvertError> {
+                let integral_digits = value.digits() as i64 - value.fractional_digit_count();
+                if integral_digits > MAX_INTEGRAL_DIGITS {
+                    return Err(ConvertError::new("too big number"));
+                }
+
+                let rounded =
+                    value.with_scale_round(i64::from(Self::PRECISION), rounding_mode(mode));
+                let (mantissa, _) = rounded.into_bigint_and_scale();
+
+                $layout::try_from(mantissa)
+                    .map(Self::from_bits)
+                    .map_err(|_| ConvertError::new("too big number"))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
+        impl<P: Precision> TryFrom<BigDecimal> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision.
+            /// Fails if the value doesn't fit or has more significant fractional digits than
+            /// `PRECISION`. Use [`FixedPoint::from_big_decimal`] to round them.
+            fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+                let fp = Self::from_big_decimal(&value, RoundMode::Floor)?;
+
+                if BigDecimal::from(fp) != value {
+                    return Err(ConvertError::new("requested precision is too high"));
+                }
+
+                Ok(fp)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
+        impl<P: Precision> From<FixedPoint<$layout, P>> for BigDecimal {
+            /// Converts without any loss of precision.
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                let scale = FixedPoint::<$layout, P>::PRECISION;
+                BigDecimal::new(BigInt::from(value.inner), i64::from(scale))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_bigdecimal!(i16);
+#[cfg(feature = "i32")]
+impl_bigdecimal!(i32);
+#[cfg(feature = "i64")]
+impl_bigdecimal!(i64);
+#[cfg(feature = "i128")]
+impl_bigdecimal!(i128);
+#[cfg(feature = "u64")]
+impl_bigdecimal!(u64);
+#[cfg(feature = "u128")]
+impl_bigdecimal!(u128);
@@ -0,0 +1,24 @@
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::FixedPoint;
+
+/// Serializes as the raw layout integer.
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl<I: BorshSerialize, P> BorshSerialize for FixedPoint<I, P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_bits().serialize(writer)
+    }
+}
+
+/// Deserializes from the raw layout integer.
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl<I: BorshDeserialize, P> BorshDeserialize for FixedPoint<I, P> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        I::deserialize_reader(reader).map(Self::from_bits)
+    }
+}
@@ -0,0 +1,232 @@
+//! Conversions between `FixedPoint` and BSON's [`Decimal128`].
+//!
+//! `FixedPoint` keeps its scale in `Decimal128`, e.g. `1.5` with `PRECISION = 9` is stored as
+//! `1.500000000`. Use [`decimal128`] and [`decimal128_option`] in `#[serde(with)]` to store
+//! fields as native `Decimal128` values instead of strings.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use bson::{doc, Bson, Decimal128};
+//! use fixnum::{typenum::U9, FixedPoint};
+//! use serde::{Deserialize, Serialize};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "fixnum::bson::decimal128")]
+//!     price: Amount,
+//! }
+//!
+//! let order = Order { price: "1.5".parse()? };
+//! let document = bson::to_document(&order)?;
+//! assert_eq!(document, doc! { "price": Bson::Decimal128("1.500000000".parse()?) });
+//!
+//! let order: Order = bson::from_document(document)?;
+//! assert_eq!(order.price, Amount::try_from(Decimal128::from(order.price))?);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use core::convert::TryFrom;
+
+use bson::Decimal128;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{power_table::power_of_10, Precision};
+use crate::{ConvertError, FixedPoint};
+
+// Significant digits supported by `Decimal128`.
+const MAX_DIGITS: u32 = 34;
+const EXPONENT_BIAS: i32 = 6176;
+const COEFFICIENT_BITS: u32 = 113;
+
+/// Builds a finite `Decimal128`, rounding half to even digits beyond the 34th.
+fn encode(negative: bool, mut coefficient: u128, mut exponent: i32) -> Decimal128 {
+    let max = power_of_10(MAX_DIGITS).unwrap() - 1;
+    let mut last = 0;
+    let mut sticky = false;
+
+    while coefficient > max {
+        sticky |= last != 0;
+        last = coefficient % 10;
+        coefficient /= 10;
+        exponent += 1;
+    }
+
+    if last > 5 || last == 5 && (sticky || coefficient % 2 == 1) {
+        coefficient += 1;
+
+        if coefficient > max {
+            coefficient /= 10;
+            exponent += 1;
+        }
+    }
+
+    let bits = u128::from(negative) << 127
+        | ((exponent + EXPONENT_BIAS) as u128) << COEFFICIENT_BITS
+        | coefficient;
+
+    Decimal128::from_bytes(bits.to_le_bytes())
+}
+
+/// Splits `Decimal128` into the sign, the coefficient and the exponent.
+fn decode(value: Decimal128) -> Result<(bool, u128, i32), ConvertError> {
+    let bits = u128::from_le_bytes(value.bytes());
+    let negative = bits >> 127 != 0;
+
+    match (bits >> 122) & 0b11111 {
+        0b11111 => return Err(ConvertError::new("NaN can't be represented")),
+        0b11110 => return Err(ConvertError::new("infinity can't be represented")),
+        _ => {}
+    }
+
+    // The form with implicit `100` bits can't hold canonical coefficients, they're zeros.
+    if (bits >> 125) & 0b11 == 0b11 {
+        let exponent = ((bits >> (COEFFICIENT_BITS - 2)) & 0x3fff) as i32 - EXPONENT_BIAS;
+        return Ok((negative, 0, exponent));
+    }
+
+    let exponent = ((bits >> COEFFICIENT_BITS) & 0x3fff) as i32 - EXPONENT_BIAS;
+    let coefficient = bits & ((1 << COEFFICIENT_BITS) - 1);
+
+    // Non-canonical coefficients are zeros too.
+    let coefficient = if coefficient < power_of_10(MAX_DIGITS).unwrap() {
+        coefficient
+    } else {
+        0
+    };
+
+    Ok((negative, coefficient, exponent))
+}
+
+macro_rules! impl_bson {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "bson")))]
+        impl<P: Precision> From<FixedPoint<$layout, P>> for Decimal128 {
+            /// Converts without any loss of precision if the value has at most 34 significant
+            /// digits, otherwise rounds half to even.
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                let (negative, abs) = match i128::try_from(value.inner) {
+                    Ok(inner) => (inner < 0, inner.unsigned_abs()),
+                    // Only `u128` values don't fit in `i128`.
+                    Err(_) => (false, value.inner as u128),
+                };
+
+                encode(negative, abs, -FixedPoint::<$layout, P>::PRECISION)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "bson")))]
+        impl<P: Precision> TryFrom<Decimal128> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision.
+            /// Fails if the value isn't finite, doesn't fit or has more significant fractional
+            /// digits than `PRECISION`.
+            fn try_from(value: Decimal128) -> Result<Self, Self::Error> {
+                let (negative, coefficient, exponent) = decode(value)?;
+                let scale = -exponent;
+
+                let abs = if coefficient == 0 {
+                    0
+                } else if scale > Self::PRECISION {
+                    // Trailing zeros can be dropped, other digits can't.
+                    power_of_10((scale - Self::PRECISION) as u32)
+                        .filter(|divisor| coefficient % divisor == 0)
+                        .map(|divisor| coefficient / divisor)
+                        .ok_or_else(|| ConvertError::new("requested precision is too high"))?
+                } else {
+                    power_of_10((Self::PRECISION - scale) as u32)
+                        .and_then(|multiplier| coefficient.checked_mul(multiplier))
+                        .ok_or_else(|| ConvertError::new("too big number"))?
+                };
+
+                let inner = if negative {
+                    // Negation of non-negative `i128` never overflows.
+                    i128::try_from(abs)
+                        .ok()
+                        .and_then(|abs| $layout::try_from(-abs).ok())
+                } else {
+                    $layout::try_from(abs).ok()
+                };
+
+                inner
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_bson!(i16);
+#[cfg(feature = "i32")]
+impl_bson!(i32);
+#[cfg(feature = "i64")]
+impl_bson!(i64);
+#[cfg(feature = "i128")]
+impl_bson!(i128);
+#[cfg(feature = "u64")]
+impl_bson!(u64);
+#[cfg(feature = "u128")]
+impl_bson!(u128);
+
+/// (De)serializes `FixedPoint` as `Decimal128`.
+pub mod decimal128 {
+    use super::*;
+
+    /// Serializes to `Decimal128`.
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        Decimal128: From<FixedPoint<I, P>>,
+        S: Serializer,
+    {
+        Decimal128::from(fp.clone().into()).serialize(serializer)
+    }
+
+    /// Deserializes from `Decimal128`.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        FixedPoint<I, P>: TryFrom<Decimal128, Error = ConvertError>,
+        D: Deserializer<'de>,
+    {
+        FixedPoint::try_from(Decimal128::deserialize(deserializer)?)
+            .map(F::from)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// (De)serializes `Option<FixedPoint>` as `Decimal128`.
+pub mod decimal128_option {
+    use super::*;
+
+    /// Serializes to `Decimal128`.
+    pub fn serialize<F, I, P, S>(fp: &Option<F>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        Decimal128: From<FixedPoint<I, P>>,
+        S: Serializer,
+    {
+        fp.clone()
+            .map(|fp| Decimal128::from(fp.into()))
+            .serialize(serializer)
+    }
+
+    /// Deserializes from `Decimal128`.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<Option<F>, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        FixedPoint<I, P>: TryFrom<Decimal128, Error = ConvertError>,
+        D: Deserializer<'de>,
+    {
+        Option::<Decimal128>::deserialize(deserializer)?
+            .map(|value| FixedPoint::try_from(value).map(F::from))
+            .transpose()
+            .map_err(D::Error::custom)
+    }
+}
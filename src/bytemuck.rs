@@ -0,0 +1,15 @@
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+use crate::{FixedPoint, Precision};
+
+// SAFETY: `FixedPoint` is `#[repr(transparent)]` over `I`, the marker is a ZST.
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+unsafe impl<I: Zeroable, P> Zeroable for FixedPoint<I, P> {}
+
+// SAFETY: the same as above, every bit pattern of `I` is a valid `FixedPoint`.
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+unsafe impl<I: Pod, P: Precision + Copy + 'static> Pod for FixedPoint<I, P> {}
+
+// SAFETY: the same as above.
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+unsafe impl<I, P> TransparentWrapper<I> for FixedPoint<I, P> {}
@@ -1,8 +1,8 @@
 #![allow(missing_docs)] // available only in `_priv` for macros.
 
-#[cfg(not(feature = "i128"))]
+#[cfg(not(any(feature = "i128", feature = "u64", feature = "u128", feature = "i256")))]
 pub type Int = i64;
-#[cfg(feature = "i128")]
+#[cfg(any(feature = "i128", feature = "u64", feature = "u128", feature = "i256"))]
 pub type Int = i128;
 
 macro_rules! const_assert {
@@ -64,6 +64,13 @@ const fn find(bytes: &[u8], pattern: u8) -> Option<usize> {
     None
 }
 
+const fn find_exponent(bytes: &[u8]) -> Option<usize> {
+    match find(bytes, b'e') {
+        Some(e) => Some(e),
+        None => find(bytes, b'E'),
+    }
+}
+
 const fn parse_digit(byte: u8) -> Int {
     let digit = byte.wrapping_sub(48);
     const_assert!(digit < 10);
@@ -86,6 +93,10 @@ const fn parse_int(bytes: &[u8], start: usize, end: usize) -> Int {
 // TODO: check overflow explicitly.
 pub const fn parse_fixed(str: &str, coef: Int) -> Int {
     let bytes = str.as_bytes();
+
+    if let Some(e) = find_exponent(bytes) {
+        return parse_fixed_with_exponent(bytes, e, coef);
+    }
     let signum = if bytes[0] == b'-' { -1 } else { 1 };
 
     let start = if bytes[0] == b'-' || bytes[0] == b'+' {
@@ -113,6 +124,62 @@ pub const fn parse_fixed(str: &str, coef: Int) -> Int {
     signum * (final_integral + final_fractional)
 }
 
+/// Parses `1.5e-3` like literals, the exponent is applied exactly.
+const fn parse_fixed_with_exponent(bytes: &[u8], e: usize, coef: Int) -> Int {
+    let signum = if bytes[0] == b'-' { -1 } else { 1 };
+    let mut i = if bytes[0] == b'-' || bytes[0] == b'+' {
+        1
+    } else {
+        0
+    };
+
+    let mut digits: Int = 0;
+    let mut fractional_len = 0;
+    let mut is_fractional = false;
+
+    while i < e {
+        if bytes[i] == b'.' {
+            is_fractional = true;
+        } else {
+            digits = digits * 10 + parse_digit(bytes[i]);
+            fractional_len += is_fractional as i32;
+        }
+        i += 1;
+    }
+
+    let exponent = match bytes[e + 1] {
+        b'-' => -(parse_int(bytes, e + 2, bytes.len()) as i32),
+        b'+' => parse_int(bytes, e + 2, bytes.len()) as i32,
+        _ => parse_int(bytes, e + 1, bytes.len()) as i32,
+    };
+
+    // `value = digits * 10^shift * coef`
+    let shift = exponent - fractional_len;
+
+    let value = if digits == 0 {
+        0
+    } else if shift >= 0 {
+        digits * pow10(shift) * coef
+    } else {
+        // Greater powers overflow, such numbers can't be represented anyway.
+        assert!(
+            -shift <= Int::MAX.ilog10() as i32,
+            "too long fractional part"
+        );
+        let divisor = pow10(-shift);
+
+        if divisor <= coef {
+            digits * (coef / divisor)
+        } else {
+            let divisor = divisor / coef;
+            assert!(digits % divisor == 0, "too long fractional part");
+            digits / divisor
+        }
+    };
+
+    signum * value
+}
+
 #[test]
 fn from_good_str() {
     let c = 1_000_000_000;
@@ -126,3 +193,16 @@ fn from_good_str() {
     assert_eq!(parse_fixed("0.1234", c), 123400000);
     assert_eq!(parse_fixed("-0.1234", c), -123400000);
 }
+
+#[test]
+fn from_good_str_with_exponent() {
+    let c = 1_000_000_000;
+    assert_eq!(parse_fixed("1e3", c), 1000000000000);
+    assert_eq!(parse_fixed("2E6", c), 2000000000000000);
+    assert_eq!(parse_fixed("1.5e-3", c), 1500000);
+    assert_eq!(parse_fixed("-1.5E+3", c), -1500000000000);
+    assert_eq!(parse_fixed("+1.2e-8", c), 12);
+    assert_eq!(parse_fixed("1000e-12", c), 1);
+    assert_eq!(parse_fixed("0.000001e3", c), 1000000);
+    assert_eq!(parse_fixed("0e-100", c), 0);
+}
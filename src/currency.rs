@@ -0,0 +1,355 @@
+//! ISO 4217 currencies and money formatting.
+//!
+//! [`Currency`] maps active ISO 4217 codes to the number of their minor units (e.g. `2` for
+//! cents of `USD`, `0` for `JPY`, `3` for fils of `KWD`) and symbols. [`format_currency`]
+//! rounds a value to minor units and adds the symbol, [`parse_currency`] is its inverse.
+//! Separators and the placement of the symbol are set by [`CurrencyFormat`].
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{currency::{self, CurrencyFormat}, typenum::U9, FixedPoint};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! let amount: Amount = "1234.555".parse()?;
+//!
+//! let us = CurrencyFormat::new();
+//! assert_eq!(currency::format_currency(&amount, "USD", &us)?.to_string(), "$1,234.56");
+//! assert_eq!(currency::format_currency(&amount, "JPY", &us)?.to_string(), "¥1,235");
+//!
+//! let de = CurrencyFormat::new()
+//!     .decimal_separator(',')
+//!     .grouping('.', 3, 3)
+//!     .symbol_after(true)
+//!     .symbol_spacing(true);
+//! assert_eq!(currency::format_currency(&amount, "EUR", &de)?.to_string(), "1.234,56 €");
+//! assert_eq!(currency::parse_currency::<i64, U9>("-1.234,56 €", "EUR", &de)?, "-1234.56".parse()?);
+//!
+//! // Cents can't have more digits.
+//! assert!(currency::parse_currency::<i64, U9>("$1.001", "USD", &us).is_err());
+//! assert!(currency::format_currency(&amount, "ABC", &us).is_err());
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use core::fmt::{self, Write};
+
+use crate::{
+    formatting::FormatOptions,
+    string::{Parse, Stringify, MAX_LEN},
+    ConvertError, FixedPoint, ParseOptions, Precision,
+};
+
+// Digits, padding to minor units and grouping separators, which can take up to 4 bytes each.
+const CAPACITY: usize = 6 * MAX_LEN;
+
+/// An ISO 4217 currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    minor_units: u8,
+    symbol: &'static str,
+}
+
+impl Currency {
+    /// Finds an active currency by its alphabetic code, case-insensitive.
+    ///
+    /// Funds and precious metals without minor units (e.g. `XAU`, `XDR`) aren't supported.
+    pub fn from_code(code: &str) -> Option<Self> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.code.eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    /// Returns the alphabetic code, e.g. `"USD"`.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the number of digits after the decimal separator, e.g. `2` for `USD`.
+    #[inline]
+    pub const fn minor_units(&self) -> u32 {
+        self.minor_units as u32
+    }
+
+    /// Returns the symbol, e.g. `"$"` for `USD`.
+    /// Currencies without a widely recognized symbol use the code.
+    #[inline]
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+}
+
+/// Returns the number of minor units of the currency, see [`Currency::minor_units`].
+#[inline]
+pub fn minor_units(code: &str) -> Option<u32> {
+    Currency::from_code(code).map(|currency| currency.minor_units())
+}
+
+/// Separators and the symbol placement used by [`format_currency`] and [`parse_currency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyFormat {
+    options: FormatOptions,
+    is_symbol_after: bool,
+    is_spaced: bool,
+    is_code: bool,
+}
+
+impl CurrencyFormat {
+    /// `$1,234.56`: `.` as the decimal separator, `,` between thousands,
+    /// the symbol before the number without a space.
+    pub const DEFAULT: Self = Self {
+        options: FormatOptions::new().grouping(',', 3, 3),
+        is_symbol_after: false,
+        is_spaced: false,
+        is_code: false,
+    };
+
+    /// Creates [`CurrencyFormat::DEFAULT`].
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Sets the separator of minor units.
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        self.options = self.options.decimal_separator(separator);
+        self
+    }
+
+    /// Groups digits of the integral part, see [`FormatOptions::grouping`].
+    pub const fn grouping(mut self, separator: char, first: usize, others: usize) -> Self {
+        self.options = self.options.grouping(separator, first, others);
+        self
+    }
+
+    /// Places the symbol after the number, e.g. `1.234,56 €`.
+    pub const fn symbol_after(mut self, enabled: bool) -> Self {
+        self.is_symbol_after = enabled;
+        self
+    }
+
+    /// Separates the symbol from the number by a space.
+    pub const fn symbol_spacing(mut self, enabled: bool) -> Self {
+        self.is_spaced = enabled;
+        self
+    }
+
+    /// Uses the code instead of the symbol, e.g. `USD 1,234.56`.
+    pub const fn code(mut self, enabled: bool) -> Self {
+        self.is_code = enabled;
+        self
+    }
+}
+
+impl Default for CurrencyFormat {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Displays `FixedPoint` as money, see [`format_currency`].
+///
+/// Formatting flags are ignored.
+#[derive(Clone, Copy)]
+pub struct FormattedCurrency<'a, I, P> {
+    value: &'a FixedPoint<I, P>,
+    currency: Currency,
+    format: &'a CurrencyFormat,
+}
+
+/// Returns a wrapper implementing `Display`, which rounds the value to minor units of
+/// the currency half to even (unless another default mode is [configured][crate::ops::RoundMode])
+/// and adds the symbol. The minus sign precedes the symbol, e.g. `-$1.50`.
+///
+/// Fails if the code is unknown, see [`Currency::from_code`].
+pub fn format_currency<'a, I, P>(
+    value: &'a FixedPoint<I, P>,
+    code: &str,
+    format: &'a CurrencyFormat,
+) -> Result<FormattedCurrency<'a, I, P>, ConvertError> {
+    let currency = Currency::from_code(code).ok_or(ConvertError::new("unknown currency"))?;
+
+    Ok(FormattedCurrency {
+        value,
+        currency,
+        format,
+    })
+}
+
+impl<I, P> fmt::Display for FormattedCurrency<'_, I, P>
+where
+    P: Precision,
+    FixedPoint<I, P>: Stringify,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatted = self.value.format(&self.format.options);
+
+        let mut buf = Buffer::default();
+        write!(buf, "{:.*}", self.currency.minor_units as usize, formatted)?;
+
+        let (sign, number) = match buf.as_str().strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", buf.as_str()),
+        };
+
+        let symbol = if self.format.is_code {
+            self.currency.code
+        } else {
+            self.currency.symbol
+        };
+        let space = if self.format.is_spaced { " " } else { "" };
+
+        if self.format.is_symbol_after {
+            write!(f, "{sign}{number}{space}{symbol}")
+        } else {
+            write!(f, "{sign}{symbol}{space}{number}")
+        }
+    }
+}
+
+/// Parses money formatted by [`format_currency`].
+///
+/// Both the symbol and the code are accepted before or after the number, with or without
+/// spaces, or can be omitted. The minus sign can precede or follow the symbol.
+///
+/// Fails if the code is unknown, on other characters, if there are more fractional digits
+/// than minor units or the number can't be represented without rounding.
+pub fn parse_currency<I, P>(
+    str: &str,
+    code: &str,
+    format: &CurrencyFormat,
+) -> Result<FixedPoint<I, P>, ConvertError>
+where
+    FixedPoint<I, P>: Parse,
+{
+    let currency = Currency::from_code(code).ok_or(ConvertError::new("unknown currency"))?;
+    let (mut is_negative, mut number) = match str.trim().strip_prefix('-') {
+        Some(str) => (true, str.trim_start()),
+        None => (false, str.trim()),
+    };
+
+    let stripped = [currency.symbol, currency.code].iter().find_map(|symbol| {
+        number
+            .strip_prefix(symbol)
+            .or_else(|| number.strip_suffix(symbol))
+    });
+    let has_symbol = stripped.is_some();
+    number = stripped.unwrap_or(number).trim();
+
+    if !is_negative && has_symbol {
+        if let Some(rest) = number.strip_prefix('-') {
+            is_negative = true;
+            number = rest;
+        }
+    }
+
+    let options = &format.options;
+    let is_valid_char = |c: char| {
+        c.is_ascii_digit() || c == options.decimal_separator || c == options.grouping_separator
+    };
+
+    if number.is_empty() || !number.chars().all(is_valid_char) {
+        return Err(ConvertError::new("unexpected character"));
+    }
+
+    let minor_units = number
+        .split_once(options.decimal_separator)
+        .map_or(0, |(_, fractional)| fractional.len());
+    if minor_units > currency.minor_units() as usize {
+        return Err(ConvertError::new("too many minor units"));
+    }
+
+    let mut buf = Buffer::default();
+    let sign = if is_negative { "-" } else { "" };
+    write!(buf, "{sign}{number}").map_err(|_| ConvertError::new("too long number"))?;
+
+    let options = ParseOptions::new()
+        .decimal_separator(options.decimal_separator)
+        .grouping_separator(options.grouping_separator)
+        .exact();
+    FixedPoint::from_str_with(buf.as_str(), options)
+}
+
+/// A stack buffer for intermediate strings.
+struct Buffer {
+    buffer: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self {
+            buffer: [0; CAPACITY],
+            len: 0,
+        }
+    }
+}
+
+impl Buffer {
+    fn as_str(&self) -> &str {
+        // Only whole strings are written.
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buffer
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+macro_rules! currencies {
+    ($($code:ident $minor_units:literal $($symbol:literal)?,)*) => {
+        const CURRENCIES: &[Currency] = &[$(
+            Currency {
+                code: stringify!($code),
+                minor_units: $minor_units,
+                symbol: currencies!(@symbol $code $($symbol)?),
+            },
+        )*];
+    };
+    (@symbol $code:ident) => { stringify!($code) };
+    (@symbol $code:ident $symbol:literal) => { $symbol };
+}
+
+currencies! {
+    AED 2, AFN 2, ALL 2, AMD 2, AOA 2, ARS 2, AUD 2 "A$", AWG 2, AZN 2,
+    BAM 2, BBD 2, BDT 2, BGN 2, BHD 3, BIF 0, BMD 2, BND 2, BOB 2, BOV 2, BRL 2 "R$", BSD 2,
+    BTN 2, BWP 2, BYN 2, BZD 2,
+    CAD 2 "CA$", CDF 2, CHE 2, CHF 2, CHW 2, CLF 4, CLP 0, CNY 2 "CN¥", COP 2, COU 2, CRC 2,
+    CUP 2, CVE 2, CZK 2,
+    DJF 0, DKK 2, DOP 2, DZD 2,
+    EGP 2, ERN 2, ETB 2, EUR 2 "€",
+    FJD 2, FKP 2,
+    GBP 2 "£", GEL 2, GHS 2, GIP 2, GMD 2, GNF 0, GTQ 2, GYD 2,
+    HKD 2 "HK$", HNL 2, HTG 2, HUF 2,
+    IDR 2, ILS 2 "₪", INR 2 "₹", IQD 3, IRR 2, ISK 0,
+    JMD 2, JOD 3, JPY 0 "¥",
+    KES 2, KGS 2, KHR 2, KMF 0, KPW 2, KRW 0 "₩", KWD 3, KYD 2, KZT 2,
+    LAK 2, LBP 2, LKR 2, LRD 2, LSL 2, LYD 3,
+    MAD 2, MDL 2, MGA 2, MKD 2, MMK 2, MNT 2, MOP 2, MRU 2, MUR 2, MVR 2, MWK 2, MXN 2 "MX$",
+    MXV 2, MYR 2, MZN 2,
+    NAD 2, NGN 2, NIO 2, NOK 2, NPR 2, NZD 2 "NZ$",
+    OMR 3,
+    PAB 2, PEN 2, PGK 2, PHP 2 "₱", PKR 2, PLN 2, PYG 0,
+    QAR 2,
+    RON 2, RSD 2, RUB 2, RWF 0,
+    SAR 2, SBD 2, SCR 2, SDG 2, SEK 2, SGD 2, SHP 2, SLE 2, SOS 2, SRD 2, SSP 2, STN 2, SVC 2,
+    SYP 2, SZL 2,
+    THB 2, TJS 2, TMT 2, TND 3, TOP 2, TRY 2, TTD 2, TWD 2 "NT$", TZS 2,
+    UAH 2, UGX 0, USD 2 "$", USN 2, UYI 0, UYU 2, UYW 4, UZS 2,
+    VED 2, VES 2, VND 0 "₫", VUV 0,
+    WST 2,
+    XAF 0, XCD 2, XCG 2, XOF 0, XPF 0,
+    YER 2,
+    ZAR 2, ZMW 2, ZWG 2,
+}
@@ -0,0 +1,328 @@
+use core::convert::TryFrom;
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::power_table::power_of_10;
+use crate::{ConvertError, FixedPoint, Precision};
+
+const SIGN_MASK: u128 = 1 << 127;
+// `10^34`, coefficients must be less than it.
+const MAX_COEFFICIENT: u128 = 10_000_000_000_000_000_000_000_000_000_000_000;
+const EXPONENT_BIAS: i32 = 6176;
+const MAX_EXPONENT: i32 = 3 * (1 << 12) - 1 - EXPONENT_BIAS;
+const MIN_EXPONENT: i32 = -EXPONENT_BIAS;
+const DECLETS_COUNT: u32 = 11;
+
+// Top bits of the combination field.
+const INFINITY: u128 = 0b11110;
+const NAN: u128 = 0b11111;
+
+/// An IEEE 754-2008 `decimal128` number.
+///
+/// Created from and converted to bit patterns of both binary (BID) and densely packed
+/// decimal (DPD) encodings, `TryFrom` converts from and to `FixedPoint` without any loss
+/// of precision.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{typenum::U9, Decimal128, FixedPoint};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let amount: Amount = "12.34".parse()?;
+/// let decimal = Decimal128::try_from(amount)?;
+/// // `12340000000 * 10^-9`
+/// assert_eq!(decimal.to_bid(), 0x302e_0000_0000_0000_0000_0002_df85_7500);
+/// assert_eq!(Amount::try_from(Decimal128::from_dpd(decimal.to_dpd()))?, amount);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Decimal128 {
+    // Canonical BID encoding.
+    bits: u128,
+}
+
+enum Parts {
+    Finite {
+        is_negative: bool,
+        coefficient: u128,
+        exponent: i32,
+    },
+    Infinity,
+    NaN,
+}
+
+impl Decimal128 {
+    /// Creates from the binary integer decimal (BID) encoding.
+    /// Non-canonical coefficients are treated as zero.
+    pub fn from_bid(bits: u128) -> Self {
+        let is_negative = bits & SIGN_MASK != 0;
+        let combination = (bits >> 122) & 0b11111;
+
+        if combination == NAN {
+            return Self::special(is_negative, NAN);
+        }
+
+        if combination == INFINITY {
+            return Self::special(is_negative, INFINITY);
+        }
+
+        let (exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+            // The implicit `100` prefix makes the coefficient greater than `10^34`.
+            ((bits >> 111) & 0x3fff, 0)
+        } else {
+            ((bits >> 113) & 0x3fff, bits & ((1 << 113) - 1))
+        };
+
+        let coefficient = if coefficient < MAX_COEFFICIENT {
+            coefficient
+        } else {
+            0
+        };
+
+        // The exponent can exceed the maximum only in non-canonical encodings.
+        let exponent = (exponent as i32 - EXPONENT_BIAS).min(MAX_EXPONENT);
+        Self::finite(is_negative, coefficient, exponent)
+    }
+
+    /// Returns the binary integer decimal (BID) encoding.
+    pub fn to_bid(self) -> u128 {
+        self.bits
+    }
+
+    /// Creates from the densely packed decimal (DPD) encoding.
+    pub fn from_dpd(bits: u128) -> Self {
+        let is_negative = bits & SIGN_MASK != 0;
+        let combination = (bits >> 122) & 0b11111;
+
+        if combination == NAN || combination == INFINITY {
+            return Self::special(is_negative, combination);
+        }
+
+        let (exponent_msb, leading_digit) = if combination >> 3 == 0b11 {
+            ((combination >> 1) & 0b11, 8 + (combination & 1))
+        } else {
+            (combination >> 3, combination & 0b111)
+        };
+
+        let exponent = (exponent_msb << 12) | ((bits >> 110) & 0xfff);
+
+        let coefficient = (0..DECLETS_COUNT).rev().fold(leading_digit, |acc, i| {
+            let declet = ((bits >> (10 * i)) & 0x3ff) as u16;
+            acc * 1000 + u128::from(dpd_to_bin(declet))
+        });
+
+        Self::finite(is_negative, coefficient, exponent as i32 - EXPONENT_BIAS)
+    }
+
+    /// Returns the densely packed decimal (DPD) encoding.
+    pub fn to_dpd(self) -> u128 {
+        let (is_negative, coefficient, exponent) = match self.parts() {
+            Parts::Finite {
+                is_negative,
+                coefficient,
+                exponent,
+            } => (is_negative, coefficient, exponent),
+            Parts::Infinity | Parts::NaN => return self.bits,
+        };
+
+        let mut bits = if is_negative { SIGN_MASK } else { 0 };
+        let mut coefficient = coefficient;
+
+        for i in 0..DECLETS_COUNT {
+            let digits = (coefficient % 1000) as u16;
+            bits |= u128::from(bin_to_dpd(digits)) << (10 * i);
+            coefficient /= 1000;
+        }
+
+        let leading_digit = coefficient;
+        let exponent = (exponent + EXPONENT_BIAS) as u128;
+        let exponent_msb = exponent >> 12;
+
+        let combination = if leading_digit < 8 {
+            (exponent_msb << 3) | leading_digit
+        } else {
+            0b11000 | (exponent_msb << 1) | (leading_digit & 1)
+        };
+
+        bits | (combination << 122) | ((exponent & 0xfff) << 110)
+    }
+
+    /// Returns `false` for infinities and `NaN`s.
+    pub fn is_finite(self) -> bool {
+        matches!(self.parts(), Parts::Finite { .. })
+    }
+
+    fn special(is_negative: bool, combination: u128) -> Self {
+        let sign = if is_negative { SIGN_MASK } else { 0 };
+        Self {
+            bits: sign | (combination << 122),
+        }
+    }
+
+    fn finite(is_negative: bool, coefficient: u128, exponent: i32) -> Self {
+        debug_assert!(coefficient < MAX_COEFFICIENT);
+        debug_assert!((MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent));
+
+        let sign = if is_negative { SIGN_MASK } else { 0 };
+        let exponent = (exponent + EXPONENT_BIAS) as u128;
+
+        Self {
+            bits: sign | (exponent << 113) | coefficient,
+        }
+    }
+
+    fn parts(self) -> Parts {
+        match (self.bits >> 122) & 0b11111 {
+            NAN => Parts::NaN,
+            INFINITY => Parts::Infinity,
+            _ => Parts::Finite {
+                is_negative: self.bits & SIGN_MASK != 0,
+                coefficient: self.bits & ((1 << 113) - 1),
+                exponent: ((self.bits >> 113) & 0x3fff) as i32 - EXPONENT_BIAS,
+            },
+        }
+    }
+}
+
+/// Decodes three digits from a declet.
+fn dpd_to_bin(declet: u16) -> u16 {
+    let bit = |i: u16| (declet >> i) & 1;
+    let (p, q, r) = (bit(9), bit(8), bit(7));
+    let (s, t, u) = (bit(6), bit(5), bit(4));
+    let (v, w, x, y) = (bit(3), bit(2), bit(1), bit(0));
+
+    let digit = |b2: u16, b1: u16, b0: u16| (b2 << 2) | (b1 << 1) | b0;
+    let big = |b0: u16| 8 | b0;
+
+    let (d2, d1, d0) = match (v, w, x, s, t) {
+        (0, ..) => (digit(p, q, r), digit(s, t, u), digit(w, x, y)),
+        (1, 0, 0, ..) => (digit(p, q, r), digit(s, t, u), big(y)),
+        (1, 0, 1, ..) => (digit(p, q, r), big(u), digit(s, t, y)),
+        (1, 1, 0, ..) => (big(r), digit(s, t, u), digit(p, q, y)),
+        (1, 1, 1, 0, 0) => (big(r), big(u), digit(p, q, y)),
+        (1, 1, 1, 0, 1) => (big(r), digit(p, q, u), big(y)),
+        (1, 1, 1, 1, 0) => (digit(p, q, r), big(u), big(y)),
+        _ => (big(r), big(u), big(y)),
+    };
+
+    d2 * 100 + d1 * 10 + d0
+}
+
+/// Encodes three digits (`0..=999`) to a declet.
+fn bin_to_dpd(digits: u16) -> u16 {
+    let (d2, d1, d0) = (digits / 100, digits / 10 % 10, digits % 10);
+    let bit = |d: u16, i: u16| (d >> i) & 1;
+    let (a, b, c, d) = (bit(d2, 3), bit(d2, 2), bit(d2, 1), bit(d2, 0));
+    let (e, f, g, h) = (bit(d1, 3), bit(d1, 2), bit(d1, 1), bit(d1, 0));
+    let (i, j, k, m) = (bit(d0, 3), bit(d0, 2), bit(d0, 1), bit(d0, 0));
+
+    let declet = |bits: [u16; 10]| bits.iter().fold(0, |acc, bit| (acc << 1) | bit);
+
+    match (a, e, i) {
+        (0, 0, 0) => declet([b, c, d, f, g, h, 0, j, k, m]),
+        (0, 0, 1) => declet([b, c, d, f, g, h, 1, 0, 0, m]),
+        (0, 1, 0) => declet([b, c, d, j, k, h, 1, 0, 1, m]),
+        (0, 1, 1) => declet([b, c, d, 1, 0, h, 1, 1, 1, m]),
+        (1, 0, 0) => declet([j, k, d, f, g, h, 1, 1, 0, m]),
+        (1, 0, 1) => declet([f, g, d, 0, 1, h, 1, 1, 1, m]),
+        (1, 1, 0) => declet([j, k, d, 0, 0, h, 1, 1, 1, m]),
+        _ => declet([0, 0, d, 1, 1, h, 1, 1, 1, m]),
+    }
+}
+
+macro_rules! impl_decimal128 {
+    ($layout:tt) => {
+        impl<P: Precision> TryFrom<Decimal128> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision.
+            /// Fails if the value isn't finite, doesn't fit or has more significant fractional
+            /// digits than `PRECISION`.
+            fn try_from(value: Decimal128) -> Result<Self, Self::Error> {
+                let (is_negative, coefficient, exponent) = match value.parts() {
+                    Parts::Finite {
+                        is_negative,
+                        coefficient,
+                        exponent,
+                    } => (is_negative, coefficient, exponent),
+                    Parts::Infinity | Parts::NaN => return Err(ConvertError::new("not finite")),
+                };
+
+                if coefficient == 0 {
+                    return Ok(Self::from_bits(0));
+                }
+
+                let shift = exponent + Self::PRECISION;
+
+                let abs = if shift < 0 {
+                    // Trailing zeros can be dropped, other digits can't.
+                    match power_of_10(shift.unsigned_abs()) {
+                        Some(divisor) if coefficient % divisor == 0 => coefficient / divisor,
+                        _ => return Err(ConvertError::new("requested precision is too high")),
+                    }
+                } else {
+                    power_of_10(shift as u32)
+                        .and_then(|multiplier| coefficient.checked_mul(multiplier))
+                        .ok_or_else(|| ConvertError::new("too big number"))?
+                };
+
+                let inner = if is_negative {
+                    // Negation of non-negative `i128` never overflows.
+                    i128::try_from(abs)
+                        .ok()
+                        .and_then(|abs| $layout::try_from(-abs).ok())
+                } else {
+                    $layout::try_from(abs).ok()
+                };
+
+                inner
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+        }
+
+        impl<P: Precision> TryFrom<FixedPoint<$layout, P>> for Decimal128 {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision.
+            /// Fails if the value has more than 34 significant digits.
+            fn try_from(value: FixedPoint<$layout, P>) -> Result<Self, Self::Error> {
+                let mut coefficient = u128::from(value.inner.unsigned_abs());
+                let mut exponent = -FixedPoint::<$layout, P>::PRECISION;
+
+                // Only trailing zeros can be dropped.
+                while coefficient >= MAX_COEFFICIENT && coefficient % 10 == 0 {
+                    coefficient /= 10;
+                    exponent += 1;
+                }
+
+                if coefficient >= MAX_COEFFICIENT {
+                    return Err(ConvertError::new("too big number"));
+                }
+
+                Ok(Self::finite(
+                    value.inner.is_negative(),
+                    coefficient,
+                    exponent,
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_decimal128!(i16);
+#[cfg(feature = "i32")]
+impl_decimal128!(i32);
+#[cfg(feature = "i64")]
+impl_decimal128!(i64);
+#[cfg(feature = "i128")]
+impl_decimal128!(i128);
+#[cfg(feature = "u64")]
+impl_decimal128!(u64);
+#[cfg(feature = "u128")]
+impl_decimal128!(u128);
@@ -0,0 +1,29 @@
+use defmt::{Format, Formatter};
+
+use crate::{
+    string::{StrBuf, Stringify},
+    ArithmeticError, ConvertError, FixedPoint,
+};
+
+impl<I, P> Format for FixedPoint<I, P>
+where
+    Self: Stringify,
+{
+    fn format(&self, f: Formatter<'_>) {
+        let mut buf = StrBuf::default();
+        self.stringify(&mut buf);
+        defmt::write!(f, "{=str}", buf.as_str());
+    }
+}
+
+impl Format for ArithmeticError {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str());
+    }
+}
+
+impl Format for ConvertError {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str());
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::Write;
+use std::str;
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    mysql::{Mysql, MysqlValue},
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Numeric,
+};
+
+use crate::numeric::{self, Numeric as PgNumeric};
+use crate::{ConvertError, FixedPoint, Precision};
+
+// Derives `AsExpression` and `FromSqlRow` for the wrapped `FixedPoint`. Deriving them on `FixedPoint`
+// itself doesn't work: `use diesel` in the generated code would refer to this module.
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive, sql_type = Numeric)]
+#[allow(dead_code)]
+struct FixedPointProxy<I, P>(FixedPoint<I, P>);
+
+macro_rules! impl_diesel {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        impl<P: Precision> ToSql<Numeric, Pg> for FixedPoint<$layout, P> {
+            /// Encodes with the display scale equal to `PRECISION`.
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                let mut buf = Vec::new();
+                self.to_numeric().encode(&mut buf);
+                out.write_all(&buf)?;
+                Ok(IsNull::No)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        impl<P: Precision> FromSql<Numeric, Pg> for FixedPoint<$layout, P> {
+            /// Decodes without any loss of precision.
+            /// Fails if the value doesn't fit or has more significant fractional digits than
+            /// `PRECISION`, on `NaN` and infinities.
+            fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+                Ok(Self::from_numeric(&PgNumeric::decode(value.as_bytes())?)?)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        impl<P: Precision> ToSql<Numeric, Mysql> for FixedPoint<$layout, P> {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+                write!(out, "{}", self)?;
+                Ok(IsNull::No)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+        impl<P: Precision> FromSql<Numeric, Mysql> for FixedPoint<$layout, P> {
+            /// Decodes without any loss of precision.
+            /// Fails if the value doesn't fit or has more significant fractional digits than
+            /// `PRECISION`.
+            fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+                // `DECIMAL` is sent as a string in both text and binary protocols.
+                let str = str::from_utf8(value.as_bytes())
+                    .map_err(|_| ConvertError::new("invalid DECIMAL"))?;
+                Ok(Self::from_str_exact(numeric::trim_scale(str))?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_diesel!(i16);
+#[cfg(feature = "i32")]
+impl_diesel!(i32);
+#[cfg(feature = "i64")]
+impl_diesel!(i64);
+#[cfg(feature = "i128")]
+impl_diesel!(i128);
+#[cfg(feature = "u64")]
+impl_diesel!(u64);
+#[cfg(feature = "u128")]
+impl_diesel!(u128);
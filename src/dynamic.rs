@@ -0,0 +1,324 @@
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::i256::I256;
+use crate::ops::*;
+use crate::power_table::power_of_10;
+use crate::{ArithmeticError, ConvertError, FixedPoint, Precision};
+
+/// A decimal number with the scale (the number of fractional digits) known only at runtime,
+/// e.g. from a DB schema or an instrument configuration.
+///
+/// The value is `repr * 10^-scale`, where the scale doesn't exceed [`MAX_SCALE`].
+/// Results of binary operations have the largest scale of operands.
+/// Comparisons are numeric, so `1.5 == 1.50`.
+///
+/// Converts from [`FixedPoint`] losslessly and to [`FixedPoint`] exactly, use
+/// [`rescale`] before to round the value.
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ops::*, typenum::U9, DynFixedPoint, FixedPoint};
+///
+/// let price = DynFixedPoint::new(12345, 2)?; // 123.45
+/// let qty: DynFixedPoint = "0.125".parse()?;
+///
+/// let total = price.rmul(qty, RoundMode::Nearest)?;
+/// assert_eq!(total.to_string(), "15.431");
+/// assert_eq!(total.rescale(2, RoundMode::Floor)?.to_string(), "15.43");
+///
+/// let total = FixedPoint::<i128, U9>::try_from(total)?;
+/// assert_eq!(DynFixedPoint::from(total).scale(), 9);
+/// # Ok(()) }
+/// ```
+///
+/// [`MAX_SCALE`]: Self::MAX_SCALE
+/// [`rescale`]: Self::rescale
+#[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+#[derive(Clone, Copy, Debug)]
+pub struct DynFixedPoint {
+    repr: i128,
+    scale: u8,
+}
+
+impl DynFixedPoint {
+    /// The largest supported scale, `10^38` is the largest power of ten fitting in `i128`.
+    pub const MAX_SCALE: u8 = 38;
+
+    /// Creates `repr * 10^-scale`. Fails if `scale` exceeds [`MAX_SCALE`](Self::MAX_SCALE).
+    pub const fn new(repr: i128, scale: u8) -> Result<Self, ConvertError> {
+        if scale > Self::MAX_SCALE {
+            return Err(ConvertError::new("too big scale"));
+        }
+        Ok(Self { repr, scale })
+    }
+
+    /// Returns the raw representation.
+    #[inline]
+    pub const fn repr(self) -> i128 {
+        self.repr
+    }
+
+    /// Returns the number of digits in the fractional part.
+    #[inline]
+    pub const fn scale(self) -> u8 {
+        self.scale
+    }
+
+    /// Converts to another scale, [rounding][RoundMode] according to `mode` if it's reduced.
+    pub fn rescale(self, scale: u8, mode: RoundMode) -> Result<Self, ArithmeticError> {
+        if scale > Self::MAX_SCALE {
+            return Err(ArithmeticError::Overflow);
+        }
+
+        let repr = match scale.cmp(&self.scale) {
+            Ordering::Equal => self.repr,
+            Ordering::Greater => self
+                .repr
+                .checked_mul(coef(scale - self.scale))
+                .ok_or(ArithmeticError::Overflow)?,
+            Ordering::Less => self.repr.rdiv(coef(self.scale - scale), mode)?,
+        };
+
+        Ok(Self { repr, scale })
+    }
+
+    /// Checked negation. Returns `Err` on overflow (you can't negate [`i128::MIN`]).
+    #[inline]
+    pub fn cneg(self) -> Result<Self, ArithmeticError> {
+        let repr = self.repr.checked_neg().ok_or(ArithmeticError::Overflow)?;
+        Ok(Self { repr, ..self })
+    }
+
+    /// Returns `repr` promoted to `scale`, which must not be less than the current one.
+    fn promote(self, scale: u8) -> I256 {
+        debug_assert!(scale >= self.scale);
+        I256::from(self.repr) * I256::from(coef(scale - self.scale))
+    }
+
+    fn from_promoted(repr: I256, scale: u8) -> Result<Self, ArithmeticError> {
+        let repr = i128::try_from(repr).map_err(|_| ArithmeticError::Overflow)?;
+        Ok(Self { repr, scale })
+    }
+}
+
+/// `10^scale`, `scale <= 38`.
+fn coef(scale: u8) -> i128 {
+    debug_assert!(scale <= DynFixedPoint::MAX_SCALE);
+    power_of_10(u32::from(scale)).unwrap() as i128
+}
+
+impl PartialEq for DynFixedPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DynFixedPoint {}
+
+impl PartialOrd for DynFixedPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DynFixedPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.scale == other.scale {
+            return self.repr.cmp(&other.repr);
+        }
+
+        // `|repr| * 10^38 < 2^255`, so it can't overflow.
+        let scale = self.scale.max(other.scale);
+        self.promote(scale).cmp(&other.promote(scale))
+    }
+}
+
+impl CheckedAdd for DynFixedPoint {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cadd(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        let scale = self.scale.max(rhs.scale);
+        Self::from_promoted(self.promote(scale) + rhs.promote(scale), scale)
+    }
+}
+
+impl CheckedSub for DynFixedPoint {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn csub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        let scale = self.scale.max(rhs.scale);
+        Self::from_promoted(self.promote(scale) - rhs.promote(scale), scale)
+    }
+}
+
+impl RoundingMul for DynFixedPoint {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+        let scale = self.scale.max(rhs.scale);
+        // The product has the scale `self.scale + rhs.scale`.
+        let product = I256::from(self.repr) * I256::from(rhs.repr);
+        let divisor = I256::from(coef(self.scale + rhs.scale - scale));
+        Self::from_promoted(product.rdiv(divisor, mode)?, scale)
+    }
+}
+
+impl RoundingDiv for DynFixedPoint {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+        if rhs.repr == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let scale = self.scale.max(rhs.scale);
+        // If the numerator overflows, the quotient doesn't fit in `i128` either.
+        let numerator = self
+            .promote(scale)
+            .checked_mul(I256::from(coef(rhs.scale)))
+            .ok_or(ArithmeticError::Overflow)?;
+        Self::from_promoted(numerator.rdiv(I256::from(rhs.repr), mode)?, scale)
+    }
+}
+
+impl fmt::Display for DynFixedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coef = coef(self.scale).unsigned_abs();
+        let abs = self.repr.unsigned_abs();
+        let sign = if self.repr < 0 { "-" } else { "" };
+
+        write!(f, "{}{}", sign, abs / coef)?;
+        if self.scale > 0 {
+            write!(
+                f,
+                ".{:0width$}",
+                abs % coef,
+                width = usize::from(self.scale)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DynFixedPoint {
+    type Err = ConvertError;
+
+    /// Parses a decimal number, the scale is the number of fractional digits, e.g. `1.50` has `2`.
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (is_negative, digits) = match str.as_bytes() {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            rest => (false, rest),
+        };
+
+        let mut abs: u128 = 0;
+        let mut scale = None;
+        let mut has_digits = false;
+
+        for &byte in digits {
+            match byte {
+                b'.' if scale.is_none() => scale = Some(0u8),
+                b'0'..=b'9' => {
+                    has_digits = true;
+                    abs = abs
+                        .checked_mul(10)
+                        .and_then(|abs| abs.checked_add(u128::from(byte - b'0')))
+                        .ok_or(ConvertError::new("too big number"))?;
+
+                    if let Some(scale) = &mut scale {
+                        *scale += 1;
+                        if *scale > Self::MAX_SCALE {
+                            return Err(ConvertError::new("too big scale"));
+                        }
+                    }
+                }
+                _ => return Err(ConvertError::new("can't parse number")),
+            }
+        }
+
+        if !has_digits {
+            return Err(ConvertError::new("can't parse number"));
+        }
+
+        let repr = if is_negative {
+            0i128.checked_sub_unsigned(abs)
+        } else {
+            i128::try_from(abs).ok()
+        };
+
+        Self::new(
+            repr.ok_or(ConvertError::new("too big number"))?,
+            scale.unwrap_or(0),
+        )
+    }
+}
+
+macro_rules! impl_dyn_fixed_point {
+    ($layout:ty, From) => {
+        impl<P: Precision> From<FixedPoint<$layout, P>> for DynFixedPoint {
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                // `10^P` fits in the layout, thus `P` doesn't exceed `MAX_SCALE`.
+                debug_assert!(P::I32 <= i32::from(Self::MAX_SCALE));
+                Self {
+                    repr: i128::from(value.into_bits()),
+                    scale: P::U8,
+                }
+            }
+        }
+
+        impl_dyn_fixed_point!(@into $layout);
+    };
+    ($layout:ty, TryFrom) => {
+        impl<P: Precision> TryFrom<FixedPoint<$layout, P>> for DynFixedPoint {
+            type Error = ConvertError;
+
+            fn try_from(value: FixedPoint<$layout, P>) -> Result<Self, Self::Error> {
+                let repr = i128::try_from(value.into_bits())
+                    .map_err(|_| ConvertError::new("too big number"))?;
+                Self::new(repr, P::U8)
+            }
+        }
+
+        impl_dyn_fixed_point!(@into $layout);
+    };
+    (@into $layout:ty) => {
+        impl<P: Precision> TryFrom<DynFixedPoint> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts exactly, fails if the value has more fractional digits than `P`.
+            fn try_from(value: DynFixedPoint) -> Result<Self, Self::Error> {
+                let scaled = value
+                    .rescale(P::U8, RoundMode::Floor)
+                    .map_err(|_| ConvertError::new("too big number"))?;
+
+                if scaled != value {
+                    return Err(ConvertError::new("requires rounding"));
+                }
+
+                <$layout>::try_from(scaled.repr)
+                    .map(Self::from_bits)
+                    .map_err(|_| ConvertError::new("too big number"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_dyn_fixed_point!(i16, From);
+#[cfg(feature = "i32")]
+impl_dyn_fixed_point!(i32, From);
+#[cfg(feature = "i64")]
+impl_dyn_fixed_point!(i64, From);
+impl_dyn_fixed_point!(i128, From);
+#[cfg(feature = "u64")]
+impl_dyn_fixed_point!(u64, From);
+#[cfg(feature = "u128")]
+impl_dyn_fixed_point!(u128, TryFrom);
@@ -1,10 +1,6 @@
 use core::fmt::{Display, Formatter, Result};
 
-#[cfg(feature = "std")]
-use derive_more::Error;
-
 /// Represents errors during arithmetic operations.
-#[cfg_attr(feature = "std", derive(Error))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ArithmeticError {
@@ -34,26 +30,270 @@ impl Display for ArithmeticError {
     }
 }
 
+impl core::error::Error for ArithmeticError {}
+
 /// Represents errors during conversions.
-#[cfg_attr(feature = "std", derive(Error))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConvertError {
     reason: &'static str,
+    location: Option<(ParseErrorKind, usize)>,
 }
 
 impl ConvertError {
-    pub(crate) fn new(reason: &'static str) -> Self {
-        Self { reason }
+    pub(crate) const fn new(reason: &'static str) -> Self {
+        Self {
+            reason,
+            location: None,
+        }
+    }
+
+    pub(crate) const fn at(self, kind: ParseErrorKind, position: usize) -> Self {
+        Self {
+            reason: self.reason,
+            location: Some((kind, position)),
+        }
+    }
+
+    pub(crate) const fn location(&self) -> Option<(ParseErrorKind, usize)> {
+        self.location
     }
 
     /// Stringify an error.
     pub const fn as_str(&self) -> &'static str {
         self.reason
     }
+
+    /// Returns the category of a failure of `FromStr`, `from_str_exact` or `from_str_with`.
+    ///
+    /// `None` for other conversions.
+    pub const fn kind(&self) -> Option<ParseErrorKind> {
+        match self.location {
+            Some((kind, _)) => Some(kind),
+            None => None,
+        }
+    }
+
+    /// Returns the byte offset in the parsed string where `FromStr`, `from_str_exact`
+    /// or `from_str_with` failed.
+    ///
+    /// Points at the first offending character. For [`ParseErrorKind::Overflow`] it's the start
+    /// of the number, for [`ParseErrorKind::Empty`] and missing characters it's the end
+    /// of the checked part. `None` for other conversions.
+    pub const fn position(&self) -> Option<usize> {
+        match self.location {
+            Some((_, position)) => Some(position),
+            None => None,
+        }
+    }
 }
 
 impl Display for ConvertError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.as_str())?;
+
+        if let Some(position) = self.position() {
+            write!(f, " at byte {}", position)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::error::Error for ConvertError {}
+
+/// The category of a string parsing failure, see [`ConvertError::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The string contains no digits.
+    Empty,
+    /// A character that isn't allowed at its position, e.g. a letter or a second sign.
+    InvalidCharacter,
+    /// More fractional digits than the precision allows without rounding.
+    TooManyFractionalDigits,
+    /// The number is out of the range of the type.
+    Overflow,
+}
+
+impl ParseErrorKind {
+    /// Stringify a kind.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Empty => "empty number",
+            Self::InvalidCharacter => "invalid character",
+            Self::TooManyFractionalDigits => "too many fractional digits",
+            Self::Overflow => "overflow",
+        }
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Represents errors during exact and lossy conversions from and to floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FloatConvertError {
+    /// The float is NaN.
+    NaN,
+    /// The float is positive or negative infinity.
+    Infinite,
+    /// The value doesn't fit in the target type.
+    OutOfRange,
+    /// The value cannot be represented exactly in the target type.
+    PrecisionLoss,
+}
+
+impl FloatConvertError {
+    /// Stringify an error.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::NaN => "NaN",
+            Self::Infinite => "infinite number",
+            Self::OutOfRange => "out of range",
+            Self::PrecisionLoss => "precision loss",
+        }
+    }
+}
+
+impl Display for FloatConvertError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.write_str(self.as_str())
     }
 }
+
+impl core::error::Error for FloatConvertError {}
+
+impl From<FloatConvertError> for ConvertError {
+    fn from(error: FloatConvertError) -> Self {
+        Self::new(error.as_str())
+    }
+}
+
+/// [`ArithmeticError`] with the failed operation and string renderings of its operands.
+///
+/// Created by [`WithContext::context`], e.g.:
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() {
+/// use fixnum::{ops::*, typenum::U9, ArithmeticError, FixedPoint, WithContext};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let (a, b) = (Price::MAX, Price::ONE);
+/// let error = a.cadd(b).context("cadd", &[&a, &b]).unwrap_err();
+///
+/// assert_eq!(error.kind(), ArithmeticError::Overflow);
+/// assert_eq!(error.operation(), Some("cadd"));
+/// assert_eq!(error.to_string(), "overflow in cadd(9223372036.854775807, 1.0)");
+/// # }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DetailedError {
+    kind: ArithmeticError,
+    operation: Option<&'static str>,
+    operands: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+impl DetailedError {
+    /// Attaches the operation and its operands to `kind`.
+    pub fn new(kind: ArithmeticError, operation: &'static str, operands: &[&dyn Display]) -> Self {
+        Self {
+            kind,
+            operation: Some(operation),
+            operands: operands.iter().map(|operand| operand.to_string()).collect(),
+        }
+    }
+
+    /// The underlying error.
+    pub fn kind(&self) -> ArithmeticError {
+        self.kind.clone()
+    }
+
+    /// The failed operation, `None` if converted from [`ArithmeticError`] without context.
+    pub fn operation(&self) -> Option<&'static str> {
+        self.operation
+    }
+
+    /// Operands of the failed operation rendered by [`Display`].
+    pub fn operands(&self) -> &[String] {
+        &self.operands
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ArithmeticError> for DetailedError {
+    fn from(kind: ArithmeticError) -> Self {
+        Self {
+            kind,
+            operation: None,
+            operands: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DetailedError> for ArithmeticError {
+    fn from(error: DetailedError) -> Self {
+        error.kind
+    }
+}
+
+#[cfg(feature = "std")]
+impl Display for DetailedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.kind.as_str())?;
+
+        if let Some(operation) = self.operation {
+            write!(f, " in {}(", operation)?;
+            for (i, operand) in self.operands.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                f.write_str(operand)?;
+            }
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for DetailedError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// Attaches the operation context to [`ArithmeticError`], see [`DetailedError`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait WithContext<T> {
+    /// Converts the error into [`DetailedError`], operands are rendered only on failure.
+    fn context(
+        self,
+        operation: &'static str,
+        operands: &[&dyn Display],
+    ) -> core::result::Result<T, DetailedError>;
+}
+
+#[cfg(feature = "std")]
+impl<T> WithContext<T> for core::result::Result<T, ArithmeticError> {
+    #[inline]
+    fn context(
+        self,
+        operation: &'static str,
+        operands: &[&dyn Display],
+    ) -> core::result::Result<T, DetailedError> {
+        self.map_err(|kind| DetailedError::new(kind, operation, operands))
+    }
+}
@@ -0,0 +1,148 @@
+//! Compound interest and discounting.
+//!
+//! Every intermediate result is [rounded][RoundMode] with the provided mode in the documented
+//! order, so results are reproducible and can be reconciled with other systems. Functions return
+//! `Err` on overflow and [`ArithmeticError::DomainViolation`] if `rate` is less than `-1`.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{finance, ops::RoundMode::*, typenum::{U2, U9}, FixedPoint};
+//!
+//! type Amount = FixedPoint<i64, U2>;
+//! type Rate = FixedPoint<i64, U9>;
+//!
+//! let (principal, rate): (Amount, Amount) = ("1000".parse()?, "0.05".parse()?);
+//! assert_eq!(finance::compound(principal, rate, 10, Floor)?, "1628.86".parse()?);
+//! assert_eq!(finance::compound(principal, rate, 10, Ceil)?, "1628.94".parse()?);
+//!
+//! let rate: Rate = "0.05".parse()?;
+//! assert_eq!(finance::discount_factor(rate, 10, Floor)?, "0.613913253".parse()?);
+//! assert_eq!(finance::discount_factor(rate, 10, Ceil)?, "0.613913255".parse()?);
+//!
+//! let rate: Amount = "0.1".parse()?;
+//! let cashflows: Vec<Amount> = ["-1000", "300", "400", "500"].iter().map(|s| s.parse()).collect::<Result<_, _>>()?;
+//! assert_eq!(finance::npv(rate, &cashflows, Floor)?, "-21.05".parse()?);
+//! assert_eq!(finance::npv(rate, &cashflows, Ceil)?, "-21.02".parse()?);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::ops::RoundMode;
+use crate::ArithmeticError;
+use crate::{
+    ops::{CheckedAdd, RoundingDiv, RoundingMul},
+    FixedPoint, Precision,
+};
+
+use self::private::Finance;
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// Returns `principal * (1 + rate)^periods`.
+///
+/// Interest is accrued and rounded every period like a bank account does, so the result matches
+/// period-by-period statements. Thus, it takes `O(periods)` multiplications.
+#[inline]
+pub fn compound<F: Finance>(principal: F, rate: F, periods: u32, mode: RoundMode) -> Result<F> {
+    F::compound(principal, rate, periods, mode)
+}
+
+/// Returns `1 / (1 + rate)^periods`, the present value of a unit paid after `periods`.
+///
+/// The power is calculated by [`FixedPoint::checked_powi`] and rounded in the opposite direction,
+/// so `Floor` and `Ceil` always return a lower and an upper bound of the mathematical result.
+/// Returns [`ArithmeticError::DivisionByZero`] if `rate` is `-1`.
+#[inline]
+pub fn discount_factor<F: Finance>(rate: F, periods: u32, mode: RoundMode) -> Result<F> {
+    F::discount_factor(rate, periods, mode)
+}
+
+/// Returns the net present value `sum(cashflows[t] / (1 + rate)^t)`,
+/// where the first cashflow isn't discounted. Returns zero for an empty slice.
+///
+/// Calculated by Horner's scheme: `c0 + (c1 + (c2 + ...) / (1 + rate)) / (1 + rate)`,
+/// so every division is rounded once. Returns [`ArithmeticError::DivisionByZero`] if `rate`
+/// is `-1`.
+#[inline]
+pub fn npv<F: Finance>(rate: F, cashflows: &[F], mode: RoundMode) -> Result<F> {
+    F::npv(rate, cashflows, mode)
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to use financial functions in generic code.
+    #[allow(unreachable_pub)]
+    pub trait Finance: Sized {
+        fn compound(principal: Self, rate: Self, periods: u32, mode: RoundMode) -> Result<Self>;
+        fn discount_factor(rate: Self, periods: u32, mode: RoundMode) -> Result<Self>;
+        fn npv(rate: Self, cashflows: &[Self], mode: RoundMode) -> Result<Self>;
+    }
+}
+
+macro_rules! impl_finance {
+    ($layout:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Returns `1 + rate`, which is negative only for `rate < -1`.
+            fn growth_factor(rate: Self) -> Result<Self> {
+                let growth = Self::ONE.cadd(rate)?;
+                if growth.inner.is_negative() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+                Ok(growth)
+            }
+        }
+
+        impl<P: Precision> Finance for FixedPoint<$layout, P> {
+            fn compound(
+                principal: Self,
+                rate: Self,
+                periods: u32,
+                mode: RoundMode,
+            ) -> Result<Self> {
+                let growth = Self::growth_factor(rate)?;
+                (0..periods).try_fold(principal, |balance, _| balance.rmul(growth, mode))
+            }
+
+            fn discount_factor(rate: Self, periods: u32, mode: RoundMode) -> Result<Self> {
+                // The denominator is rounded in the opposite direction to keep bounds.
+                let opposite = match mode {
+                    RoundMode::Floor => RoundMode::Ceil,
+                    RoundMode::Ceil => RoundMode::Floor,
+                    mode => mode,
+                };
+
+                let growth = Self::growth_factor(rate)?.checked_powi(periods, opposite)?;
+                growth.recip(mode)
+            }
+
+            fn npv(rate: Self, cashflows: &[Self], mode: RoundMode) -> Result<Self> {
+                let growth = Self::growth_factor(rate)?;
+                let Some((&last, rest)) = cashflows.split_last() else {
+                    return Ok(Self::ZERO);
+                };
+
+                rest.iter().rev().try_fold(last, |value, &cashflow| {
+                    cashflow.cadd(value.rdiv(growth, mode)?)
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_finance!(i16);
+#[cfg(feature = "i32")]
+impl_finance!(i32);
+#[cfg(feature = "i64")]
+impl_finance!(i64);
+#[cfg(feature = "i128")]
+impl_finance!(i128);
+#[cfg(feature = "u64")]
+impl_finance!(u64);
+#[cfg(feature = "u128")]
+impl_finance!(u128);
@@ -0,0 +1,156 @@
+//! Formatting and parsing of FIX protocol `Price`, `Qty` and other `float` fields.
+//!
+//! [`to_fix_str`] produces the canonical wire form: no exponent, no `+`, fractional digits
+//! padded to the tick precision and no trailing zeros beyond it. [`from_fix_str`] parses
+//! raw bytes of a field value without rounding. Both functions don't allocate.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{fix, typenum::U9, FixedPoint};
+//!
+//! type Price = FixedPoint<i64, U9>;
+//!
+//! let price: Price = "101.5".parse()?;
+//! // Ticks of `0.01`, at most 15 characters.
+//! assert_eq!(fix::to_fix_str(&price, 2, 15)?.as_str(), "101.50");
+//! assert_eq!(fix::to_fix_str(&"101.125".parse::<Price>()?, 2, 15)?.as_str(), "101.125");
+//! assert!(fix::to_fix_str(&price, 2, 5).is_err());
+//!
+//! let message = b"44=101.50\x0138=100\x01";
+//! assert_eq!(fix::from_fix_str::<i64, U9>(&message[3..9])?, price);
+//! assert!(fix::from_fix_str::<i64, U9>(b"1.01e2").is_err());
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use crate::{
+    string::{Parse, StrBuf, Stringify, MAX_LEN},
+    ConvertError, FixedPoint,
+};
+
+// Leaves room for padding of low-precision values.
+const CAPACITY: usize = 2 * MAX_LEN;
+
+/// The FIX representation of a number, see [`to_fix_str`].
+#[derive(Clone)]
+pub struct FixStr {
+    buffer: [u8; CAPACITY],
+    len: usize,
+}
+
+impl FixStr {
+    /// Returns the representation as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Only ASCII characters are written.
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    /// Returns the representation as bytes to be written as is.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    fn push_str(&mut self, s: &str) -> Result<(), ConvertError> {
+        self.buffer
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(ConvertError::new("too long number"))?
+            .copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for FixStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<str> for FixStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Formats for a FIX `float` field.
+///
+/// The fractional part has at least `decimals` digits (the precision of the tick size),
+/// excess digits are kept until the last non-zero one, the point is omitted if there are none.
+///
+/// Fails if the result is longer than `max_len` characters.
+pub fn to_fix_str<I, P>(
+    fp: &FixedPoint<I, P>,
+    decimals: u32,
+    max_len: usize,
+) -> Result<FixStr, ConvertError>
+where
+    FixedPoint<I, P>: Stringify,
+{
+    let mut buf = StrBuf::default();
+    fp.stringify(&mut buf);
+
+    // `Stringify` always emits the point.
+    let str = buf.as_str();
+    let (integral, fractional) = str.split_once('.').unwrap_or((str, ""));
+    let fractional = fractional.trim_end_matches('0');
+
+    let mut fix = FixStr {
+        buffer: [0; CAPACITY],
+        len: 0,
+    };
+
+    fix.push_str(integral)?;
+
+    if !fractional.is_empty() || decimals > 0 {
+        fix.push_str(".")?;
+        fix.push_str(fractional)?;
+        for _ in fractional.len()..decimals as usize {
+            fix.push_str("0")?;
+        }
+    }
+
+    if fix.len > max_len {
+        return Err(ConvertError::new("too long number"));
+    }
+
+    Ok(fix)
+}
+
+/// Parses a FIX `float` field: an optional `-`, digits and an optional fractional part.
+///
+/// Leading and trailing zeros are accepted. Fails on other characters (including `+`,
+/// whitespaces and exponents) and if the number can't be represented without rounding.
+pub fn from_fix_str<I, P>(bytes: &[u8]) -> Result<FixedPoint<I, P>, ConvertError>
+where
+    FixedPoint<I, P>: Parse,
+{
+    let digits = bytes.strip_prefix(b"-").unwrap_or(bytes);
+    let (integral, fractional) = match digits.iter().position(|&b| b == b'.') {
+        Some(point) => (&digits[..point], &digits[point + 1..]),
+        None => (digits, &[][..]),
+    };
+
+    if integral.is_empty() {
+        return Err(ConvertError::new("can't parse integral part"));
+    }
+
+    if !integral.iter().chain(fractional).all(u8::is_ascii_digit) {
+        return Err(ConvertError::new("unexpected character"));
+    }
+
+    // Trailing zeros beyond the precision don't require rounding.
+    let zeros = fractional.iter().rev().take_while(|&&b| b == b'0').count();
+    let len = bytes.len() - zeros;
+
+    // Only ASCII characters are accepted.
+    let str = core::str::from_utf8(&bytes[..len])
+        .map_err(|_| ConvertError::new("unexpected character"))?;
+    // `FromStr` requires digits after the point.
+    FixedPoint::parse(str.strip_suffix('.').unwrap_or(str), None)
+}
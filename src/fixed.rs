@@ -0,0 +1,119 @@
+use core::{fmt, hash::Hash, str::FromStr};
+
+#[cfg(feature = "i256")]
+use crate::i256::I256;
+use crate::ops::*;
+use crate::{ArithmeticError, ConvertError, FixedPoint, Precision};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented by every `FixedPoint<I, P>`, allows to write code generic over layouts
+/// and precisions without listing bounds of all required [`ops`](crate::ops).
+///
+/// Operations with integers (e.g. [`CheckedMul<Self::Layout>`][CheckedMul]) still require
+/// explicit bounds.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ops::*, typenum::U9, ArithmeticError, Fixed, FixedPoint};
+///
+/// fn total_cost<F: Fixed>(prices: &[F], fee: F) -> Result<F, ArithmeticError> {
+///     let sum = prices.iter().try_fold(F::ZERO, |acc, &price| acc.cadd(price))?;
+///     sum.rmul(F::ONE.cadd(fee)?, RoundMode::Ceil)
+/// }
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let prices: [Amount; 2] = ["1.5".parse()?, "2.5".parse()?];
+/// assert_eq!(total_cost(&prices, "0.01".parse()?)?, "4.04".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub trait Fixed:
+    sealed::Sealed
+    + Copy
+    + Ord
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + FromStr<Err = ConvertError>
+    + Zero
+    + One
+    + Bounded
+    + CheckedAdd<Output = Self, Error = ArithmeticError>
+    + CheckedSub<Output = Self, Error = ArithmeticError>
+    + SaturatingAdd<Output = Self>
+    + SaturatingSub<Output = Self>
+    + RoundingMul<Output = Self, Error = ArithmeticError>
+    + SaturatingRMul<Output = Self>
+    + RoundingDiv<Output = Self, Error = ArithmeticError>
+{
+    /// The underlying integer type.
+    type Layout: Copy + Ord + Hash + fmt::Debug;
+
+    /// The number of digits in the fractional part.
+    const PRECISION: i32;
+
+    /// Creates from the raw representation, see [`FixedPoint::from_bits`].
+    fn from_bits(raw: Self::Layout) -> Self;
+
+    /// Returns the raw representation, see [`FixedPoint::into_bits`].
+    fn into_bits(self) -> Self::Layout;
+
+    /// Checked negation, see [`FixedPoint::cneg`].
+    fn cneg(self) -> Result<Self, ArithmeticError>;
+
+    /// Takes [rounded][RoundMode] integral part of the number, see [`FixedPoint::integral`].
+    fn integral(self, mode: RoundMode) -> Self::Layout;
+}
+
+macro_rules! impl_fixed {
+    ($layout:tt) => {
+        impl<P: Precision> sealed::Sealed for FixedPoint<$layout, P> {}
+
+        impl<P: Precision + Ord + Hash> Fixed for FixedPoint<$layout, P> {
+            type Layout = $layout;
+
+            const PRECISION: i32 = FixedPoint::<$layout, P>::PRECISION;
+
+            #[inline]
+            fn from_bits(raw: $layout) -> Self {
+                FixedPoint::from_bits(raw)
+            }
+
+            #[inline]
+            fn into_bits(self) -> $layout {
+                FixedPoint::into_bits(self)
+            }
+
+            #[inline]
+            fn cneg(self) -> Result<Self, ArithmeticError> {
+                FixedPoint::<$layout, P>::cneg(self)
+            }
+
+            #[inline]
+            fn integral(self, mode: RoundMode) -> $layout {
+                FixedPoint::<$layout, P>::integral(self, mode)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_fixed!(i16);
+#[cfg(feature = "i32")]
+impl_fixed!(i32);
+#[cfg(feature = "i64")]
+impl_fixed!(i64);
+#[cfg(feature = "i128")]
+impl_fixed!(i128);
+#[cfg(feature = "u64")]
+impl_fixed!(u64);
+#[cfg(feature = "u128")]
+impl_fixed!(u128);
+#[cfg(feature = "i256")]
+impl_fixed!(I256);
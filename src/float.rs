@@ -1,157 +1,284 @@
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
 use crate::{
-    ops::Zero,
-    power_table::{
-        power_of_10, rdiv_by_exponent_10, MAX_EXPONENT_5, NEXT_EXPONENT_10, POWERS_OF_10,
-        POWERS_OF_5,
-    },
-    ConvertError, FixedPoint, Precision,
+    ops::RoundMode,
+    power_table::{power_of_10, MAX_EXPONENT_5, NEXT_EXPONENT_10, POWERS_OF_10, POWERS_OF_5},
+    ConvertError,
 };
+#[cfg(primitive_layout)]
+use crate::{power_table::rdiv_by_exponent_10, FixedPoint, FloatConvertError, Precision};
 
-macro_rules! impl_try_from_f64 {
-    ($layout:tt) => {
-        impl<P: Precision> TryFrom<f64> for FixedPoint<$layout, P> {
-            type Error = ConvertError;
+/// Splits a finite `value` into `(positive, significand, exponent10)`,
+/// so that `value = (-1)^(!positive) * significand * 10^exponent10`.
+/// The significand is rounded to the precision guaranteed by `f64`.
+/// Returns `None` for zero.
+///
+/// Implementation courtesy of [`rust_decimal` crate][rust_decimal]
+///
+/// [rust_decimal]: https://github.com/paupino/rust-decimal/blob/2de2a6dd2f385e98c4019ebe38b5c6de5fef6cba/src/decimal.rs#L2059
+pub(crate) fn decompose(value: f64) -> Result<Option<(bool, u128, i32)>, ConvertError> {
+    if !value.is_finite() {
+        return Err(ConvertError::new("not finite"));
+    }
 
-            /// Implementation courtesy of [`rust_decimal` crate][rust_decimal]
-            ///
-            /// [rust_decimal]: https://github.com/paupino/rust-decimal/blob/2de2a6dd2f385e98c4019ebe38b5c6de5fef6cba/src/decimal.rs#L2059
-            fn try_from(value: f64) -> Result<Self, Self::Error> {
-                if !value.is_finite() {
-                    return Err(ConvertError::new("not finite"));
-                }
+    // f64 is being broken up by bits i.e. 1/11/52 (sign, biased_exponent, mantissa)
+    // See https://en.wikipedia.org/wiki/IEEE_754-1985
+    let raw = value.to_bits();
+    let positive = (raw >> 63) == 0;
+    let biased_exponent = ((raw >> 52) & 0x7FF) as i32;
+    let bits = (raw & 0x000F_FFFF_FFFF_FFFF) as u128;
 
-                // f64 is being broken up by bits i.e. 1/11/52 (sign, biased_exponent, mantissa)
-                // See https://en.wikipedia.org/wiki/IEEE_754-1985
-                // n = (-1)^sign * 2^exp * significand
-                // fixnum stores it differently: n = significand * 10^(-PRECISION)
-                let raw = value.to_bits();
-                let positive = (raw >> 63) == 0;
-                let biased_exponent = ((raw >> 52) & 0x7FF) as i32;
-                let mut bits = (raw & 0x000F_FFFF_FFFF_FFFF) as u128;
-
-                // Handle the special zero case
-                if biased_exponent == 0 && bits == 0 {
-                    return Ok(Self::ZERO);
-                }
+    Ok(decompose_parts::<52, 1023>(positive, biased_exponent, bits))
+}
 
-                // Get the bits and exponent2
-                let mut exponent2 = if biased_exponent == 0 {
-                    // Denormalized number
-                    -1022
-                } else {
-                    // Add extra hidden bit to mantissa
-                    bits |= 0x0010_0000_0000_0000;
-                    biased_exponent - 1023
-                };
+/// The same as [`decompose`], but for `f32`, the significand is rounded
+/// to the precision guaranteed by `f32`.
+pub(crate) fn decompose_f32(value: f32) -> Result<Option<(bool, u128, i32)>, ConvertError> {
+    if !value.is_finite() {
+        return Err(ConvertError::new("not finite"));
+    }
 
-                // The act of copying a significand as integer bits is equivalent to shifting
-                // left the significand 52 bits. The exponent is reduced to compensate.
-                exponent2 -= 52;
-
-                // 2^exponent2 = 10^exponent2 / 5^exponent2 =
-                //             = 10^exponent2 * 5^(-exponent2)
-                let mut exponent5 = -exponent2;
-                let mut exponent10 = exponent2; // Ultimately, we want this for the scale
-
-                if exponent5 > 0 {
-                    // Divide significand by 2 as much as possible without losing precision
-                    let excess_exponent2 = bits.trailing_zeros().min(exponent5 as u32) as i32;
-                    exponent10 += excess_exponent2;
-                    exponent5 -= excess_exponent2;
-                    bits >>= excess_exponent2;
-
-                    if exponent5 > 0 {
-                        // The significand is no more divisible by 2. Therefore the significand should
-                        // be multiplied by 5, unless the multiplication overflows.
-                        let lz = bits.leading_zeros() as usize;
-                        let reduced_exponent5 = if lz == 0 {
-                            0
-                        } else {
-                            let multiplier_exponent5 = exponent5.min(MAX_EXPONENT_5[lz - 1] as i32);
-                            bits *= POWERS_OF_5[multiplier_exponent5 as usize];
-                            if let (true, Some(b)) =
-                                (multiplier_exponent5 < exponent5, bits.checked_mul(5))
-                            {
-                                bits = b;
-                                multiplier_exponent5 + 1
-                            } else {
-                                multiplier_exponent5
-                            }
-                        };
-
-                        if reduced_exponent5 == 0 {
-                            // Multiplication by 5 overflows. The significand should be divided
-                            // by 2, and therefore will lose significant digits.
-                            exponent10 += 1;
-                            exponent5 -= 1;
-                            bits >>= 1;
-                        } else {
-                            exponent5 -= reduced_exponent5;
-                        }
-
-                        while exponent5 > 0 {
-                            if bits & 1 == 0 {
-                                exponent10 += 1;
-                                exponent5 -= 1;
-                                bits >>= 1;
-                            } else {
-                                if let Some(b) = bits.checked_mul(5) {
-                                    exponent5 -= 1;
-                                    bits = b;
-                                } else {
-                                    // Multiplication by 5 overflows. The significand should be divided
-                                    // by 2, and therefore will lose significant digits.
-                                    exponent10 += 1;
-                                    exponent5 -= 1;
-                                    bits >>= 1;
-                                }
-                            }
-                        }
-                    }
-                }
+    // f32 is being broken up by bits i.e. 1/8/23 (sign, biased_exponent, mantissa)
+    let raw = value.to_bits();
+    let positive = (raw >> 31) == 0;
+    let biased_exponent = ((raw >> 23) & 0xFF) as i32;
+    let bits = (raw & 0x007F_FFFF) as u128;
 
-                // In order to divide the value by 5, it is best to multiply by 2/10.
-                // Therefore, exponent10 is decremented, and the significand should be multiplied by 2.
-                while exponent5 < 0 {
-                    const MOST_SIGNIFICANT_BIT: u128 = !(u128::MAX >> 1);
-                    bits = if bits & MOST_SIGNIFICANT_BIT == 0 {
-                        // No far left bit, the significand can withstand a shift-left without overflowing
-                        exponent10 -= 1;
-                        exponent5 += 1;
-                        bits << 1
-                    } else {
-                        // The significand would overflow if shifted. Therefore it should be
-                        // directly divided by 5. This will lose significant digits, unless
-                        // by chance the significand happens to be divisible by 5.
-                        exponent5 += 1;
-                        bits / 5
-                    };
+    Ok(decompose_parts::<23, 127>(positive, biased_exponent, bits))
+}
+
+/// Decomposes a finite float with `MANTISSA_BITS` explicit bits of the significand
+/// and the exponent bias `BIAS`.
+fn decompose_parts<const MANTISSA_BITS: u32, const BIAS: i32>(
+    positive: bool,
+    biased_exponent: i32,
+    mut bits: u128,
+) -> Option<(bool, u128, i32)> {
+    // n = (-1)^sign * 2^exp * significand
+    // fixnum stores it differently: n = significand * 10^(-PRECISION)
+
+    // Handle the special zero case
+    if biased_exponent == 0 && bits == 0 {
+        return None;
+    }
+
+    // Get the bits and exponent2
+    let mut exponent2 = if biased_exponent == 0 {
+        // Denormalized number
+        1 - BIAS
+    } else {
+        // Add extra hidden bit to mantissa
+        bits |= 1 << MANTISSA_BITS;
+        biased_exponent - BIAS
+    };
+
+    // The act of copying a significand as integer bits is equivalent to shifting
+    // left the significand `MANTISSA_BITS` bits. The exponent is reduced to compensate.
+    exponent2 -= MANTISSA_BITS as i32;
+
+    // 2^exponent2 = 10^exponent2 / 5^exponent2 =
+    //             = 10^exponent2 * 5^(-exponent2)
+    let mut exponent5 = -exponent2;
+    let mut exponent10 = exponent2; // Ultimately, we want this for the scale
+
+    if exponent5 > 0 {
+        // Divide significand by 2 as much as possible without losing precision
+        let excess_exponent2 = bits.trailing_zeros().min(exponent5 as u32) as i32;
+        exponent10 += excess_exponent2;
+        exponent5 -= excess_exponent2;
+        bits >>= excess_exponent2;
+
+        if exponent5 > 0 {
+            // The significand is no more divisible by 2. Therefore the significand should
+            // be multiplied by 5, unless the multiplication overflows.
+            let lz = bits.leading_zeros() as usize;
+            let reduced_exponent5 = if lz == 0 {
+                0
+            } else {
+                let multiplier_exponent5 = exponent5.min(MAX_EXPONENT_5[lz - 1] as i32);
+                bits *= POWERS_OF_5[multiplier_exponent5 as usize];
+                if let (true, Some(b)) = (multiplier_exponent5 < exponent5, bits.checked_mul(5)) {
+                    bits = b;
+                    multiplier_exponent5 + 1
+                } else {
+                    multiplier_exponent5
                 }
+            };
 
-                // At this point, the significand has assimilated the exponent5
-
-                // This step is required in order to remove excess bits of precision from the
-                // end of the bit representation, down to the precision guaranteed by the
-                // floating point number
-                // Guaranteed to about 16 dp
-                let prefix = bits >> 52;
-                if exponent10 < 0 && prefix > 0 {
-                    let lz = (bits.leading_zeros() + 52) as usize;
-                    let mut divisor_exponent_10 = NEXT_EXPONENT_10[lz] as i32;
-                    let divisor = power_of_10(divisor_exponent_10 as u32).unwrap();
-                    if prefix >= divisor {
-                        divisor_exponent_10 = NEXT_EXPONENT_10[lz - 1] as i32;
-                    }
-                    let divisor_exponent_10 = divisor_exponent_10.min(-exponent10 as i32);
-                    let (divisor, remainder) = POWERS_OF_10[divisor_exponent_10 as usize];
-                    let res = bits / divisor;
-                    bits = if bits % divisor > remainder {
-                        res + 1
+            if reduced_exponent5 == 0 {
+                // Multiplication by 5 overflows. The significand should be divided
+                // by 2, and therefore will lose significant digits.
+                exponent10 += 1;
+                exponent5 -= 1;
+                bits >>= 1;
+            } else {
+                exponent5 -= reduced_exponent5;
+            }
+
+            while exponent5 > 0 {
+                if bits & 1 == 0 {
+                    exponent10 += 1;
+                    exponent5 -= 1;
+                    bits >>= 1;
+                } else {
+                    if let Some(b) = bits.checked_mul(5) {
+                        exponent5 -= 1;
+                        bits = b;
                     } else {
-                        res
-                    };
-                    exponent10 += divisor_exponent_10;
+                        // Multiplication by 5 overflows. The significand should be divided
+                        // by 2, and therefore will lose significant digits.
+                        exponent10 += 1;
+                        exponent5 -= 1;
+                        bits >>= 1;
+                    }
                 }
+            }
+        }
+    }
+
+    // In order to divide the value by 5, it is best to multiply by 2/10.
+    // Therefore, exponent10 is decremented, and the significand should be multiplied by 2.
+    while exponent5 < 0 {
+        const MOST_SIGNIFICANT_BIT: u128 = !(u128::MAX >> 1);
+        bits = if bits & MOST_SIGNIFICANT_BIT == 0 {
+            // No far left bit, the significand can withstand a shift-left without overflowing
+            exponent10 -= 1;
+            exponent5 += 1;
+            bits << 1
+        } else {
+            // The significand would overflow if shifted. Therefore it should be
+            // directly divided by 5. This will lose significant digits, unless
+            // by chance the significand happens to be divisible by 5.
+            exponent5 += 1;
+            bits / 5
+        };
+    }
+
+    // At this point, the significand has assimilated the exponent5
+
+    // This step is required in order to remove excess bits of precision from the
+    // end of the bit representation, down to the precision guaranteed by the
+    // floating point number
+    // Guaranteed to about 16 dp for f64 and 7 dp for f32
+    let prefix = bits >> MANTISSA_BITS;
+    if exponent10 < 0 && prefix > 0 {
+        let lz = (bits.leading_zeros() + MANTISSA_BITS) as usize;
+        let mut divisor_exponent_10 = NEXT_EXPONENT_10[lz] as i32;
+        let divisor = power_of_10(divisor_exponent_10 as u32).unwrap();
+        if prefix >= divisor {
+            divisor_exponent_10 = NEXT_EXPONENT_10[lz - 1] as i32;
+        }
+        let divisor_exponent_10 = divisor_exponent_10.min(-exponent10);
+        let (divisor, remainder) = POWERS_OF_10[divisor_exponent_10 as usize];
+        let res = bits / divisor;
+        bits = if bits % divisor > remainder {
+            res + 1
+        } else {
+            res
+        };
+        exponent10 += divisor_exponent_10;
+    }
+
+    Some((positive, bits, exponent10))
+}
+
+/// Checks that `value` is finite.
+#[cfg(primitive_layout)]
+fn check_finite(value: f64) -> Result<(), FloatConvertError> {
+    if value.is_nan() {
+        Err(FloatConvertError::NaN)
+    } else if value.is_infinite() {
+        Err(FloatConvertError::Infinite)
+    } else {
+        Ok(())
+    }
+}
+
+/// Splits a finite `value` into `(positive, significand, exponent2)` exactly,
+/// so that `value = (-1)^(!positive) * significand * 2^exponent2` and the significand is odd.
+/// Returns `None` for zero.
+#[cfg(primitive_layout)]
+fn split_exact(value: f64) -> Result<Option<(bool, u64, i32)>, FloatConvertError> {
+    check_finite(value)?;
+
+    let raw = value.to_bits();
+    let positive = (raw >> 63) == 0;
+    let biased_exponent = ((raw >> 52) & 0x7FF) as i32;
+    let mut significand = raw & 0x000F_FFFF_FFFF_FFFF;
+
+    if biased_exponent == 0 && significand == 0 {
+        return Ok(None);
+    }
+
+    let mut exponent2 = if biased_exponent == 0 {
+        -1022 - 52
+    } else {
+        significand |= 0x0010_0000_0000_0000;
+        biased_exponent - 1023 - 52
+    };
+
+    let trailing_zeros = significand.trailing_zeros();
+    significand >>= trailing_zeros;
+    exponent2 += trailing_zeros as i32;
+
+    Ok(Some((positive, significand, exponent2)))
+}
+
+/// Divides the absolute value `abs` by `10^exp10`, [rounding][RoundMode] the result
+/// according to `mode` and the sign.
+pub(crate) fn rdiv_abs_by_exponent_10(
+    abs: u128,
+    exp10: u32,
+    positive: bool,
+    mode: RoundMode,
+) -> u128 {
+    let (quotient, loss, divisor) = match power_of_10(exp10) {
+        Some(divisor) => (abs / divisor, abs % divisor, divisor),
+        // The divisor exceeds `u128::MAX`, thus `loss < divisor / 2`.
+        None => (0, abs, u128::MAX),
+    };
+
+    if loss == 0 {
+        return quotient;
+    }
+
+    let sign = if positive { 1 } else { -1 };
+    let half = loss.cmp(&(divisor - loss));
+    if mode.is_away_from_zero(sign, half, quotient % 2 != 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+#[cfg(primitive_layout)]
+macro_rules! impl_try_from_f64 {
+    ($layout:tt) => {
+        impl<P: Precision> TryFrom<f64> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Considers only digits guaranteed by `f64` and rounds extra fractional digits
+            /// to the nearest value unless another default mode is [configured][RoundMode].
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                Self::from_decomposed(decompose(value)?)
+            }
+        }
+
+        impl<P: Precision> TryFrom<f32> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// The same as `TryFrom<f64>`, but considers only digits guaranteed by `f32`.
+            fn try_from(value: f32) -> Result<Self, Self::Error> {
+                Self::from_decomposed(decompose_f32(value)?)
+            }
+        }
+
+        impl<P: Precision> FixedPoint<$layout, P> {
+            fn from_decomposed(parts: Option<(bool, u128, i32)>) -> Result<Self, ConvertError> {
+                let (positive, mut bits, exponent10) = match parts {
+                    Some(parts) => parts,
+                    None => return Ok(Self::ZERO),
+                };
 
                 // exponent10 must equal to -PRECISION, so the significand must be scaled up or down appropriately.
                 if exponent10 > -Self::PRECISION {
@@ -165,7 +292,11 @@ macro_rules! impl_try_from_f64 {
                     // In order to bring exponent up to -PRECISION, the significand should
                     // be divided by 10 to compensate. If the exponent10 is too small, this
                     // will cause the significand to underflow and become 0.
-                    bits = rdiv_by_exponent_10(bits, (-Self::PRECISION - exponent10) as u32);
+                    let exp10 = (-Self::PRECISION - exponent10) as u32;
+                    bits = match RoundMode::configured() {
+                        Some(mode) => rdiv_abs_by_exponent_10(bits, exp10, positive, mode),
+                        None => rdiv_by_exponent_10(bits, exp10),
+                    };
                 }
 
                 let bits: $layout = bits
@@ -181,6 +312,125 @@ macro_rules! impl_try_from_f64 {
                 }
             }
         }
+
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Converts to the nearest `f64`, the same as `f64::from`.
+            #[inline]
+            pub fn to_f64_lossy(self) -> f64 {
+                f64::from(self)
+            }
+
+            /// Converts to `f64`, fails with [`FloatConvertError::PrecisionLoss`]
+            /// unless the value is representable exactly, e.g. `0.5`, but not `0.1`.
+            pub fn try_to_f64_exact(self) -> Result<f64, FloatConvertError> {
+                // `value = inner / (2^P * 5^P)`, the denominator must be a power of two.
+                let mut abs = self.inner.unsigned_abs();
+                for _ in 0..Self::PRECISION {
+                    if abs % 5 != 0 {
+                        return Err(FloatConvertError::PrecisionLoss);
+                    }
+                    abs /= 5;
+                }
+
+                // Now `value = abs / 2^P`, and the odd part of `abs` must fit in the significand.
+                if abs != 0 && u128::from(abs >> abs.trailing_zeros()) >= 1 << 53 {
+                    return Err(FloatConvertError::PrecisionLoss);
+                }
+
+                // Both conversions and the division by a power of two are exact.
+                let abs = abs as f64 / (1u128 << Self::PRECISION) as f64;
+                Ok(if self.inner.is_negative() { -abs } else { abs })
+            }
+
+            /// Converts from `f64`, [rounding][RoundMode] extra fractional digits
+            /// according to `mode`. Like `TryFrom<f64>`, considers only digits guaranteed
+            /// by `f64` (about 16 significant digits), so `0.1` is converted to `0.1`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ops::RoundMode::*, typenum::U2, FixedPoint, FloatConvertError};
+            ///
+            /// type Cents = FixedPoint<i64, U2>;
+            ///
+            /// assert_eq!(Cents::from_f64_lossy(1.005, Floor)?, "1".parse()?);
+            /// assert_eq!(Cents::from_f64_lossy(1.005, Ceil)?, "1.01".parse()?);
+            /// assert_eq!(Cents::from_f64_lossy(f64::NAN, Floor), Err(FloatConvertError::NaN));
+            ///
+            /// assert_eq!(Cents::try_from_f64_exact(0.25)?, "0.25".parse()?);
+            /// assert_eq!(Cents::try_from_f64_exact(0.1), Err(FloatConvertError::PrecisionLoss));
+            /// assert_eq!(Cents::try_from_f64_exact(1e300), Err(FloatConvertError::OutOfRange));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn from_f64_lossy(value: f64, mode: RoundMode) -> Result<Self, FloatConvertError> {
+                check_finite(value)?;
+
+                let (positive, mut bits, exponent10) = match decompose(value) {
+                    Ok(Some(parts)) => parts,
+                    Ok(None) => return Ok(Self::ZERO),
+                    Err(_) => unreachable!("the value is finite"),
+                };
+
+                if exponent10 > -Self::PRECISION {
+                    bits = power_of_10((exponent10 + Self::PRECISION) as u32)
+                        .and_then(|multiplier| bits.checked_mul(multiplier))
+                        .ok_or(FloatConvertError::OutOfRange)?;
+                } else if exponent10 < -Self::PRECISION {
+                    let exp10 = (-Self::PRECISION - exponent10) as u32;
+                    bits = rdiv_abs_by_exponent_10(bits, exp10, positive, mode);
+                }
+
+                Self::from_sign_and_abs(positive, bits)
+            }
+
+            /// Converts from `f64`, fails with [`FloatConvertError::PrecisionLoss`]
+            /// unless the exact binary value of the float is representable, e.g. `0.5`, but not `0.1`.
+            pub fn try_from_f64_exact(value: f64) -> Result<Self, FloatConvertError> {
+                let (positive, significand, exponent2) = match split_exact(value)? {
+                    Some(parts) => parts,
+                    None => return Ok(Self::ZERO),
+                };
+
+                let precision = Self::PRECISION as u32;
+                let significand = u128::from(significand);
+
+                // `abs = significand * 2^exponent2 * 10^P`, all intermediate products
+                // don't exceed the result, so any overflow means that it's out of range.
+                let abs = if exponent2 >= 0 {
+                    2u128
+                        .checked_pow(exponent2 as u32)
+                        .and_then(|multiplier| significand.checked_mul(multiplier))
+                        .and_then(|abs| abs.checked_mul(power_of_10(precision)?))
+                } else {
+                    // The significand is odd, so `2^-exponent2` must divide `10^P`.
+                    let exponent2 = exponent2.unsigned_abs();
+                    if exponent2 > precision {
+                        return Err(FloatConvertError::PrecisionLoss);
+                    }
+
+                    5u128
+                        .checked_pow(precision)
+                        .and_then(|multiplier| significand.checked_mul(multiplier))
+                        .and_then(|abs| abs.checked_mul(1 << (precision - exponent2)))
+                };
+
+                Self::from_sign_and_abs(positive, abs.ok_or(FloatConvertError::OutOfRange)?)
+            }
+
+            fn from_sign_and_abs(positive: bool, abs: u128) -> Result<Self, FloatConvertError> {
+                let abs: $layout = abs.try_into().map_err(|_| FloatConvertError::OutOfRange)?;
+
+                if positive {
+                    Ok(Self::from_bits(abs))
+                } else {
+                    abs.checked_neg()
+                        .map(Self::from_bits)
+                        .ok_or(FloatConvertError::OutOfRange)
+                }
+            }
+        }
     };
 }
 
@@ -193,3 +443,7 @@ impl_try_from_f64!(i32);
 impl_try_from_f64!(i64);
 #[cfg(feature = "i128")]
 impl_try_from_f64!(i128);
+#[cfg(feature = "u64")]
+impl_try_from_f64!(u64);
+#[cfg(feature = "u128")]
+impl_try_from_f64!(u128);
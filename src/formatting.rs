@@ -0,0 +1,471 @@
+use core::fmt;
+
+use crate::{
+    ops::RoundMode,
+    string::{pad_number, Parse, StrBuf, Stringify, MAX_LEN},
+    ConvertError, FixedPoint, ParseErrorKind, Precision,
+};
+
+/// Separators and grouping used by [`FixedPoint::format`].
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{typenum::U9, FixedPoint, FormatOptions};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let amount: Amount = "-1234567.891".parse()?;
+///
+/// let us = FormatOptions::new().grouping(',', 3, 3);
+/// assert_eq!(format!("{:.2}", amount.format(&us)), "-1,234,567.89");
+///
+/// let de = FormatOptions::new().decimal_separator(',').grouping('.', 3, 3);
+/// assert_eq!(format!("{}", amount.format(&de)), "-1.234.567,891");
+///
+/// let indian = FormatOptions::new().grouping(',', 3, 2);
+/// assert_eq!(format!("{:>14.0}", amount.format(&indian)), "    -12,34,568");
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub(crate) decimal_separator: char,
+    pub(crate) grouping_separator: char,
+    // Zero disables grouping.
+    first_group: usize,
+    other_groups: usize,
+}
+
+impl FormatOptions {
+    /// The same as `Display`: `.` as the decimal separator, no grouping.
+    pub const DEFAULT: Self = Self {
+        decimal_separator: '.',
+        grouping_separator: ',',
+        first_group: 0,
+        other_groups: 0,
+    };
+
+    /// Creates [`FormatOptions::DEFAULT`].
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Sets the separator of the fractional part.
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Groups digits of the integral part: `first` digits before the decimal separator,
+    /// then by `others` digits, e.g. `(3, 3)` for thousands or `(3, 2)` for Indian grouping.
+    /// Zero `first` disables grouping, zero `others` separates only the first group.
+    pub const fn grouping(mut self, separator: char, first: usize, others: usize) -> Self {
+        self.grouping_separator = separator;
+        self.first_group = first;
+        self.other_groups = others;
+        self
+    }
+
+    /// Returns whether the separator precedes the digit at `position` counting from the point.
+    pub(crate) fn is_group_start(&self, position: usize, len: usize) -> bool {
+        if position == len || self.first_group == 0 || position < self.first_group {
+            return false;
+        }
+
+        let rest = position - self.first_group;
+        rest == 0 || self.other_groups > 0 && rest % self.other_groups == 0
+    }
+
+    pub(crate) fn separators_count(&self, len: usize) -> usize {
+        (1..len)
+            .filter(|&position| self.is_group_start(position, len))
+            .count()
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Displays `FixedPoint` with [`FormatOptions`], see [`FixedPoint::format`].
+///
+/// Supports precision, width, fill, alignment, sign and alternate flags like `FixedPoint`'s
+/// `Display`.
+#[derive(Clone, Copy)]
+pub struct Formatted<'a, I, P> {
+    value: &'a FixedPoint<I, P>,
+    options: &'a FormatOptions,
+}
+
+impl<I, P> FixedPoint<I, P> {
+    /// Returns a wrapper implementing `Display` with custom separators and grouping.
+    pub fn format<'a>(&'a self, options: &'a FormatOptions) -> Formatted<'a, I, P> {
+        Formatted {
+            value: self,
+            options,
+        }
+    }
+}
+
+impl<I, P> fmt::Display for Formatted<'_, I, P>
+where
+    P: Precision,
+    FixedPoint<I, P>: Stringify,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = StrBuf::default();
+        self.value.stringify(&mut buf);
+        pad_number(buf.as_str(), P::U32, self.options, f)
+    }
+}
+
+// `e`, the sign and digits of `i32`.
+const EXPONENT_LEN: usize = 12;
+
+// The sign, the integral part, the point, kept fractional digits, the collapsed rest
+// and the exponent.
+const REWRITTEN_LEN: usize = 2 * MAX_LEN + 3 + EXPONENT_LEN;
+
+/// The input of `from_str_with` rewritten to the `FromStr` syntax.
+struct Rewritten {
+    bytes: [u8; REWRITTEN_LEN],
+    /// Offsets of rewritten bytes in the original string.
+    positions: [usize; REWRITTEN_LEN],
+    len: usize,
+}
+
+impl Default for Rewritten {
+    fn default() -> Self {
+        Self {
+            bytes: [0; REWRITTEN_LEN],
+            positions: [0; REWRITTEN_LEN],
+            len: 0,
+        }
+    }
+}
+
+impl Rewritten {
+    fn push(&mut self, byte: u8, position: usize) {
+        debug_assert!(byte.is_ascii());
+        self.bytes[self.len] = byte;
+        self.positions[self.len] = position;
+        self.len += 1;
+    }
+
+    fn as_str(&self) -> &str {
+        // Only ASCII bytes are pushed.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Maps an offset in the rewritten string to the original one ending at `end`.
+    fn original_position(&self, position: usize, end: usize) -> usize {
+        self.positions[..self.len]
+            .get(position)
+            .copied()
+            .unwrap_or(end)
+    }
+}
+
+/// Separators accepted by [`FixedPoint::from_str_with`].
+///
+/// Like `FromStr`, surrounding whitespace, an explicit `+` sign and exponent notation (`1.5e-3`)
+/// are always accepted.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ops::RoundMode, typenum::U2, FixedPoint, ParseOptions};
+///
+/// type Amount = FixedPoint<i64, U2>;
+///
+/// let de = ParseOptions::new().decimal_separator(',').grouping_separator('.');
+/// assert_eq!(Amount::from_str_with(" -1.234.567,891 ", de)?, "-1234567.89".parse()?);
+///
+/// let rust = ParseOptions::new().underscores(true).rounding(RoundMode::Ceil);
+/// assert_eq!(Amount::from_str_with("+1_000.000_1", rust)?, "1000.01".parse()?);
+///
+/// assert!(Amount::from_str_with("1.001", ParseOptions::new().exact()).is_err());
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    decimal_separator: char,
+    grouping_separator: Option<char>,
+    underscores: bool,
+    mode: Option<RoundMode>,
+}
+
+impl ParseOptions {
+    /// The same as `FromStr`: `.` as the decimal separator, no digit separators,
+    /// excess fractional digits are rounded by [`RoundMode::Nearest`].
+    pub const DEFAULT: Self = Self {
+        decimal_separator: '.',
+        grouping_separator: None,
+        underscores: false,
+        mode: Some(RoundMode::Nearest),
+    };
+
+    /// Creates [`ParseOptions::DEFAULT`].
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Sets the separator of the fractional part.
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Accepts `separator` between digits of the integral part, e.g. `,` in `1,234.5`.
+    /// Sizes of groups aren't checked. Parsing fails if it's the same as the decimal separator.
+    pub const fn grouping_separator(mut self, separator: char) -> Self {
+        self.grouping_separator = Some(separator);
+        self
+    }
+
+    /// Accepts `_` between any digits, e.g. `1_000.000_1`.
+    pub const fn underscores(mut self, enabled: bool) -> Self {
+        self.underscores = enabled;
+        self
+    }
+
+    /// Rounds excess fractional digits according to `mode`.
+    pub const fn rounding(mut self, mode: RoundMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Fails on excess fractional digits like `from_str_exact`.
+    pub const fn exact(mut self) -> Self {
+        self.mode = None;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<RoundMode> for ParseOptions {
+    /// The default separators with the provided rounding.
+    fn from(mode: RoundMode) -> Self {
+        Self::DEFAULT.rounding(mode)
+    }
+}
+
+impl<I, P> FixedPoint<I, P>
+where
+    Self: Parse,
+{
+    /// Parses a string slice with custom separators or rounding, see [`ParseOptions`].
+    ///
+    /// Passing [`RoundMode`] rounds excess fractional digits explicitly instead of failing
+    /// like `from_str_exact` or rounding to the nearest like `FromStr`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{ops::RoundMode, typenum::U9, FixedPoint};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let input = "0.123456789987";
+    /// assert_eq!(Amount::from_str_with(input, RoundMode::Floor)?, "0.123456789".parse()?);
+    /// assert_eq!(Amount::from_str_with(input, RoundMode::Ceil)?, "0.12345679".parse()?);
+    /// assert!(Amount::from_str_exact(input).is_err());
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    pub fn from_str_with(
+        str: &str,
+        options: impl Into<ParseOptions>,
+    ) -> Result<Self, ConvertError> {
+        let options = options.into();
+        if Some(options.decimal_separator) == options.grouping_separator
+            || (options.decimal_separator == '_' && options.underscores)
+        {
+            return Err(ConvertError::new("ambiguous decimal separator"));
+        }
+
+        let offset = str.len() - str.trim_start().len();
+        let str = str.trim();
+        let end = offset + str.len();
+        let (mantissa, exponent) = match str.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (str, None),
+        };
+
+        let invalid = |reason, position| {
+            ConvertError::new(reason).at(ParseErrorKind::InvalidCharacter, offset + position)
+        };
+        let overflow = |reason| ConvertError::new(reason).at(ParseErrorKind::Overflow, offset);
+
+        let mut buf = Rewritten::default();
+        let mut chars = mantissa.char_indices().peekable();
+        match chars.peek() {
+            Some(&(position, '-')) => {
+                buf.push(b'-', offset + position);
+                chars.next();
+            }
+            Some((_, '+')) => {
+                chars.next();
+            }
+            _ => {}
+        }
+
+        let mut has_integral = false;
+        let mut integral_len = 0;
+        let mut is_fractional = false;
+        let mut fractional_len = 0;
+        let mut is_prev_digit = false;
+        // Digits that don't fit into the buffer affect only rounding.
+        let mut rest: Option<(bool, usize)> = None;
+
+        while let Some((position, c)) = chars.next() {
+            if c.is_ascii_digit() {
+                if is_fractional {
+                    if fractional_len < MAX_LEN {
+                        buf.push(c as u8, offset + position);
+                        fractional_len += 1;
+                    } else {
+                        rest = Some(match rest {
+                            Some((is_nonzero, first)) => (is_nonzero || c != '0', first),
+                            None => (c != '0', offset + position),
+                        });
+                    }
+                } else if c != '0' || integral_len > 0 {
+                    // Leading zeros are skipped.
+                    if integral_len == MAX_LEN {
+                        return Err(overflow("too big integral"));
+                    }
+                    buf.push(c as u8, offset + position);
+                    integral_len += 1;
+                }
+
+                has_integral |= !is_fractional;
+                is_prev_digit = true;
+            } else if c == options.decimal_separator && !is_fractional {
+                if has_integral && integral_len == 0 {
+                    buf.push(b'0', offset + position);
+                }
+                buf.push(b'.', offset + position);
+                is_fractional = true;
+                is_prev_digit = false;
+            } else if (Some(c) == options.grouping_separator && !is_fractional)
+                || (c == '_' && options.underscores)
+            {
+                if !is_prev_digit || !chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) {
+                    return Err(invalid("misplaced digit separator", position));
+                }
+                is_prev_digit = false;
+            } else {
+                return Err(invalid("unexpected character", position));
+            }
+        }
+
+        if !is_fractional && has_integral && integral_len == 0 {
+            buf.push(b'0', offset + mantissa.len());
+        }
+
+        // Only the first dropped digit and whether the rest is zero matter for rounding.
+        if let Some((is_nonzero, first)) = rest {
+            buf.push(if is_nonzero { b'1' } else { b'0' }, first);
+        }
+
+        if let Some(exponent) = exponent {
+            // Collapsed digits would be shifted by the exponent.
+            if rest.is_some() {
+                return Err(overflow("too long number"));
+            }
+
+            let exponent_start = mantissa.len() + 1;
+            if let Some(i) = exponent.bytes().position(|b| !b.is_ascii()) {
+                return Err(invalid("can't parse exponent", exponent_start + i));
+            }
+            if exponent.len() >= EXPONENT_LEN {
+                return Err(overflow("can't parse exponent"));
+            }
+
+            buf.push(b'e', offset + mantissa.len());
+            for (i, b) in exponent.bytes().enumerate() {
+                buf.push(b, offset + exponent_start + i);
+            }
+        }
+
+        // Positions refer to the rewritten string, point them at the original one.
+        Self::parse(buf.as_str(), options.mode).map_err(|err| match err.location() {
+            Some((ParseErrorKind::Overflow, _)) => err.at(ParseErrorKind::Overflow, offset),
+            Some((kind, position)) => err.at(kind, buf.original_position(position, end)),
+            None => err,
+        })
+    }
+    /// Parses a number from the front of `bytes` and returns it with the number of consumed
+    /// bytes. Everything after the number is left untouched, so delimited streams
+    /// (FIX, ITCH, CSV) can be parsed without slicing tokens out first.
+    ///
+    /// Accepts an optional sign, digits, an optional fractional part and an optional exponent.
+    /// Excess fractional digits are rounded to the nearest value like `FromStr` does.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{typenum::U9, FixedPoint};
+    ///
+    /// type Price = FixedPoint<i64, U9>;
+    ///
+    /// let message = b"44=101.25\x0138=100\x01";
+    /// let (price, len) = Price::parse_partial(&message[3..])?;
+    /// assert_eq!(price, "101.25".parse()?);
+    /// assert_eq!(len, 6);
+    /// assert!(Price::parse_partial(b"=1").is_err());
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    pub fn parse_partial(bytes: &[u8]) -> Result<(Self, usize), ConvertError> {
+        let digits_from = |start: usize| {
+            bytes[start.min(bytes.len())..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+        };
+
+        let mut len = usize::from(matches!(bytes.first(), Some(b'-' | b'+')));
+
+        let integral_len = digits_from(len);
+        if integral_len == 0 {
+            return Err(ConvertError::new("can't parse integral part"));
+        }
+        len += integral_len;
+
+        // The point is consumed only with fractional digits, e.g. `7.` is parsed as `7`.
+        let fractional_len = digits_from(len + 1);
+        if bytes.get(len) == Some(&b'.') && fractional_len > 0 {
+            len += 1 + fractional_len;
+        }
+
+        // The exponent is consumed only if it's complete, e.g. `1.5e` is parsed as `1.5`.
+        if matches!(bytes.get(len), Some(b'e' | b'E')) {
+            let sign_len = usize::from(matches!(bytes.get(len + 1), Some(b'-' | b'+')));
+            let exponent_len = digits_from(len + 1 + sign_len);
+
+            if exponent_len > 0 {
+                len += 1 + sign_len + exponent_len;
+            }
+        }
+
+        // Only ASCII characters are consumed.
+        let str = core::str::from_utf8(&bytes[..len])
+            .map_err(|_| ConvertError::new("unexpected character"))?;
+        Ok((Self::parse(str, Some(RoundMode::Nearest))?, len))
+    }
+}
@@ -0,0 +1,55 @@
+use core::ops::Add;
+
+use typenum::Sum;
+
+#[cfg(all(feature = "i128", feature = "i256"))]
+use crate::i256::I256;
+use crate::{FixedPoint, Precision};
+
+macro_rules! impl_full_mul {
+    ($layout:tt, $promotion:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Multiplies without rounding and overflow: the result has the sum of precisions
+            /// and the wider layout. It allows to defer rounding to the end of a calculation,
+            /// e.g. by [`rescale`](FixedPoint::rescale).
+            ///
+            /// ```
+            /// # #[cfg(all(feature = "i64", feature = "i128"))]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{typenum::{U4, U5, U9}, FixedPoint};
+            ///
+            /// let price: FixedPoint<i64, U4> = "1.2345".parse()?;
+            /// let quantity: FixedPoint<i64, U5> = "0.00001".parse()?;
+            /// let cost: FixedPoint<i128, U9> = price.full_mul(quantity);
+            /// assert_eq!(cost, "0.000012345".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(all(feature = "i64", feature = "i128")))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn full_mul<Q>(
+                self,
+                rhs: FixedPoint<$layout, Q>,
+            ) -> FixedPoint<$promotion, Sum<P, Q>>
+            where
+                Q: Precision,
+                P: Add<Q>,
+                Sum<P, Q>: Precision,
+            {
+                // The product always fits in the twice wider integer.
+                FixedPoint::from_bits($promotion::from(self.inner) * $promotion::from(rhs.inner))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_full_mul!(i16, i32);
+#[cfg(feature = "i32")]
+impl_full_mul!(i32, i64);
+#[cfg(feature = "i64")]
+impl_full_mul!(i64, i128);
+#[cfg(all(feature = "i128", feature = "i256"))]
+impl_full_mul!(i128, I256);
+#[cfg(feature = "u64")]
+impl_full_mul!(u64, u128);
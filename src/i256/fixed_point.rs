@@ -0,0 +1,765 @@
+//! `FixedPoint<I256, P>` implementation.
+//!
+//! Unlike primitive layouts, all operations work with absolute values and signs separately,
+//! `U512` is used to store intermediate results of multiplication and division.
+
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use super::{I256, U256, U512};
+use crate::float::{decompose, decompose_f32, rdiv_abs_by_exponent_10};
+use crate::ops::{sqrt::Sqrt, *};
+use crate::power_table::{power_of_10, rdiv_by_exponent_10};
+use crate::string::{
+    debug_number, max_str_len, pad_exponent, pad_number, parse_number, Number, Parse, StrBuf,
+    Stringify,
+};
+use crate::{
+    const_fn, ArithmeticError, ConvertError, FixedPoint, FormatOptions, Precision, Result,
+};
+
+impl<P: Precision> FixedPoint<I256, P> {
+    /// The number of digits in the fractional part.
+    pub const PRECISION: i32 = P::I32;
+    /// `0`, the same as [`Zero::ZERO`], but doesn't require the trait.
+    pub const ZERO: Self = Self::from_bits(I256::ZERO);
+    /// `1`, the same as [`One::ONE`], but doesn't require the trait.
+    pub const ONE: Self = Self::from_bits(I256::new(Self::COEF));
+    /// The difference between `0.0` and the next larger representable number.
+    pub const EPSILON: Self = Self::from_bits(I256::from_i128(1));
+    /// The smallest value, the same as [`Bounded::MIN`], but doesn't require the trait.
+    pub const MIN: Self = Self::from_bits(I256::MIN);
+    /// The largest value, the same as [`Bounded::MAX`], but doesn't require the trait.
+    pub const MAX: Self = Self::from_bits(I256::MAX);
+
+    const COEF: U256 = U256::from_u128(const_fn::pow10(Self::PRECISION) as u128);
+}
+
+/// Calculates `numerator / denominator` [rounded][RoundMode] according to `mode`
+/// and applies the sign.
+fn rdiv_abs(
+    numerator: U512,
+    denominator: U512,
+    is_negative: bool,
+    mode: RoundMode,
+) -> Result<I256> {
+    debug_assert_ne!(denominator, U512::ZERO);
+
+    let (mut result, loss) = numerator.div_mod(denominator);
+
+    if loss != U512::ZERO {
+        let sign = if is_negative { -1 } else { 1 };
+        // Compare `loss` with `denominator / 2` avoiding overflow.
+        let half = loss.cmp(&(denominator - loss));
+        let is_odd = result.0[0] % 2 != 0;
+
+        if mode.is_away_from_zero(sign, half, is_odd) {
+            result = result + U512::from(1u64);
+        }
+    }
+
+    let result = U256::try_from(result)?;
+    I256::from_sign_and_abs(is_negative, result).ok_or(ArithmeticError::Overflow)
+}
+
+impl<P: Precision> Zero for FixedPoint<I256, P> {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl<P: Precision> One for FixedPoint<I256, P> {
+    const ONE: Self = Self::ONE;
+}
+
+impl<P: Precision> Bounded for FixedPoint<I256, P> {
+    const MIN: Self = Self::MIN;
+    const MAX: Self = Self::MAX;
+}
+
+impl<P: Precision> RoundingMul for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+        let value = U512::from(self.inner.unsigned_abs()) * U512::from(rhs.inner.unsigned_abs());
+        let is_negative = self.inner.is_negative() != rhs.inner.is_negative();
+        rdiv_abs(value, U512::from(Self::COEF), is_negative, mode).map(Self::from_bits)
+    }
+}
+
+impl<P: Precision> SaturatingRMul for FixedPoint<I256, P> {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_rmul(self, rhs: Self, mode: RoundMode) -> Self::Output {
+        self.rmul(rhs, mode).unwrap_or_else(|_| {
+            if self.inner.is_negative() == rhs.inner.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+}
+
+impl<P: Precision> RoundingDiv for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+        if rhs.inner == I256::ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let numerator = U512::from(self.inner.unsigned_abs()) * U512::from(Self::COEF);
+        let denominator = U512::from(rhs.inner.unsigned_abs());
+        let is_negative = self.inner.is_negative() != rhs.inner.is_negative();
+        rdiv_abs(numerator, denominator, is_negative, mode).map(Self::from_bits)
+    }
+}
+
+impl<P: Precision> RoundingDiv<I256> for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: I256, mode: RoundMode) -> Result<Self> {
+        if rhs == I256::ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let numerator = U512::from(self.inner.unsigned_abs());
+        let denominator = U512::from(rhs.unsigned_abs());
+        let is_negative = self.inner.is_negative() != rhs.is_negative();
+        rdiv_abs(numerator, denominator, is_negative, mode).map(Self::from_bits)
+    }
+}
+
+impl<P: Precision> RoundingDiv<FixedPoint<I256, P>> for I256 {
+    type Output = FixedPoint<I256, P>;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: FixedPoint<I256, P>, mode: RoundMode) -> Result<FixedPoint<I256, P>> {
+        let lhs = FixedPoint::<I256, P>::ONE.cmul(self)?;
+        lhs.rdiv(rhs, mode)
+    }
+}
+
+impl<P: Precision> CheckedAdd for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cadd(self, rhs: Self) -> Result<Self> {
+        self.inner
+            .checked_add(rhs.inner)
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+}
+
+impl<P: Precision> SaturatingAdd for FixedPoint<I256, P> {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self::Output {
+        self.cadd(rhs).unwrap_or_else(|_| {
+            if rhs.inner.is_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        })
+    }
+}
+
+impl<P: Precision> CheckedSub for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn csub(self, rhs: Self) -> Result<Self> {
+        self.inner
+            .checked_sub(rhs.inner)
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+}
+
+impl<P: Precision> SaturatingSub for FixedPoint<I256, P> {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_sub(self, rhs: Self) -> Self::Output {
+        self.csub(rhs).unwrap_or_else(|_| {
+            if rhs.inner.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+}
+
+impl<P: Precision> CheckedMul<I256> for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cmul(self, rhs: I256) -> Result<Self> {
+        self.inner
+            .checked_mul(rhs)
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+}
+
+impl<P: Precision> SaturatingMul<I256> for FixedPoint<I256, P> {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_mul(self, rhs: I256) -> Self::Output {
+        self.cmul(rhs).unwrap_or_else(|_| {
+            if self.inner.is_negative() == rhs.is_negative() {
+                Self::MAX
+            } else {
+                Self::MIN
+            }
+        })
+    }
+}
+
+impl<P: Precision> CheckedMul<FixedPoint<I256, P>> for I256 {
+    type Output = FixedPoint<I256, P>;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cmul(self, rhs: FixedPoint<I256, P>) -> Result<FixedPoint<I256, P>> {
+        rhs.cmul(self)
+    }
+}
+
+impl<P: Precision> SaturatingMul<FixedPoint<I256, P>> for I256 {
+    type Output = FixedPoint<I256, P>;
+
+    #[inline]
+    fn saturating_mul(self, rhs: FixedPoint<I256, P>) -> Self::Output {
+        SaturatingMul::saturating_mul(rhs, self)
+    }
+}
+
+// Inherent methods take precedence over the deprecated ones of `Checked*` and `RoundingMul`,
+// so method calls aren't ambiguous when both traits are in scope, e.g. by `ops::*`.
+impl<P: Precision> FixedPoint<I256, P> {
+    /// The same as [`SaturatingAdd::saturating_add`].
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        SaturatingAdd::saturating_add(self, rhs)
+    }
+
+    /// The same as [`SaturatingSub::saturating_sub`].
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        SaturatingSub::saturating_sub(self, rhs)
+    }
+
+    /// The same as [`SaturatingMul::saturating_mul`].
+    #[inline]
+    pub fn saturating_mul(self, rhs: I256) -> Self {
+        SaturatingMul::saturating_mul(self, rhs)
+    }
+
+    /// The same as [`SaturatingRMul::saturating_rmul`].
+    #[inline]
+    pub fn saturating_rmul(self, rhs: Self, mode: RoundMode) -> Self {
+        SaturatingRMul::saturating_rmul(self, rhs, mode)
+    }
+}
+
+impl<P: Precision> FixedPoint<I256, P> {
+    /// Returns a number representing sign of self.
+    /// * `0` if the number is zero
+    /// * `1` if the number is positive
+    /// * `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> Self {
+        match self.inner.cmp(&I256::ZERO) {
+            Ordering::Less => Self::from_bits(-Self::ONE.inner),
+            Ordering::Equal => Self::ZERO,
+            Ordering::Greater => Self::ONE,
+        }
+    }
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`,
+    /// zero is considered positive.
+    /// Returns `Err` on overflow (you can't make [`MIN` value][MIN] positive).
+    ///
+    /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Result<Self> {
+        if self.inner.is_negative() == sign.inner.is_negative() {
+            Ok(self)
+        } else {
+            self.cneg()
+        }
+    }
+
+    /// Returns `1/n` [rounded][RoundMode] according to `mode`.
+    /// Returns [`ArithmeticError::DivisionByZero`] for zero
+    /// and [`ArithmeticError::Overflow`] if the result doesn't fit.
+    #[inline]
+    pub fn recip(self, mode: RoundMode) -> Result<Self> {
+        Self::ONE.rdiv(self, mode)
+    }
+
+    /// The same as [`recip`](Self::recip), named after the primitive integers' methods.
+    #[inline]
+    pub fn checked_recip(self, mode: RoundMode) -> Result<Self> {
+        self.recip(mode)
+    }
+
+    /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
+    ///
+    /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+    #[inline]
+    pub fn cneg(self) -> Result<Self> {
+        self.inner
+            .checked_neg()
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// The same as [`cneg`](Self::cneg), named after the primitive integers' method.
+    #[inline]
+    pub fn checked_neg(self) -> Result<Self> {
+        self.cneg()
+    }
+
+    /// Calculates `(a + b) / 2`.
+    #[inline]
+    pub fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+        let (a_abs, b_abs) = (a.inner.unsigned_abs(), b.inner.unsigned_abs());
+
+        let (is_negative, sum) = if a.inner.is_negative() == b.inner.is_negative() {
+            (a.inner.is_negative(), U512::from(a_abs) + U512::from(b_abs))
+        } else if a_abs >= b_abs {
+            (a.inner.is_negative(), U512::from(a_abs - b_abs))
+        } else {
+            (b.inner.is_negative(), U512::from(b_abs - a_abs))
+        };
+
+        // The half of the sum always fits.
+        rdiv_abs(sum, U512::from(2u64), is_negative, mode)
+            .map(Self::from_bits)
+            .unwrap()
+    }
+
+    /// Takes [rounded][RoundMode] integral part of the number.
+    #[inline]
+    pub fn integral(self, mode: RoundMode) -> I256 {
+        let value = U512::from(self.inner.unsigned_abs());
+        // `|integral| <= |self|`, thus it always fits.
+        rdiv_abs(
+            value,
+            U512::from(Self::COEF),
+            self.inner.is_negative(),
+            mode,
+        )
+        .unwrap()
+    }
+
+    /// Returns the largest integer less than or equal to a number.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self::ONE.cmul(self.integral(RoundMode::Floor)).unwrap()
+    }
+
+    /// Returns the smallest integer greater than or equal to a number.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self::ONE.cmul(self.integral(RoundMode::Ceil)).unwrap()
+    }
+
+    /// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self::ONE.cmul(self.integral(RoundMode::Nearest)).unwrap()
+    }
+
+    /// Returns the absolute value of a number.
+    #[inline]
+    pub fn abs(self) -> Result<Self> {
+        if self.inner.is_negative() {
+            self.cneg()
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// The same as [`abs`](Self::abs), named after the primitive integers' method.
+    /// Returns `Err` on overflow (you can't take the absolute value of [`MIN` value][MIN]).
+    ///
+    /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+    #[inline]
+    pub fn checked_abs(self) -> Result<Self> {
+        self.abs()
+    }
+
+    /// Checked [rounding][RoundMode] square root.
+    /// Returns `Err` for negative argument.
+    ///
+    /// Square root of a non-negative F is a non-negative S such that:
+    /// * `Floor`: `S ≤ sqrt(F)`
+    /// * `Ceil`: `S ≥ sqrt(F)`
+    /// * `Nearest` and `HalfEven`: `Floor` or `Ceil`, which one is closer to `sqrt(F)`
+    ///
+    /// The fastest mode is `Floor`.
+    #[inline]
+    pub fn rsqrt(self, mode: RoundMode) -> Result<Self> {
+        if self.inner.is_negative() {
+            return Err(ArithmeticError::DomainViolation);
+        }
+
+        // `sqrt(S) * COEF = sqrt(S_inner * COEF)`, see the primitive layouts.
+        let squared = U512::from(self.inner.unsigned_abs()) * U512::from(Self::COEF);
+        let lo = squared.sqrt()?;
+
+        let add_one = match mode {
+            RoundMode::Floor => false,
+            // The square root of an integer is never a half-way case.
+            RoundMode::Nearest | RoundMode::HalfEven => {
+                let lo2 = lo * lo;
+                // (lo+1)^2 = lo^2 +2lo + 1
+                let hi2 = lo2 + lo + lo + U512::from(1u64);
+                squared - lo2 >= hi2 - squared
+            }
+            RoundMode::Ceil => lo * lo != squared,
+        };
+
+        // `sqrt` can't take more bits than `self` already does, thus `unwrap()` is ok.
+        let lo = U256::try_from(lo).unwrap();
+        let inner = if add_one { lo + U256::ONE } else { lo };
+
+        Ok(Self::from_bits(I256::new(inner)))
+    }
+
+    /// The maximum length of strings produced by [`write_to`](Self::write_to)
+    /// and `Display` without flags.
+    // `I256::MIN` has 77 digits.
+    pub const MAX_STR_LEN: usize = max_str_len(true, 77, P::U32);
+
+    /// Parses a string slice into a fixed point.
+    /// If the value cannot be represented then this will return an error.
+    ///
+    /// Use the `FromStr` instance to parse with rounding.
+    pub fn from_str_exact(str: &str) -> Result<Self, ConvertError> {
+        Self::parse_str::<true>(str, RoundMode::Nearest)
+    }
+
+    /// Parses a string slice. Excess fractional digits are rounded according to `mode`.
+    /// `mode` is ignored if `EXACT` is set.
+    fn parse_str<const EXACT: bool>(str: &str, mode: RoundMode) -> Result<Self, ConvertError> {
+        parse_number(str, P::U32, |number| {
+            Self::parse_plain::<EXACT>(number, mode)
+        })
+    }
+
+    fn parse_plain<const EXACT: bool>(
+        number: &Number<'_>,
+        mode: RoundMode,
+    ) -> Result<Self, ConvertError> {
+        let is_negative = number.is_negative;
+        let fractional_str = number.fractional;
+
+        let integral = parse_digits(number.integral)
+            .ok_or_else(|| number.overflow("can't parse integral part"))?;
+
+        let prec = Self::PRECISION as usize;
+
+        if EXACT && fractional_str.len() > prec {
+            return Err(number.too_many_fractional_digits(prec));
+        }
+
+        // Digits that cannot be represented and must be rounded.
+        let (fractional_str, dropped) = if fractional_str.len() > prec {
+            let (kept, dropped) = fractional_str.split_at(prec);
+            (kept, dropped.trim_end_matches('0'))
+        } else {
+            (fractional_str, "")
+        };
+
+        // Can be empty for zero precision.
+        let fractional = if fractional_str.is_empty() {
+            U256::ZERO
+        } else {
+            parse_digits(fractional_str)
+                .ok_or_else(|| number.overflow("can't parse fractional part"))?
+        };
+
+        let exp = U256::from_u128(const_fn::pow10(fractional_str.len() as i32) as u128);
+
+        let (final_integral, is_overflow) = integral.overflowing_mul(Self::COEF);
+        if is_overflow {
+            return Err(number.overflow("too big integral"));
+        }
+
+        let final_fractional = Self::COEF / exp * fractional;
+
+        let (mut value, is_overflow) = final_integral.overflowing_add(final_fractional);
+        if is_overflow {
+            return Err(number.overflow("too big number"));
+        }
+
+        if !dropped.is_empty() {
+            debug_assert!(!EXACT);
+
+            // `dropped` has no trailing zeros, so it's a half only if it's exactly "5".
+            let half = dropped.as_bytes()[0]
+                .cmp(&b'5')
+                .then_with(|| dropped.len().cmp(&1));
+            let sign = if is_negative { -1 } else { 1 };
+
+            if mode.is_away_from_zero(sign, half, value.0[0] % 2 != 0) {
+                let (rounded, is_overflow) = value.overflowing_add(U256::ONE);
+                if is_overflow {
+                    return Err(number.overflow("too big number"));
+                }
+                value = rounded;
+            }
+        }
+
+        I256::from_sign_and_abs(is_negative, value)
+            .map(Self::from_bits)
+            .ok_or_else(|| number.overflow("too big number"))
+    }
+}
+
+/// Parses a non-empty string of decimal digits.
+fn parse_digits(str: &str) -> Option<U256> {
+    if str.is_empty() {
+        return None;
+    }
+
+    str.bytes().try_fold(U256::ZERO, |value, byte| {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+
+        let (value, is_overflow) = value.overflowing_mul(U256::from(10u64));
+        let digit = U256::from(u64::from(byte - b'0'));
+        let (value, is_carry) = value.overflowing_add(digit);
+        (!is_overflow && !is_carry).then_some(value)
+    })
+}
+
+impl<P: Precision> FromStr for FixedPoint<I256, P> {
+    type Err = ConvertError;
+
+    /// Parses a string slice into a fixed point.
+    /// If the value cannot be represented, it will be rounded to the nearest value.
+    /// Half-way cases are rounded away from zero (see [`RoundMode::Nearest`])
+    /// unless another default mode is [configured][RoundMode].
+    /// Exponent notation is accepted, e.g. `1.5e-3` or `2E6`.
+    ///
+    /// Use `from_str_exact` to parse without rounding.
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::parse_str::<false>(str, RoundMode::Nearest.or_configured())
+    }
+}
+
+impl<P: Precision> Parse for FixedPoint<I256, P> {
+    fn parse(str: &str, mode: Option<RoundMode>) -> Result<Self, ConvertError> {
+        match mode {
+            Some(mode) => Self::parse_str::<false>(str, mode),
+            None => Self::parse_str::<true>(str, RoundMode::Nearest),
+        }
+    }
+}
+
+impl<P: Precision> Stringify for FixedPoint<I256, P> {
+    fn stringify(&self, buf: &mut StrBuf) {
+        // `10^38` is the largest power of ten fitting in `u128`.
+        const E38: u128 = 10u128.pow(38);
+
+        let mut fmt = itoa::Buffer::new();
+
+        if self.inner.is_negative() {
+            buf.push('-');
+        }
+
+        let (integral, fractional) = self.inner.unsigned_abs().div_mod(Self::COEF);
+
+        // `2^256 / 10^76 < 10^38 < 2^128`, thus all parts fit in `u128`.
+        let (hi, lo) = integral.div_mod(U256::from_u128(E38));
+        let (hi, mid) = hi.div_mod(U256::from_u128(E38));
+        let (hi, mid, lo) = (
+            u128::try_from(hi).unwrap(),
+            u128::try_from(mid).unwrap(),
+            u128::try_from(lo).unwrap(),
+        );
+
+        if hi > 0 {
+            buf.push_str(fmt.format(hi));
+            buf.push_str(&fmt.format(mid + E38)[1..]);
+            buf.push_str(&fmt.format(lo + E38)[1..]);
+        } else if mid > 0 {
+            buf.push_str(fmt.format(mid));
+            buf.push_str(&fmt.format(lo + E38)[1..]);
+        } else {
+            buf.push_str(fmt.format(lo));
+        }
+
+        buf.push('.');
+
+        // `fractional < COEF <= 10^38`.
+        let fractional = u128::try_from(fractional).unwrap();
+        let coef = u128::try_from(Self::COEF).unwrap();
+
+        if fractional > 0 {
+            let fractional_with_leading_one = fractional + coef;
+            let s = &fmt.format(fractional_with_leading_one)[1..];
+            buf.push_str(s.trim_end_matches('0'));
+        } else {
+            buf.push('0');
+        }
+    }
+}
+
+impl<P: Precision> fmt::Debug for FixedPoint<I256, P> {
+    /// Writes the decimal value, e.g. `10.042`. The alternate flag (`{:#?}`) adds the type
+    /// and the raw representation: `FixedPoint<I256, U9>(10.042 = 10042000000)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Default::default();
+        self.stringify(&mut buf);
+        if f.alternate() {
+            debug_number(buf.as_str(), "I256", P::U32, f)
+        } else {
+            f.write_str(buf.as_str())
+        }
+    }
+}
+
+impl<P: Precision> fmt::Display for FixedPoint<I256, P> {
+    /// Supports precision (rounding half to even), width, fill, alignment and sign flags.
+    /// The alternate flag (`{:#}`) writes exactly `P` fractional digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Default::default();
+        self.stringify(&mut buf);
+        pad_number(buf.as_str(), P::U32, &FormatOptions::DEFAULT, f)
+    }
+}
+
+impl<P: Precision> fmt::LowerExp for FixedPoint<I256, P> {
+    /// Writes normalized scientific notation like primitive integers do, e.g. `1.5e-3`.
+    /// Supports precision (rounding half to even), width, fill, alignment and sign flags.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Default::default();
+        self.stringify(&mut buf);
+        pad_exponent(buf.as_str(), false, f)
+    }
+}
+
+impl<P: Precision> fmt::UpperExp for FixedPoint<I256, P> {
+    /// The same as `LowerExp`, but with `E`, e.g. `1.5E-3`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Default::default();
+        self.stringify(&mut buf);
+        pad_exponent(buf.as_str(), true, f)
+    }
+}
+
+impl<P: Precision> From<FixedPoint<I256, P>> for f64 {
+    fn from(value: FixedPoint<I256, P>) -> Self {
+        // Parsing the decimal representation gives the correctly rounded result,
+        // while combining 64-bit words accumulates rounding errors.
+        let mut buf = Default::default();
+        value.stringify(&mut buf);
+        buf.as_str().parse().unwrap()
+    }
+}
+
+impl<P: Precision> From<FixedPoint<I256, P>> for f32 {
+    fn from(value: FixedPoint<I256, P>) -> Self {
+        let mut buf = Default::default();
+        value.stringify(&mut buf);
+        buf.as_str().parse().unwrap()
+    }
+}
+
+impl<P: Precision> TryFrom<f64> for FixedPoint<I256, P> {
+    type Error = ConvertError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::from_decomposed(decompose(value)?)
+    }
+}
+
+impl<P: Precision> TryFrom<f32> for FixedPoint<I256, P> {
+    type Error = ConvertError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::from_decomposed(decompose_f32(value)?)
+    }
+}
+
+impl<P: Precision> FixedPoint<I256, P> {
+    fn from_decomposed(parts: Option<(bool, u128, i32)>) -> Result<Self, ConvertError> {
+        let (positive, bits, exponent10) = match parts {
+            Some(parts) => parts,
+            None => return Ok(Self::ZERO),
+        };
+
+        // exponent10 must equal to -PRECISION, see the primitive layouts.
+        let mut abs = U256::from(bits);
+
+        if exponent10 > -Self::PRECISION {
+            let mut exponent = (exponent10 + Self::PRECISION) as u32;
+
+            while exponent > 0 {
+                // `10^38` is the largest power of ten fitting in `u128`.
+                let step = exponent.min(38);
+                let multiplier = U256::from(power_of_10(step).unwrap());
+                let (result, is_overflow) = abs.overflowing_mul(multiplier);
+
+                if is_overflow {
+                    return Err(ConvertError::new("too big number"));
+                }
+
+                abs = result;
+                exponent -= step;
+            }
+        } else if exponent10 < -Self::PRECISION {
+            let exp10 = (-Self::PRECISION - exponent10) as u32;
+            abs = U256::from(match RoundMode::configured() {
+                Some(mode) => rdiv_abs_by_exponent_10(bits, exp10, positive, mode),
+                None => rdiv_by_exponent_10(bits, exp10),
+            });
+        }
+
+        I256::from_sign_and_abs(!positive, abs)
+            .map(Self::from_bits)
+            .ok_or_else(|| ConvertError::new("too big number"))
+    }
+}
+
+macro_rules! impl_try_from_int {
+    ($( $int:ty => $via:ty ),* $(,)?) => {
+        $(
+            impl<P: Precision> TryFrom<$int> for FixedPoint<I256, P> {
+                type Error = ConvertError;
+
+                fn try_from(value: $int) -> Result<Self, Self::Error> {
+                    Self::ONE
+                        .cmul(I256::from(value as $via))
+                        .map_err(|_| ConvertError::new("too big number"))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int!(
+    i8 => i128, u8 => u128, i16 => i128, u16 => u128, i32 => i128, u32 => u128,
+    i64 => i128, u64 => u128, i128 => i128, u128 => u128, isize => i128, usize => u128,
+);
@@ -2,7 +2,7 @@ use core::cmp::{Ordering, PartialOrd};
 use core::ops::{Add, Div, Mul, Neg, Sub};
 
 use crate::ops::sqrt::Sqrt;
-use crate::ops::{One, Zero};
+use crate::ops::{One, RoundMode, RoundingDiv, Zero};
 use crate::{ArithmeticError, ConvertError};
 
 const TOTAL_BITS_COUNT: usize = 256;
@@ -10,27 +10,37 @@ const UINT_CHUNK_BITS_COUNT: usize = 64;
 const UINT_CHUNKS_COUNT: usize = TOTAL_BITS_COUNT / UINT_CHUNK_BITS_COUNT;
 const SIGN_MASK: u64 = 1 << (UINT_CHUNK_BITS_COUNT - 1); // MSB = 1, other are equal to 0.
 
+#[cfg(feature = "i256")]
+mod fixed_point;
 mod u256;
 
-use u256::U256;
+pub(crate) use u256::U256;
+#[cfg(feature = "i256")]
+pub(crate) use u256::U512;
 
 /// Signed 256-bit number. Works on top of U256 with help of two's complement.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct I256 {
     inner: U256,
 }
 
 impl I256 {
+    /// `i128::MAX` as `I256`.
     pub const I128_MAX: Self = Self::from_i128(i128::MAX);
+    /// `i128::MIN` as `I256`.
     pub const I128_MIN: Self = Self::from_i128(i128::MIN);
+    /// `u128::MAX` as `I256`.
     pub const U128_MAX: Self = Self::new(U256([u64::MAX, u64::MAX, 0, 0]));
+    /// The largest value, `2^255 - 1`.
     pub const MAX: Self = Self::new(U256([u64::MAX, u64::MAX, u64::MAX, !SIGN_MASK]));
+    /// The smallest value, `-2^255`.
     pub const MIN: Self = Self::new(U256([0, 0, 0, SIGN_MASK]));
 
     const fn new(x: U256) -> Self {
         I256 { inner: x }
     }
 
+    /// Creates from `i128` in const context.
     pub const fn from_i128(x: i128) -> Self {
         let msb = if x < 0 { u64::MAX } else { 0 };
         Self::new(U256([x as u64, (x >> 64) as u64, msb, msb])) // The only way to do it const
@@ -44,6 +54,59 @@ impl I256 {
     const fn chunks(&self) -> &[u64; UINT_CHUNKS_COUNT] {
         &self.inner.0
     }
+
+    /// Returns the absolute value, `I256::MIN` is mapped to `2^255`.
+    pub(crate) fn unsigned_abs(self) -> U256 {
+        if self.is_negative() {
+            // Unlike `neg()`, it's fine for `I256::MIN`.
+            (!self.inner).overflowing_add(Self::ONE.inner).0
+        } else {
+            self.inner
+        }
+    }
+
+    /// Creates from the sign and the absolute value. Returns `None` on overflow.
+    pub(crate) fn from_sign_and_abs(is_negative: bool, abs: U256) -> Option<Self> {
+        let value = Self::new(abs);
+
+        if !value.is_negative() {
+            Some(if is_negative { -value } else { value })
+        } else if is_negative && value == Self::MIN {
+            Some(Self::MIN)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(any(feature = "i128", feature = "u128", feature = "i256"))]
+    pub(crate) fn checked_add(self, rhs: Self) -> Option<Self> {
+        let result = self + rhs;
+        let is_overflow =
+            self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative();
+        (!is_overflow).then_some(result)
+    }
+
+    #[cfg(any(feature = "i128", feature = "i256"))]
+    pub(crate) fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let result = self - rhs;
+        let is_overflow =
+            self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative();
+        (!is_overflow).then_some(result)
+    }
+
+    #[cfg(any(feature = "i128", feature = "u128", feature = "i256"))]
+    pub(crate) fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (abs, is_overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        let is_negative = self.is_negative() != rhs.is_negative();
+        (!is_overflow)
+            .then_some(abs)
+            .and_then(|abs| Self::from_sign_and_abs(is_negative, abs))
+    }
+
+    #[cfg(any(feature = "i128", feature = "u128", feature = "i256"))]
+    pub(crate) fn checked_neg(self) -> Option<Self> {
+        (self != Self::MIN).then(|| -self)
+    }
 }
 
 impl Mul for I256 {
@@ -144,6 +207,20 @@ impl From<i128> for I256 {
     }
 }
 
+macro_rules! impl_from_int {
+    ($( $int:ty ),*) => {
+        $(
+            impl From<$int> for I256 {
+                fn from(x: $int) -> Self {
+                    Self::from_i128(x.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
 impl TryFrom<I256> for i128 {
     type Error = ArithmeticError;
 
@@ -190,6 +267,171 @@ impl Sqrt for I256 {
     }
 }
 
+impl RoundingDiv for I256 {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self, Self::Error> {
+        if rhs == Self::ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let numerator = self.unsigned_abs();
+        let denominator = rhs.unsigned_abs();
+        let is_negative = self.is_negative() != rhs.is_negative();
+
+        let (mut result, loss) = numerator.div_mod(denominator);
+
+        if loss != U256::ZERO {
+            let sign = if is_negative { -1 } else { 1 };
+            // Compare `loss` with `denominator / 2` avoiding overflow.
+            let half = loss.cmp(&(denominator - loss));
+            let is_odd = result.0[0] % 2 != 0;
+
+            if mode.is_away_from_zero(sign, half, is_odd) {
+                // `result <= 2^255`, so it can't overflow.
+                result = result + U256::from_u128(1);
+            }
+        }
+
+        Self::from_sign_and_abs(is_negative, result).ok_or(ArithmeticError::Overflow)
+    }
+}
+
+impl RoundingDiv for U256 {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self, Self::Error> {
+        if rhs == Self::ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let (mut result, loss) = self.div_mod(rhs);
+
+        if loss != Self::ZERO {
+            // Compare `loss` with `rhs / 2` avoiding overflow.
+            let half = loss.cmp(&(rhs - loss));
+            let is_odd = result.0[0] % 2 != 0;
+
+            if mode.is_away_from_zero(1, half, is_odd) {
+                // `result < self`, so it can't overflow.
+                result = result + U256::from_u128(1);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl U256 {
+    pub(crate) const fn from_u128(x: u128) -> Self {
+        Self([x as u64, (x >> 64) as u64, 0, 0]) // The only way to do it const
+    }
+
+    #[cfg(feature = "u128")]
+    pub(crate) fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (result, is_overflow) = self.overflowing_add(rhs);
+        (!is_overflow).then_some(result)
+    }
+
+    #[cfg(feature = "u128")]
+    pub(crate) fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (result, is_overflow) = self.overflowing_sub(rhs);
+        (!is_overflow).then_some(result)
+    }
+}
+
+macro_rules! impl_add_sub {
+    ($uint:ty) => {
+        impl Add for $uint {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                let (x, overflow) = self.overflowing_add(rhs);
+                debug_assert!(!overflow);
+                x
+            }
+        }
+
+        impl Sub for $uint {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                let (x, overflow) = self.overflowing_sub(rhs);
+                debug_assert!(!overflow);
+                x
+            }
+        }
+    };
+}
+
+impl_add_sub!(U256);
+#[cfg(feature = "i256")]
+impl_add_sub!(U512);
+
+impl One for U256 {
+    const ONE: Self = Self::from_u128(1);
+}
+
+#[cfg(feature = "i256")]
+impl From<U256> for U512 {
+    fn from(x: U256) -> Self {
+        let mut words = [0; 8];
+        words[..UINT_CHUNKS_COUNT].copy_from_slice(&x.0);
+        U512(words)
+    }
+}
+
+#[cfg(feature = "i256")]
+impl TryFrom<U512> for U256 {
+    type Error = ArithmeticError;
+
+    fn try_from(x: U512) -> Result<Self, Self::Error> {
+        if x.0[UINT_CHUNKS_COUNT..].iter().any(|&word| word != 0) {
+            return Err(ArithmeticError::Overflow);
+        }
+
+        let mut words = [0; UINT_CHUNKS_COUNT];
+        words.copy_from_slice(&x.0[..UINT_CHUNKS_COUNT]);
+        Ok(U256(words))
+    }
+}
+
+#[cfg(all(feature = "i256", feature = "serde"))]
+impl serde::Serialize for I256 {
+    /// Serializes as little-endian 64-bit words of two's complement.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.chunks().serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "i256", feature = "serde"))]
+impl<'de> serde::Deserialize<'de> for I256 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[u64; UINT_CHUNKS_COUNT]>::deserialize(deserializer).map(|words| Self::new(U256(words)))
+    }
+}
+
+#[cfg(all(feature = "i256", feature = "borsh"))]
+impl borsh::BorshSerialize for I256 {
+    /// Serializes as little-endian 64-bit words of two's complement.
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.chunks().serialize(writer)
+    }
+}
+
+#[cfg(all(feature = "i256", feature = "borsh"))]
+impl borsh::BorshDeserialize for I256 {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u64; UINT_CHUNKS_COUNT]>::deserialize_reader(reader).map(|words| Self::new(U256(words)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -333,4 +575,25 @@ mod tests {
         t(35, 5, 7);
         t(-35, 5, -7);
     }
+
+    #[test]
+    fn rdiv() {
+        fn t(a: i128, b: i128, mode: RoundMode, expected: i128) {
+            let result = I256::from(a).rdiv(I256::from(b), mode).unwrap();
+            assert_eq!(i128::try_from(result).unwrap(), expected);
+            assert_eq!(a.rdiv(b, mode).unwrap(), expected);
+        }
+        t(7, 2, RoundMode::Floor, 3);
+        t(7, 2, RoundMode::Nearest, 4);
+        t(-7, 2, RoundMode::Nearest, -4);
+        t(-7, 2, RoundMode::Floor, -4);
+        t(-7, 2, RoundMode::Ceil, -3);
+        t(7, -3, RoundMode::Nearest, -2);
+        t(6, 3, RoundMode::Ceil, 2);
+
+        let err = I256::ONE.rdiv(I256::ZERO, RoundMode::Floor);
+        assert_eq!(err, Err(ArithmeticError::DivisionByZero));
+        let err = I256::MIN.rdiv(-I256::ONE, RoundMode::Floor);
+        assert_eq!(err, Err(ArithmeticError::Overflow));
+    }
 }
@@ -59,7 +59,7 @@ macro_rules! uint_overflowing_binop {
 
 macro_rules! uint_full_mul_reg {
     ($name:ident, 8, $self_expr:expr, $other:expr) => {
-        $crate::uint_full_mul_reg!($name, 8, $self_expr, $other, |a, b| a != 0 || b != 0);
+        uint_full_mul_reg!($name, 8, $self_expr, $other, |a, b| a != 0 || b != 0)
     };
     ($name:ident, $n_words:tt, $self_expr:expr, $other:expr) => {
         uint_full_mul_reg!($name, $n_words, $self_expr, $other, |_, _| true)
@@ -286,11 +286,11 @@ macro_rules! uint {
             ///
             /// # Panics
             ///
-            /// Panics if the number is larger than usize::max_value().
+            /// Panics if the number is larger than usize::MAX.
             #[inline]
             fn as_usize(&self) -> usize {
                 let &$name(ref arr) = self;
-                if !self.fits_word() || arr[0] > usize::max_value() as u64 {
+                if !self.fits_word() || arr[0] > usize::MAX as u64 {
                     panic!("Integer overflow when casting to usize")
                 }
                 arr[0] as usize
@@ -412,7 +412,7 @@ macro_rules! uint {
                         q_hat
                     } else {
                         // here q_hat >= q_j >= q_hat - 1
-                        u64::max_value()
+                        u64::MAX
                     };
 
                     // ex. 20:
@@ -457,7 +457,7 @@ macro_rules! uint {
             /// # Panics
             ///
             /// Panics if `other` is zero.
-            fn div_mod(self, other: Self) -> (Self, Self) {
+            pub(crate) fn div_mod(self, other: Self) -> (Self, Self) {
                 let my_bits = self.bits();
                 let your_bits = other.bits();
 
@@ -611,7 +611,7 @@ macro_rules! uint {
                 (self, carry)
             }
 
-            fn leading_zeros(&self) -> u32 {
+            pub(crate) fn leading_zeros(&self) -> u32 {
                 self.0.iter().rev().fold((0, false), |(acc, one_was_met), &chunk| {
                     if one_was_met {
                         (acc, true)
@@ -812,7 +812,7 @@ macro_rules! uint {
                     Err(_) => {
                         let lo = (self >> 2u32).sqrt()? << 1u32;
                         let hi = least_significant_word_or(lo, 1);
-                        let (hi_square, _): (U256, _) = hi.overflowing_mul(hi);
+                        let (hi_square, _): ($name, _) = hi.overflowing_mul(hi);
                         if hi_square <= self {
                             hi
                         } else {
@@ -842,12 +842,22 @@ macro_rules! uint {
         { const $v: usize = $a + 2; $c }
         { const $v: usize = $a + 3; $c }
     };
+
+    (@unroll @$v:ident, $a:expr, 8, $c:block) => {
+        uint!(@unroll @$v, $a, 4, $c);
+        uint!(@unroll @$v, $a + 4, 4, $c);
+    };
 }
 
 uint! {
     pub(crate) struct U256(4);
 }
 
+#[cfg(feature = "i256")]
+uint! {
+    pub(crate) struct U512(8);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,52 @@
+use core::iter::{Product, Sum};
+
+use crate::ops::*;
+use crate::{ArithmeticError, FixedPoint};
+
+type Result<T> = core::result::Result<T, ArithmeticError>;
+
+/// Returns `Err` on overflow, see also [`CheckedSum`].
+impl<I, P> Sum<FixedPoint<I, P>> for Result<FixedPoint<I, P>>
+where
+    FixedPoint<I, P>: Zero + CheckedAdd<Output = FixedPoint<I, P>, Error = ArithmeticError>,
+{
+    fn sum<It: Iterator<Item = FixedPoint<I, P>>>(iter: It) -> Self {
+        iter.checked_sum()
+    }
+}
+
+/// Returns `Err` on overflow, see also [`CheckedSum`].
+impl<'a, I, P> Sum<&'a FixedPoint<I, P>> for Result<FixedPoint<I, P>>
+where
+    FixedPoint<I, P>: Copy + Zero + CheckedAdd<Output = FixedPoint<I, P>, Error = ArithmeticError>,
+{
+    fn sum<It: Iterator<Item = &'a FixedPoint<I, P>>>(iter: It) -> Self {
+        iter.copied().checked_sum()
+    }
+}
+
+/// Returns `Err` on overflow.
+/// Every multiplication is rounded to the nearest value (see [`RoundMode::Nearest`])
+/// unless another default mode is [configured][RoundMode], use `try_fold` with [`RoundingMul`] to choose another mode.
+impl<I, P> Product<FixedPoint<I, P>> for Result<FixedPoint<I, P>>
+where
+    FixedPoint<I, P>: One + RoundingMul<Output = FixedPoint<I, P>, Error = ArithmeticError>,
+{
+    fn product<It: Iterator<Item = FixedPoint<I, P>>>(mut iter: It) -> Self {
+        iter.try_fold(<FixedPoint<I, P> as One>::ONE, |acc, x| {
+            acc.rmul(x, RoundMode::Nearest.or_configured())
+        })
+    }
+}
+
+/// Returns `Err` on overflow.
+/// Every multiplication is rounded to the nearest value (see [`RoundMode::Nearest`])
+/// unless another default mode is [configured][RoundMode], use `try_fold` with [`RoundingMul`] to choose another mode.
+impl<'a, I, P> Product<&'a FixedPoint<I, P>> for Result<FixedPoint<I, P>>
+where
+    FixedPoint<I, P>: Copy + One + RoundingMul<Output = FixedPoint<I, P>, Error = ArithmeticError>,
+{
+    fn product<It: Iterator<Item = &'a FixedPoint<I, P>>>(iter: It) -> Self {
+        iter.copied().product()
+    }
+}
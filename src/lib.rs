@@ -2,7 +2,7 @@
 //!
 //! [Fixed-point][FixedPoint] numbers with explicit rounding.
 //!
-//! Uses various signed integer types to store the number.
+//! Uses various signed and unsigned integer types to store the number.
 //!
 //! ## Features
 //! Turn them on in `Cargo.toml`:
@@ -12,13 +12,69 @@
 //! - `i64` — `i64` layout support which will be promoted to `i128` for multiplication and division.
 //! - `i32` — `i32` layout support which will be promoted to `i64` for multiplication and division.
 //! - `i16` — `i16` layout support which will be promoted to `i32` for multiplication and division.
-//! - `parity` — [`parity-scale-codec`][parity_scale_codec] support (`Encode` and `Decode`
-//!   implementations).
+//! - `u128` — `u128` layout support which will be promoted to internally implemented `U256` for
+//!   multiplication and division.
+//! - `u64` — `u64` layout support which will be promoted to `u128` for multiplication and division.
+//! - `i256` — internally implemented [`I256`] layout support for values that don't fit in `i128`,
+//!   e.g. token amounts with 18 decimal places. Only a basic subset of operations is available
+//!   (arithmetic, rounding, `rsqrt`, conversions), the `fixnum!` macro isn't supported.
+//! - `diesel` — [`diesel`][diesel] support of Postgres `NUMERIC` and MySQL `DECIMAL` (`ToSql` and
+//!   `FromSql` implementations), allows to use `FixedPoint` in `Queryable` and `Insertable` structs.
+//! - `parity` — [`parity-scale-codec`][parity_scale_codec] support (`Encode`, `Decode`,
+//!   `CompactAs`, `MaxEncodedLen` and [`scale_info::TypeInfo`][scale_info] implementations).
+//! - `num-traits` — [`num-traits`][num_traits] implementations; `Zero`, `One`, `Num`, `Signed`
+//!   and `Checked*` additionally require `panicking-ops` for their operator supertraits.
+//! - `panicking-ops` — `core::ops` operators (`+`, `-`, `*`, `/`, `%`, `+=`, etc.) panicking on
+//!   overflow like primitive integers do, handy for prototypes and tests.
+//! - `borsh` — [`borsh`][borsh] serialization as the raw layout integer.
+//! - `arrow` — [conversions][arrow] between `FixedPoint<i128, P>` and [`arrow`][arrow_array]'s
+//!   `Decimal128` values and arrays.
+//! - `bson` — [conversions][bson] from and to [`bson::Decimal128`][bson_decimal128] with helpers for
+//!   `#[serde(with)]`.
+//! - `async-graphql` — [`async-graphql`][async_graphql] scalars named like `FixedPoint_i64_9`,
+//!   accepting strings and numbers and output as strings.
+//! - `pg-numeric` — [encoding and decoding][pg_numeric] of Postgres' binary `NUMERIC` format.
+//! - `money-proto` — [conversions][money_proto] from and to the `(units, nanos)` pair of Google's
+//!   `money.proto` for gRPC services.
+//! - `fix` — [formatting and parsing][fix] of FIX protocol `float` fields without allocations.
+//! - `currency` — ISO 4217 [minor units][currency] and money formatting like `$1,234.56`.
+//! - `arbitrary` — [`arbitrary::Arbitrary`][arbitrary] implementations for fuzzing, biased toward
+//!   boundary values.
+//! - `proptest` — [`proptest::arbitrary::Arbitrary`][proptest] implementations biased toward
+//!   boundary values.
+//! - `rand` — [uniform sampling][UniformFixedPoint] in a range by [`rand`][rand].
+//! - `rkyv` — [`rkyv`][rkyv] zero-copy archives, `FixedPoint` is archived as `#[repr(transparent)]`
+//!   `FixedPoint` over the archived layout with `to_native()`.
+//! - `bytemuck` — [`bytemuck::{Pod, Zeroable, TransparentWrapper}`][bytemuck] implementations to
+//!   reinterpret slices of the layout as slices of `FixedPoint` without copying.
+//! - `zerocopy` — [`zerocopy::{FromBytes, IntoBytes, Immutable, KnownLayout}`][zerocopy]
+//!   implementations for the same purpose.
+//! - `stats` — [descriptive statistics][stats] over slices, requires `std`.
+//! - `finance` — [compound interest and discounting][finance] with the documented rounding order.
+//! - `overflow-tracing` — [reporting][overflow_tracing] of overflows, saturations and rounding with
+//!   locations of callers to a hook for debugging, requires `std`.
+//! - `default-round-nearest`, `default-round-half-even` — the [rounding mode][RoundMode] used by
+//!   convenience APIs without an explicit mode (parsing, float conversions, `Display` with
+//!   precision and operators) instead of their historical ones.
+//! - `trig` — deterministic `sin`, `cos`, `sin_cos` and `atan2` calculated by CORDIC using only
+//!   integer arithmetic, e.g. for motor control on targets without FPU.
+//! - `rust_decimal` — lossless conversions from and to [`rust_decimal::Decimal`][rust_decimal].
+//! - `bigdecimal` — conversions from and to [`bigdecimal::BigDecimal`][bigdecimal], lossless or
+//!   with explicit rounding.
+//! - `num-rational` — conversions from and to [`num_rational::Ratio<i128>`][num_rational], exact
+//!   and [rounding][RoundMode] ones.
+//! - `defmt` — [`defmt::Format`][defmt] implementations logging decimals, not raw integers.
+//! - `derive` — [`#[derive(FixedPointOps)]`][FixedPointOps] for newtypes over `FixedPoint` and
+//!   the [`fixnum_expr!`] macro for checked arithmetic expressions.
 //! - `serde` — support for `serde`.
+//! - `serde-arbitrary-precision` — `serde::float` (de)serializes `serde_json::Number` with all
+//!   digits, enables `arbitrary_precision` of `serde_json`.
 //! - `schemars` — support for `schemars`.
+//! - `sqlx` — [`sqlx`][sqlx] support of Postgres `NUMERIC` (`Type`, `Encode` and `Decode`
+//!   implementations) without intermediate strings.
 //! - `std` — Enabled by default.
 //!
-//! At least one of `i128`, `i64`, `i32`, `i16` must be enabled.
+//! At least one of `i256`, `i128`, `i64`, `i32`, `i16`, `u128`, `u64` must be enabled.
 //!
 //! ## Example
 //! ```
@@ -56,10 +112,13 @@
 //! | ------ | --------------------- | ----------- |
 //! | [`cadd`][cadd] | `let result: Result<FixedPoint, ArithmeticError> = a.cadd(b)` | Checked addition. Returns `Err` on overflow. |
 //! | [`csub`][csub] | `let result: Result<FixedPoint, ArithmeticError> = a.csub(b)` | Checked subtraction. Returns `Err` on overflow. |
+//! | [`crem`][crem] | `let result: Result<FixedPoint, ArithmeticError> = a.crem(b)` | Checked remainder. Returns `Err` on overflow or division by zero. See also [`CheckedEuclid`][CheckedEuclid]. |
 //! | [`cmul`][cmul] | `let result: Result<FixedPoint, ArithmeticError> = a.cmul(b)` | Checked multiplication. Returns `Err` on overflow. This is multiplication without rounding, hence it's available only when at least one operand is integer. |
 //! | [`rmul`][rmul] | `let result: Result<FixedPoint, ArithmeticError> = a.rmul(b, RoundMode::Ceil)` | Checked rounding multiplication. Returns `Err` on overflow. Because of provided [`RoundMode`][RoundMode] it's possible across the [`FixedPoint`][FixedPoint] values. |
 //! | [`rdiv`][rdiv] | `let result: Result<FixedPoint, ArithmeticError> = a.rdiv(b, RoundMode::Floor)` | Checked [rounding][RoundMode] division. Returns `Err` on overflow. |
+//! | [`mul_div`][mul_div] | `let result: Result<FixedPoint, ArithmeticError> = a.mul_div(b, c, RoundMode::Floor)` | Checked [rounding][RoundMode] `a * b / c` with the promoted intermediate product. Returns `Err` on overflow or division by zero. |
 //! | [`rsqrt`][rsqrt] | `let result: Result<FixedPoint, ArithmeticError> = a.rsqrt(RoundMode::Floor)` | Checked [rounding][RoundMode] square root. Returns `Err` for negative argument. |
+//! | [`ln`][ln], [`log2`][log2], [`log10`][log10], [`exp`][exp] | `let result: Result<FixedPoint, ArithmeticError> = a.ln()` | Checked logarithms and exponent rounded to the nearest. Returns `Err` for non-positive argument of logarithms and on overflow. |
 //! | [`cneg`][cneg] | `let result: Result<FixedPoint, ArithmeticError> = a.cneg()` | Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]). |
 //! | [`integral`][integral] | `let y: {integer} = x.integral(RoundMode::Floor)` | Takes [rounded][RoundMode] integral part of the number. |
 //! | [`saturating_add`][saturating_add] | `let z: FixedPoint = x.saturating_add(y)` | Saturating addition |
@@ -115,19 +174,44 @@
 //! [cneg]: ./struct.FixedPoint.html#method.cneg
 //! [csub]: ./ops/trait.CheckedSub.html#tymethod.csub
 //! [cmul]: ./ops/trait.CheckedMul.html#tymethod.cmul
+//! [crem]: ./ops/trait.CheckedRem.html#tymethod.crem
+//! [CheckedEuclid]: ./ops/trait.CheckedEuclid.html
+//! [exp]: ./struct.FixedPoint.html#method.exp
 //! [fixnum]: ./macro.fixnum.html
 //! [FixedPoint]: ./struct.FixedPoint.html
 //! [integral]: ./struct.FixedPoint.html#method.integral
+//! [ln]: ./struct.FixedPoint.html#method.ln
+//! [log10]: ./struct.FixedPoint.html#method.log10
+//! [log2]: ./struct.FixedPoint.html#method.log2
 //! [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+//! [mul_div]: ./struct.FixedPoint.html#method.mul_div
 //! [parity_scale_codec]: https://docs.rs/parity-scale-codec
+//! [scale_info]: https://docs.rs/scale-info
+//! [borsh]: https://docs.rs/borsh
+//! [proptest]: https://docs.rs/proptest
+//! [arbitrary]: https://docs.rs/arbitrary
+//! [rand]: https://docs.rs/rand/0.8
+//! [defmt]: https://docs.rs/defmt
+//! [rkyv]: https://docs.rs/rkyv
+//! [bytemuck]: https://docs.rs/bytemuck
+//! [zerocopy]: https://docs.rs/zerocopy
+//! [arrow_array]: https://docs.rs/arrow-array
+//! [async_graphql]: https://docs.rs/async-graphql
+//! [diesel]: https://docs.rs/diesel
+//! [bson_decimal128]: https://docs.rs/bson/2/bson/struct.Decimal128.html
+//! [num_rational]: https://docs.rs/num-rational
+//! [num_traits]: https://docs.rs/num-traits
+//! [rust_decimal]: https://docs.rs/rust_decimal
+//! [bigdecimal]: https://docs.rs/bigdecimal
 //! [rdiv]: ./ops/trait.RoundingDiv.html#tymethod.rdiv
 //! [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
 //! [rsqrt]: ./struct.FixedPoint.html#method.rsqrt
 //! [RoundMode]: ./ops/enum.RoundMode.html
-//! [saturating_add]: ./ops/trait.CheckedAdd.html#tymethod.saturating_add
-//! [saturating_mul]: ./ops/trait.CheckedMul.html#tymethod.saturating_mul
-//! [saturating_rmul]: ./ops/trait.RoundingMul.html#tymethod.saturating_rmul
-//! [saturating_sub]: ./ops/trait.CheckedSub.html#tymethod.saturating_sub
+//! [saturating_add]: ./ops/trait.SaturatingAdd.html#tymethod.saturating_add
+//! [saturating_mul]: ./ops/trait.SaturatingMul.html#tymethod.saturating_mul
+//! [saturating_rmul]: ./ops/trait.SaturatingRMul.html#tymethod.saturating_rmul
+//! [saturating_sub]: ./ops/trait.SaturatingSub.html#tymethod.saturating_sub
+//! [sqlx]: https://docs.rs/sqlx
 
 #![warn(rust_2018_idioms, unreachable_pub, missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -135,36 +219,249 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 use core::cmp::Ord;
-use core::{fmt, i64, marker::PhantomData};
+#[cfg(primitive_layout)]
+use core::num::NonZero;
+use core::{fmt, marker::PhantomData};
 
 use typenum::Unsigned;
 
-#[cfg(feature = "i128")]
+#[cfg(feature = "i256")]
+pub use crate::i256::I256;
+#[cfg(all(any(feature = "i128", feature = "u128"), not(feature = "i256")))]
 use crate::i256::I256;
-use crate::ops::{sqrt::Sqrt, *};
+#[cfg(feature = "u128")]
+use crate::i256::U256;
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+#[cfg(primitive_layout)]
+use crate::ops::sqrt::Sqrt;
+use crate::ops::*;
+#[cfg(all(feature = "overflow-tracing", primitive_layout))]
+use crate::overflow_tracing::EventKind;
+#[cfg(primitive_layout)]
 use crate::string::Stringify;
 
+#[cfg(primitive_layout)]
+use self::private::IntOperand;
+
+#[cfg(primitive_layout)]
+mod accumulator;
+#[cfg(primitive_layout)]
+mod allocate;
+#[cfg(all(feature = "arbitrary", primitive_layout))]
+mod arbitrary;
+#[cfg(all(feature = "async-graphql", primitive_layout))]
+mod async_graphql;
+#[cfg(any(feature = "i16", feature = "i32", feature = "i64", feature = "u64"))]
+mod atomic;
+#[cfg(primitive_layout)]
+mod average;
+#[cfg(all(feature = "bigdecimal", primitive_layout))]
+mod bigdecimal;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod const_fn;
+#[cfg(primitive_layout)]
+mod decimal128;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(all(feature = "diesel", primitive_layout))]
+mod diesel;
+#[cfg(feature = "i128")]
+mod dynamic;
 mod errors;
+mod fixed;
 mod float;
-#[cfg(feature = "i128")]
+mod formatting;
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    all(feature = "i128", feature = "i256"),
+    feature = "u64"
+))]
+mod full_mul;
+#[cfg(any(
+    feature = "i128",
+    feature = "u128",
+    feature = "i256",
+    feature = "num-rational"
+))]
 mod i256;
+mod iter;
 mod macros;
-#[cfg(feature = "parity")]
+#[cfg(primitive_layout)]
+mod math;
+#[cfg(all(feature = "num-rational", primitive_layout))]
+mod num_rational;
+#[cfg(all(feature = "num-traits", primitive_layout))]
+mod num_traits;
+#[cfg(all(any(feature = "sqlx", feature = "diesel"), primitive_layout))]
+mod numeric;
+#[cfg(all(feature = "parity", primitive_layout))]
 mod parity;
 mod power_table;
+#[cfg(all(feature = "proptest", primitive_layout))]
+mod proptest;
+#[cfg(all(feature = "rand", primitive_layout))]
+mod rand;
+#[cfg(primitive_layout)]
+mod range;
+#[cfg(primitive_layout)]
+mod ratio;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(all(feature = "rust_decimal", primitive_layout))]
+mod rust_decimal;
+#[cfg(all(feature = "sqlx", primitive_layout))]
+mod sqlx;
+#[cfg(all(feature = "panicking-ops", primitive_layout))]
+mod std_ops;
 mod string;
 
-#[cfg(not(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")))]
-compile_error!("Some of the next features must be enabled: \"i128\", \"i64\", \"i32\", \"i16\"");
+#[cfg(not(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "i128",
+    feature = "u64",
+    feature = "u128",
+    feature = "i256"
+)))]
+compile_error!(
+    "Some of the next features must be enabled: \"i256\", \"i128\", \"i64\", \"i32\", \"i16\", \"u128\", \"u64\""
+);
 
+#[cfg(all(feature = "rand", primitive_layout))]
+pub use crate::rand::UniformFixedPoint;
+#[cfg(primitive_layout)]
+pub use accumulator::Accumulator;
+#[cfg(primitive_layout)]
+pub use allocate::Allocation;
+#[cfg(any(feature = "i16", feature = "i32", feature = "i64", feature = "u64"))]
+pub use atomic::AtomicFixedPoint;
+#[cfg(primitive_layout)]
+pub use average::{Ema, RunningMean, Vwap};
+#[cfg(primitive_layout)]
+pub use decimal128::Decimal128;
+#[cfg(feature = "i128")]
+pub use dynamic::DynFixedPoint;
 pub use errors::*;
+pub use fixed::Fixed;
+pub use formatting::{FormatOptions, Formatted, ParseOptions};
+#[cfg(primitive_layout)]
+pub use range::{Linspace, RangeStep};
+#[cfg(primitive_layout)]
+pub use ratio::Ratio;
 pub use typenum;
 
+/// Forwards operations of a tuple-struct newtype to the wrapped `FixedPoint`.
+///
+/// Implements `CheckedAdd` and `CheckedSub` with the same newtype, `RoundingMul` and
+/// `RoundingDiv` by the wrapped type, `RoundingDiv` by the same newtype (the result is
+/// the wrapped type), `Display` and `FromStr`.
+///
+/// With `#[fixnum(serde)]` also implements `Serialize` and `Deserialize` like `FixedPoint` does,
+/// `#[fixnum(serde = "str")]` uses the provided [`serde`](mod@crate::serde) module instead.
+/// The `serde` feature must be enabled.
+///
+/// Use [`impl_op!`] for operations across different types.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ops::*, typenum::U9, FixedPoint, FixedPointOps};
+///
+/// type Fp64 = FixedPoint<i64, U9>;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, FixedPointOps)]
+/// struct Price(Fp64);
+///
+/// let a: Price = "1.5".parse()?;
+/// let b: Price = "0.25".parse()?;
+/// assert_eq!(a.cadd(b)?.to_string(), "1.75");
+/// assert_eq!(a.rmul("0.1".parse::<Fp64>()?, RoundMode::Floor)?, "0.15".parse()?);
+/// assert_eq!(b.rdiv(a, RoundMode::Floor)?, "0.166666666".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use fixnum_derive::FixedPointOps;
+
+/// Rewrites an arithmetic expression into the chain of checked operations.
+///
+/// `fixnum_expr!(mode, expr)` replaces `+`, `-`, `%` and unary `-` with [`cadd`], [`csub`],
+/// [`crem`] and [`cneg`], `*` and `/` with [`rmul`] and [`rdiv`] rounded according to `mode`.
+/// The usual precedence applies, other expressions (variables, calls, literals) are operands.
+/// Returns `Result<_, ArithmeticError>` failing on the first error.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{fixnum_expr, ops::RoundMode::*, typenum::U9, ArithmeticError, FixedPoint};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let (a, b, c, d): (Price, Price, Price, Price) =
+///     ("1.5".parse()?, "2".parse()?, "3".parse()?, "0.25".parse()?);
+///
+/// // The same as `a.rmul(b, Floor)?.rdiv(c, Floor)?.cadd(d)?`.
+/// assert_eq!(fixnum_expr!(Floor, a * b / c + d)?, "1.25".parse()?);
+/// assert_eq!(fixnum_expr!(Ceil, (a - b) / c * 2)?, "-0.333333332".parse()?);
+/// assert_eq!(fixnum_expr!(Floor, Price::MAX + a), Err(ArithmeticError::Overflow));
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// [`cadd`]: ops::CheckedAdd::cadd
+/// [`csub`]: ops::CheckedSub::csub
+/// [`crem`]: ops::CheckedRem::crem
+/// [`cneg`]: FixedPoint::cneg
+/// [`rmul`]: ops::RoundingMul::rmul
+/// [`rdiv`]: ops::RoundingDiv::rdiv
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use fixnum_derive::fixnum_expr;
+
+pub mod amount;
+#[cfg(all(feature = "arrow", feature = "i128"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "arrow", feature = "i128"))))]
+pub mod arrow;
+#[cfg(all(feature = "bson", primitive_layout))]
+#[cfg_attr(docsrs, doc(cfg(feature = "bson")))]
+pub mod bson;
+#[cfg(feature = "currency")]
+#[cfg_attr(docsrs, doc(cfg(feature = "currency")))]
+pub mod currency;
+#[cfg(all(feature = "finance", primitive_layout))]
+#[cfg_attr(docsrs, doc(cfg(feature = "finance")))]
+pub mod finance;
+#[cfg(feature = "fix")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fix")))]
+pub mod fix;
+#[cfg(all(feature = "money-proto", primitive_layout))]
+#[cfg_attr(docsrs, doc(cfg(feature = "money-proto")))]
+pub mod money_proto;
 pub mod ops;
+#[cfg(feature = "overflow-tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "overflow-tracing")))]
+pub mod overflow_tracing;
+#[cfg(feature = "pg-numeric")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pg-numeric")))]
+pub mod pg_numeric;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serde;
+#[cfg(primitive_layout)]
+pub mod simd;
+#[cfg(all(feature = "stats", primitive_layout))]
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+pub mod stats;
 
 #[cfg(feature = "schemars")]
 mod schemars;
@@ -174,6 +471,8 @@ pub mod _priv {
     pub use crate::const_fn::*;
     pub use crate::macros::Operand;
     pub use crate::ops::*;
+    #[cfg(feature = "serde")]
+    pub use ::serde;
 }
 
 type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
@@ -186,6 +485,7 @@ type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
 /// where `PRECISION` is a compile-time-defined decimal places count.
 ///
 /// Maximal possible value: `MAX = (2 ^ (BITS_COUNT - 1) - 1) / 10 ^ PRECISION`
+/// (`MAX = (2 ^ BITS_COUNT - 1) / 10 ^ PRECISION` for unsigned layouts)
 /// Maximal possible calculation error: `ERROR_MAX = 0.5 / (10 ^ PRECISION)`
 ///
 /// E.g. for `i64` with 9 decimal places:
@@ -195,9 +495,26 @@ type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
 /// ERROR_MAX = 0.5 / 1e9 = 5e-10
 /// ```
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 #[cfg_attr(
     docsrs,
-    doc(cfg(any(feature = "i128", feature = "i64", feature = "i32", feature = "i16")))
+    doc(cfg(any(
+        feature = "i128",
+        feature = "i64",
+        feature = "i32",
+        feature = "i16",
+        feature = "u128",
+        feature = "u64",
+        feature = "i256"
+    )))
 )]
 #[repr(transparent)]
 pub struct FixedPoint<I, P> {
@@ -228,8 +545,107 @@ impl<I, P> FixedPoint<I, P> {
     pub fn into_bits(self) -> I {
         self.inner
     }
+
+    /// Converts to a wider layout with the same precision, e.g. `i64` to `i128`.
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "i64", feature = "i128"))]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{typenum::U9, FixedPoint};
+    ///
+    /// let a: FixedPoint<i64, U9> = "-1.5".parse()?;
+    /// let b: FixedPoint<i128, U9> = a.into_layout();
+    /// assert_eq!(b, "-1.5".parse()?);
+    /// assert_eq!(b.try_into_layout::<i64>()?, a);
+    /// assert!(FixedPoint::<i128, U9>::MAX.try_into_layout::<i64>().is_err());
+    /// # Ok(()) }
+    /// # #[cfg(not(all(feature = "i64", feature = "i128")))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    pub fn into_layout<J: From<I>>(self) -> FixedPoint<J, P> {
+        FixedPoint::from_bits(J::from(self.inner))
+    }
+
+    /// Converts to a narrower layout with the same precision, e.g. `i128` to `i64`.
+    /// Fails if the value doesn't fit in the layout.
+    #[inline]
+    pub fn try_into_layout<J: TryFrom<I>>(self) -> Result<FixedPoint<J, P>, ConvertError> {
+        J::try_from(self.inner)
+            .map(FixedPoint::from_bits)
+            .map_err(|_| ConvertError::new("too big number"))
+    }
+
+    /// Replaces the value with the result of `f`, keeping it unchanged if `f` fails.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::{RoundingMul, RoundMode::*}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let (mut balance, rate): (Amount, Amount) = ("100".parse()?, "1.05".parse()?);
+    /// balance.update_checked(|balance| balance.rmul(rate, Floor))?;
+    /// assert_eq!(balance, "105".parse()?);
+    ///
+    /// balance.checked_sub_assign("5.5".parse()?)?;
+    /// balance.checked_add_assign("0.5".parse()?)?;
+    /// assert_eq!(balance, "100".parse()?);
+    ///
+    /// assert_eq!(balance.checked_add_assign(Amount::MAX), Err(ArithmeticError::Overflow));
+    /// assert_eq!(balance, "100".parse()?);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    pub fn update_checked<E>(&mut self, f: impl FnOnce(Self) -> Result<Self, E>) -> Result<(), E>
+    where
+        Self: Copy,
+    {
+        *self = f(*self)?;
+        Ok(())
+    }
+
+    /// Checked addition in place. Returns `Err` on overflow, keeping the value unchanged.
+    ///
+    /// See [`update_checked`](Self::update_checked) for the example.
+    #[inline]
+    pub fn checked_add_assign(&mut self, rhs: Self) -> Result<()>
+    where
+        Self: Copy + CheckedAdd<Output = Self, Error = ArithmeticError>,
+    {
+        self.update_checked(|value| value.cadd(rhs))
+    }
+
+    /// Checked subtraction in place. Returns `Err` on overflow, keeping the value unchanged.
+    ///
+    /// See [`update_checked`](Self::update_checked) for the example.
+    #[inline]
+    pub fn checked_sub_assign(&mut self, rhs: Self) -> Result<()>
+    where
+        Self: Copy + CheckedSub<Output = Self, Error = ArithmeticError>,
+    {
+        self.update_checked(|value| value.csub(rhs))
+    }
+}
+
+macro_rules! impl_bits_fmt {
+    ($($trait:ident),*) => {$(
+        impl<I: fmt::$trait, P> fmt::$trait for FixedPoint<I, P> {
+            /// Formats the raw representation with all flags, e.g. `{:#x}` of `1.5` with `U2`
+            /// writes `0x96`. Handy to debug serialization.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$trait::fmt(&self.inner, f)
+            }
+        }
+    )*};
 }
 
+impl_bits_fmt!(LowerHex, UpperHex, Binary);
+
+#[cfg(primitive_layout)]
 macro_rules! impl_fixed_point {
     (
         $(#[$attr:meta])?
@@ -237,33 +653,41 @@ macro_rules! impl_fixed_point {
         promoted_to = $promotion:tt;
         convert = $convert:expr;
         try_from = [$($try_from:ty),*];
+        int_operands = [$($int_operand:tt),*] via $int_promotion:tt;
     ) => {
         $(#[$attr])?
         impl<P: Precision> FixedPoint<$layout, P> {
             /// The number of digits in the fractional part.
             pub const PRECISION: i32 = P::I32;
+            /// `0`, the same as [`Zero::ZERO`], but doesn't require the trait.
+            pub const ZERO: Self = Self::from_bits(0);
+            /// `1`, the same as [`One::ONE`], but doesn't require the trait.
+            pub const ONE: Self = Self::from_bits(Self::COEF);
             /// The difference between `0.0` and the next larger representable number.
             pub const EPSILON: Self = Self::from_bits(1);
+            /// The smallest value, the same as [`Bounded::MIN`], but doesn't require the trait.
+            pub const MIN: Self = Self::from_bits($layout::MIN);
+            /// The largest value, the same as [`Bounded::MAX`], but doesn't require the trait.
+            pub const MAX: Self = Self::from_bits($layout::MAX);
 
             const COEF: $layout = const_fn::pow10(Self::PRECISION) as _;
-            const NEG_COEF: $layout = -Self::COEF;
             const COEF_PROMOTED: $promotion = $convert(Self::COEF) as _;
         }
 
         $(#[$attr])?
         impl<P: Precision> Zero for FixedPoint<$layout, P> {
-            const ZERO: Self = Self::from_bits(0);
+            const ZERO: Self = Self::ZERO;
         }
 
         $(#[$attr])?
         impl<P: Precision> One for FixedPoint<$layout, P> {
-            const ONE: Self = Self::from_bits(Self::COEF);
+            const ONE: Self = Self::ONE;
         }
 
         $(#[$attr])?
         impl<P: Precision> Bounded for FixedPoint<$layout, P> {
-            const MIN: Self = Self::from_bits($layout::MIN);
-            const MAX: Self = Self::from_bits($layout::MAX);
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
         }
 
         $(#[$attr])?
@@ -272,11 +696,28 @@ macro_rules! impl_fixed_point {
             type Error = ArithmeticError;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
                 // TODO: avoid 128bit arithmetic when possible,
                 //       because LLVM doesn't replace 128bit division by const with multiplication.
 
                 let value = $promotion::from(self.inner) * $promotion::from(rhs.inner);
+                let result = Self::from_promoted(value, mode);
+
+                #[cfg(feature = "overflow-tracing")]
+                overflow_tracing::report_result("rmul", &result, |result| {
+                    $promotion::from(result.inner) * Self::COEF_PROMOTED != value
+                });
+
+                result
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Divides the promoted product of raw values by `COEF` with rounding.
+            #[inline]
+            fn from_promoted(value: $promotion, mode: RoundMode) -> Result<Self> {
                 // TODO: replace with multiplication by a constant.
                 let result = value / Self::COEF_PROMOTED;
                 let loss = value - result * Self::COEF_PROMOTED;
@@ -286,30 +727,72 @@ macro_rules! impl_fixed_point {
 
                 // `|loss| < COEF`, thus it fits in the layout.
                 let loss = $layout::try_from(loss).unwrap();
-                let sign = self.inner.signum() * rhs.inner.signum();
 
-                let add_signed_one = if mode == RoundMode::Nearest {
-                    sign as i32 >= 0 && loss + loss >= Self::COEF
-                                     || loss + loss <= Self::NEG_COEF
-                } else {
-                    loss != 0 && mode as i32 == sign as i32
-                };
+                if loss != 0 {
+                    // The loss has the same sign as the value.
+                    let sign = loss.signum();
+                    let loss_abs = loss.abs();
+                    let half = loss_abs.cmp(&(Self::COEF - loss_abs));
 
-                if add_signed_one {
-                    result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    if mode.is_away_from_zero(sign as i32, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
                 }
 
                 Ok(Self::from_bits(result))
             }
         }
 
+        $(#[$attr])?
+        impl<P: Precision> SaturatingRMul for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
+            fn saturating_rmul(self, rhs: Self, mode: RoundMode) -> Self::Output {
+                let value = $promotion::from(self.inner) * $promotion::from(rhs.inner);
+                let result = Self::from_promoted(value, mode);
+
+                #[cfg(feature = "overflow-tracing")]
+                if result.is_err() {
+                    overflow_tracing::report(EventKind::Saturation, "saturating_rmul");
+                }
+
+                result.unwrap_or_else(|_| {
+                    if self.inner.signum() == rhs.inner.signum() {
+                        Self::MAX
+                    } else {
+                        Self::MIN
+                    }
+                })
+            }
+        }
+
         $(#[$attr])?
         impl<P: Precision> RoundingDiv for FixedPoint<$layout, P> {
             type Output = Self;
             type Error = ArithmeticError;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                let result = Self::rdiv_untraced(self, rhs, mode);
+
+                #[cfg(feature = "overflow-tracing")]
+                overflow_tracing::report_result("rdiv", &result, |result| {
+                    let denominator = $promotion::from(rhs.inner);
+                    $promotion::from(result.inner) * denominator
+                        != $promotion::from(self.inner) * Self::COEF_PROMOTED
+                });
+
+                result
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            #[inline]
+            fn rdiv_untraced(self, rhs: Self, mode: RoundMode) -> Result<Self> {
                 // TODO: avoid 128bit arithmetic when possible,
                 //       because LLVM doesn't replace 128bit division by const with multiplication.
 
@@ -330,15 +813,11 @@ macro_rules! impl_fixed_point {
 
                 if loss != 0 {
                     let sign = self.inner.signum() * rhs.inner.signum();
+                    // Compare `|loss|` with `|rhs| / 2` avoiding overflow.
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(rhs.inner.unsigned_abs() - loss_abs));
 
-                    let add_signed_one = if mode == RoundMode::Nearest {
-                        let loss_abs = loss.abs();
-                        loss_abs + loss_abs >= rhs.inner.abs()
-                    } else {
-                        mode as i32 == sign as i32
-                    };
-
-                    if add_signed_one {
+                    if mode.is_away_from_zero(sign as i32, half, result % 2 != 0) {
                         result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                     }
                 }
@@ -348,40 +827,115 @@ macro_rules! impl_fixed_point {
         }
 
         $(#[$attr])?
-        impl<P: Precision> RoundingDiv<$layout> for FixedPoint<$layout, P> {
+        impl<P: Precision> RoundingDiv<FixedPoint<$layout, P>> for $layout {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: FixedPoint<$layout, P>, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                let lhs = FixedPoint::<$layout, P>::try_from(self).map_err(|_| ArithmeticError::Overflow)?;
+                lhs.rdiv(rhs, mode)
+            }
+        }
+
+        // A single generic impl per trait keeps integer literals inferable, e.g. `x.rdiv(2, mode)`.
+        $(#[$attr])?
+        impl<P: Precision, I: IntOperand<$layout>> RoundingMul<I> for FixedPoint<$layout, P> {
             type Output = Self;
             type Error = ArithmeticError;
 
+            /// Multiplication by an integer is exact, so `mode` is ignored.
             #[inline]
-            fn rdiv(self, rhs: $layout, mode: RoundMode) -> Result<Self> {
-                self.inner.rdiv(rhs, mode).map(Self::from_bits)
+            fn rmul(self, rhs: I, _mode: RoundMode) -> Result<Self> {
+                I::mul_int(self.inner, rhs).map(Self::from_bits)
             }
         }
 
         $(#[$attr])?
-        impl<P: Precision> RoundingDiv<FixedPoint<$layout, P>> for $layout {
-            type Output = FixedPoint<$layout, P>;
+        impl<P: Precision, I: IntOperand<$layout>> RoundingDiv<I> for FixedPoint<$layout, P> {
+            type Output = Self;
             type Error = ArithmeticError;
 
             #[inline]
-            fn rdiv(self, rhs: FixedPoint<$layout, P>, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
-                let lhs = FixedPoint::<$layout, P>::try_from(self).map_err(|_| ArithmeticError::Overflow)?;
+            fn rdiv(self, rhs: I, mode: RoundMode) -> Result<Self> {
+                I::div_int(self.inner, rhs, mode).map(Self::from_bits)
+            }
+        }
+
+        $(#[$attr])?
+        impl IntOperand<$layout> for $layout {
+            #[inline]
+            fn mul_int(lhs: $layout, rhs: Self) -> Result<$layout> {
+                lhs.checked_mul(rhs).ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn div_int(lhs: $layout, rhs: Self, mode: RoundMode) -> Result<$layout> {
                 lhs.rdiv(rhs, mode)
             }
         }
 
+        $(
+            impl IntOperand<$layout> for $int_operand {
+                #[inline]
+                fn mul_int(lhs: $layout, rhs: Self) -> Result<$layout> {
+                    let result = $int_promotion::from(lhs)
+                        .checked_mul($int_promotion::from(rhs))
+                        .ok_or(ArithmeticError::Overflow)?;
+
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)
+                }
+
+                #[inline]
+                #[allow(irrefutable_let_patterns)] // for operands smaller than the layout
+                fn div_int(lhs: $layout, rhs: Self, mode: RoundMode) -> Result<$layout> {
+                    if let Ok(rhs) = $layout::try_from(rhs) {
+                        return lhs.rdiv(rhs, mode);
+                    }
+
+                    let result = $int_promotion::from(lhs).rdiv($int_promotion::from(rhs), mode)?;
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)
+                }
+            }
+        )*
+
+        impl_fixed_point!(@nonzero $layout [$($int_operand,)* $layout]);
+        impl_fixed_point!(@by_ref $layout FixedPoint<$layout, P>, RoundingMul::rmul(mode: RoundMode));
+        impl_fixed_point!(@by_ref $layout FixedPoint<$layout, P>, RoundingDiv::rdiv(mode: RoundMode));
+        impl_fixed_point!(@by_ref $layout $layout, RoundingMul::rmul(mode: RoundMode));
+        impl_fixed_point!(@by_ref $layout $layout, RoundingDiv::rdiv(mode: RoundMode));
+        impl_fixed_point!(@by_ref $layout FixedPoint<$layout, P>, CheckedAdd::cadd());
+        impl_fixed_point!(@by_ref $layout FixedPoint<$layout, P>, CheckedSub::csub());
+        impl_fixed_point!(@by_ref $layout $layout, CheckedMul::cmul());
+        impl_fixed_point!(@by_ref $layout FixedPoint<$layout, P>, CheckedRem::crem());
+
         $(#[$attr])?
         impl<P: Precision> CheckedAdd for FixedPoint<$layout, P> {
             type Output = Self;
             type Error = ArithmeticError;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn cadd(self, rhs: Self) -> Result<Self> {
-                self.inner.cadd(rhs.inner).map(Self::from_bits)
+                let result = self.const_cadd(rhs);
+                #[cfg(feature = "overflow-tracing")]
+                overflow_tracing::report_result("cadd", &result, |_| false);
+                result
             }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> SaturatingAdd for FixedPoint<$layout, P> {
+            type Output = Self;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn saturating_add(self, rhs: Self) -> Self::Output {
+                #[cfg(feature = "overflow-tracing")]
+                if self.inner.checked_add(rhs.inner).is_none() {
+                    overflow_tracing::report(EventKind::Saturation, "saturating_add");
+                }
+
                 Self::Output::from_bits(self.inner.saturating_add(rhs.inner))
             }
         }
@@ -392,12 +946,27 @@ macro_rules! impl_fixed_point {
             type Error = ArithmeticError;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn csub(self, rhs: Self) -> Result<Self> {
-                self.inner.csub(rhs.inner).map(Self::from_bits)
+                let result = self.const_csub(rhs);
+                #[cfg(feature = "overflow-tracing")]
+                overflow_tracing::report_result("csub", &result, |_| false);
+                result
             }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> SaturatingSub for FixedPoint<$layout, P> {
+            type Output = Self;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn saturating_sub(self, rhs: Self) -> Self::Output {
+                #[cfg(feature = "overflow-tracing")]
+                if self.inner.checked_sub(rhs.inner).is_none() {
+                    overflow_tracing::report(EventKind::Saturation, "saturating_sub");
+                }
+
                 Self::Output::from_bits(self.inner.saturating_sub(rhs.inner))
             }
         }
@@ -408,12 +977,55 @@ macro_rules! impl_fixed_point {
             type Error = ArithmeticError;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn cmul(self, rhs: $layout) -> Result<Self> {
-                self.inner.cmul(rhs).map(Self::from_bits)
+                let result = self.const_cmul(rhs);
+                #[cfg(feature = "overflow-tracing")]
+                overflow_tracing::report_result("cmul", &result, |_| false);
+                result
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedRem for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn crem(self, rhs: Self) -> Result<Self> {
+                self.inner.crem(rhs.inner).map(Self::from_bits)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedEuclid for FixedPoint<$layout, P> {
+            type Quotient = $layout;
+            type Remainder = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn checked_div_euclid(self, rhs: Self) -> Result<$layout> {
+                CheckedEuclid::checked_div_euclid(self.inner, rhs.inner)
+            }
+
+            #[inline]
+            fn checked_rem_euclid(self, rhs: Self) -> Result<Self> {
+                CheckedEuclid::checked_rem_euclid(self.inner, rhs.inner).map(Self::from_bits)
             }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> SaturatingMul<$layout> for FixedPoint<$layout, P> {
+            type Output = Self;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn saturating_mul(self, rhs: $layout) -> Self::Output {
+                #[cfg(feature = "overflow-tracing")]
+                if self.inner.checked_mul(rhs).is_none() {
+                    overflow_tracing::report(EventKind::Saturation, "saturating_mul");
+                }
+
                 Self::Output::from_bits(self.inner.saturating_mul(rhs))
             }
         }
@@ -427,10 +1039,49 @@ macro_rules! impl_fixed_point {
             fn cmul(self, rhs: FixedPoint<$layout, P>) -> Result<FixedPoint<$layout, P>> {
                 rhs.cmul(self)
             }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> SaturatingMul<FixedPoint<$layout, P>> for $layout {
+            type Output = FixedPoint<$layout, P>;
 
             #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
             fn saturating_mul(self, rhs: FixedPoint<$layout, P>) -> Self::Output {
-                Self::Output::from_bits(self.saturating_mul(rhs.inner))
+                SaturatingMul::saturating_mul(rhs, self)
+            }
+        }
+
+        // Inherent methods take precedence over the deprecated ones of `Checked*` and `RoundingMul`,
+        // so method calls aren't ambiguous when both traits are in scope, e.g. by `ops::*`.
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// The same as [`SaturatingAdd::saturating_add`].
+            #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                SaturatingAdd::saturating_add(self, rhs)
+            }
+
+            /// The same as [`SaturatingSub::saturating_sub`].
+            #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                SaturatingSub::saturating_sub(self, rhs)
+            }
+
+            /// The same as [`SaturatingMul::saturating_mul`].
+            #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
+            pub fn saturating_mul(self, rhs: $layout) -> Self {
+                SaturatingMul::saturating_mul(self, rhs)
+            }
+
+            /// The same as [`SaturatingRMul::saturating_rmul`].
+            #[inline]
+            #[cfg_attr(feature = "overflow-tracing", track_caller)]
+            pub fn saturating_rmul(self, rhs: Self, mode: RoundMode) -> Self {
+                SaturatingRMul::saturating_rmul(self, rhs, mode)
             }
         }
 
@@ -441,16 +1092,38 @@ macro_rules! impl_fixed_point {
             /// * `1` if the number is positive
             /// * `-1` if the number is negative
             #[inline]
-            pub fn signum(self) -> $layout {
-                self.inner.signum()
+            pub fn signum(self) -> Self {
+                Self::from_bits(self.inner.signum() * Self::COEF)
+            }
+
+            /// Returns a number with the magnitude of `self` and the sign of `sign`,
+            /// zero is considered positive.
+            /// Returns `Err` on overflow (you can't make [`MIN` value][MIN] positive).
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Result<Self> {
+                if self.inner.is_negative() == sign.inner.is_negative() {
+                    Ok(self)
+                } else {
+                    self.cneg()
+                }
             }
 
-            /// Returns `1/n`.
+            /// Returns `1/n` [rounded][RoundMode] according to `mode`.
+            /// Returns [`ArithmeticError::DivisionByZero`] for zero
+            /// and [`ArithmeticError::Overflow`] if the result doesn't fit.
             #[inline]
             pub fn recip(self, mode: RoundMode) -> Result<Self> {
                 Self::ONE.rdiv(self, mode)
             }
 
+            /// The same as [`recip`](Self::recip), named after the primitive integers' methods.
+            #[inline]
+            pub fn checked_recip(self, mode: RoundMode) -> Result<Self> {
+                self.recip(mode)
+            }
+
             /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
             ///
             /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
@@ -462,6 +1135,12 @@ macro_rules! impl_fixed_point {
                     .ok_or_else(|| ArithmeticError::Overflow)
             }
 
+            /// The same as [`cneg`](Self::cneg), named after the primitive integers' method.
+            #[inline]
+            pub fn checked_neg(self) -> Result<Self> {
+                self.cneg()
+            }
+
             /// Calculates `(a + b) / 2`.
             #[inline]
             pub fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
@@ -475,6 +1154,56 @@ macro_rules! impl_fixed_point {
                 }
             }
 
+            /// Calculates the middle point of `self` and `other` rounded to the nearest value
+            /// (see [`RoundMode::Nearest`]), never overflows.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let bid: Price = "100.5".parse()?;
+            /// let ask: Price = "100.75".parse()?;
+            /// assert_eq!(bid.midpoint(ask), "100.625".parse()?);
+            /// assert_eq!(Price::MAX.midpoint(Price::MAX), Price::MAX);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn midpoint(self, other: Self) -> Self {
+                Self::half_sum(self, other, RoundMode::Nearest)
+            }
+
+            /// Calculates the absolute difference between `self` and `other`.
+            ///
+            /// The difference of signed numbers can exceed `MAX`, e.g. `MAX.abs_diff(MIN)`,
+            /// such results are saturated to `MAX`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let a: Price = "100.5".parse()?;
+            /// let b: Price = "-0.25".parse()?;
+            /// assert_eq!(a.abs_diff(b), "100.75".parse()?);
+            /// assert_eq!(b.abs_diff(a), "100.75".parse()?);
+            /// assert_eq!(Price::MAX.abs_diff(Price::MIN), Price::MAX);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn abs_diff(self, other: Self) -> Self {
+                let diff = self.inner.abs_diff(other.inner);
+                Self::from_bits($layout::try_from(diff).unwrap_or($layout::MAX))
+            }
+
             /// Takes [rounded][RoundMode] integral part of the number.
             ///
             /// ```
@@ -493,6 +1222,10 @@ macro_rules! impl_fixed_point {
             /// assert_eq!(a.integral(Floor), -8274);
             /// assert_eq!(a.integral(Nearest), -8274);
             /// assert_eq!(a.integral(Ceil), -8273);
+            ///
+            /// let a: Amount = "8272.5".parse()?;
+            /// assert_eq!(a.integral(Nearest), 8273);
+            /// assert_eq!(a.integral(HalfEven), 8272);
             /// # Ok(()) }
             /// # #[cfg(not(feature = "i64"))]
             /// # fn main() {}
@@ -500,21 +1233,60 @@ macro_rules! impl_fixed_point {
             #[inline]
             pub fn integral(self, mode: RoundMode) -> $layout {
                 let sign = self.inner.signum();
-                let (mut int, frac) = (self.inner / Self::COEF, self.inner.abs() % Self::COEF);
+                let (mut int, frac) = (self.inner / Self::COEF, (self.inner % Self::COEF).abs());
 
-                let add_signed_one = if mode == RoundMode::Nearest {
-                    frac + frac >= Self::COEF
-                } else {
-                    mode as i32 == sign as i32 && frac > 0
-                };
+                if frac != 0 {
+                    let half = frac.cmp(&(Self::COEF - frac));
 
-                if add_signed_one {
-                    int += sign;
+                    if mode.is_away_from_zero(sign as i32, half, int % 2 != 0) {
+                        int += sign;
+                    }
                 }
 
                 int
             }
 
+            /// Converts to an integer, unlike [`integral`](Self::integral) fails
+            /// if the value has a fractional part or doesn't fit in `T`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{typenum::U9, FixedPoint};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// assert_eq!(Amount::try_from_int(-42)?.try_into_int::<i8>()?, -42);
+            /// assert!("1.5".parse::<Amount>()?.try_into_int::<i64>().is_err());
+            /// assert!(Amount::try_from_int(300)?.try_into_int::<u8>().is_err());
+            /// assert!(Amount::try_from_int(-1)?.try_into_int::<u32>().is_err());
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn try_into_int<T: TryFrom<$layout>>(self) -> Result<T, ConvertError> {
+                if self.inner % Self::COEF != 0 {
+                    return Err(ConvertError::new("fractional part is not zero"));
+                }
+
+                T::try_from(self.inner / Self::COEF)
+                    .map_err(|_| ConvertError::new("too big number"))
+            }
+
+            /// Creates from an integer, fails if it doesn't fit in the layout.
+            #[inline]
+            pub fn try_from_int<T>(value: T) -> Result<Self, ConvertError>
+            where
+                $layout: TryFrom<T>,
+            {
+                $layout::try_from(value)
+                    .ok()
+                    .and_then(|int| int.checked_mul(Self::COEF))
+                    .map(Self::from_bits)
+                    .ok_or(ConvertError::new("too big number"))
+            }
+
             /// Returns the largest integer less than or equal to a number.
             #[inline]
             pub fn floor(self) -> Self {
@@ -533,31 +1305,66 @@ macro_rules! impl_fixed_point {
                 Self::from_decimal(self.integral(RoundMode::Nearest), 0).unwrap()
             }
 
-            /// Rounds towards zero by the provided precision.
+            /// Returns the integer part of a number, rounding towards zero. Never overflows.
             #[inline]
-            pub fn round_towards_zero_by(self, precision: Self) -> Self {
-                self.inner
-                    .checked_div(precision.inner)
-                    .and_then(|v| v.checked_mul(precision.inner))
-                    .map_or(self, Self::from_bits)
+            pub fn trunc(self) -> Self {
+                Self::from_bits(self.inner - self.inner % Self::COEF)
             }
 
-            /// Returns the next power of ten:
-            /// * For positive: the smallest greater than or equal to a number.
-            /// * For negative: the largest less than or equal to a number.
+            /// Returns the fractional part of a number, it has the same sign as the number,
+            /// so `x.trunc() + x.fract() == x`.
             #[inline]
-            pub fn next_power_of_ten(self) -> Result<Self> {
-                if self.inner < 0 {
-                    return self.cneg()?.next_power_of_ten()?.cneg();
-                }
-
-                let lz = self.inner.leading_zeros() as usize;
-                assert!(lz > 0, "unexpected negative value");
-
-                let value = power_table::$layout[lz];
+            pub fn fract(self) -> Self {
+                Self::from_bits(self.inner % Self::COEF)
+            }
+
+            /// Splits into the integral part and the fractional part in raw units, i.e. `10^-P`,
+            /// both have the same sign as the number.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2};
+            ///
+            /// type Price = FixedPoint<i64, U2>;
+            ///
+            /// let price: Price = "-12.34".parse()?;
+            /// assert_eq!(price.into_parts(), (-12, -34));
+            /// assert_eq!(price.trunc(), "-12".parse()?);
+            /// assert_eq!(price.fract(), "-0.34".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn into_parts(self) -> ($layout, $layout) {
+                (self.inner / Self::COEF, self.inner % Self::COEF)
+            }
+
+            /// Rounds towards zero by the provided precision.
+            #[inline]
+            pub fn round_towards_zero_by(self, precision: Self) -> Self {
+                self.inner
+                    .checked_div(precision.inner)
+                    .and_then(|v| v.checked_mul(precision.inner))
+                    .map_or(self, Self::from_bits)
+            }
+
+            /// Returns the next power of ten:
+            /// * For positive: the smallest greater than or equal to a number.
+            /// * For negative: the largest less than or equal to a number.
+            #[inline]
+            pub fn next_power_of_ten(self) -> Result<Self> {
+                if self.inner.is_negative() {
+                    return self.cneg()?.next_power_of_ten()?.cneg();
+                }
+
+                let lz = self.inner.leading_zeros() as usize;
+                let value = power_table::$layout[lz];
 
                 let value = if self.inner > value {
-                    power_table::$layout[lz - 1]
+                    // Only unsigned layouts can have no leading zeros here.
+                    lz.checked_sub(1).map_or(0, |lz| power_table::$layout[lz])
                 } else {
                     value
                 };
@@ -572,20 +1379,29 @@ macro_rules! impl_fixed_point {
             /// Returns the absolute value of a number.
             #[inline]
             pub fn abs(self) -> Result<Self> {
-                if self.inner < 0 {
+                if self.inner.is_negative() {
                     self.cneg()
                 } else {
                     Ok(self)
                 }
             }
 
+            /// The same as [`abs`](Self::abs), named after the primitive integers' method.
+            /// Returns `Err` on overflow (you can't take the absolute value of [`MIN` value][MIN]).
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn checked_abs(self) -> Result<Self> {
+                self.abs()
+            }
+
             /// Checked [rounding][RoundMode] square root.
             /// Returns `Err` for negative argument.
             ///
             /// Square root of a non-negative F is a non-negative S such that:
             /// * `Floor`: `S ≤ sqrt(F)`
             /// * `Ceil`: `S ≥ sqrt(F)`
-            /// * `Nearest`: `Floor` or `Ceil`, which one is closer to `sqrt(F)`
+            /// * `Nearest` and `HalfEven`: `Floor` or `Ceil`, which one is closer to `sqrt(F)`
             ///
             /// The fastest mode is `Floor`.
             ///
@@ -618,54 +1434,597 @@ macro_rules! impl_fixed_point {
                 // We'd like to gain `sqrt(S) * COEF`:
                 // `sqrt(S) * COEF = sqrt(S * COEF^2) = sqrt(S_inner * COEF)`
                 let squared = $promotion::from(self.inner) * Self::COEF_PROMOTED;
+                // `sqrt` can't take more bits than `self` already does, thus it never fails.
+                Self::sqrt_promoted(squared, mode)
+            }
+
+            /// Checked [rounding][RoundMode] length of the hypotenuse of a right-angle triangle,
+            /// i.e. `sqrt(self^2 + other^2)`. Returns `Err` on overflow.
+            ///
+            /// Squares are calculated exactly in the promoted type, so it doesn't overflow
+            /// for representable results. See [`rsqrt`][Self::rsqrt] for rounding details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            /// use fixnum::ops::RoundMode::*;
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "3".parse()?;
+            /// let b: Amount = "-4".parse()?;
+            /// assert_eq!(a.hypot(b, Floor)?, "5".parse()?);
+            ///
+            /// let c: Amount = "6000000000".parse()?;
+            /// assert_eq!(c.hypot(c, Floor)?, "8485281374.238570292".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn hypot(self, other: Self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::l2_norm(&[self, other], mode)
+            }
+
+            /// Checked [rounding][RoundMode] Euclidean norm (2-norm) of a vector,
+            /// i.e. `sqrt(x1^2 + x2^2 + ... + xn^2)`. Returns `Err` on overflow.
+            ///
+            /// Squares are summed exactly in the promoted type, so it doesn't overflow
+            /// for representable results. See [`rsqrt`][Self::rsqrt] for rounding details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            /// use fixnum::ops::RoundMode::*;
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let values: [Amount; 3] = ["1".parse()?, "-2".parse()?, "2".parse()?];
+            /// assert_eq!(Amount::l2_norm(&values, Floor)?, "3".parse()?);
+            /// assert_eq!(Amount::l2_norm(&values[..2], Floor)?, "2.236067977".parse()?);
+            /// assert_eq!(Amount::l2_norm(&[], Floor)?, "0".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn l2_norm(values: &[Self], mode: RoundMode) -> Result<Self, ArithmeticError> {
+                // `sqrt(S_inner1^2 + S_inner2^2 + ...) = sqrt(S1^2 + S2^2 + ...) * COEF`.
+                // Partial sums don't decrease, so if any of them overflows the promoted type,
+                // the result doesn't fit in the layout either.
+                let squared = values.iter().try_fold($promotion::ZERO, |sum, value| {
+                    let value = $promotion::from(value.inner);
+                    sum.checked_add(value * value).ok_or(ArithmeticError::Overflow)
+                })?;
+
+                Self::sqrt_promoted(squared, mode)
+            }
+
+            /// [Rounding][RoundMode] square root of the non-negative promoted raw value.
+            fn sqrt_promoted(squared: $promotion, mode: RoundMode) -> Result<Self, ArithmeticError> {
                 let lo = squared.sqrt()?;
 
                 let add_one = match mode {
                     RoundMode::Floor => false,
-                    RoundMode::Nearest => {
-                        let lo2 = lo * lo;
-                        // (lo+1)^2 = lo^2 +2lo + 1
-                        let hi2 = lo2 + lo + lo + $promotion::ONE;
-                        squared - lo2 >= hi2 - squared
-                    },
+                    // The square root of an integer is never a half-way case.
+                    // `sqrt(S) ≥ lo + 1/2` iff `S ≥ lo^2 + lo + 1/4`, i.e. `S - lo^2 > lo`
+                    // for integers, it doesn't overflow unlike `(lo + 1)^2`.
+                    RoundMode::Nearest | RoundMode::HalfEven => squared - lo * lo > lo,
                     RoundMode::Ceil if lo * lo == squared => false,
                     RoundMode::Ceil => true,
                 };
 
-                // `sqrt` can't take more bits than `self` already does, thus `unwrap()` is ok.
-                let lo = $layout::try_from(lo).unwrap();
+                let lo = $layout::try_from(lo).map_err(|_| ArithmeticError::Overflow)?;
                 let inner = if add_one {
-                    lo + $layout::ONE
+                    lo.checked_add($layout::ONE).ok_or(ArithmeticError::Overflow)?
                 } else {
                     lo
                 };
 
                 Ok(Self::from_bits(inner))
             }
+
+            /// Checked [rounding][RoundMode] raising to an integer power.
+            /// Returns `Err` on overflow.
+            ///
+            /// Uses exponentiation by squaring, every multiplication is rounded with the provided
+            /// mode. The magnitude is rounded consistently, so `Floor` and `Ceil` always return
+            /// a lower and an upper bound of the mathematical result respectively.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::{Bounded, One, RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "1.5".parse()?;
+            /// let b: Amount = "1.001".parse()?;
+            /// assert_eq!(a.checked_powi(3, Floor)?, "3.375".parse()?);
+            /// assert_eq!(a.checked_powi(0, Floor)?, Amount::ONE);
+            /// assert_eq!(b.checked_powi(365, Floor)?, "1.440251273".parse()?);
+            /// assert_eq!(b.checked_powi(365, Ceil)?, "1.440251524".parse()?);
+            /// assert_eq!(Amount::MAX.checked_powi(2, Floor), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn checked_powi(self, exp: u32, mode: RoundMode) -> Result<Self> {
+                match exp {
+                    0 => return Ok(Self::ONE),
+                    1 => return Ok(self),
+                    _ => {}
+                }
+
+                // Work with the magnitude, so the result of odd powers of negative numbers
+                // is rounded in the opposite direction.
+                let is_negative = self.inner.is_negative() && exp % 2 == 1;
+                let mode = match mode {
+                    RoundMode::Floor if is_negative => RoundMode::Ceil,
+                    RoundMode::Ceil if is_negative => RoundMode::Floor,
+                    mode => mode,
+                };
+
+                let mut base = self.abs()?;
+                let mut result = Self::ONE;
+                let mut exp = exp;
+
+                loop {
+                    if exp & 1 == 1 {
+                        result = result.rmul(base, mode)?;
+                    }
+
+                    exp >>= 1;
+                    if exp == 0 {
+                        break;
+                    }
+
+                    base = base.rmul(base, mode)?;
+                }
+
+                if is_negative {
+                    result.cneg()
+                } else {
+                    Ok(result)
+                }
+            }
+
+            /// Checked [rounding][RoundMode] `self * mul / div`.
+            /// Returns `Err` on overflow or division by zero.
+            ///
+            /// The product is calculated using the promoted type, so it can exceed the range of
+            /// the layout. Only the final result is rounded, thus it's more precise than
+            /// `self.rmul(mul, mode)?.rdiv(div, mode)`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::{Bounded, RoundMode::*, Zero};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "1000".parse()?;
+            /// let b: Amount = "7".parse()?;
+            /// let c: Amount = "3".parse()?;
+            /// assert_eq!(a.mul_div(b, c, Floor)?, "2333.333333333".parse()?);
+            /// assert_eq!(a.mul_div(b, c, Ceil)?, "2333.333333334".parse()?);
+            ///
+            /// // The product doesn't fit, but the result does.
+            /// assert_eq!(Amount::MAX.mul_div(c, c, Floor)?, Amount::MAX);
+            /// assert_eq!(a.mul_div(b, Amount::ZERO, Floor), Err(ArithmeticError::DivisionByZero));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn mul_div(self, mul: Self, div: Self, mode: RoundMode) -> Result<Self> {
+                if div.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `(a * COEF) * (b * COEF) / (c * COEF) = (a * b / c) * COEF`
+                let numerator = $promotion::from(self.inner) * $promotion::from(mul.inner);
+                Self::div_promoted(numerator, div.inner, mode)
+            }
+
+            /// Converts a fraction to percents, e.g. `0.025` to `2.5`.
+            /// Returns `Err` on overflow.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U4, ops::RoundMode::*};
+            ///
+            /// type Rate = FixedPoint<i64, U4>;
+            ///
+            /// let rate: Rate = "0.025".parse()?;
+            /// assert_eq!(rate.as_percent()?, "2.5".parse()?);
+            /// assert_eq!(Rate::from_percent("2.5".parse()?, Floor)?, rate);
+            /// assert_eq!(Rate::from_percent("0.005".parse()?, Ceil)?, "0.0001".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn as_percent(self) -> Result<Self> {
+                let inner = self.inner.checked_mul(100).ok_or(ArithmeticError::Overflow)?;
+                Ok(Self::from_bits(inner))
+            }
+
+            /// Converts percents to a fraction, e.g. `2.5` to `0.025`,
+            /// [rounding][RoundMode] extra fractional digits according to `mode`.
+            ///
+            /// See [`as_percent`](Self::as_percent) for the example.
+            #[inline]
+            pub fn from_percent(percent: Self, mode: RoundMode) -> Result<Self> {
+                percent.inner.rdiv(100, mode).map(Self::from_bits)
+            }
+
+            /// Takes `self` percents of `base`, i.e. calculates `self * base / 100`
+            /// [rounded][RoundMode] once according to `mode`.
+            /// Returns `Err` if the result doesn't fit.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U2>;
+            ///
+            /// let fee: Amount = "2.5".parse()?;
+            /// let price: Amount = "10.1".parse()?;
+            /// assert_eq!(fee.apply_percent(price, Floor)?, "0.25".parse()?);
+            /// assert_eq!(fee.apply_percent(price, Ceil)?, "0.26".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn apply_percent(self, base: Self, mode: RoundMode) -> Result<Self> {
+                // The product of raw values has `2 * PRECISION` fractional digits.
+                let product = $promotion::from(self.inner) * $promotion::from(base.inner);
+                let divisor = Self::COEF_PROMOTED * $promotion::from(100 as $layout);
+                let result = product.rdiv(divisor, mode)?;
+                $layout::try_from(result)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Creates a fraction from basis points, e.g. `25` to `0.0025`.
+            /// Fails if the value doesn't fit or requires rounding because `PRECISION < 4`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::{U2, U6}, ops::RoundMode::*};
+            ///
+            /// type Rate = FixedPoint<i64, U6>;
+            /// type Cents = FixedPoint<i64, U2>;
+            ///
+            /// let spread = Rate::from_bps(25)?;
+            /// assert_eq!(spread, "0.0025".parse()?);
+            /// assert_eq!(spread.to_bps(Floor)?, 25);
+            /// assert_eq!(Rate::from_bps(-100)?, "-0.01".parse()?);
+            /// assert_eq!(Cents::from_bps(100)?, "0.01".parse()?);
+            /// assert!(Cents::from_bps(25).is_err());
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn from_bps(bps: i64) -> Result<Self, ConvertError> {
+                let bps_coef: $layout = 10_000;
+                let inner = if Self::COEF >= bps_coef {
+                    $layout::try_from(bps)
+                        .ok()
+                        .and_then(|bps| bps.checked_mul(Self::COEF / bps_coef))
+                } else {
+                    // `10_000 / COEF` is a power of ten and fits in `i64`.
+                    let divisor = (bps_coef / Self::COEF) as i64;
+                    if bps % divisor != 0 {
+                        return Err(ConvertError::new("requires rounding"));
+                    }
+                    $layout::try_from(bps / divisor).ok()
+                };
+
+                inner
+                    .map(Self::from_bits)
+                    .ok_or(ConvertError::new("too big number"))
+            }
+
+            /// Converts to basis points, e.g. `0.0025` to `25`,
+            /// [rounding][RoundMode] fractions of a basis point according to `mode`.
+            /// Returns `Err` if the result doesn't fit in `i64`.
+            ///
+            /// See [`from_bps`](Self::from_bps) for the example.
+            pub fn to_bps(self, mode: RoundMode) -> Result<i64> {
+                let bps_coef: $layout = 10_000;
+                let bps = if Self::COEF > bps_coef {
+                    self.inner.rdiv(Self::COEF / bps_coef, mode)?
+                } else {
+                    self.inner.cmul(bps_coef / Self::COEF)?
+                };
+
+                i64::try_from(bps).map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Takes `bps` basis points of `self`, i.e. calculates `self * bps / 10_000`
+            /// [rounded][RoundMode] once according to `mode`.
+            /// Returns `Err` if `bps` or the result doesn't fit in the layout.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U2>;
+            ///
+            /// let notional: Amount = "1234.56".parse()?;
+            /// assert_eq!(notional.apply_bps(15, Floor)?, "1.85".parse()?);
+            /// assert_eq!(notional.apply_bps(15, Ceil)?, "1.86".parse()?);
+            /// assert_eq!(notional.apply_bps(-15, Floor)?, "-1.86".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn apply_bps(self, bps: i64, mode: RoundMode) -> Result<Self> {
+                let bps = $layout::try_from(bps).map_err(|_| ArithmeticError::Overflow)?;
+                let product = $promotion::from(self.inner) * $promotion::from(bps);
+                let result = product.rdiv($promotion::from(10_000 as $layout), mode)?;
+                $layout::try_from(result)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Linearly interpolates between `self` and `other`, i.e. calculates
+            /// `self + (other - self) * t` [rounded][RoundMode] according to `mode`.
+            /// `t` isn't limited to `[0, 1]`, so it extrapolates as well.
+            ///
+            /// Intermediate values are widened, so it fails only if the result doesn't fit.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Rate = FixedPoint<i64, U9>;
+            ///
+            /// let one_year: Rate = "0.031".parse()?;
+            /// let two_years: Rate = "0.034".parse()?;
+            /// let t: Rate = "0.25".parse()?;
+            /// assert_eq!(one_year.lerp(two_years, t, Nearest)?, "0.03175".parse()?);
+            ///
+            /// let half: Rate = "0.5".parse()?;
+            /// assert_eq!(Rate::MIN.lerp(Rate::MAX, half, Floor)?, "-0.000000001".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn lerp(self, other: Self, t: Self, mode: RoundMode) -> Result<Self> {
+                let (lhs, rhs) = ($promotion::from(self.inner), $promotion::from(other.inner));
+                let base = lhs * Self::COEF_PROMOTED;
+                let t = $promotion::from(t.inner);
+
+                // `|other - self| * |t| < 2^(2 * BITS - 1)`, so products can't overflow.
+                let numerator = if rhs >= lhs {
+                    base.checked_add((rhs - lhs) * t)
+                } else {
+                    base.checked_sub((lhs - rhs) * t)
+                };
+
+                Self::from_promoted(numerator.ok_or(ArithmeticError::Overflow)?, mode)
+            }
+
+            /// Divides the promoted raw value by the non-zero raw `div` with rounding.
+            fn div_promoted(numerator: $promotion, div: $layout, mode: RoundMode) -> Result<Self> {
+                let denominator = $promotion::from(div);
+                let result = numerator / denominator;
+                let loss = numerator - result * denominator;
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                // `|loss| < denominator`, thus it fits in the layout.
+                let loss = $layout::try_from(loss).unwrap();
+
+                if loss != 0 {
+                    // The loss has the same sign as the numerator.
+                    let sign = loss.signum() * div.signum();
+                    // Compare `|loss|` with `|div| / 2` avoiding overflow.
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(div.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign as i32, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+
+            /// Rounds to a multiple of `step`, e.g. a price to the tick size.
+            ///
+            /// It also covers cash rounding of point-of-sale totals to legal increments,
+            /// e.g. `0.05` for CHF, `0.1` for NZD or `1` for SEK, usually with `Nearest`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let tick: Price = "0.05".parse()?;
+            /// let a: Price = "1.234".parse()?;
+            /// assert_eq!(a.round_to_step(tick, Floor)?, "1.2".parse()?);
+            /// assert_eq!(a.round_to_step(tick, Nearest)?, "1.25".parse()?);
+            /// assert_eq!(a.round_to_step(tick, Ceil)?, "1.25".parse()?);
+            ///
+            /// let b: Price = "-1.225".parse()?;
+            /// assert_eq!(b.round_to_step(tick, Floor)?, "-1.25".parse()?);
+            /// assert_eq!(b.round_to_step(tick, Nearest)?, "-1.25".parse()?);
+            /// assert_eq!(b.round_to_step(tick, HalfEven)?, "-1.2".parse()?);
+            /// assert_eq!(a.round_to_step(Price::ZERO, Floor), Err(ArithmeticError::DivisionByZero));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn round_to_step(self, step: Self, mode: RoundMode) -> Result<Self> {
+                if step.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let mut quotient = self
+                    .inner
+                    .checked_div(step.inner)
+                    .ok_or(ArithmeticError::Overflow)?;
+                let loss = self.inner - quotient * step.inner;
+
+                if loss != 0 {
+                    let sign = self.inner.signum();
+                    // Compare `|loss|` with `|step| / 2` avoiding overflow.
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(step.inner.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign as i32, half, quotient % 2 != 0) {
+                        // Moves the result away from zero regardless of the sign of `step`.
+                        quotient += sign * step.inner.signum();
+                    }
+                }
+
+                quotient
+                    .checked_mul(step.inner)
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// [Rounds][RoundMode] to `fractional_digits` decimal places, zeroing out
+            /// the digits below while keeping the precision of the type.
+            /// Returns `self` if `fractional_digits` isn't less than the precision.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let a: Price = "-12.3456".parse()?;
+            /// assert_eq!(a.round_to_precision(2, Floor)?, "-12.35".parse()?);
+            /// assert_eq!(a.round_to_precision(2, Ceil)?, "-12.34".parse()?);
+            /// assert_eq!(a.round_to_precision(0, Nearest)?, "-12".parse()?);
+            /// assert_eq!(a.round_to_precision(12, Floor)?, a);
+            /// assert_eq!(Price::MAX.round_to_precision(2, Ceil), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn round_to_precision(self, fractional_digits: u8, mode: RoundMode) -> Result<Self> {
+                if i32::from(fractional_digits) >= Self::PRECISION {
+                    return Ok(self);
+                }
+
+                let ten: $layout = 10;
+                let step = Self::COEF / ten.pow(u32::from(fractional_digits));
+                self.round_to_step(Self::from_bits(step), mode)
+            }
+
+            /// [Rounds][RoundMode] to `figures` significant digits.
+            /// Returns [`ArithmeticError::DomainViolation`] if `figures` is zero.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "12345.678".parse()?;
+            /// assert_eq!(a.round_significant(3, Floor)?, "12300".parse()?);
+            /// assert_eq!(a.round_significant(3, Ceil)?, "12400".parse()?);
+            ///
+            /// let b: Amount = "-0.0012345".parse()?;
+            /// assert_eq!(b.round_significant(2, Nearest)?, "-0.0012".parse()?);
+            /// assert_eq!(b.round_significant(10, Nearest)?, b);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn round_significant(self, figures: u8, mode: RoundMode) -> Result<Self> {
+                if figures == 0 {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                let digits = match self.inner.unsigned_abs().checked_ilog10() {
+                    Some(log) => log + 1,
+                    None => return Ok(self),
+                };
+
+                if digits <= u32::from(figures) {
+                    return Ok(self);
+                }
+
+                // `step < |self|`, thus it fits in the layout.
+                let ten: $layout = 10;
+                let step = ten.pow(digits - u32::from(figures));
+                self.round_to_step(Self::from_bits(step), mode)
+            }
         }
 
         $(#[$attr])?
         impl<P: Precision> fmt::Debug for FixedPoint<$layout, P> {
+            /// Writes the decimal value, e.g. `10.042`. The alternate flag (`{:#?}`) adds the type
+            /// and the raw representation: `FixedPoint<i64, U9>(10.042 = 10042000000)`.
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let mut buf = Default::default();
                 self.stringify(&mut buf);
-                f.write_str(buf.as_str())
+                if f.alternate() {
+                    string::debug_number(buf.as_str(), stringify!($layout), P::U32, f)
+                } else {
+                    f.write_str(buf.as_str())
+                }
             }
         }
 
         $(#[$attr])?
         impl<P: Precision> fmt::Display for FixedPoint<$layout, P> {
+            /// Supports precision (rounding half to even), width, fill, alignment and sign flags.
+            /// The alternate flag (`{:#}`) writes exactly `P` fractional digits.
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let mut buf = Default::default();
                 self.stringify(&mut buf);
-                f.write_str(buf.as_str())
+                string::pad_number(buf.as_str(), P::U32, &FormatOptions::DEFAULT, f)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::LowerExp for FixedPoint<$layout, P> {
+            /// Writes normalized scientific notation like primitive integers do, e.g. `1.5e-3`.
+            /// Supports precision (rounding half to even), width, fill, alignment and sign flags.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = Default::default();
+                self.stringify(&mut buf);
+                string::pad_exponent(buf.as_str(), false, f)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::UpperExp for FixedPoint<$layout, P> {
+            /// The same as `LowerExp`, but with `E`, e.g. `1.5E-3`.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = Default::default();
+                self.stringify(&mut buf);
+                string::pad_exponent(buf.as_str(), true, f)
             }
         }
 
         $(#[$attr])?
         impl<P: Precision> FixedPoint<$layout, P> {
             /// Creates a new number from separate mantissa and exponent.
-            pub fn from_decimal(mantissa: $layout, exponent: i32) -> Result<Self, ConvertError> {
+            pub const fn from_decimal(
+                mantissa: $layout,
+                exponent: i32,
+            ) -> Result<Self, ConvertError> {
                 if exponent < -Self::PRECISION || exponent > 10 {
                     return Err(ConvertError::new("unsupported exponent"));
                 }
@@ -673,10 +2032,112 @@ macro_rules! impl_fixed_point {
                 let ten: $layout = 10;
                 let multiplier = ten.pow((exponent + Self::PRECISION) as u32);
 
-                mantissa
-                    .checked_mul(multiplier)
-                    .map(Self::from_bits)
-                    .map_or_else(|| Err(ConvertError::new("too big mantissa")), Ok)
+                match mantissa.checked_mul(multiplier) {
+                    Some(inner) => Ok(Self::from_bits(inner)),
+                    None => Err(ConvertError::new("too big mantissa")),
+                }
+            }
+
+            /// Returns the raw representation as a byte array in little-endian byte order.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; core::mem::size_of::<$layout>()] {
+                self.inner.to_le_bytes()
+            }
+
+            /// Returns the raw representation as a byte array in big-endian byte order.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; core::mem::size_of::<$layout>()] {
+                self.inner.to_be_bytes()
+            }
+
+            /// Creates from the raw representation as a byte array in little-endian byte order.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; core::mem::size_of::<$layout>()]) -> Self {
+                Self::from_bits($layout::from_le_bytes(bytes))
+            }
+
+            /// Creates from the raw representation as a byte array in big-endian byte order.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; core::mem::size_of::<$layout>()]) -> Self {
+                Self::from_bits($layout::from_be_bytes(bytes))
+            }
+
+            /// Encodes as big-endian bytes with the flipped sign bit, so lexicographic order
+            /// of encoded values matches their numeric order, e.g. for range scans over keys.
+            #[inline]
+            pub const fn to_sortable_bytes(self) -> [u8; core::mem::size_of::<$layout>()] {
+                // `MIN` is the sign bit for signed layouts and zero for unsigned ones.
+                (self.inner ^ $layout::MIN).to_be_bytes()
+            }
+
+            /// Decodes bytes produced by [`to_sortable_bytes`](Self::to_sortable_bytes).
+            #[inline]
+            pub const fn from_sortable_bytes(bytes: [u8; core::mem::size_of::<$layout>()]) -> Self {
+                Self::from_bits($layout::from_be_bytes(bytes) ^ $layout::MIN)
+            }
+
+            /// The same as [`CheckedAdd::cadd`], but usable in `const` contexts.
+            #[inline]
+            pub const fn const_cadd(self, rhs: Self) -> Result<Self> {
+                match self.inner.checked_add(rhs.inner) {
+                    Some(inner) => Ok(Self::from_bits(inner)),
+                    None => Err(ArithmeticError::Overflow),
+                }
+            }
+
+            /// The same as [`CheckedSub::csub`], but usable in `const` contexts.
+            #[inline]
+            pub const fn const_csub(self, rhs: Self) -> Result<Self> {
+                match self.inner.checked_sub(rhs.inner) {
+                    Some(inner) => Ok(Self::from_bits(inner)),
+                    None => Err(ArithmeticError::Overflow),
+                }
+            }
+
+            /// The same as [`CheckedMul::cmul`], but usable in `const` contexts.
+            #[inline]
+            pub const fn const_cmul(self, rhs: $layout) -> Result<Self> {
+                match self.inner.checked_mul(rhs) {
+                    Some(inner) => Ok(Self::from_bits(inner)),
+                    None => Err(ArithmeticError::Overflow),
+                }
+            }
+
+            /// The same as [`Ord::min`], but usable in `const` contexts.
+            #[inline]
+            pub const fn min(self, other: Self) -> Self {
+                if self.inner <= other.inner {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            /// The same as [`Ord::max`], but usable in `const` contexts.
+            #[inline]
+            pub const fn max(self, other: Self) -> Self {
+                if self.inner >= other.inner {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            /// The same as [`Ord::clamp`], but usable in `const` contexts.
+            ///
+            /// # Panics
+            /// If `min > max`.
+            #[inline]
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min.inner <= max.inner);
+
+                if self.inner < min.inner {
+                    min
+                } else if self.inner > max.inner {
+                    max
+                } else {
+                    self
+                }
             }
 
             /// Returns a pair `(mantissa, exponent)` where `exponent`
@@ -709,6 +2170,99 @@ macro_rules! impl_fixed_point {
 
                 (mantissa, exponent)
             }
+
+            /// Converts to another precision.
+            /// Returns `Err` on overflow.
+            ///
+            /// Widening is lossless, narrowing is [rounded][RoundMode] with the provided mode.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::{U2, U9}};
+            /// use fixnum::ops::RoundMode::*;
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            /// type Cents = FixedPoint<i64, U2>;
+            ///
+            /// let a: Amount = "12.345".parse()?;
+            /// assert_eq!(a.rescale::<U2>(Floor)?, "12.34".parse::<Cents>()?);
+            /// assert_eq!(a.rescale::<U2>(Nearest)?, "12.35".parse::<Cents>()?);
+            /// assert_eq!(a.rescale::<U2>(HalfEven)?, "12.34".parse::<Cents>()?);
+            ///
+            /// let c: Cents = "12.34".parse()?;
+            /// assert_eq!(c.rescale::<U9>(Floor)?, "12.34".parse::<Amount>()?);
+            /// assert_eq!(Cents::MAX.rescale::<U9>(Floor), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rescale<Q: Precision>(self, mode: RoundMode) -> Result<FixedPoint<$layout, Q>> {
+                let from = Self::COEF;
+                let to = FixedPoint::<$layout, Q>::COEF;
+
+                // Both coefficients are powers of ten, thus the division is exact.
+                let inner = if to >= from {
+                    self.inner.cmul(to / from)?
+                } else {
+                    self.inner.rdiv(from / to, mode)?
+                };
+
+                Ok(FixedPoint::from_bits(inner))
+            }
+
+            /// Converts an amount to another currency by `rate`, i.e. calculates `self * rate`
+            /// with the precision `Q` of the quote currency.
+            /// Returns `Err` if the result doesn't fit.
+            ///
+            /// The product of raw values is calculated exactly using the promoted type,
+            /// then it's [rounded][RoundMode] once according to `mode` to `Q` fractional digits.
+            /// Thus, it's more precise than `self.rmul(rate, mode)?.rescale::<Q>(mode)`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::{U0, U2, U4, U6}, ops::RoundMode::*};
+            ///
+            /// type Eur = FixedPoint<i64, U2>;
+            /// type Usd = FixedPoint<i64, U2>;
+            /// type Jpy = FixedPoint<i64, U0>;
+            ///
+            /// let amount: Eur = "1234.56".parse()?;
+            /// let eur_usd: FixedPoint<i64, U6> = "1.084321".parse()?;
+            /// let eur_jpy: FixedPoint<i64, U4> = "162.3456".parse()?;
+            ///
+            /// let usd: Usd = amount.convert(eur_usd, Floor)?;
+            /// assert_eq!(usd, "1338.65".parse()?);
+            /// assert_eq!(amount.convert::<_, U2>(eur_usd, Ceil)?, "1338.66".parse()?);
+            /// let jpy: Jpy = amount.convert(eur_jpy, Nearest)?;
+            /// assert_eq!(jpy, "200425".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn convert<R: Precision, Q: Precision>(
+                self,
+                rate: FixedPoint<$layout, R>,
+                mode: RoundMode,
+            ) -> Result<FixedPoint<$layout, Q>> {
+                // The product has `P + R` fractional digits, the scale fits in the promoted type.
+                let product = $promotion::from(self.inner) * $promotion::from(rate.inner);
+                let scale = Self::COEF_PROMOTED * FixedPoint::<$layout, R>::COEF_PROMOTED;
+                let to = FixedPoint::<$layout, Q>::COEF_PROMOTED;
+
+                // Both are powers of ten, thus divisions are exact.
+                let (product, multiplier) = if scale >= to {
+                    (product.rdiv(scale / to, mode)?, 1)
+                } else {
+                    (product, $layout::try_from(to / scale).map_err(|_| ArithmeticError::Overflow)?)
+                };
+
+                $layout::try_from(product)
+                    .map_err(|_| ArithmeticError::Overflow)?
+                    .cmul(multiplier)
+                    .map(FixedPoint::from_bits)
+            }
         }
 
         impl<P: Precision> From<FixedPoint<$layout, P>> for f64 {
@@ -720,6 +2274,15 @@ macro_rules! impl_fixed_point {
             }
         }
 
+        impl<P: Precision> From<FixedPoint<$layout, P>> for f32 {
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                let coef = FixedPoint::<$layout, P>::COEF;
+                let integral = (value.inner / coef) as f32;
+                let fractional = ((value.inner % coef) as f32) / (coef as f32);
+                integral + fractional
+            }
+        }
+
         $(
             // TODO: how to make the repetition replacement trick with `$(#[$attr])`?
             impl<P: Precision> TryFrom<$try_from> for FixedPoint<$layout, P> {
@@ -735,9 +2298,71 @@ macro_rules! impl_fixed_point {
             }
         )*
     };
+    (@nonzero $layout:tt [$($int:tt),*]) => {
+        $(
+            impl IntOperand<$layout> for NonZero<$int> {
+                #[inline]
+                fn mul_int(lhs: $layout, rhs: Self) -> Result<$layout> {
+                    <$int as IntOperand<$layout>>::mul_int(lhs, rhs.get())
+                }
+
+                #[inline]
+                fn div_int(lhs: $layout, rhs: Self, mode: RoundMode) -> Result<$layout> {
+                    <$int as IntOperand<$layout>>::div_int(lhs, rhs.get(), mode)
+                }
+            }
+        )*
+    };
+    (@by_ref $layout:tt $rhs:ty, $trait:ident::$method:ident($( $arg:ident: $arg_ty:ty ),*)) => {
+        impl<'a, P: Precision> $trait<&'a $rhs> for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn $method(self, rhs: &'a $rhs $(, $arg: $arg_ty )*) -> Result<Self> {
+                self.$method(*rhs $(, $arg )*)
+            }
+        }
+
+        impl<'a, P: Precision> $trait<$rhs> for &'a FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn $method(self, rhs: $rhs $(, $arg: $arg_ty )*) -> Result<Self::Output> {
+                (*self).$method(rhs $(, $arg )*)
+            }
+        }
+
+        impl<'a, 'b, P: Precision> $trait<&'b $rhs> for &'a FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn $method(self, rhs: &'b $rhs $(, $arg: $arg_ty )*) -> Result<Self::Output> {
+                (*self).$method(*rhs $(, $arg )*)
+            }
+        }
+    };
 }
 
-#[cfg(any(feature = "i64", feature = "i32", feature = "i16"))]
+#[cfg(primitive_layout)]
+mod private {
+    use crate::ops::RoundMode;
+    use crate::ArithmeticError;
+
+    /// An integer operand of [`RoundingMul`] and [`RoundingDiv`] for the layout `I`.
+    ///
+    /// [`RoundingMul`]: crate::ops::RoundingMul
+    /// [`RoundingDiv`]: crate::ops::RoundingDiv
+    #[allow(unreachable_pub)]
+    pub trait IntOperand<I>: Copy {
+        fn mul_int(lhs: I, rhs: Self) -> Result<I, ArithmeticError>;
+        fn div_int(lhs: I, rhs: Self, mode: RoundMode) -> Result<I, ArithmeticError>;
+    }
+}
+
+#[cfg(any(feature = "i64", feature = "i32", feature = "i16", feature = "u64"))]
 const fn identity<T>(x: T) -> T {
     x
 }
@@ -749,6 +2374,7 @@ impl_fixed_point!(
     promoted_to = i32;
     convert = identity;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    int_operands = [i8, u8, u16, i32, u32, i64, u64, i128] via i128;
 );
 #[cfg(feature = "i32")]
 impl_fixed_point!(
@@ -757,6 +2383,7 @@ impl_fixed_point!(
     promoted_to = i64;
     convert = identity;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    int_operands = [i8, u8, i16, u16, u32, i64, u64, i128] via i128;
 );
 #[cfg(feature = "i64")]
 impl_fixed_point!(
@@ -765,6 +2392,7 @@ impl_fixed_point!(
     promoted_to = i128;
     convert = identity;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    int_operands = [i8, u8, i16, u16, i32, u32, u64, i128] via i128;
 );
 #[cfg(feature = "i128")]
 impl_fixed_point!(
@@ -773,4 +2401,23 @@ impl_fixed_point!(
     promoted_to = I256;
     convert = I256::from_i128;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    int_operands = [i8, u8, i16, u16, i32, u32, i64, u64, u128] via I256;
+);
+#[cfg(feature = "u64")]
+impl_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]
+    inner = u64;
+    promoted_to = u128;
+    convert = identity;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    int_operands = [i8, u8, i16, u16, i32, u32, i64, i128] via i128;
+);
+#[cfg(feature = "u128")]
+impl_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]
+    inner = u128;
+    promoted_to = U256;
+    convert = U256::from_u128;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+    int_operands = [i8, u8, i16, u16, i32, u32, i64, u64, i128] via I256;
 );
@@ -41,12 +41,16 @@ macro_rules! impl_int_operand {
     }
 }
 
-// TODO: unsigned?
 impl_int_operand!(i8 => i8, i16, i32, i64, i128);
 impl_int_operand!(i16 => i16, i32, i64, i128);
 impl_int_operand!(i32 => i32, i64, i128);
 impl_int_operand!(i64 => i64, i128);
 impl_int_operand!(i128 => i128);
+impl_int_operand!(u8 => u8, u16, u32, u64, u128);
+impl_int_operand!(u16 => u16, u32, u64, u128);
+impl_int_operand!(u32 => u32, u64, u128);
+impl_int_operand!(u64 => u64, u128);
+impl_int_operand!(u128 => u128);
 
 /// Defines an operation for some wrapper. See top-level documentation.
 #[macro_export]
@@ -60,10 +64,16 @@ macro_rules! impl_op {
             fn cadd(self, rhs: $rhs) -> Result<$res, $crate::ArithmeticError> {
                 $crate::impl_op!(@checked_method (l = self, r = rhs) => l.cadd(r), $res)
             }
+        }
+
+        impl $crate::ops::SaturatingAdd<$rhs> for $lhs {
+            type Output = $res;
 
             #[inline]
             fn saturating_add(self, rhs: $rhs) -> Self::Output {
-                $crate::impl_op!(@method (l = self, r = rhs) => l.saturating_add(r), $res)
+                $crate::impl_op!(@method (l = self, r = rhs) => {
+                    $crate::ops::SaturatingAdd::saturating_add(l, r)
+                }, $res)
             }
         }
     };
@@ -76,10 +86,16 @@ macro_rules! impl_op {
             fn csub(self, rhs: $rhs) -> Result<$res, $crate::ArithmeticError> {
                 $crate::impl_op!(@checked_method (l = self, r = rhs) => l.csub(r), $res)
             }
+        }
+
+        impl $crate::ops::SaturatingSub<$rhs> for $lhs {
+            type Output = $res;
 
             #[inline]
             fn saturating_sub(self, rhs: $rhs) -> Self::Output {
-                $crate::impl_op!(@method (l = self, r = rhs) => l.saturating_sub(r), $res)
+                $crate::impl_op!(@method (l = self, r = rhs) => {
+                    $crate::ops::SaturatingSub::saturating_sub(l, r)
+                }, $res)
             }
         }
     };
@@ -93,6 +109,17 @@ macro_rules! impl_op {
                 $crate::impl_op!(@checked_method (l = self, r = rhs) => l.cmul(r), $res)
             }
         }
+
+        impl $crate::ops::SaturatingMul<$rhs> for $lhs {
+            type Output = $res;
+
+            #[inline]
+            fn saturating_mul(self, rhs: $rhs) -> Self::Output {
+                $crate::impl_op!(@method (l = self, r = rhs) => {
+                    $crate::ops::SaturatingMul::saturating_mul(l, r)
+                }, $res)
+            }
+        }
     };
     ($lhs:ty [rmul] $rhs:ty = $res:tt) => {
         impl $crate::ops::RoundingMul<$rhs> for $lhs {
@@ -108,6 +135,17 @@ macro_rules! impl_op {
                 $crate::impl_op!(@checked_method (l = self, r = rhs) => l.rmul(r, mode), $res)
             }
         }
+
+        impl $crate::ops::SaturatingRMul<$rhs> for $lhs {
+            type Output = $res;
+
+            #[inline]
+            fn saturating_rmul(self, rhs: $rhs, mode: $crate::ops::RoundMode) -> Self::Output {
+                $crate::impl_op!(@method (l = self, r = rhs) => {
+                    $crate::ops::SaturatingRMul::saturating_rmul(l, r, mode)
+                }, $res)
+            }
+        }
     };
     ($lhs:ty [rdiv] $rhs:ty = $res:tt) => {
         impl $crate::ops::RoundingDiv<$rhs> for $lhs {
@@ -150,6 +188,7 @@ macro_rules! impl_op {
 }
 
 /// Macro to create fixed-point const "literals".
+/// Exponent notation is supported, e.g. `fixnum_const!(2E6, 9)`.
 ///
 /// ```
 /// use derive_more::From;
@@ -169,7 +208,7 @@ macro_rules! impl_op {
 ///
 /// macro_rules! fp_const {
 ///     ($value:literal) => {
-///         fixnum::fixnum_const!($value, 9);
+///         fixnum::fixnum_const!($value, 9)
 ///     };
 /// }
 ///
@@ -178,8 +217,8 @@ macro_rules! impl_op {
 #[macro_export]
 macro_rules! fixnum_const {
     ($value:literal, $precision:literal) => {{
-        use $crate::FixedPoint;
         use $crate::_priv::*;
+        use $crate::FixedPoint;
         const VALUE_INNER: Int = parse_fixed(stringify!($value), pow10($precision));
         FixedPoint::from_bits(VALUE_INNER as _)
     }};
@@ -187,6 +226,8 @@ macro_rules! fixnum_const {
 
 /// Macro to create fixed-point "literals". Contains `.into()` call inside so you can use it with your
 /// `From<FixedPoint>` wrapper types.
+/// Literals can be written in exponent notation, e.g. `fixnum!(1.5e-3, 9)`,
+/// but must be representable with the precision exactly.
 ///
 /// ```
 /// use derive_more::From;
@@ -215,7 +256,7 @@ macro_rules! fixnum_const {
 ///
 /// macro_rules! fp {
 ///     ($val:literal) => {
-///         fixnum::fixnum!($val, 9);
+///         fixnum::fixnum!($val, 9)
 ///     };
 /// }
 ///
@@ -0,0 +1,670 @@
+//! Transcendental functions.
+//!
+//! All calculations are performed in the binary fixed-point format with 256 bits in total
+//! and `FRAC_BITS` bits in the fractional part, which gives more than 70 significant decimal
+//! digits. Only the final result is rounded to the precision of the layout.
+
+use core::cmp::Ordering;
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::{ops::RoundMode, ArithmeticError, FixedPoint, Precision};
+
+#[cfg(feature = "trig")]
+mod trig;
+
+const FRAC_BITS: u32 = 248;
+
+/// `ln(2)` with `FRAC_BITS` bits in the fractional part.
+const LN2: Wide = Wide([
+    0x2d8a0d175b8baafa,
+    0xaf40f343267298b6,
+    0xabc9e3b39803f2f6,
+    0x00b17217f7d1cf79,
+]);
+
+/// `ln(10)` with `FRAC_BITS` bits in the fractional part.
+const LN10: Wide = Wide([
+    0x3a0f187a0807c0b6,
+    0xa38a3fb3e76977e4,
+    0x5ba95b58ae0b4c28,
+    0x024d763776aaa2b0,
+]);
+
+/// Unsigned 256-bit number, little-endian words.
+///
+/// Depending on the context, it's treated either as an integer or as a fixed-point number
+/// with `FRAC_BITS` bits in the fractional part.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Wide([u64; 4]);
+
+impl Wide {
+    const ZERO: Self = Self([0; 4]);
+    const ONE: Self = Self([0, 0, 0, 1 << (FRAC_BITS - 192)]);
+
+    const fn from_int(x: u128) -> Self {
+        Self([x as u64, (x >> 64) as u64, 0, 0])
+    }
+
+    fn to_int(self) -> Option<u128> {
+        if self.0[2] != 0 || self.0[3] != 0 {
+            return None;
+        }
+
+        Some(u128::from(self.0[0]) | u128::from(self.0[1]) << 64)
+    }
+
+    fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    /// Returns the integral part of the fixed-point number.
+    fn integral(self) -> u32 {
+        (self.0[3] >> (FRAC_BITS - 192)) as u32
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        let mut result = [0; 4];
+        let mut carry = false;
+
+        for (i, word) in result.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(rhs.0[i]);
+            let (sum, c2) = sum.overflowing_add(u64::from(carry));
+            *word = sum;
+            carry = c1 || c2;
+        }
+
+        debug_assert!(!carry, "overflow");
+        Self(result)
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        let mut result = [0; 4];
+        let mut borrow = false;
+
+        for (i, word) in result.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+            *word = diff;
+            borrow = b1 || b2;
+        }
+
+        debug_assert!(!borrow, "underflow");
+        Self(result)
+    }
+
+    fn shl(self, shift: u32) -> Self {
+        debug_assert!(shift < 256);
+        let (words, bits) = ((shift / 64) as usize, shift % 64);
+        let mut result = [0; 4];
+
+        for (i, word) in result.iter_mut().enumerate().skip(words) {
+            *word = self.0[i - words] << bits;
+            if bits > 0 && i > words {
+                *word |= self.0[i - words - 1] >> (64 - bits);
+            }
+        }
+
+        Self(result)
+    }
+
+    /// Multiplies by a small integer.
+    fn mul_int(self, rhs: u32) -> Self {
+        let mut result = [0; 4];
+        let mut carry = 0;
+
+        for (i, word) in result.iter_mut().enumerate() {
+            let t = u128::from(self.0[i]) * u128::from(rhs) + carry;
+            *word = t as u64;
+            carry = t >> 64;
+        }
+
+        debug_assert_eq!(carry, 0, "overflow");
+        Self(result)
+    }
+
+    /// Divides by a small integer, the result is truncated.
+    fn div_int(self, rhs: u64) -> Self {
+        self.div_rem_int(rhs).0
+    }
+
+    /// Divides by a small integer, returns the quotient and the remainder.
+    fn div_rem_int(self, rhs: u64) -> (Self, u64) {
+        let mut result = [0; 4];
+        let mut rem = 0u128;
+
+        for i in (0..4).rev() {
+            let t = rem << 64 | u128::from(self.0[i]);
+            result[i] = (t / u128::from(rhs)) as u64;
+            rem = t % u128::from(rhs);
+        }
+
+        (Self(result), rem as u64)
+    }
+
+    /// Divides by a power of ten, returns `None` if there is a remainder.
+    fn div_exact(self, mut coef: u128) -> Option<Self> {
+        debug_assert!(coef > 0);
+        let mut result = self;
+
+        // `coef` can exceed `u64`, thus divide by parts.
+        while coef > 1 {
+            let divisor = if coef % 10_000_000_000_000_000_000 == 0 {
+                10_000_000_000_000_000_000
+            } else {
+                coef as u64
+            };
+
+            let (quotient, rem) = result.div_rem_int(divisor);
+            if rem != 0 {
+                return None;
+            }
+
+            result = quotient;
+            coef /= u128::from(divisor);
+        }
+
+        Some(result)
+    }
+
+    /// Calculates `self * rhs / 2^shift`, the result is truncated.
+    /// Returns `None` if the result doesn't fit.
+    fn mul_shr(self, rhs: Self, shift: u32) -> Option<Self> {
+        let mut product = [0u64; 8];
+
+        for i in 0..4 {
+            let mut carry = 0;
+            for j in 0..4 {
+                let t = u128::from(self.0[i]) * u128::from(rhs.0[j])
+                    + u128::from(product[i + j])
+                    + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + 4] = carry as u64;
+        }
+
+        let (words, bits) = ((shift / 64) as usize, shift % 64);
+        let word = |i: usize| {
+            let lo = product.get(i + words).map_or(0, |w| w >> bits);
+            let hi = match product.get(i + words + 1) {
+                Some(w) if bits > 0 => w << (64 - bits),
+                _ => 0,
+            };
+            lo | hi
+        };
+
+        if (4..8).any(|i| word(i) != 0) {
+            return None;
+        }
+
+        Some(Self([word(0), word(1), word(2), word(3)]))
+    }
+
+    /// Multiplies two fixed-point numbers, the result is truncated.
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_shr(rhs, FRAC_BITS).expect("overflow")
+    }
+
+    /// Calculates `self / rhs` as a fixed-point number, the result is truncated.
+    /// Both operands must be of the same scale and `self / rhs < 2^(256 - FRAC_BITS)`.
+    fn div(self, rhs: Self) -> Self {
+        debug_assert!(!rhs.is_zero());
+
+        let mut result = Self::ZERO;
+        let mut rem = Self::ZERO;
+        let total_bits = 256 - self.leading_zeros() + FRAC_BITS;
+
+        // Simple restoring division of `self * 2^FRAC_BITS` by `rhs`.
+        for i in (0..total_bits).rev() {
+            debug_assert!(rem.leading_zeros() > 0);
+            rem = rem.shl(1);
+
+            if i >= FRAC_BITS && self.bit(i - FRAC_BITS) {
+                rem.0[0] |= 1;
+            }
+
+            if rem >= rhs {
+                rem = rem.sub(rhs);
+                debug_assert!(i < 256, "overflow");
+                result.0[(i / 64) as usize] |= 1 << (i % 64);
+            }
+        }
+
+        result
+    }
+
+    fn bit(self, i: u32) -> bool {
+        self.0[(i / 64) as usize] & (1 << (i % 64)) != 0
+    }
+
+    fn leading_zeros(self) -> u32 {
+        let mut zeros = 0;
+
+        for word in self.0.iter().rev() {
+            zeros += word.leading_zeros();
+            if *word != 0 {
+                break;
+            }
+        }
+
+        zeros
+    }
+
+    /// Calculates `self * coef / 2^shift` rounded to the nearest integer (half-way cases away
+    /// from zero). Returns `None` if the result doesn't fit.
+    fn round_to_int(self, coef: u128, shift: u32) -> Option<u128> {
+        let doubled = self.mul_shr(Self::from_int(coef), shift - 1)?.to_int()?;
+        // `doubled / 2 + doubled % 2` without overflow.
+        Some((doubled >> 1) + (doubled & 1))
+    }
+}
+
+impl Ord for Wide {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for Wide {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Base {
+    E,
+    Two,
+    Ten,
+}
+
+/// Calculates `ln(value / 10^precision)`, returns its sign and absolute value.
+fn ln(value: u128, precision: u32) -> (bool, Wide) {
+    debug_assert!(value > 0);
+
+    // `value = m * 2^e`, where `1 <= m < 2`.
+    let e = 127 - value.leading_zeros();
+    let m = Wide::from_int(value).shl(FRAC_BITS - e);
+
+    // `ln(m) = 2 * atanh(s) = 2 * (s + s^3/3 + s^5/5 + ...)`, where `s = (m - 1) / (m + 1)`.
+    // Because of `0 <= s < 1/3`, every term adds more than three bits of precision.
+    let s = m.sub(Wide::ONE).div(m.add(Wide::ONE));
+    let s2 = s.mul(s);
+    let mut term = s;
+    let mut sum = s;
+
+    for n in (3..).step_by(2) {
+        term = term.mul(s2);
+        let addend = term.div_int(n);
+        if addend.is_zero() {
+            break;
+        }
+        sum = sum.add(addend);
+    }
+
+    // `ln(value / 10^precision) = e * ln(2) + ln(m) - precision * ln(10)`
+    let positive = LN2.mul_int(e).add(sum.shl(1));
+    let negative = LN10.mul_int(precision);
+
+    if positive >= negative {
+        (false, positive.sub(negative))
+    } else {
+        (true, negative.sub(positive))
+    }
+}
+
+/// Calculates `log(value / 10^precision)` and returns it multiplied by `10^precision`.
+/// Returns `None` if the result doesn't fit.
+fn log(value: u128, precision: u32, base: Base) -> Option<i128> {
+    let (is_negative, abs) = ln(value, precision);
+
+    let abs = match base {
+        Base::E => abs,
+        Base::Two => abs.div(LN2),
+        Base::Ten => abs.div(LN10),
+    };
+
+    let abs = i128::try_from(abs.round_to_int(10u128.pow(precision), FRAC_BITS)?).ok()?;
+    Some(if is_negative { -abs } else { abs })
+}
+
+/// Calculates `exp(x)` or `exp(-x)` if `is_negative`, where `x < 256`.
+/// Returns `(m, k)` such that the result is `m * 2^k`.
+fn exp_parts(is_negative: bool, x: Wide) -> (Wide, i32) {
+    // `x = k * ln(2) + r`, where `k` is an integer and `0 <= r < ln(2)`.
+    let k = x.div(LN2).integral();
+    let r = x.sub(LN2.mul_int(k));
+
+    // `exp(r) = 1 + r + r^2/2! + r^3/3! + ...`
+    let mut term = Wide::ONE;
+    let mut sum = Wide::ONE;
+
+    for n in 1.. {
+        term = term.mul(r).div_int(n);
+        if term.is_zero() {
+            break;
+        }
+        sum = sum.add(term);
+    }
+
+    // `exp(x) = exp(r) * 2^k` and `exp(-x) = exp(r)^-1 * 2^-k`.
+    if is_negative {
+        (Wide::ONE.div(sum), -(k as i32))
+    } else {
+        (sum, k as i32)
+    }
+}
+
+/// Calculates `exp(value / 10^precision)` and returns it multiplied by `10^precision`.
+/// Returns `None` if the result doesn't fit.
+fn exp(value: i128, precision: u32) -> Option<i128> {
+    let coef = 10u128.pow(precision);
+    let abs = value.unsigned_abs();
+
+    // `exp(89) > i128::MAX` and `exp(-89) < 0.5e-38`, thus it's rounded to zero
+    // for any supported precision.
+    if abs / coef >= 89 {
+        return if value > 0 { None } else { Some(0) };
+    }
+
+    let x = Wide::from_int(abs).div(Wide::from_int(coef));
+    let (m, k) = exp_parts(value < 0, x);
+    let result = m.round_to_int(coef, (FRAC_BITS as i32 - k) as u32)?;
+
+    i128::try_from(result).ok()
+}
+
+/// Calculates `value / 10^precision` to the power of `1/n` multiplied by `10^precision`.
+/// Returns the integral part and the fractional part in `2^-64` units, which is zero only
+/// for exact roots.
+fn root(value: u128, precision: u32, n: u32) -> (u128, u64) {
+    debug_assert!(value > 0 && n > 1);
+
+    let coef = 10u128.pow(precision);
+
+    // `x^(1/n) = exp(ln(x) / n)`, the result doesn't exceed `max(x, 1)`.
+    let (is_negative, ln) = ln(value, precision);
+    let (m, k) = exp_parts(is_negative, ln.div_int(u64::from(n)));
+    let scaled = m
+        .mul_shr(Wide::from_int(coef), (FRAC_BITS as i32 - k - 64) as u32)
+        .expect("root <= max(x, 1)");
+
+    let integral = u128::from(scaled.0[1]) | u128::from(scaled.0[2]) << 64;
+    let fractional = scaled.0[0];
+    debug_assert_eq!(scaled.0[3], 0);
+
+    // The error is much less than `2^-64`, so only roots very close to integers
+    // can be exact. Check them to return exact results, e.g. `8^(1/3) = 2`.
+    let candidate = match fractional {
+        0..=0xffff => integral,
+        0xffff_ffff_ffff_0000.. => integral + 1,
+        _ => return (integral, fractional),
+    };
+
+    if is_exact_root(candidate, value, coef, n) {
+        (candidate, 0)
+    } else {
+        (integral, fractional.max(1))
+    }
+}
+
+/// Checks that `(root / coef)^n = value / coef`, i.e. `root^n = value * coef^(n - 1)`.
+fn is_exact_root(root: u128, value: u128, coef: u128, n: u32) -> bool {
+    if root == coef {
+        return value == coef;
+    }
+
+    // If `root / coef = a / b` in lowest terms, then `b^n` must divide `coef`, thus every
+    // `root^k / coef^(k - 1) = coef * (a / b)^k` is an integer between `coef` and `value`.
+    // Both conditions are violated after at most ~128 iterations for inexact roots.
+    let limit = value.max(coef);
+    let mut power = root;
+
+    for _ in 1..n {
+        let Some(product) = Wide::from_int(power).mul_shr(Wide::from_int(root), 0) else {
+            return false;
+        };
+        let Some(quotient) = product.div_exact(coef) else {
+            return false;
+        };
+
+        match quotient.to_int() {
+            Some(quotient) if quotient <= limit => power = quotient,
+            _ => return false,
+        }
+    }
+
+    power == value
+}
+
+macro_rules! impl_math {
+    ($layout:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Checked natural logarithm.
+            /// Returns `Err` for non-positive argument and on overflow.
+            ///
+            /// The result is calculated with more than 70 significant digits and then rounded to
+            /// the nearest representable number, thus the error doesn't exceed 1 ULP
+            /// ([`EPSILON`][Self::EPSILON]). Actually, the result is correctly rounded unless
+            /// the exact value is extremely close to a half-way case.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::{One, Zero};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "2".parse()?;
+            /// let b: Amount = "0.05".parse()?;
+            /// assert_eq!(a.ln()?, "0.693147181".parse()?);
+            /// assert_eq!(b.ln()?, "-2.995732274".parse()?);
+            /// assert_eq!(Amount::ONE.ln()?, Amount::ZERO);
+            /// assert_eq!(Amount::ZERO.ln(), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn ln(self) -> Result<Self, ArithmeticError> {
+                self.log(Base::E)
+            }
+
+            /// Checked binary logarithm.
+            /// Returns `Err` for non-positive argument and on overflow.
+            ///
+            /// The error doesn't exceed 1 ULP, see [`ln`][Self::ln] for details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "1024".parse()?;
+            /// let b: Amount = "3".parse()?;
+            /// assert_eq!(a.log2()?, "10".parse()?);
+            /// assert_eq!(b.log2()?, "1.584962501".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn log2(self) -> Result<Self, ArithmeticError> {
+                self.log(Base::Two)
+            }
+
+            /// Checked decimal logarithm.
+            /// Returns `Err` for non-positive argument and on overflow.
+            ///
+            /// The error doesn't exceed 1 ULP, see [`ln`][Self::ln] for details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "0.001".parse()?;
+            /// let b: Amount = "2".parse()?;
+            /// assert_eq!(a.log10()?, "-3".parse()?);
+            /// assert_eq!(b.log10()?, "0.301029996".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn log10(self) -> Result<Self, ArithmeticError> {
+                self.log(Base::Ten)
+            }
+
+            /// Checked exponential function `e^x`.
+            /// Returns `Err` on overflow.
+            ///
+            /// The error doesn't exceed 1 ULP, see [`ln`][Self::ln] for details.
+            /// Too small results are rounded to zero.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::{One, Zero};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "0.05".parse()?;
+            /// let b: Amount = "-30".parse()?;
+            /// let c: Amount = "30".parse()?;
+            /// assert_eq!(a.exp()?, "1.051271096".parse()?);
+            /// assert_eq!(Amount::ZERO.exp()?, Amount::ONE);
+            /// assert_eq!(b.exp()?, Amount::ZERO);
+            /// assert_eq!(c.exp(), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn exp(self) -> Result<Self, ArithmeticError> {
+                let value = i128::try_from(self.inner).map_err(|_| ArithmeticError::Overflow)?;
+                let inner = exp(value, Self::PRECISION as u32).ok_or(ArithmeticError::Overflow)?;
+
+                $layout::try_from(inner)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Checked [rounding][RoundMode] cube root.
+            ///
+            /// The same as [`nth_root(3, mode)`][Self::nth_root].
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            /// use fixnum::ops::RoundMode::*;
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "-27".parse()?;
+            /// let b: Amount = "2".parse()?;
+            /// assert_eq!(a.cbrt(Floor)?, "-3".parse()?);
+            /// assert_eq!(b.cbrt(Floor)?, "1.259921049".parse()?);
+            /// assert_eq!(b.cbrt(Ceil)?, "1.259921050".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn cbrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                self.nth_root(3, mode)
+            }
+
+            /// Checked [rounding][RoundMode] `n`-th root.
+            /// Returns `Err` for zero `n` and for negative argument if `n` is even.
+            ///
+            /// Exact roots are always returned as is, e.g. `0.008^(1/3) = 0.2`. Otherwise,
+            /// the root is calculated with more than 70 significant digits and then rounded
+            /// with the provided mode, see [`ln`][Self::ln] for details, thus the error
+            /// doesn't exceed 1 ULP ([`EPSILON`][Self::EPSILON]).
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::RoundMode::*;
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// // The geometric mean of yearly growth factors.
+            /// let growth: Amount = "1.1".parse::<Amount>()?.checked_powi(5, Floor)?;
+            /// assert_eq!(growth.nth_root(5, Nearest)?, "1.1".parse()?);
+            ///
+            /// let a: Amount = "0.0001".parse()?;
+            /// assert_eq!(a.nth_root(4, Floor)?, "0.1".parse()?);
+            /// assert_eq!(a.cneg()?.nth_root(4, Floor), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn nth_root(self, n: u32, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                let is_negative = self.inner.is_negative();
+
+                if n == 0 || is_negative && n % 2 == 0 {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                if n == 1 || self.inner == 0 {
+                    return Ok(self);
+                }
+
+                let (abs, fractional) =
+                    root(self.inner.unsigned_abs() as u128, Self::PRECISION as u32, n);
+
+                // Exact half-way cases are impossible: `(2a + 1)^n` is odd.
+                let half = if fractional >= 1 << 63 {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
+                let sign = if is_negative { -1 } else { 1 };
+
+                let abs = if fractional != 0 && mode.is_away_from_zero(sign, half, abs % 2 != 0) {
+                    abs + 1
+                } else {
+                    abs
+                };
+
+                // `|root| <= max(|self|, 1)`, thus it fits.
+                let abs = $layout::try_from(abs).map_err(|_| ArithmeticError::Overflow)?;
+                let inner = if is_negative { 0 - abs } else { abs };
+                Ok(Self::from_bits(inner))
+            }
+
+            fn log(self, base: Base) -> Result<Self, ArithmeticError> {
+                if self.inner == 0 || self.inner.is_negative() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                let inner = log(self.inner as u128, Self::PRECISION as u32, base)
+                    .ok_or(ArithmeticError::Overflow)?;
+
+                $layout::try_from(inner)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_math!(i16);
+#[cfg(feature = "i32")]
+impl_math!(i32);
+#[cfg(feature = "i64")]
+impl_math!(i64);
+#[cfg(feature = "i128")]
+impl_math!(i128);
+#[cfg(feature = "u64")]
+impl_math!(u64);
+#[cfg(feature = "u128")]
+impl_math!(u128);
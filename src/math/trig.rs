@@ -0,0 +1,367 @@
+//! Trigonometric functions calculated by [CORDIC] using only integer arithmetic,
+//! thus results are the same on every target.
+//!
+//! Arguments are reduced modulo `π/2` exactly enough for any representable value, then CORDIC
+//! runs on `i128` with `ANGLE_BITS` bits in the fractional part. The accumulated error is less
+//! than `2^-115`, only the final result is rounded to the precision of the layout.
+//!
+//! [CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::{ArithmeticError, FixedPoint, Precision};
+
+use super::{Wide, FRAC_BITS};
+
+/// The number of bits in the fractional part of CORDIC values.
+const ANGLE_BITS: u32 = 124;
+
+/// Every iteration adds a bit of precision.
+const ITERATIONS: u32 = ANGLE_BITS;
+
+/// `atan(2^-i)` with `ANGLE_BITS` bits in the fractional part.
+/// For larger `i` it's indistinguishable from `2^-i`.
+const ATAN: [i128; 42] = [
+    0x0c90fdaa22168c234c4c6628b80dc1cd,
+    0x076b19c1586ed3da2b7f222f65e1d468,
+    0x03eb6ebf25901bac55b71e7bd7de8860,
+    0x01fd5ba9aac2f6dc65912f313e7d111e,
+    0x00ffaaddb967ef4e36cb2792dc0e2e0d,
+    0x007ff556eea5d892a13bcebbb6ed4631,
+    0x003ffeaab776e5356ef9e31590057dd8,
+    0x001fffd555bbba972d00c46a3f77cc16,
+    0x000ffffaaaaddddb94bb12afb6b6d4f8,
+    0x0007ffff55556eeeea5ca6adeab02252,
+    0x0003ffffeaaaab77776e52e5a019fbcf,
+    0x0001fffffd55555bbbbba97297625625,
+    0x0000ffffffaaaaaaddddddb94b94d5bd,
+    0x00007ffffff5555556eeeeeea5ca5cb4,
+    0x00003ffffffeaaaaaab7777776e52e53,
+    0x00001fffffffd5555555bbbbbbba9729,
+    0x00000ffffffffaaaaaaaaddddddddb95,
+    0x000007ffffffff555555556eeeeeeeea,
+    0x000003ffffffffeaaaaaaaab77777777,
+    0x000001fffffffffd555555555bbbbbbc,
+    0x000000ffffffffffaaaaaaaaaaddddde,
+    0x0000007ffffffffff55555555556eeef,
+    0x0000003ffffffffffeaaaaaaaaaab777,
+    0x0000001fffffffffffd55555555555bc,
+    0x0000000ffffffffffffaaaaaaaaaaaae,
+    0x00000007ffffffffffff555555555555,
+    0x00000003ffffffffffffeaaaaaaaaaab,
+    0x00000001fffffffffffffd5555555555,
+    0x00000000ffffffffffffffaaaaaaaaab,
+    0x000000007ffffffffffffff555555555,
+    0x000000003ffffffffffffffeaaaaaaab,
+    0x000000001fffffffffffffffd5555555,
+    0x000000000ffffffffffffffffaaaaaab,
+    0x0000000007ffffffffffffffff555555,
+    0x0000000003ffffffffffffffffeaaaab,
+    0x0000000001fffffffffffffffffd5555,
+    0x0000000000ffffffffffffffffffaaab,
+    0x00000000007ffffffffffffffffff555,
+    0x00000000003ffffffffffffffffffeab,
+    0x00000000001fffffffffffffffffffd5,
+    0x00000000000ffffffffffffffffffffb,
+    0x000000000007ffffffffffffffffffff,
+];
+
+/// `1/K` with `ANGLE_BITS` bits in the fractional part,
+/// where `K = sqrt(1 + 2^0) * sqrt(1 + 2^-2) * sqrt(1 + 2^-4) * ...` is the CORDIC gain.
+const GAIN_INV: i128 = 0x09b74eda8435e5a67f5f9092bd7fd40f;
+
+/// `π` with `ANGLE_BITS` bits in the fractional part.
+const PI: i128 = 0x3243f6a8885a308d313198a2e0370734;
+
+/// `2/π` with 320 bits in the fractional part, little-endian words.
+const TWO_OVER_PI_320: [u64; 5] = [
+    0xb7246e3a424dd2e0,
+    0xfe5163abdebbc561,
+    0xdb6295993c439041,
+    0xfc2757d1f534ddc0,
+    0xa2f9836e4e441529,
+];
+
+/// `2/π` with `FRAC_BITS` bits in the fractional part.
+const TWO_OVER_PI: Wide = Wide([
+    0x41fe5163abdebbc5,
+    0xc0db6295993c4390,
+    0x29fc2757d1f534dd,
+    0x00a2f9836e4e4415,
+]);
+
+/// `π/2` with `FRAC_BITS` bits in the fractional part.
+const HALF_PI: Wide = Wide([
+    0xe804177d4c762736,
+    0xa252049c1114cf98,
+    0x69898cc51701b839,
+    0x01921fb54442d184,
+]);
+
+fn atan_pow2(i: u32) -> i128 {
+    ATAN.get(i as usize)
+        .copied()
+        .unwrap_or(1 << (ANGLE_BITS - i))
+}
+
+/// Rotates the vector `(1/K, 0)` by `angle`, `|angle| <= π/2`. Returns `(cos, sin)`.
+fn rotate(mut angle: i128) -> (i128, i128) {
+    let (mut x, mut y) = (GAIN_INV, 0);
+
+    for i in 0..ITERATIONS {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if angle >= 0 {
+            (x, y) = (x - dx, y + dy);
+            angle -= atan_pow2(i);
+        } else {
+            (x, y) = (x + dx, y - dy);
+            angle += atan_pow2(i);
+        }
+    }
+
+    (x, y)
+}
+
+/// Rotates the vector `(x, y)` to the X axis, `x, y >= 0`. Returns the angle `atan(y/x)`.
+fn vectoring(mut x: i128, mut y: i128) -> i128 {
+    let mut angle = 0;
+
+    for i in 0..ITERATIONS {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if y > 0 {
+            (x, y) = (x + dx, y - dy);
+            angle += atan_pow2(i);
+        } else {
+            (x, y) = (x - dx, y + dy);
+            angle -= atan_pow2(i);
+        }
+    }
+
+    angle
+}
+
+/// Reduces `value / 10^precision` modulo `π/2`.
+/// Returns the quadrant and the remainder with `ANGLE_BITS` bits in the fractional part.
+fn reduce(value: u128, precision: u32) -> (u32, i128) {
+    let coef = 10u128.pow(precision);
+    let (integral, fractional) = (value / coef, value % coef);
+
+    // `integral * 2/π` in quarter turns. Only two bits of the integral part (the quadrant)
+    // and 128 bits of the fractional part are required, 320 bits of `2/π` are enough for that.
+    let words = [integral as u64, (integral >> 64) as u64];
+    let mut product = [0u64; 7];
+
+    for (i, &lhs) in words.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &rhs) in TWO_OVER_PI_320.iter().enumerate() {
+            let t = u128::from(lhs) * u128::from(rhs) + u128::from(product[i + j]) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + 5] = carry as u64;
+    }
+
+    let quadrant = (product[5] % 4) as u32;
+    let turns = u128::from(product[3]) | u128::from(product[4]) << 64;
+    let turns = Wide::from_int(turns).shl(FRAC_BITS - 128);
+
+    // Add `fractional / coef * 2/π`, thus `0 <= turns < 2`.
+    let fractional = Wide::from_int(fractional).div(Wide::from_int(coef));
+    let turns = turns.add(fractional.mul(TWO_OVER_PI));
+
+    let carry = turns.integral();
+    let turns = turns.sub(Wide::ONE.mul_int(carry));
+    let angle = turns
+        .mul_shr(HALF_PI, 2 * FRAC_BITS - ANGLE_BITS)
+        .and_then(Wide::to_int)
+        .expect("angle < π/2");
+
+    ((quadrant + carry) % 4, angle as i128)
+}
+
+/// Calculates `sin(x)` and `cos(x)` with `ANGLE_BITS` bits in the fractional part,
+/// where `x = ±abs / 10^precision`.
+fn sin_cos(is_negative: bool, abs: u128, precision: u32) -> (i128, i128) {
+    let (quadrant, angle) = reduce(abs, precision);
+    let (cos, sin) = rotate(angle);
+
+    let (sin, cos) = match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+
+    (if is_negative { -sin } else { sin }, cos)
+}
+
+/// Calculates `atan2(y, x)` with `ANGLE_BITS` bits in the fractional part,
+/// where both arguments are passed as `(is_negative, abs)` and aren't zero simultaneously.
+fn atan2((y_is_negative, y): (bool, u128), (x_is_negative, x): (bool, u128)) -> i128 {
+    debug_assert!(x != 0 || y != 0);
+
+    // Scale both to make the larger one in `[1/2, 1)`, only the ratio matters.
+    let shift = x.max(y).leading_zeros() as i32 - (128 - ANGLE_BITS) as i32;
+    let scale = |value: u128| {
+        let value = if shift >= 0 {
+            value << shift
+        } else {
+            value >> -shift
+        };
+        value as i128
+    };
+
+    let angle = vectoring(scale(x), scale(y));
+    let angle = if x_is_negative { PI - angle } else { angle };
+
+    if y_is_negative {
+        -angle
+    } else {
+        angle
+    }
+}
+
+/// Rounds `value / 2^ANGLE_BITS` to the nearest multiple of `10^-precision` (half-way cases away
+/// from zero) and returns it multiplied by `10^precision`.
+/// Returns `None` if the result doesn't fit.
+fn round(value: i128, precision: u32) -> Option<i128> {
+    let abs =
+        Wide::from_int(value.unsigned_abs()).round_to_int(10u128.pow(precision), ANGLE_BITS)?;
+    let abs = i128::try_from(abs).ok()?;
+    Some(if value < 0 { -abs } else { abs })
+}
+
+macro_rules! impl_trig {
+    ($layout:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Checked sine of the angle in radians.
+            /// Returns `Err` only for unsigned layouts if the result is negative.
+            ///
+            /// Calculated by CORDIC using only integer arithmetic, thus results are the same
+            /// on every target. The error doesn't exceed 1 ULP ([`EPSILON`][Self::EPSILON])
+            /// for precisions up to 34 and `10^-34` for higher ones.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let a: Angle = "0.5".parse()?;
+            /// let b: Angle = "-100".parse()?;
+            /// assert_eq!(a.sin()?, "0.479425539".parse()?);
+            /// assert_eq!(b.sin()?, "0.506365641".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn sin(self) -> Result<Self, ArithmeticError> {
+                self.sin_cos().map(|(sin, _)| sin)
+            }
+
+            /// Checked cosine of the angle in radians.
+            /// Returns `Err` only for unsigned layouts if the result is negative.
+            ///
+            /// The error doesn't exceed 1 ULP, see [`sin`][Self::sin] for details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            /// use fixnum::ops::{One, Zero};
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let a: Angle = "0.5".parse()?;
+            /// assert_eq!(a.cos()?, "0.877582562".parse()?);
+            /// assert_eq!(Angle::ZERO.cos()?, Angle::ONE);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn cos(self) -> Result<Self, ArithmeticError> {
+                self.sin_cos().map(|(_, cos)| cos)
+            }
+
+            /// Checked sine and cosine of the angle in radians calculated at once,
+            /// so it's twice as fast as calling [`sin`][Self::sin] and [`cos`][Self::cos].
+            /// Returns `Err` only for unsigned layouts if any result is negative.
+            ///
+            /// The error doesn't exceed 1 ULP, see [`sin`][Self::sin] for details.
+            pub fn sin_cos(self) -> Result<(Self, Self), ArithmeticError> {
+                let (sin, cos) = sin_cos(
+                    self.inner.is_negative(),
+                    self.inner.unsigned_abs() as u128,
+                    Self::PRECISION as u32,
+                );
+
+                let convert = |value| {
+                    round(value, Self::PRECISION as u32)
+                        .and_then(|inner| $layout::try_from(inner).ok())
+                        .map(Self::from_bits)
+                        .ok_or(ArithmeticError::Overflow)
+                };
+
+                Ok((convert(sin)?, convert(cos)?))
+            }
+
+            /// Checked four-quadrant arctangent of `self` (`y`) and `other` (`x`) in radians,
+            /// in the range `[-π, π]`.
+            /// Returns `Err` if both are zero and on overflow, e.g. if `π` doesn't fit.
+            ///
+            /// The error doesn't exceed 1 ULP, see [`sin`][Self::sin] for details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::Zero;
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let y: Angle = "1".parse()?;
+            /// let x: Angle = "-1".parse()?;
+            /// assert_eq!(y.atan2(x)?, "2.356194490".parse()?);
+            /// assert_eq!(x.atan2(y)?, "-0.785398163".parse()?);
+            /// assert_eq!(Angle::ZERO.atan2(Angle::ZERO), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn atan2(self, other: Self) -> Result<Self, ArithmeticError> {
+                if self.inner == 0 && other.inner == 0 {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                let angle = atan2(
+                    (self.inner.is_negative(), self.inner.unsigned_abs() as u128),
+                    (
+                        other.inner.is_negative(),
+                        other.inner.unsigned_abs() as u128,
+                    ),
+                );
+
+                round(angle, Self::PRECISION as u32)
+                    .and_then(|inner| $layout::try_from(inner).ok())
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_trig!(i16);
+#[cfg(feature = "i32")]
+impl_trig!(i32);
+#[cfg(feature = "i64")]
+impl_trig!(i64);
+#[cfg(feature = "i128")]
+impl_trig!(i128);
+#[cfg(feature = "u64")]
+impl_trig!(u64);
+#[cfg(feature = "u128")]
+impl_trig!(u128);
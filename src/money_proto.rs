@@ -0,0 +1,148 @@
+//! Conversions from and to the `(units, nanos)` pair of Google's [`money.proto`].
+//!
+//! `units` is the whole part as `i64`, `nanos` is the number of nano (`10^-9`) units
+//! in `-999_999_999..=999_999_999`. If `units` is non-zero, `nanos` must have the same sign
+//! or be zero, e.g. `-1.75` is `(-1, -750_000_000)`.
+//!
+//! Functions take and return plain integers, so they fit message types generated
+//! by `prost` or any other protobuf implementation.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{money_proto, typenum::U2, FixedPoint};
+//!
+//! type Amount = FixedPoint<i64, U2>;
+//!
+//! // Generated by `prost` from `google/type/money.proto`.
+//! struct Money {
+//!     currency_code: String,
+//!     units: i64,
+//!     nanos: i32,
+//! }
+//!
+//! let amount: Amount = "-1.75".parse()?;
+//! let (units, nanos) = money_proto::to_units_nanos(amount)?;
+//! let money = Money { currency_code: "USD".into(), units, nanos };
+//! assert_eq!((money.units, money.nanos), (-1, -750_000_000));
+//!
+//! let restored: Amount = money_proto::from_units_nanos(money.units, money.nanos)?;
+//! assert_eq!(restored, amount);
+//!
+//! // Signs must agree, cents can't represent nanos exactly.
+//! assert!(money_proto::from_units_nanos::<Amount>(1, -500_000_000).is_err());
+//! assert!(money_proto::from_units_nanos::<Amount>(1, 1).is_err());
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+//!
+//! [`money.proto`]: https://github.com/googleapis/googleapis/blob/master/google/type/money.proto
+
+use crate::ConvertError;
+use crate::{FixedPoint, Precision};
+
+use self::private::UnitsNanos;
+
+const MAX_NANOS: i32 = 999_999_999;
+const NANOS_PRECISION: i32 = 9;
+
+/// Converts to `(units, nanos)`.
+///
+/// Returns `Err` if the whole part doesn't fit in `i64` or the precision is higher than `9`
+/// and the value isn't a whole number of nanos, use
+/// [`rescale`](FixedPoint::rescale) to round it first.
+#[inline]
+pub fn to_units_nanos<F: UnitsNanos>(value: F) -> Result<(i64, i32), ConvertError> {
+    value.to_units_nanos()
+}
+
+/// Converts from `(units, nanos)`.
+///
+/// Returns `Err` if `nanos` is out of range, signs of `units` and `nanos` differ,
+/// the value doesn't fit in the layout or the precision is lower than `9`
+/// and can't represent `nanos` exactly.
+#[inline]
+pub fn from_units_nanos<F: UnitsNanos>(units: i64, nanos: i32) -> Result<F, ConvertError> {
+    if !(-MAX_NANOS..=MAX_NANOS).contains(&nanos) {
+        return Err(ConvertError::new("nanos out of range"));
+    }
+
+    if units > 0 && nanos < 0 || units < 0 && nanos > 0 {
+        return Err(ConvertError::new("units and nanos have different signs"));
+    }
+
+    F::from_units_nanos(units, nanos)
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to convert from and to `(units, nanos)` in generic code.
+    #[allow(unreachable_pub)]
+    pub trait UnitsNanos: Sized {
+        fn to_units_nanos(self) -> Result<(i64, i32), ConvertError>;
+        /// `nanos` is already validated.
+        fn from_units_nanos(units: i64, nanos: i32) -> Result<Self, ConvertError>;
+    }
+}
+
+macro_rules! impl_units_nanos {
+    ($layout:tt) => {
+        impl<P: Precision> UnitsNanos for FixedPoint<$layout, P> {
+            fn to_units_nanos(self) -> Result<(i64, i32), ConvertError> {
+                let units = i64::try_from(self.inner / Self::COEF)
+                    .map_err(|_| ConvertError::new("too big number"))?;
+
+                // `|fractional| < 10^38`, so it fits in `i128` for any layout.
+                let fractional = (self.inner % Self::COEF) as i128;
+                let nanos = if Self::PRECISION <= NANOS_PRECISION {
+                    fractional * 10i128.pow((NANOS_PRECISION - Self::PRECISION) as u32)
+                } else {
+                    let divisor = 10i128.pow((Self::PRECISION - NANOS_PRECISION) as u32);
+                    if fractional % divisor != 0 {
+                        return Err(ConvertError::new("requires rounding"));
+                    }
+                    fractional / divisor
+                };
+
+                // `|nanos| < 10^9`, both have the sign of `self`.
+                Ok((units, nanos as i32))
+            }
+
+            fn from_units_nanos(units: i64, nanos: i32) -> Result<Self, ConvertError> {
+                let nanos = i128::from(nanos);
+                let fractional = if Self::PRECISION >= NANOS_PRECISION {
+                    nanos * 10i128.pow((Self::PRECISION - NANOS_PRECISION) as u32)
+                } else {
+                    let divisor = 10i128.pow((NANOS_PRECISION - Self::PRECISION) as u32);
+                    if nanos % divisor != 0 {
+                        return Err(ConvertError::new("requires rounding"));
+                    }
+                    nanos / divisor
+                };
+
+                $layout::try_from(units)
+                    .ok()
+                    .and_then(|units| units.checked_mul(Self::COEF))
+                    .zip($layout::try_from(fractional).ok())
+                    .and_then(|(units, fractional)| units.checked_add(fractional))
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_units_nanos!(i16);
+#[cfg(feature = "i32")]
+impl_units_nanos!(i32);
+#[cfg(feature = "i64")]
+impl_units_nanos!(i64);
+#[cfg(feature = "i128")]
+impl_units_nanos!(i128);
+#[cfg(feature = "u64")]
+impl_units_nanos!(u64);
+#[cfg(feature = "u128")]
+impl_units_nanos!(u128);
@@ -0,0 +1,118 @@
+use core::convert::TryFrom;
+
+use num_rational::Ratio;
+
+use crate::i256::I256;
+use crate::ops::{RoundMode, RoundingDiv};
+use crate::{ConvertError, FixedPoint, Precision};
+
+macro_rules! impl_num_rational {
+    ($layout:tt, $via:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Converts from [`Ratio<i128>`][Ratio], [rounding][RoundMode] to the representable
+            /// value according to `mode`. Fails if the value doesn't fit.
+            ///
+            /// Use `TryFrom<Ratio<i128>>` to fail instead of rounding.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ops::RoundMode::*, typenum::U2, FixedPoint};
+            /// use num_rational::Ratio;
+            ///
+            /// type Cents = FixedPoint<i64, U2>;
+            ///
+            /// let third = Ratio::new(1, 3);
+            /// assert_eq!(Cents::from_ratio(&third, Floor)?, "0.33".parse()?);
+            /// assert_eq!(Cents::from_ratio(&third, Ceil)?, "0.34".parse()?);
+            /// assert!(Cents::try_from(third).is_err());
+            ///
+            /// let quarter = Ratio::new(-1, 4);
+            /// assert_eq!(Cents::try_from(quarter)?, "-0.25".parse()?);
+            /// assert_eq!(Ratio::from(Cents::try_from(quarter)?), quarter);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[cfg_attr(docsrs, doc(cfg(feature = "num-rational")))]
+            pub fn from_ratio(value: &Ratio<i128>, mode: RoundMode) -> Result<Self, ConvertError> {
+                // `Ratio::new_raw` doesn't check the denominator.
+                if *value.denom() == 0 {
+                    return Err(ConvertError::new("zero denominator"));
+                }
+
+                // `|numer| * COEF < 2^127 * 2^127`, so it can't overflow.
+                let numer = I256::from(*value.numer()) * I256::from(Self::COEF);
+                let inner = numer
+                    .rdiv(I256::from(*value.denom()), mode)
+                    .map_err(|_| ConvertError::new("too big number"))?;
+
+                $via::try_from(inner)
+                    .ok()
+                    .and_then(|inner| $layout::try_from(inner).ok())
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-rational")))]
+        impl<P: Precision> TryFrom<Ratio<i128>> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts exactly, fails if the value doesn't fit or isn't representable with
+            /// `PRECISION` fractional digits, e.g. `1/3`.
+            fn try_from(value: Ratio<i128>) -> Result<Self, Self::Error> {
+                let result = Self::from_ratio(&value, RoundMode::Floor)?;
+
+                if result.inner != Self::from_ratio(&value, RoundMode::Ceil)?.inner {
+                    return Err(ConvertError::new("requires rounding"));
+                }
+
+                Ok(result)
+            }
+        }
+    };
+    ($layout:tt, $via:tt, From) => {
+        impl_num_rational!($layout, $via);
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-rational")))]
+        impl<P: Precision> From<FixedPoint<$layout, P>> for Ratio<i128> {
+            /// Converts without any loss of precision, the result is reduced.
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                let coef = FixedPoint::<$layout, P>::COEF;
+                Ratio::new(i128::from(value.inner), i128::from(coef))
+            }
+        }
+    };
+    ($layout:tt, $via:tt, TryFrom) => {
+        impl_num_rational!($layout, $via);
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-rational")))]
+        impl<P: Precision> TryFrom<FixedPoint<$layout, P>> for Ratio<i128> {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision, the result is reduced.
+            /// Fails if the value doesn't fit in `i128`.
+            fn try_from(value: FixedPoint<$layout, P>) -> Result<Self, Self::Error> {
+                let inner =
+                    i128::try_from(value.inner).map_err(|_| ConvertError::new("too big number"))?;
+                // `10^P` fits in `i128`, because `P <= 38`.
+                let coef = FixedPoint::<$layout, P>::COEF as i128;
+                Ok(Ratio::new(inner, coef))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_num_rational!(i16, i128, From);
+#[cfg(feature = "i32")]
+impl_num_rational!(i32, i128, From);
+#[cfg(feature = "i64")]
+impl_num_rational!(i64, i128, From);
+#[cfg(feature = "i128")]
+impl_num_rational!(i128, i128, From);
+#[cfg(feature = "u64")]
+impl_num_rational!(u64, i128, From);
+#[cfg(feature = "u128")]
+impl_num_rational!(u128, u128, TryFrom);
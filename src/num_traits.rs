@@ -0,0 +1,236 @@
+//! Traits of `num-traits` requiring `core::ops` operators as supertraits
+//! (`Zero`, `One`, `Num`, `Signed` and `Checked*`) are implemented only with `panicking-ops`.
+
+#[cfg(feature = "panicking-ops")]
+use core::str::FromStr;
+
+#[cfg(all(
+    feature = "panicking-ops",
+    any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")
+))]
+use num_traits::Signed;
+use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+#[cfg(feature = "panicking-ops")]
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Num, One, Zero};
+
+use crate::ops;
+#[cfg(feature = "panicking-ops")]
+use crate::{ops::RoundMode, ConvertError};
+use crate::{FixedPoint, Precision};
+
+macro_rules! impl_num_traits {
+    ($layout:tt) => {
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision> Zero for FixedPoint<$layout, P> {
+            #[inline]
+            fn zero() -> Self {
+                <Self as ops::Zero>::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.inner == 0
+            }
+        }
+
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision> One for FixedPoint<$layout, P> {
+            #[inline]
+            fn one() -> Self {
+                <Self as ops::One>::ONE
+            }
+        }
+
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision + PartialEq> Num for FixedPoint<$layout, P> {
+            type FromStrRadixErr = ConvertError;
+
+            /// Parses like `FromStr` does, only `10` is supported as `radix`.
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, ConvertError> {
+                if radix != 10 {
+                    return Err(ConvertError::new("only decimal radix is supported"));
+                }
+
+                Self::from_str(str)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        impl<P: Precision> Bounded for FixedPoint<$layout, P> {
+            #[inline]
+            fn min_value() -> Self {
+                <Self as ops::Bounded>::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                <Self as ops::Bounded>::MAX
+            }
+        }
+
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision> CheckedAdd for FixedPoint<$layout, P> {
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                ops::CheckedAdd::cadd(*self, *v).ok()
+            }
+        }
+
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision> CheckedSub for FixedPoint<$layout, P> {
+            #[inline]
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                ops::CheckedSub::csub(*self, *v).ok()
+            }
+        }
+
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision> CheckedMul for FixedPoint<$layout, P> {
+            /// Rounds to the nearest value, see [`RoundMode::Nearest`],
+            /// unless another default mode is [configured][RoundMode].
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                ops::RoundingMul::rmul(*self, *v, RoundMode::Nearest.or_configured()).ok()
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        impl<P: Precision> FromPrimitive for FixedPoint<$layout, P> {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+        impl<P: Precision> ToPrimitive for FixedPoint<$layout, P> {
+            /// Truncates the fractional part like `as` casting of floats does.
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                (self.inner / Self::COEF).try_into().ok()
+            }
+
+            /// Truncates the fractional part like `as` casting of floats does.
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                (self.inner / Self::COEF).try_into().ok()
+            }
+
+            /// Truncates the fractional part like `as` casting of floats does.
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                (self.inner / Self::COEF).try_into().ok()
+            }
+
+            /// Truncates the fractional part like `as` casting of floats does.
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                (self.inner / Self::COEF).try_into().ok()
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(f64::from(*self))
+            }
+        }
+    };
+    (@signed $layout:tt) => {
+        impl_num_traits!($layout);
+
+        #[cfg(feature = "panicking-ops")]
+        #[cfg_attr(
+            docsrs,
+            doc(cfg(all(feature = "num-traits", feature = "panicking-ops")))
+        )]
+        impl<P: Precision + PartialEq> Signed for FixedPoint<$layout, P> {
+            /// Panics on overflow (for `MIN` value) like primitive integers do.
+            #[inline]
+            #[track_caller]
+            fn abs(&self) -> Self {
+                FixedPoint::<$layout, P>::abs(*self).expect("attempt to negate with overflow")
+            }
+
+            #[inline]
+            #[track_caller]
+            fn abs_sub(&self, other: &Self) -> Self {
+                if self.inner <= other.inner {
+                    Self::zero()
+                } else {
+                    *self - *other
+                }
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                FixedPoint::<$layout, P>::signum(*self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                self.inner > 0
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                self.inner < 0
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_num_traits!(@signed i16);
+#[cfg(feature = "i32")]
+impl_num_traits!(@signed i32);
+#[cfg(feature = "i64")]
+impl_num_traits!(@signed i64);
+#[cfg(feature = "i128")]
+impl_num_traits!(@signed i128);
+#[cfg(feature = "u64")]
+impl_num_traits!(u64);
+#[cfg(feature = "u128")]
+impl_num_traits!(u128);
@@ -0,0 +1,313 @@
+//! SQL `NUMERIC` support shared by drivers.
+//!
+//! Postgres sends it in the binary wire format: base-10000 digits with the weight of the first.
+//! Textual forms are parsed by `from_str_exact` after [`trim_scale`].
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::power_table::power_of_10;
+use crate::{ConvertError, FixedPoint, Precision};
+
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xc000;
+const SIGN_POSITIVE_INFINITY: u16 = 0xd000;
+const SIGN_NEGATIVE_INFINITY: u16 = 0xf000;
+
+const HEADER_LEN: usize = 8;
+const NBASE: u128 = 10_000;
+const DEC_DIGITS: u32 = 4;
+
+pub(crate) struct Numeric {
+    is_negative: bool,
+    /// The power of `NBASE` of the first digit.
+    weight: i16,
+    /// The display scale, i.e. the number of decimal digits after the point.
+    scale: u16,
+    /// Base-10000 digits without leading and trailing zeros.
+    digits: Vec<i16>,
+}
+
+impl Numeric {
+    /// Builds from `abs * 10^-precision`, the display scale is equal to the precision.
+    fn new(is_negative: bool, abs: u128, precision: u32) -> Self {
+        // The precision of supported layouts never exceeds 38.
+        let coef = power_of_10(precision).unwrap();
+        let (mut integral, fractional) = (abs / coef, abs % coef);
+
+        let mut digits = Vec::new();
+        while integral > 0 {
+            digits.push((integral % NBASE) as i16);
+            integral /= NBASE;
+        }
+        digits.reverse();
+
+        let mut weight = digits.len() as i16 - 1;
+
+        // Groups of the fractional part are padded with zeros at the end.
+        let mut rest = precision;
+        while rest > 0 {
+            let digit = if rest >= DEC_DIGITS {
+                fractional / power_of_10(rest - DEC_DIGITS).unwrap() % NBASE
+            } else {
+                fractional % power_of_10(rest).unwrap() * power_of_10(DEC_DIGITS - rest).unwrap()
+            };
+            rest = rest.saturating_sub(DEC_DIGITS);
+
+            // Leading zeros are encoded by the weight.
+            if digits.is_empty() && digit == 0 {
+                weight -= 1;
+                continue;
+            }
+
+            digits.push(digit as i16);
+        }
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        if digits.is_empty() {
+            weight = 0;
+        }
+
+        Self {
+            is_negative: is_negative && !digits.is_empty(),
+            weight,
+            scale: precision as u16,
+            digits,
+        }
+    }
+
+    /// Returns the absolute value multiplied by `10^precision`.
+    /// Fails if it doesn't fit or has more significant fractional digits than `precision`.
+    fn to_scaled(&self, precision: u32) -> Result<u128, ConvertError> {
+        let mut abs = 0u128;
+
+        for (index, &digit) in self.digits.iter().enumerate() {
+            if digit == 0 {
+                continue;
+            }
+
+            // The power of ten of the digit's last decimal place after scaling.
+            let exp =
+                DEC_DIGITS as i32 * (i32::from(self.weight) - index as i32) + precision as i32;
+            let digit = digit as u128;
+
+            let value = if exp >= 0 {
+                power_of_10(exp as u32)
+                    .and_then(|multiplier| digit.checked_mul(multiplier))
+                    .ok_or_else(|| ConvertError::new("too big number"))?
+            } else {
+                // Trailing zeros can be dropped, other digits can't.
+                match power_of_10(exp.unsigned_abs()) {
+                    Some(divisor) if digit % divisor == 0 => digit / divisor,
+                    _ => return Err(ConvertError::new("requested precision is too high")),
+                }
+            };
+
+            abs = abs
+                .checked_add(value)
+                .ok_or_else(|| ConvertError::new("too big number"))?;
+        }
+
+        Ok(abs)
+    }
+
+    pub(crate) fn encode(&self, buf: &mut Vec<u8>) {
+        let sign = if self.is_negative {
+            SIGN_NEGATIVE
+        } else {
+            SIGN_POSITIVE
+        };
+
+        buf.reserve(HEADER_LEN + 2 * self.digits.len());
+        buf.extend_from_slice(&(self.digits.len() as i16).to_be_bytes());
+        buf.extend_from_slice(&self.weight.to_be_bytes());
+        buf.extend_from_slice(&sign.to_be_bytes());
+        buf.extend_from_slice(&self.scale.to_be_bytes());
+
+        for digit in &self.digits {
+            buf.extend_from_slice(&digit.to_be_bytes());
+        }
+    }
+
+    /// Fails on malformed input, `NaN` and infinities.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Self, ConvertError> {
+        let invalid = || ConvertError::new("invalid NUMERIC");
+
+        if bytes.len() < HEADER_LEN {
+            return Err(invalid());
+        }
+
+        let read = |index: usize| [bytes[2 * index], bytes[2 * index + 1]];
+        let ndigits = usize::try_from(i16::from_be_bytes(read(0))).map_err(|_| invalid())?;
+        let weight = i16::from_be_bytes(read(1));
+        let sign = u16::from_be_bytes(read(2));
+        let scale = u16::from_be_bytes(read(3));
+
+        if bytes.len() != HEADER_LEN + 2 * ndigits {
+            return Err(invalid());
+        }
+
+        let is_negative = match sign {
+            SIGN_POSITIVE => false,
+            SIGN_NEGATIVE => true,
+            SIGN_NAN => return Err(ConvertError::new("NaN can't be represented")),
+            SIGN_POSITIVE_INFINITY | SIGN_NEGATIVE_INFINITY => {
+                return Err(ConvertError::new("infinity can't be represented"))
+            }
+            _ => return Err(invalid()),
+        };
+
+        let digits = (0..ndigits)
+            .map(|index| i16::from_be_bytes(read(HEADER_LEN / 2 + index)))
+            .collect::<Vec<_>>();
+
+        if digits
+            .iter()
+            .any(|&digit| !(0..NBASE as i16).contains(&digit))
+        {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            is_negative,
+            weight,
+            scale,
+            digits,
+        })
+    }
+}
+
+/// Trims trailing zeros of the fractional part added by the display scale of a column,
+/// which can exceed `PRECISION`.
+pub(crate) fn trim_scale(str: &str) -> &str {
+    match str.contains('.') {
+        true => str.trim_end_matches('0'),
+        false => str,
+    }
+}
+
+macro_rules! impl_numeric {
+    ($layout:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            pub(crate) fn to_numeric(self) -> Numeric {
+                Numeric::new(
+                    self.inner.is_negative(),
+                    self.inner.unsigned_abs() as u128,
+                    P::U32,
+                )
+            }
+
+            /// Converts without any loss of precision.
+            /// Fails if the value doesn't fit or has more significant fractional digits than
+            /// `PRECISION`.
+            pub(crate) fn from_numeric(numeric: &Numeric) -> Result<Self, ConvertError> {
+                let abs = numeric.to_scaled(P::U32)?;
+
+                let inner = if numeric.is_negative {
+                    0i128
+                        .checked_sub_unsigned(abs)
+                        .and_then(|value| $layout::try_from(value).ok())
+                } else {
+                    $layout::try_from(abs).ok()
+                };
+
+                inner
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_numeric!(i16);
+#[cfg(feature = "i32")]
+impl_numeric!(i32);
+#[cfg(feature = "i64")]
+impl_numeric!(i64);
+#[cfg(feature = "i128")]
+impl_numeric!(i128);
+#[cfg(feature = "u64")]
+impl_numeric!(u64);
+#[cfg(feature = "u128")]
+impl_numeric!(u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(is_negative: bool, abs: u128, precision: u32) -> Vec<u8> {
+        let mut buf = Vec::new();
+        Numeric::new(is_negative, abs, precision).encode(&mut buf);
+        buf
+    }
+
+    fn decode(bytes: &[u8], precision: u32) -> Result<(bool, u128), ConvertError> {
+        let numeric = Numeric::decode(bytes)?;
+        Ok((numeric.is_negative, numeric.to_scaled(precision)?))
+    }
+
+    #[test]
+    fn round_trip() {
+        for precision in [0, 1, 4, 9, 18, 38] {
+            for abs in [
+                0,
+                1,
+                9_999,
+                10_000,
+                123_456_789,
+                u64::MAX as u128,
+                u128::MAX,
+            ] {
+                for is_negative in [false, true] {
+                    let bytes = encode(is_negative, abs, precision);
+                    let expected = (is_negative && abs != 0, abs);
+                    assert_eq!(decode(&bytes, precision).unwrap(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decode_rescales() {
+        // 12.34 with the display scale 4.
+        let bytes = [0, 2, 0, 0, 0, 0, 0, 4, 0, 12, 0x0d, 0x48];
+        assert_eq!(decode(&bytes, 2).unwrap(), (false, 1234));
+        assert_eq!(decode(&bytes, 9).unwrap(), (false, 12_340_000_000));
+        assert_eq!(
+            decode(&bytes, 1).unwrap_err().as_str(),
+            "requested precision is too high"
+        );
+
+        // 10^40, doesn't fit.
+        let bytes = [0, 1, 0, 10, 0, 0, 0, 0, 0, 1];
+        assert_eq!(decode(&bytes, 0).unwrap_err().as_str(), "too big number");
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let nan = [0, 0, 0, 0, 0xc0, 0, 0, 0];
+        assert_eq!(
+            decode(&nan, 9).unwrap_err().as_str(),
+            "NaN can't be represented"
+        );
+        let infinity = [0, 0, 0, 0, 0xd0, 0, 0, 0];
+        assert_eq!(
+            decode(&infinity, 9).unwrap_err().as_str(),
+            "infinity can't be represented"
+        );
+
+        for bytes in [
+            &[0, 0, 0, 0, 0, 0, 0][..],
+            &[0, 1, 0, 0, 0, 0, 0, 0][..],
+            &[0xff, 0xff, 0, 0, 0, 0, 0, 0][..],
+            &[0, 1, 0, 0, 0x12, 0, 0, 0, 0, 1][..],
+            &[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10][..],
+        ] {
+            assert_eq!(decode(bytes, 9).unwrap_err().as_str(), "invalid NUMERIC");
+        }
+    }
+}
@@ -1,7 +1,12 @@
 //! Contains traits for checked and rounding operations.
 
+use core::cmp::Ordering;
+
 use crate::ArithmeticError;
 
+use self::sign::Sign;
+
+pub(crate) mod sign;
 pub(crate) mod sqrt;
 
 /// Represents `0`.
@@ -50,35 +55,8 @@ pub trait CheckedAdd<Rhs = Self> {
     /// ```
     fn cadd(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
 
-    /// Saturating addition. Computes `self + rhs`, saturating at the numeric bounds
-    /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
-    ///
-    /// ```
-    /// # #[cfg(feature = "i64")]
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*, CheckedAdd}};
-    ///
-    /// type Amount = FixedPoint<i64, U9>;
-    ///
-    /// let a: Amount = "1000.00002".parse()?;
-    /// let b: Amount = "9222000000".parse()?;
-    /// let c: Amount = "9222001000.00002".parse()?;
-    /// // 1000.00002 + 9222000000 = 9222001000.00002
-    /// assert_eq!(a.saturating_add(b), c);
-    ///
-    /// // 9222000000 + 9222000000 = MAX
-    /// assert_eq!(c.saturating_add(c), Amount::MAX);
-    ///
-    /// let d: Amount = "-9222000000".parse()?;
-    /// // -9222000000 + (-9222000000) = MIN
-    /// assert_eq!(d.saturating_add(d), Amount::MIN);
-    /// # Ok(()) }
-    /// # #[cfg(not(feature = "i64"))]
-    /// # fn main() {}
-    /// ```
-    ///
-    /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
-    /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
+    /// Saturating addition, see [`SaturatingAdd::saturating_add`].
+    #[deprecated(since = "0.10.0", note = "use `SaturatingAdd::saturating_add` instead")]
     fn saturating_add(self, rhs: Rhs) -> Self::Output
     where
         Self: Sized,
@@ -122,35 +100,8 @@ pub trait CheckedSub<Rhs = Self> {
     /// ```
     fn csub(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
 
-    /// Saturating subtraction. Computes `self - rhs`, saturating at the numeric bounds
-    /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
-    ///
-    /// ```
-    /// # #[cfg(feature = "i64")]
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*, CheckedSub}};
-    ///
-    /// type Amount = FixedPoint<i64, U9>;
-    ///
-    /// let a: Amount = "9222001000.00002".parse()?;
-    /// let b: Amount = "9222000000".parse()?;
-    /// let c: Amount = "1000.00002".parse()?;
-    /// // 9222001000.00002 - 9222000000 = 1000.00002
-    /// assert_eq!(a.saturating_sub(b), c);
-    ///
-    /// let d: Amount = "-9222000000".parse()?;
-    /// // 9222000000 - (-9222000000) = MAX
-    /// assert_eq!(b.saturating_sub(d), Amount::MAX);
-    ///
-    /// // -9222000000 - 9222000000 = MIN
-    /// assert_eq!(d.saturating_sub(b), Amount::MIN);
-    /// # Ok(()) }
-    /// # #[cfg(not(feature = "i64"))]
-    /// # fn main() {}
-    /// ```
-    ///
-    /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
-    /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
+    /// Saturating subtraction, see [`SaturatingSub::saturating_sub`].
+    #[deprecated(since = "0.10.0", note = "use `SaturatingSub::saturating_sub` instead")]
     fn saturating_sub(self, rhs: Rhs) -> Self::Output
     where
         Self: Sized,
@@ -195,6 +146,186 @@ pub trait CheckedMul<Rhs = Self> {
     /// ```
     fn cmul(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
 
+    /// Saturating multiplication, see [`SaturatingMul::saturating_mul`].
+    #[deprecated(since = "0.10.0", note = "use `SaturatingMul::saturating_mul` instead")]
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output
+    where
+        Self: PartialOrd + Zero + Sized,
+        Rhs: PartialOrd + Zero,
+        Self::Output: Bounded,
+    {
+        let is_lhs_negative = self < Self::ZERO;
+        let is_rhs_negative = rhs < Rhs::ZERO;
+        self.cmul(rhs).unwrap_or({
+            if is_lhs_negative == is_rhs_negative {
+                Self::Output::MAX
+            } else {
+                Self::Output::MIN
+            }
+        })
+    }
+}
+
+/// Checked remainder.
+pub trait CheckedRem<Rhs = Self> {
+    /// Result of the remainder operation.
+    type Output;
+    /// Usually [`ArithmeticError`].
+    type Error;
+
+    /// Checked remainder of the truncated division, like `%` of integers, the result has
+    /// the sign of `self`. Returns `Err` on division by zero or overflow.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::{CheckedRem, Zero}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "7.5".parse()?;
+    /// let b: Amount = "2".parse()?;
+    /// assert_eq!(a.crem(b)?, "1.5".parse()?);
+    /// assert_eq!(a.cneg()?.crem(b)?, "-1.5".parse()?);
+    /// assert_eq!(a.crem(Amount::ZERO), Err(ArithmeticError::DivisionByZero));
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn crem(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
+}
+
+/// Checked Euclidean division.
+pub trait CheckedEuclid<Rhs = Self> {
+    /// Result of the division, an integer for fixed-point numbers.
+    type Quotient;
+    /// Result of the remainder operation.
+    type Remainder;
+    /// Usually [`ArithmeticError`].
+    type Error;
+
+    /// Calculates the quotient `q` of Euclidean division, such that
+    /// `self = q * rhs + r` and `0 <= r < |rhs|`, like `div_euclid` of integers.
+    /// Returns `Err` on division by zero or overflow.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedEuclid};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let qty: Amount = "7.5".parse()?;
+    /// let lot: Amount = "2".parse()?;
+    /// assert_eq!(qty.checked_div_euclid(lot)?, 3);
+    /// assert_eq!(qty.cneg()?.checked_div_euclid(lot)?, -4);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn checked_div_euclid(self, rhs: Rhs) -> Result<Self::Quotient, Self::Error>;
+
+    /// Calculates the non-negative remainder `r` of Euclidean division, such that
+    /// `self = q * rhs + r` and `0 <= r < |rhs|`, like `rem_euclid` of integers.
+    /// Returns `Err` on division by zero or overflow.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedEuclid};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let qty: Amount = "7.5".parse()?;
+    /// let lot: Amount = "2".parse()?;
+    /// assert_eq!(qty.checked_rem_euclid(lot)?, "1.5".parse()?);
+    /// assert_eq!(qty.cneg()?.checked_rem_euclid(lot)?, "0.5".parse()?);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn checked_rem_euclid(self, rhs: Rhs) -> Result<Self::Remainder, Self::Error>;
+}
+
+/// Saturating addition.
+pub trait SaturatingAdd<Rhs = Self> {
+    /// Result of addition.
+    type Output;
+
+    /// Saturating addition. Computes `self + rhs`, saturating at the numeric bounds
+    /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, SaturatingAdd}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "1000.00002".parse()?;
+    /// let b: Amount = "9222000000".parse()?;
+    /// let c: Amount = "9222001000.00002".parse()?;
+    /// // 1000.00002 + 9222000000 = 9222001000.00002
+    /// assert_eq!(a.saturating_add(b), c);
+    ///
+    /// // 9222000000 + 9222000000 = MAX
+    /// assert_eq!(c.saturating_add(c), Amount::MAX);
+    ///
+    /// let d: Amount = "-9222000000".parse()?;
+    /// // -9222000000 + (-9222000000) = MIN
+    /// assert_eq!(d.saturating_add(d), Amount::MIN);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
+    /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Saturating subtraction.
+pub trait SaturatingSub<Rhs = Self> {
+    /// Result of subtraction.
+    type Output;
+
+    /// Saturating subtraction. Computes `self - rhs`, saturating at the numeric bounds
+    /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, SaturatingSub}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "9222001000.00002".parse()?;
+    /// let b: Amount = "9222000000".parse()?;
+    /// let c: Amount = "1000.00002".parse()?;
+    /// // 9222001000.00002 - 9222000000 = 1000.00002
+    /// assert_eq!(a.saturating_sub(b), c);
+    ///
+    /// let d: Amount = "-9222000000".parse()?;
+    /// // 9222000000 - (-9222000000) = MAX
+    /// assert_eq!(b.saturating_sub(d), Amount::MAX);
+    ///
+    /// // -9222000000 - 9222000000 = MIN
+    /// assert_eq!(d.saturating_sub(b), Amount::MIN);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
+    /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Saturating multiplication.
+pub trait SaturatingMul<Rhs = Self> {
+    /// Result of multiplication.
+    type Output;
+
     /// Saturating multiplication. Computes `self * rhs`, saturating at the numeric bounds
     /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
     /// This is multiplication without rounding, hence it's available only when at least one operand is integer.
@@ -202,7 +333,7 @@ pub trait CheckedMul<Rhs = Self> {
     /// ```
     /// # #[cfg(feature = "i64")]
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use fixnum::{FixedPoint, typenum::U9, ops::{Zero, Bounded, RoundMode::*, CheckedMul}};
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, SaturatingMul}};
     ///
     /// type Amount = FixedPoint<i64, U9>;
     ///
@@ -226,34 +357,69 @@ pub trait CheckedMul<Rhs = Self> {
     /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
     /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
     /// [RoundMode]: ./enum.RoundMode.html
-    fn saturating_mul(self, rhs: Rhs) -> Self::Output
-    where
-        Self: PartialOrd + Zero + Sized,
-        Rhs: PartialOrd + Zero,
-        Self::Output: Bounded,
-    {
-        let is_lhs_negative = self < Self::ZERO;
-        let is_rhs_negative = rhs < Rhs::ZERO;
-        self.cmul(rhs).unwrap_or({
-            if is_lhs_negative == is_rhs_negative {
-                Self::Output::MAX
-            } else {
-                Self::Output::MIN
-            }
-        })
-    }
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
 }
 
 /// Mode of rounding.
+///
+/// Convenience APIs without an explicit mode (`FromStr`, `TryFrom<f64>` and `TryFrom<f32>`
+/// including `serde`, `Display` with precision, `panicking-ops` operators, `Product` and
+/// `num-traits`) use historical modes documented on them. The `default-round-nearest` and
+/// `default-round-half-even` features replace all of them with [`Nearest`](Self::Nearest)
+/// and [`HalfEven`](Self::HalfEven) respectively, e.g. to follow a jurisdiction consistently.
+/// If both are enabled, e.g. by different dependents, `default-round-half-even` wins.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RoundMode {
     /// Rounds up. The result is equal or greater than a mathematical result.
     Ceil = 1,
     /// Rounds to nearest representable value.
     /// The result is nearest to a mathematical result.
+    /// Half-way cases are rounded away from zero.
     Nearest = 0,
     /// Rounds down. The result is equal or less than a mathematical result.
     Floor = -1,
+    /// Rounds to nearest representable value.
+    /// The result is nearest to a mathematical result.
+    /// Half-way cases are rounded to the value with an even last digit (banker's rounding).
+    HalfEven = 2,
+}
+
+impl RoundMode {
+    /// Decides whether an inexact result truncated towards zero must be moved away from zero.
+    ///
+    /// * `sign` is the sign of the mathematical result.
+    /// * `half` is the dropped remainder compared with a half of the last digit.
+    /// * `is_odd` is whether the truncated result is odd.
+    #[inline]
+    pub(crate) fn is_away_from_zero(self, sign: i32, half: Ordering, is_odd: bool) -> bool {
+        match self {
+            RoundMode::Ceil | RoundMode::Floor => self as i32 == sign,
+            RoundMode::Nearest => half != Ordering::Less,
+            RoundMode::HalfEven => half == Ordering::Greater || half == Ordering::Equal && is_odd,
+        }
+    }
+
+    /// Returns the mode selected by `default-round-*` features, if any.
+    #[inline]
+    pub(crate) const fn configured() -> Option<Self> {
+        if cfg!(feature = "default-round-half-even") {
+            Some(RoundMode::HalfEven)
+        } else if cfg!(feature = "default-round-nearest") {
+            Some(RoundMode::Nearest)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [configured](Self::configured) mode or `self` if there is none.
+    /// Used by convenience APIs with `self` being their historical mode.
+    #[inline]
+    pub(crate) const fn or_configured(self) -> Self {
+        match Self::configured() {
+            Some(mode) => mode,
+            None => self,
+        }
+    }
 }
 
 /// Rounding multiplication.
@@ -291,6 +457,34 @@ pub trait RoundingMul<Rhs = Self> {
     /// [RoundMode]: ./enum.RoundMode.html
     fn rmul(self, rhs: Rhs, mode: RoundMode) -> Result<Self::Output, Self::Error>;
 
+    /// Saturating rounding multiplication, see [`SaturatingRMul::saturating_rmul`].
+    #[deprecated(
+        since = "0.10.0",
+        note = "use `SaturatingRMul::saturating_rmul` instead"
+    )]
+    fn saturating_rmul(self, rhs: Rhs, mode: RoundMode) -> Self::Output
+    where
+        Self: PartialOrd + Zero + Sized,
+        Rhs: PartialOrd + Zero,
+        Self::Output: Bounded,
+    {
+        let is_lhs_negative = self < Self::ZERO;
+        let is_rhs_negative = rhs < Rhs::ZERO;
+        self.rmul(rhs, mode).unwrap_or({
+            if is_lhs_negative == is_rhs_negative {
+                Self::Output::MAX
+            } else {
+                Self::Output::MIN
+            }
+        })
+    }
+}
+
+/// Saturating rounding multiplication.
+pub trait SaturatingRMul<Rhs = Self> {
+    /// Result of rounding multiplication.
+    type Output;
+
     /// Saturating rounding multiplication. Computes `self * rhs`, saturating at the numeric bounds
     /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
     /// Because of provided [`RoundMode`][RoundMode] it's possible to perform across the [`FixedPoint`][FixedPoint]
@@ -299,7 +493,7 @@ pub trait RoundingMul<Rhs = Self> {
     /// ```
     /// # #[cfg(feature = "i64")]
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use fixnum::{FixedPoint, typenum::U9, ops::{Zero, Bounded, RoundMode::*, RoundingMul}};
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Zero, Bounded, RoundMode::*, SaturatingRMul}};
     ///
     /// type Amount = FixedPoint<i64, U9>;
     ///
@@ -325,22 +519,7 @@ pub trait RoundingMul<Rhs = Self> {
     /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
     /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
     /// [RoundMode]: ./enum.RoundMode.html
-    fn saturating_rmul(self, rhs: Rhs, round_mode: RoundMode) -> Self::Output
-    where
-        Self: PartialOrd + Zero + Sized,
-        Rhs: PartialOrd + Zero,
-        Self::Output: Bounded,
-    {
-        let is_lhs_negative = self < Self::ZERO;
-        let is_rhs_negative = rhs < Rhs::ZERO;
-        self.rmul(rhs, round_mode).unwrap_or({
-            if is_lhs_negative == is_rhs_negative {
-                Self::Output::MAX
-            } else {
-                Self::Output::MIN
-            }
-        })
-    }
+    fn saturating_rmul(self, rhs: Rhs, mode: RoundMode) -> Self::Output;
 }
 
 /// Rounding division.
@@ -377,6 +556,38 @@ pub trait RoundingDiv<Rhs = Self> {
     fn rdiv(self, rhs: Rhs, mode: RoundMode) -> Result<Self::Output, Self::Error>;
 }
 
+/// Checked summation of iterators.
+pub trait CheckedSum: Iterator {
+    /// Sums up the elements of the iterator. Returns `Err` on overflow.
+    /// An empty iterator returns [`ZERO`][ZERO].
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, CheckedSum}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let amounts: Vec<Amount> = vec!["0.1".parse()?, "0.2".parse()?];
+    /// assert_eq!(amounts.iter().copied().checked_sum()?, "0.3".parse()?);
+    /// assert!([Amount::MAX, Amount::MAX].into_iter().checked_sum().is_err());
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [ZERO]: ./trait.Zero.html#associatedconstant.ZERO
+    fn checked_sum(mut self) -> Result<Self::Item, ArithmeticError>
+    where
+        Self: Sized,
+        Self::Item: Zero + CheckedAdd<Output = Self::Item, Error = ArithmeticError>,
+    {
+        self.try_fold(Self::Item::ZERO, CheckedAdd::cadd)
+    }
+}
+
+impl<I: Iterator> CheckedSum for I {}
+
 // Impls for primitives.
 
 macro_rules! impl_for_ints {
@@ -406,6 +617,11 @@ macro_rules! impl_for_ints {
                 self.checked_add(rhs).ok_or(ArithmeticError::Overflow)
             }
 
+        }
+
+        impl SaturatingAdd for $int {
+            type Output = $int;
+
             #[inline]
             fn saturating_add(self, rhs: Self) -> Self::Output {
                 <$int>::saturating_add(self, rhs)
@@ -421,6 +637,11 @@ macro_rules! impl_for_ints {
                 self.checked_sub(rhs).ok_or(ArithmeticError::Overflow)
             }
 
+        }
+
+        impl SaturatingSub for $int {
+            type Output = $int;
+
             #[inline]
             fn saturating_sub(self, rhs: Self) -> Self::Output {
                 <$int>::saturating_sub(self, rhs)
@@ -436,6 +657,49 @@ macro_rules! impl_for_ints {
                 self.checked_mul(rhs).ok_or(ArithmeticError::Overflow)
             }
 
+        }
+
+        impl CheckedRem for $int {
+            type Output = $int;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn crem(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+                if rhs == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                self.checked_rem(rhs).ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        impl CheckedEuclid for $int {
+            type Quotient = $int;
+            type Remainder = $int;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn checked_div_euclid(self, rhs: Self) -> Result<Self::Quotient, Self::Error> {
+                if rhs == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                <$int>::checked_div_euclid(self, rhs).ok_or(ArithmeticError::Overflow)
+            }
+
+            #[inline]
+            fn checked_rem_euclid(self, rhs: Self) -> Result<Self::Remainder, Self::Error> {
+                if rhs == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                <$int>::checked_rem_euclid(self, rhs).ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        impl SaturatingMul for $int {
+            type Output = $int;
+
             #[inline]
             fn saturating_mul(self, rhs: Self) -> Self::Output {
                 <$int>::saturating_mul(self, rhs)
@@ -452,20 +716,16 @@ macro_rules! impl_for_ints {
                     return Err(ArithmeticError::DivisionByZero);
                 }
 
-                let mut result = self / rhs;
+                let mut result = self.checked_div(rhs).ok_or(ArithmeticError::Overflow)?;
                 let loss = self - result * rhs;
 
                 if loss != 0 {
                     let sign = self.signum() * rhs.signum();
+                    // Compare `|loss|` with `|rhs| / 2` avoiding overflow.
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(rhs.unsigned_abs() - loss_abs));
 
-                    let add_signed_one = if mode == RoundMode::Nearest {
-                        let loss_abs = loss.abs();
-                        loss_abs + loss_abs >= rhs.abs()
-                    } else {
-                        mode as i32 == sign as i32
-                    };
-
-                    if add_signed_one {
+                    if mode.is_away_from_zero(sign as i32, half, result % 2 != 0) {
                         result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                     }
                 }
@@ -476,4 +736,5 @@ macro_rules! impl_for_ints {
     };
 }
 
-impl_for_ints!(i8, i16, i32, i64, i128); // TODO: unsigned?
+impl_for_ints!(i8, i16, i32, i64, i128);
+impl_for_ints!(u8, u16, u32, u64, u128);
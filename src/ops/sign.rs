@@ -0,0 +1,46 @@
+/// Sign-related methods of signed integers for unsigned ones.
+///
+/// Allows to share the code between signed and unsigned layouts: signed integers use inherent
+/// methods with the same names, which take precedence over the trait ones.
+#[cfg_attr(not(any(feature = "u64", feature = "u128")), allow(dead_code))]
+pub(crate) trait Sign: Copy {
+    /// Returns `0` if the number is zero and `1` otherwise.
+    fn signum(self) -> Self;
+    /// Returns the number itself.
+    fn abs(self) -> Self;
+    /// Returns the number itself.
+    fn unsigned_abs(self) -> Self;
+    /// Always returns `false`.
+    fn is_negative(self) -> bool;
+}
+
+macro_rules! impl_sign {
+    ($( $int:ty ),+ $(,)?) => {
+        $( impl_sign!(@single $int); )*
+    };
+    (@single $int:ty) => {
+        impl Sign for $int {
+            #[inline]
+            fn signum(self) -> Self {
+                (self != 0) as $int
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn unsigned_abs(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn is_negative(self) -> bool {
+                false
+            }
+        }
+    };
+}
+
+impl_sign!(u8, u16, u32, u64, u128);
@@ -0,0 +1,138 @@
+//! Reporting of overflows, saturations and rounding for debugging, e.g. to audit where precision
+//! is lost in a large pipeline without changing call sites.
+//!
+//! Instrumented operations report the location of their caller to the hook installed by
+//! [`set_hook`]:
+//! * [`RoundingMul::rmul`] and [`RoundingDiv::rdiv`] of two `FixedPoint` values report errors
+//!   and inexact results.
+//! * [`CheckedAdd::cadd`], [`CheckedSub::csub`] and [`CheckedMul::cmul`] report errors.
+//!   Their `const` versions (`const_cadd` and others) can't call the hook and aren't reported.
+//! * [`SaturatingAdd`], [`SaturatingSub`], [`SaturatingMul`] and [`SaturatingRMul`] report
+//!   saturations.
+//!
+//! Other operations built on top of them report locations inside `fixnum`.
+//! The `i256` layout isn't instrumented.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+//! use fixnum::{overflow_tracing::{self, EventKind}, ops::*, typenum::U2, FixedPoint};
+//!
+//! type Amount = FixedPoint<i64, U2>;
+//!
+//! static ROUNDINGS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! overflow_tracing::set_hook(Box::new(|event| {
+//!     if event.kind == EventKind::Rounding {
+//!         ROUNDINGS.fetch_add(1, Relaxed);
+//!         eprintln!("{} rounded at {}", event.operation, event.location);
+//!     }
+//! }));
+//!
+//! let price: Amount = "10".parse()?;
+//! price.rdiv(Amount::from_bits(300), RoundMode::Floor)?; // `10 / 3` is inexact.
+//! price.rdiv(Amount::from_bits(200), RoundMode::Floor)?;
+//! assert_eq!(ROUNDINGS.load(Relaxed), 1);
+//!
+//! drop(overflow_tracing::take_hook());
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+//!
+//! [`RoundingMul::rmul`]: crate::ops::RoundingMul::rmul
+//! [`RoundingDiv::rdiv`]: crate::ops::RoundingDiv::rdiv
+//! [`CheckedAdd::cadd`]: crate::ops::CheckedAdd::cadd
+//! [`CheckedSub::csub`]: crate::ops::CheckedSub::csub
+//! [`CheckedMul::cmul`]: crate::ops::CheckedMul::cmul
+//! [`SaturatingAdd`]: crate::ops::SaturatingAdd
+//! [`SaturatingSub`]: crate::ops::SaturatingSub
+//! [`SaturatingMul`]: crate::ops::SaturatingMul
+//! [`SaturatingRMul`]: crate::ops::SaturatingRMul
+
+use core::cell::Cell;
+use core::panic::Location;
+use std::{boxed::Box, sync::RwLock};
+
+use crate::ArithmeticError;
+
+type Hook = Box<dyn Fn(&Event) + Send + Sync + 'static>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+std::thread_local! {
+    // Prevents recursion if the hook itself uses instrumented operations.
+    static IS_REPORTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A kind of [`Event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventKind {
+    /// The operation has failed.
+    Error(ArithmeticError),
+    /// The result has been clamped to `MIN` or `MAX`.
+    Saturation,
+    /// The result has been rounded, i.e. it differs from the mathematical one.
+    Rounding,
+}
+
+/// An event reported to the hook.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Event {
+    /// What has happened.
+    pub kind: EventKind,
+    /// The name of the operation, e.g. `"rmul"`.
+    pub operation: &'static str,
+    /// The location of the operation's caller.
+    pub location: &'static Location<'static>,
+}
+
+/// Registers the hook called on every event, replacing the previous one.
+///
+/// The hook is called synchronously in the thread performing the operation.
+pub fn set_hook(hook: Hook) {
+    *HOOK.write().unwrap_or_else(|err| err.into_inner()) = Some(hook);
+}
+
+/// Unregisters the hook, returning it.
+pub fn take_hook() -> Option<Hook> {
+    HOOK.write().unwrap_or_else(|err| err.into_inner()).take()
+}
+
+#[cfg(primitive_layout)]
+#[track_caller]
+pub(crate) fn report(kind: EventKind, operation: &'static str) {
+    let location = Location::caller();
+
+    if IS_REPORTING.with(|flag| flag.replace(true)) {
+        return;
+    }
+
+    if let Some(hook) = &*HOOK.read().unwrap_or_else(|err| err.into_inner()) {
+        hook(&Event {
+            kind,
+            operation,
+            location,
+        });
+    }
+
+    IS_REPORTING.with(|flag| flag.set(false));
+}
+
+/// Reports an error or, if `is_rounded` returns `true`, rounding.
+#[cfg(primitive_layout)]
+#[track_caller]
+pub(crate) fn report_result<T>(
+    operation: &'static str,
+    result: &Result<T, ArithmeticError>,
+    is_rounded: impl FnOnce(&T) -> bool,
+) {
+    match result {
+        Ok(value) if is_rounded(value) => report(EventKind::Rounding, operation),
+        Ok(_) => {}
+        Err(error) => report(EventKind::Error(error.clone()), operation),
+    }
+}
@@ -1,6 +1,9 @@
 use core::result::Result;
 
-use parity_scale_codec::{Compact, CompactAs, Decode, Encode, EncodeLike, Error, Input, Output};
+use parity_scale_codec::{
+    Compact, CompactAs, Decode, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output,
+};
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
 use static_assertions::{assert_eq_align, assert_eq_size};
 
 use crate::FixedPoint;
@@ -43,6 +46,34 @@ macro_rules! impl_codec {
             }
         }
 
+        #[cfg_attr(docsrs, doc(cfg(feature = "parity")))]
+        $(#[$attr])?
+        impl<P> MaxEncodedLen for FixedPoint<$layout, P> {
+            #[inline]
+            fn max_encoded_len() -> usize {
+                <$representation as MaxEncodedLen>::max_encoded_len()
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "parity")))]
+        $(#[$attr])?
+        impl<P: 'static> TypeInfo for FixedPoint<$layout, P> {
+            type Identity = Self;
+
+            // Described as a wrapper over the representation, which is actually encoded.
+            fn type_info() -> Type {
+                Type::builder()
+                    .path(Path::new("FixedPoint", "fixnum"))
+                    .type_params([
+                        TypeParameter::new("I", Some(meta_type::<$layout>())),
+                        TypeParameter::new("P", None),
+                    ])
+                    .composite(Fields::unnamed().field(|f| {
+                        f.ty::<$representation>().type_name(stringify!($representation))
+                    }))
+            }
+        }
+
         #[cfg_attr(docsrs, doc(cfg(feature = "parity")))]
         $(#[$attr])?
         impl<P> CompactAs for FixedPoint<$layout, P> {
@@ -74,3 +105,7 @@ impl_codec!(i32, u32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
 impl_codec!(i64, u64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
 #[cfg(feature = "i128")]
 impl_codec!(i128, u128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
+#[cfg(feature = "u64")]
+impl_codec!(u64, u64, #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]);
+#[cfg(feature = "u128")]
+impl_codec!(u128, u128, #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]);
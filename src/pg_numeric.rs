@@ -0,0 +1,276 @@
+//! Encoding and decoding of Postgres' binary `NUMERIC` format.
+//!
+//! It allows to implement `ToSql` and `FromSql` of `tokio-postgres` (or any other driver)
+//! for newtypes over `FixedPoint` without intermediate strings:
+//! [`encode`] returns bytes to be written as is, [`decode`] accepts the received ones.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{pg_numeric, typenum::U9, FixedPoint};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! let amount: Amount = "12.34".parse()?;
+//! let encoded = pg_numeric::encode(&amount);
+//! // ndigits, weight, sign, dscale, then base-10000 digits: `12` and `3400`.
+//! assert_eq!(encoded.as_bytes(), [0, 2, 0, 0, 0, 0, 0, 9, 0, 12, 0x0d, 0x48]);
+//! assert_eq!(pg_numeric::decode::<i64, U9>(encoded.as_bytes())?, amount);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use crate::{
+    string::{Parse, StrBuf, Stringify},
+    ConvertError, FixedPoint, Precision,
+};
+
+const HEADER_LEN: usize = 8;
+// Any representable number has at most 78 digits, i.e. at most 21 base-10000 digits.
+const MAX_DIGITS: usize = 22;
+const MAX_LEN: usize = HEADER_LEN + 2 * MAX_DIGITS;
+
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xc000;
+const SIGN_POSITIVE_INFINITY: u16 = 0xd000;
+const SIGN_NEGATIVE_INFINITY: u16 = 0xf000;
+
+const NBASE: i16 = 10_000;
+const DEC_DIGITS: usize = 4;
+// Enough for any representable number with the sign, the point and leading fractional zeros.
+const DIGITS_LEN: usize = 3 + 2 * DEC_DIGITS * MAX_DIGITS;
+
+/// The binary `NUMERIC` representation, see [`encode`].
+#[derive(Clone)]
+pub struct Encoded {
+    buffer: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Encoded {
+    /// Returns encoded bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    fn push(&mut self, value: [u8; 2]) {
+        self.buffer[self.len..self.len + 2].copy_from_slice(&value);
+        self.len += 2;
+    }
+}
+
+impl AsRef<[u8]> for Encoded {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Encodes to the binary `NUMERIC` format, the display scale is equal to the precision.
+pub fn encode<I, P>(fp: &FixedPoint<I, P>) -> Encoded
+where
+    P: Precision,
+    FixedPoint<I, P>: Stringify,
+{
+    let mut buf = StrBuf::default();
+    fp.stringify(&mut buf);
+
+    let str = buf.as_str();
+    let (is_negative, str) = match str.strip_prefix('-') {
+        Some(str) => (true, str),
+        None => (false, str),
+    };
+
+    // `Stringify` always emits the point.
+    let (integral, fractional) = str.split_once('.').unwrap_or((str, ""));
+    let integral = integral.trim_start_matches('0');
+    let fractional = fractional.trim_end_matches('0');
+
+    let mut digits = [0i16; MAX_DIGITS];
+    let mut ndigits = 0;
+
+    // Groups of the integral part are aligned to the point, thus the first one can be shorter.
+    let first_len = match integral.len() % DEC_DIGITS {
+        0 => DEC_DIGITS,
+        len => len,
+    };
+    let mut rest = integral.as_bytes();
+    let mut len = first_len.min(rest.len());
+    while !rest.is_empty() {
+        let (group, tail) = rest.split_at(len);
+        digits[ndigits] = parse_group(group);
+        ndigits += 1;
+        rest = tail;
+        len = DEC_DIGITS;
+    }
+
+    let mut weight = ndigits as i16 - 1;
+
+    // Groups of the fractional part are padded with zeros at the end.
+    for group in fractional.as_bytes().chunks(DEC_DIGITS) {
+        let mut digit = parse_group(group);
+        for _ in group.len()..DEC_DIGITS {
+            digit *= 10;
+        }
+
+        // Leading zeros are encoded by the weight.
+        if ndigits == 0 && digit == 0 {
+            weight -= 1;
+            continue;
+        }
+
+        digits[ndigits] = digit;
+        ndigits += 1;
+    }
+
+    while ndigits > 0 && digits[ndigits - 1] == 0 {
+        ndigits -= 1;
+    }
+
+    let (weight, sign) = match (ndigits, is_negative) {
+        (0, _) => (0, SIGN_POSITIVE),
+        (_, false) => (weight, SIGN_POSITIVE),
+        (_, true) => (weight, SIGN_NEGATIVE),
+    };
+
+    let mut encoded = Encoded {
+        buffer: [0; MAX_LEN],
+        len: 0,
+    };
+
+    encoded.push((ndigits as i16).to_be_bytes());
+    encoded.push(weight.to_be_bytes());
+    encoded.push(sign.to_be_bytes());
+    encoded.push(P::U16.to_be_bytes());
+
+    for digit in &digits[..ndigits] {
+        encoded.push(digit.to_be_bytes());
+    }
+
+    encoded
+}
+
+/// Decodes from the binary `NUMERIC` format.
+///
+/// Fails if the number can't be represented without rounding, on `NaN` and infinities.
+pub fn decode<I, P>(bytes: &[u8]) -> Result<FixedPoint<I, P>, ConvertError>
+where
+    FixedPoint<I, P>: Parse,
+{
+    let invalid = || ConvertError::new("invalid NUMERIC");
+
+    if bytes.len() < HEADER_LEN {
+        return Err(invalid());
+    }
+
+    let read = |index: usize| [bytes[2 * index], bytes[2 * index + 1]];
+    let ndigits = i16::from_be_bytes(read(0));
+    let weight = i16::from_be_bytes(read(1));
+    let sign = u16::from_be_bytes(read(2));
+
+    let ndigits = usize::try_from(ndigits).map_err(|_| invalid())?;
+    if bytes.len() != HEADER_LEN + 2 * ndigits {
+        return Err(invalid());
+    }
+
+    let is_negative = match sign {
+        SIGN_POSITIVE => false,
+        SIGN_NEGATIVE => true,
+        SIGN_NAN => return Err(ConvertError::new("NaN can't be represented")),
+        SIGN_POSITIVE_INFINITY | SIGN_NEGATIVE_INFINITY => {
+            return Err(ConvertError::new("infinity can't be represented"))
+        }
+        _ => return Err(invalid()),
+    };
+
+    let digit = |index: i32| -> Result<i16, ConvertError> {
+        if index < 0 || index >= ndigits as i32 {
+            return Ok(0);
+        }
+
+        let digit = i16::from_be_bytes(read(HEADER_LEN / 2 + index as usize));
+        if (0..NBASE).contains(&digit) {
+            Ok(digit)
+        } else {
+            Err(invalid())
+        }
+    };
+
+    let weight = i32::from(weight);
+    if weight >= MAX_DIGITS as i32 {
+        return Err(ConvertError::new("too big number"));
+    }
+
+    let mut buf = [0; DIGITS_LEN];
+    let mut len = 0;
+
+    if is_negative {
+        buf[len] = b'-';
+        len += 1;
+    }
+
+    // Digits with indexes `0..=weight` form the integral part.
+    for index in 0..=weight {
+        for c in group_to_ascii(digit(index)?) {
+            // Skip leading zeros.
+            if c != b'0' || buf[..len].iter().any(u8::is_ascii_digit) {
+                buf[len] = c;
+                len += 1;
+            }
+        }
+    }
+
+    if !buf[..len].iter().any(u8::is_ascii_digit) {
+        buf[len] = b'0';
+        len += 1;
+    }
+
+    buf[len] = b'.';
+    len += 1;
+
+    // The rest form the fractional part, negative indexes are leading zeros if `weight < -1`.
+    // The point is dropped if there are no fractional digits.
+    let mut end = len - 1;
+    for index in weight + 1..ndigits as i32 {
+        for c in group_to_ascii(digit(index)?) {
+            if len == DIGITS_LEN {
+                if c == b'0' {
+                    continue;
+                }
+
+                return Err(ConvertError::new("requested precision is too high"));
+            }
+
+            buf[len] = c;
+            len += 1;
+
+            // Trailing zeros are trimmed.
+            if c != b'0' {
+                end = len;
+            }
+        }
+    }
+
+    // Only ASCII digits, `-` and `.` are written.
+    let str = core::str::from_utf8(&buf[..end]).map_err(|_| invalid())?;
+    FixedPoint::parse(str, None)
+}
+
+fn group_to_ascii(digit: i16) -> [u8; DEC_DIGITS] {
+    let digit = digit as u16;
+    [
+        b'0' + (digit / 1000) as u8,
+        b'0' + (digit / 100 % 10) as u8,
+        b'0' + (digit / 10 % 10) as u8,
+        b'0' + (digit % 10) as u8,
+    ]
+}
+
+fn parse_group(group: &[u8]) -> i16 {
+    group
+        .iter()
+        .fold(0, |acc, c| acc * 10 + i16::from(c - b'0'))
+}
@@ -0,0 +1,54 @@
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    sample::select,
+    strategy::{BoxedStrategy, Strategy, Union},
+};
+
+use crate::{FixedPoint, Precision};
+
+// Out of 10 values, 2 are boundary ones.
+const BOUNDARY_WEIGHT: u32 = 2;
+const REGULAR_WEIGHT: u32 = 8;
+
+macro_rules! impl_arbitrary {
+    ($layout:tt) => {
+        /// Generates any values, biased toward `MIN`, `MAX`, `±EPSILON`, `±1` and zero.
+        #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+        impl<P: Precision + 'static> Arbitrary for FixedPoint<$layout, P> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                let boundaries = [Self::EPSILON, Self::ONE]
+                    .into_iter()
+                    // Skipped for unsigned layouts.
+                    .flat_map(|value| [Some(value), value.cneg().ok()])
+                    .flatten()
+                    .chain([Self::MIN, Self::MAX, Self::ZERO])
+                    .collect::<Vec<_>>();
+
+                Union::new_weighted(vec![
+                    (BOUNDARY_WEIGHT, select(boundaries).boxed()),
+                    (
+                        REGULAR_WEIGHT,
+                        any::<$layout>().prop_map(Self::from_bits).boxed(),
+                    ),
+                ])
+                .boxed()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_arbitrary!(i16);
+#[cfg(feature = "i32")]
+impl_arbitrary!(i32);
+#[cfg(feature = "i64")]
+impl_arbitrary!(i64);
+#[cfg(feature = "i128")]
+impl_arbitrary!(i128);
+#[cfg(feature = "u64")]
+impl_arbitrary!(u64);
+#[cfg(feature = "u128")]
+impl_arbitrary!(u128);
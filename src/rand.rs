@@ -0,0 +1,103 @@
+use core::marker::PhantomData;
+
+use rand::{
+    distributions::uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+    Rng,
+};
+
+use crate::FixedPoint;
+
+/// Samples `FixedPoint` uniformly in a range, used by `rng.gen_range(low..high)`.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{typenum::U9, FixedPoint};
+/// use rand::Rng;
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let low: Amount = "0.5".parse()?;
+/// let high: Amount = "1.5".parse()?;
+/// let x = rand::thread_rng().gen_range(low..high);
+/// assert!(low <= x && x < high);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+#[derive(Clone, Copy, Debug)]
+pub struct UniformFixedPoint<I, P> {
+    inner: UniformInt<I>,
+    _marker: PhantomData<P>,
+}
+
+macro_rules! impl_rand {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+        impl<P> SampleUniform for FixedPoint<$layout, P> {
+            type Sampler = UniformFixedPoint<$layout, P>;
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+        impl<P> UniformSampler for UniformFixedPoint<$layout, P> {
+            type X = FixedPoint<$layout, P>;
+
+            #[inline]
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Self {
+                    inner: UniformInt::new(low.borrow().inner, high.borrow().inner),
+                    _marker: PhantomData,
+                }
+            }
+
+            #[inline]
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Self {
+                    inner: UniformInt::new_inclusive(low.borrow().inner, high.borrow().inner),
+                    _marker: PhantomData,
+                }
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                FixedPoint::from_bits(self.inner.sample(rng))
+            }
+
+            #[inline]
+            fn sample_single<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let bits = UniformInt::<$layout>::sample_single(
+                    low.borrow().inner,
+                    high.borrow().inner,
+                    rng,
+                );
+                FixedPoint::from_bits(bits)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_rand!(i16);
+#[cfg(feature = "i32")]
+impl_rand!(i32);
+#[cfg(feature = "i64")]
+impl_rand!(i64);
+#[cfg(feature = "i128")]
+impl_rand!(i128);
+#[cfg(feature = "u64")]
+impl_rand!(u64);
+#[cfg(feature = "u128")]
+impl_rand!(u128);
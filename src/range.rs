@@ -0,0 +1,307 @@
+use core::iter::FusedIterator;
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+use crate::ops::RoundMode;
+use crate::{ArithmeticError, FixedPoint, Precision};
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// An iterator over values from `start` by `step`, see [`FixedPoint::range_step`]
+/// and [`FixedPoint::range_step_inclusive`].
+#[derive(Clone)]
+pub struct RangeStep<I, P> {
+    next: Option<FixedPoint<I, P>>,
+    step: I,
+    // The number of steps after `next`, it can exceed `usize`.
+    steps_left: u128,
+}
+
+/// An iterator over evenly spaced values, see [`FixedPoint::linspace`].
+#[derive(Clone)]
+pub struct Linspace<I, P> {
+    start: FixedPoint<I, P>,
+    is_descending: bool,
+    // `|end - start| = quotient * intervals + remainder`.
+    quotient: u128,
+    remainder: u128,
+    intervals: u128,
+    mode: RoundMode,
+    index: usize,
+    len: usize,
+}
+
+macro_rules! impl_range_step {
+    ($layout:tt) => {
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Returns values from `start` (inclusive) to `end` (exclusive) by `step`,
+            /// like `(start..end).step_by(step)` does for integers. The step can be negative
+            /// to iterate downwards, the range is empty if `end` isn't reachable.
+            ///
+            /// Values are calculated exactly without accumulating errors.
+            /// Returns `Err(DomainViolation)` if `step` is zero.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2};
+            ///
+            /// type Price = FixedPoint<i64, U2>;
+            ///
+            /// let (start, end, step): (Price, Price, Price) =
+            ///     ("100".parse()?, "100.2".parse()?, "0.05".parse()?);
+            ///
+            /// let ladder: Vec<String> = Price::range_step(start, end, step)?
+            ///     .map(|price| price.to_string())
+            ///     .collect();
+            /// assert_eq!(ladder, ["100.0", "100.05", "100.1", "100.15"]);
+            ///
+            /// let ladder = Price::range_step(end, start, step.cneg()?)?;
+            /// assert_eq!(ladder.last(), Some("100.05".parse()?));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn range_step(start: Self, end: Self, step: Self) -> Result<RangeStep<$layout, P>> {
+                Self::new_range_step(start, end, step, false)
+            }
+
+            /// Returns values from `start` to `end` (both inclusive) by `step`,
+            /// like `(start..=end).step_by(step)` does for integers.
+            /// `end` is yielded only if it's reachable by whole steps.
+            ///
+            /// See [`range_step`](Self::range_step) for details.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U2};
+            ///
+            /// type Price = FixedPoint<i64, U2>;
+            ///
+            /// let (start, end, step): (Price, Price, Price) =
+            ///     ("100".parse()?, "101".parse()?, "0.05".parse()?);
+            ///
+            /// let ladder = Price::range_step_inclusive(start, end, step)?;
+            /// assert_eq!(ladder.clone().count(), 21);
+            /// assert_eq!(ladder.last(), Some(end));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn range_step_inclusive(
+                start: Self,
+                end: Self,
+                step: Self,
+            ) -> Result<RangeStep<$layout, P>> {
+                Self::new_range_step(start, end, step, true)
+            }
+
+            fn new_range_step(
+                start: Self,
+                end: Self,
+                step: Self,
+                is_inclusive: bool,
+            ) -> Result<RangeStep<$layout, P>> {
+                if step.inner == 0 {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                let is_reachable = if step.inner.is_negative() {
+                    end.inner < start.inner || is_inclusive && end.inner == start.inner
+                } else {
+                    end.inner > start.inner || is_inclusive && end.inner == start.inner
+                };
+
+                if !is_reachable {
+                    return Ok(RangeStep {
+                        next: None,
+                        step: step.inner,
+                        steps_left: 0,
+                    });
+                }
+
+                // Unsigned operations can't overflow and the result fits in `u128`.
+                let distance = start.inner.abs_diff(end.inner) as u128;
+                let step_abs = step.inner.unsigned_abs() as u128;
+                // `distance > 0` in the exclusive case.
+                let distance = if is_inclusive { distance } else { distance - 1 };
+
+                Ok(RangeStep {
+                    next: Some(start),
+                    step: step.inner,
+                    steps_left: distance / step_abs,
+                })
+            }
+        }
+
+        impl<P: Precision> Iterator for RangeStep<$layout, P> {
+            type Item = FixedPoint<$layout, P>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let value = self.next?;
+
+                self.next = if self.steps_left > 0 {
+                    self.steps_left -= 1;
+                    // The next value lies between `start` and `end`, thus no overflow.
+                    Some(FixedPoint::from_bits(value.inner + self.step))
+                } else {
+                    None
+                };
+
+                Some(value)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.next.is_none() {
+                    return (0, Some(0));
+                }
+
+                match usize::try_from(self.steps_left)
+                    .ok()
+                    .and_then(|n| n.checked_add(1))
+                {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+        }
+
+        impl<P: Precision> FusedIterator for RangeStep<$layout, P> {}
+
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Returns `n` evenly spaced values from `start` to `end` (both inclusive),
+            /// e.g. for numerical tables. Every value is [rounded][RoundMode] independently
+            /// according to `mode`, so errors don't accumulate and the last value is exactly `end`.
+            /// The only value is `start` if `n == 1`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ops::RoundMode::*, typenum::U2, FixedPoint};
+            ///
+            /// type Amount = FixedPoint<i64, U2>;
+            ///
+            /// let (start, end): (Amount, Amount) = ("0".parse()?, "1".parse()?);
+            ///
+            /// let grid: Vec<String> = Amount::linspace(start, end, 4, Floor)
+            ///     .map(|value| value.to_string())
+            ///     .collect();
+            /// assert_eq!(grid, ["0.0", "0.33", "0.66", "1.0"]);
+            ///
+            /// let grid: Vec<String> = Amount::linspace(end, start, 4, Nearest)
+            ///     .map(|value| value.to_string())
+            ///     .collect();
+            /// assert_eq!(grid, ["1.0", "0.67", "0.33", "0.0"]);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn linspace(
+                start: Self,
+                end: Self,
+                n: usize,
+                mode: RoundMode,
+            ) -> Linspace<$layout, P> {
+                // Unsigned operations can't overflow and the result fits in `u128`.
+                let distance = start.inner.abs_diff(end.inner) as u128;
+                let intervals = (n.max(2) - 1) as u128;
+
+                Linspace {
+                    start,
+                    is_descending: end.inner < start.inner,
+                    quotient: distance / intervals,
+                    remainder: distance % intervals,
+                    intervals,
+                    mode,
+                    index: 0,
+                    len: n,
+                }
+            }
+        }
+
+        impl<P: Precision> Iterator for Linspace<$layout, P> {
+            type Item = FixedPoint<$layout, P>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.index == self.len {
+                    return None;
+                }
+
+                let index = self.index as u128;
+                self.index += 1;
+
+                // The offset is `quotient * index + remainder * index / intervals`,
+                // it doesn't exceed the distance. `remainder * index < intervals^2 < 2^128`.
+                let scaled = self.remainder * index;
+                let offset = self.quotient * index + scaled / self.intervals;
+                let loss = scaled % self.intervals;
+
+                // The exact value lies between `start` and `end`, thus wrapping is fine.
+                let truncated = if self.is_descending {
+                    self.start.inner.wrapping_sub(offset as $layout)
+                } else {
+                    self.start.inner.wrapping_add(offset as $layout)
+                };
+
+                if loss == 0 {
+                    return Some(FixedPoint::from_bits(truncated));
+                }
+
+                // The exact value is between `lower` and `lower + 1`.
+                let lower = if self.is_descending {
+                    truncated - 1
+                } else {
+                    truncated
+                };
+                let lower_half = if self.is_descending {
+                    (self.intervals - loss).cmp(&loss)
+                } else {
+                    loss.cmp(&(self.intervals - loss))
+                };
+
+                // Round towards zero first, like `RoundMode` expects.
+                let is_negative = lower.is_negative();
+                let (toward_zero, half, away) = if is_negative {
+                    (lower + 1, lower_half.reverse(), lower)
+                } else {
+                    (lower, lower_half, lower + 1)
+                };
+                let sign = if is_negative { -1 } else { 1 };
+                let is_odd = toward_zero % 2 != 0;
+
+                Some(FixedPoint::from_bits(
+                    if self.mode.is_away_from_zero(sign, half, is_odd) {
+                        away
+                    } else {
+                        toward_zero
+                    },
+                ))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.len - self.index;
+                (len, Some(len))
+            }
+        }
+
+        impl<P: Precision> ExactSizeIterator for Linspace<$layout, P> {}
+
+        impl<P: Precision> FusedIterator for Linspace<$layout, P> {}
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_range_step!(i16);
+#[cfg(feature = "i32")]
+impl_range_step!(i32);
+#[cfg(feature = "i64")]
+impl_range_step!(i64);
+#[cfg(feature = "i128")]
+impl_range_step!(i128);
+#[cfg(feature = "u64")]
+impl_range_step!(u64);
+#[cfg(feature = "u128")]
+impl_range_step!(u128);
@@ -0,0 +1,281 @@
+#[cfg(any(feature = "i128", feature = "u128"))]
+use crate::i256::I256;
+use crate::ops::{CheckedAdd, CheckedMul, CheckedSub, RoundMode, RoundingDiv};
+use crate::ArithmeticError;
+use crate::{FixedPoint, Precision};
+
+use self::private::{RatioOperand, Wide};
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// An exact quotient of two values, which defers division (and thus rounding) until [`resolve`].
+///
+/// The numerator and the denominator are kept reduced in `i128` for 64-bit and smaller layouts
+/// and in 256 bits for 128-bit ones, so a chain of additions and multiplications, e.g. in
+/// pro-rata calculations, is rounded only once. Operations return `Err` only if the reduced
+/// numerator or denominator doesn't fit.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ops::*, typenum::U9, FixedPoint, Ratio};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let fee: Amount = "100".parse()?;
+/// let (weight, total): (Amount, Amount) = ("1".parse()?, "3".parse()?);
+///
+/// // Rounding on every step loses precision.
+/// let share = fee.rmul(weight.rdiv(total, RoundMode::Floor)?, RoundMode::Floor)?;
+/// assert_eq!(share.rmul(total, RoundMode::Floor)?, "99.9999999".parse()?);
+///
+/// // `fee * (weight / total) * total` is exact.
+/// let share = Ratio::from(fee).cmul(Ratio::new(weight, total)?)?;
+/// assert_eq!(share.resolve(RoundMode::Floor)?, "33.333333333".parse()?);
+/// assert_eq!(share.cmul(Ratio::from(total))?.resolve(RoundMode::Floor)?, fee);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// [`resolve`]: Ratio::resolve
+pub struct Ratio<F: RatioOperand> {
+    // Invariant: `denom > 0` and `gcd(numer, denom) == 1`.
+    numer: F::Wide,
+    denom: F::Wide,
+}
+
+impl<F: RatioOperand> Ratio<F> {
+    /// Creates `numer / denom`. Returns `Err` if `denom` is zero.
+    #[inline]
+    pub fn new(numer: F, denom: F) -> Result<Self> {
+        Self::reduce(numer.to_wide(), denom.to_wide())
+    }
+
+    /// Returns `1 / self`. Returns `Err` if the value is zero.
+    #[inline]
+    pub fn recip(self) -> Result<Self> {
+        Self::reduce(self.denom, self.numer)
+    }
+
+    /// Converts to the value [rounded][RoundMode] according to `mode`.
+    /// Returns `Err` if the value doesn't fit in the layout.
+    #[inline]
+    pub fn resolve(self, mode: RoundMode) -> Result<F> {
+        let numer = self.numer.mul(F::COEF)?;
+        F::from_wide(numer.rdiv(self.denom, mode)?)
+    }
+
+    fn reduce(numer: F::Wide, denom: F::Wide) -> Result<Self> {
+        if denom == F::Wide::ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        let (numer, denom) = if denom < F::Wide::ZERO {
+            (numer.neg()?, denom.neg()?)
+        } else {
+            (numer, denom)
+        };
+
+        let gcd = numer.gcd(denom);
+        Ok(Self {
+            numer: numer.div(gcd),
+            denom: denom.div(gcd),
+        })
+    }
+}
+
+impl<F: RatioOperand> From<F> for Ratio<F> {
+    #[inline]
+    fn from(value: F) -> Self {
+        // `COEF` is a power of ten, so the result is reduced by removing common factors.
+        Self::reduce(value.to_wide(), F::COEF).expect("COEF is positive")
+    }
+}
+
+impl<F: RatioOperand> Clone for Ratio<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: RatioOperand> Copy for Ratio<F> {}
+
+impl<F: RatioOperand> CheckedAdd for Ratio<F> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cadd(self, rhs: Self) -> Result<Self> {
+        // `a/b + c/d = (a * (d/g) + c * (b/g)) / (b * (d/g))`, where `g = gcd(b, d)`.
+        let gcd = self.denom.gcd(rhs.denom);
+        let (lhs_factor, rhs_factor) = (rhs.denom.div(gcd), self.denom.div(gcd));
+        let numer = self
+            .numer
+            .mul(lhs_factor)?
+            .add(rhs.numer.mul(rhs_factor)?)?;
+        Self::reduce(numer, self.denom.mul(lhs_factor)?)
+    }
+}
+
+impl<F: RatioOperand> CheckedSub for Ratio<F> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn csub(self, rhs: Self) -> Result<Self> {
+        let rhs = Self {
+            numer: rhs.numer.neg()?,
+            ..rhs
+        };
+        self.cadd(rhs)
+    }
+}
+
+impl<F: RatioOperand> CheckedMul for Ratio<F> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cmul(self, rhs: Self) -> Result<Self> {
+        // Cancel common factors crosswise to keep intermediate values small.
+        let lhs_gcd = self.numer.gcd(rhs.denom);
+        let rhs_gcd = rhs.numer.gcd(self.denom);
+        let numer = self.numer.div(lhs_gcd).mul(rhs.numer.div(rhs_gcd))?;
+        let denom = self.denom.div(rhs_gcd).mul(rhs.denom.div(lhs_gcd))?;
+        Self::reduce(numer, denom)
+    }
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to use `Ratio` in generic code.
+    #[allow(unreachable_pub)]
+    pub trait RatioOperand: Sized {
+        type Wide: Wide;
+
+        const COEF: Self::Wide;
+
+        fn to_wide(self) -> Self::Wide;
+        fn from_wide(value: Self::Wide) -> Result<Self>;
+    }
+
+    /// A signed integer wide enough for numerators and denominators.
+    #[allow(unreachable_pub)]
+    pub trait Wide: Copy + Ord + RoundingDiv<Output = Self, Error = ArithmeticError> {
+        const ZERO: Self;
+
+        fn add(self, rhs: Self) -> Result<Self>;
+        fn mul(self, rhs: Self) -> Result<Self>;
+        fn neg(self) -> Result<Self>;
+        /// Exact division by a positive divisor.
+        fn div(self, rhs: Self) -> Self;
+        /// Returns `gcd(|self|, rhs)`, where `rhs > 0`.
+        fn gcd(self, rhs: Self) -> Self;
+    }
+}
+
+impl Wide for i128 {
+    const ZERO: Self = 0;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Result<Self> {
+        self.checked_add(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Result<Self> {
+        self.checked_mul(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn neg(self) -> Result<Self> {
+        self.checked_neg().ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    fn gcd(self, rhs: Self) -> Self {
+        let (mut a, mut b) = (self.unsigned_abs(), rhs.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        // `gcd <= rhs`, thus it fits.
+        a as i128
+    }
+}
+
+#[cfg(any(feature = "i128", feature = "u128"))]
+impl Wide for I256 {
+    const ZERO: Self = I256::from_i128(0);
+
+    #[inline]
+    fn add(self, rhs: Self) -> Result<Self> {
+        self.checked_add(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Result<Self> {
+        self.checked_mul(rhs).ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn neg(self) -> Result<Self> {
+        self.checked_neg().ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    fn gcd(self, rhs: Self) -> Self {
+        let zero = Self::ZERO.unsigned_abs();
+        let (mut a, mut b) = (self.unsigned_abs(), rhs.unsigned_abs());
+        while b != zero {
+            (a, b) = (b, a.div_mod(b).1);
+        }
+        // `gcd <= rhs`, thus it fits.
+        I256::from_sign_and_abs(false, a).expect("gcd doesn't exceed rhs")
+    }
+}
+
+macro_rules! impl_ratio_operand {
+    ($layout:tt, $wide:tt, $coef:expr) => {
+        impl<P: Precision> RatioOperand for FixedPoint<$layout, P> {
+            type Wide = $wide;
+
+            const COEF: $wide = $coef;
+
+            #[inline]
+            fn to_wide(self) -> $wide {
+                $wide::from(self.inner)
+            }
+
+            #[inline]
+            fn from_wide(value: $wide) -> Result<Self> {
+                $layout::try_from(value)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_ratio_operand!(i16, i128, Self::COEF as i128);
+#[cfg(feature = "i32")]
+impl_ratio_operand!(i32, i128, Self::COEF as i128);
+#[cfg(feature = "i64")]
+impl_ratio_operand!(i64, i128, Self::COEF as i128);
+#[cfg(feature = "i128")]
+impl_ratio_operand!(i128, I256, I256::from_i128(Self::COEF));
+#[cfg(feature = "u64")]
+impl_ratio_operand!(u64, i128, Self::COEF as i128);
+#[cfg(feature = "u128")]
+impl_ratio_operand!(u128, I256, I256::from_i128(Self::COEF as i128));
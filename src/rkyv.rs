@@ -0,0 +1,97 @@
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, ResultExt, Trace},
+    traits::CopyOptimization,
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::FixedPoint;
+
+// SAFETY: `FixedPoint` is a `#[repr(transparent)]` wrapper around the layout,
+// so it is `Portable` when the layout is `Portable`.
+unsafe impl<I: Portable, P> Portable for FixedPoint<I, P> {}
+
+// SAFETY: `FixedPoint` is a `#[repr(transparent)]` wrapper around the layout,
+// so a pointer to it is a valid pointer to the layout.
+unsafe impl<I, P, C> CheckBytes<C> for FixedPoint<I, P>
+where
+    I: CheckBytes<C>,
+    C: Fallible + ?Sized,
+    C::Error: Trace,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        unsafe {
+            I::check_bytes(value.cast::<I>(), context)
+                .trace("while checking the layout of `FixedPoint`")
+        }
+    }
+}
+
+/// Archived as `FixedPoint` over the archived layout, e.g. `FixedPoint<i64_le, P>`,
+/// which is also `#[repr(transparent)]`. Use `to_native()` to get the value.
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<I: Archive, P> Archive for FixedPoint<I, P> {
+    const COPY_OPTIMIZATION: CopyOptimization<Self> =
+        unsafe { CopyOptimization::enable_if(I::COPY_OPTIMIZATION.is_enabled()) };
+
+    type Archived = FixedPoint<I::Archived, P>;
+    type Resolver = I::Resolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `FixedPoint<I::Archived, P>` is `#[repr(transparent)]` over `I::Archived`.
+        let out = unsafe { out.cast_unchecked::<I::Archived>() };
+        self.as_bits().resolve(resolver, out);
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<I: Serialize<S>, P, S: Fallible + ?Sized> Serialize<S> for FixedPoint<I, P> {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.as_bits().serialize(serializer)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<I, P, D> Deserialize<FixedPoint<I, P>, D> for FixedPoint<I::Archived, P>
+where
+    I: Archive,
+    I::Archived: Deserialize<I, D>,
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<FixedPoint<I, P>, D::Error> {
+        self.as_bits()
+            .deserialize(deserializer)
+            .map(FixedPoint::from_bits)
+    }
+}
+
+#[cfg(primitive_layout)]
+macro_rules! impl_to_native {
+    ($layout:ty, $archived:ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+        impl<P> FixedPoint<rkyv::primitive::$archived, P> {
+            /// Returns the value of the archived number without deserialization.
+            #[inline]
+            pub fn to_native(&self) -> FixedPoint<$layout, P> {
+                FixedPoint::from_bits(self.as_bits().to_native())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_to_native!(i16, ArchivedI16);
+#[cfg(feature = "i32")]
+impl_to_native!(i32, ArchivedI32);
+#[cfg(feature = "i64")]
+impl_to_native!(i64, ArchivedI64);
+#[cfg(feature = "i128")]
+impl_to_native!(i128, ArchivedI128);
+#[cfg(feature = "u64")]
+impl_to_native!(u64, ArchivedU64);
+#[cfg(feature = "u128")]
+impl_to_native!(u128, ArchivedU128);
@@ -0,0 +1,90 @@
+use core::convert::TryFrom;
+
+use rust_decimal::Decimal;
+
+use crate::power_table::power_of_10;
+use crate::{ConvertError, FixedPoint, Precision};
+
+// Fractional digits supported by `Decimal`.
+const MAX_SCALE: i32 = 28;
+
+macro_rules! impl_rust_decimal {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+        impl<P: Precision> TryFrom<Decimal> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision.
+            /// Fails if the value doesn't fit or has more significant fractional digits than
+            /// `PRECISION`.
+            fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+                let abs = value.mantissa().unsigned_abs();
+                let scale = value.scale() as i32;
+
+                let abs = if scale > Self::PRECISION {
+                    // Trailing zeros can be dropped, other digits can't.
+                    let divisor = power_of_10((scale - Self::PRECISION) as u32).unwrap();
+                    if abs % divisor != 0 {
+                        return Err(ConvertError::new("requested precision is too high"));
+                    }
+                    abs / divisor
+                } else {
+                    power_of_10((Self::PRECISION - scale) as u32)
+                        .and_then(|multiplier| abs.checked_mul(multiplier))
+                        .ok_or_else(|| ConvertError::new("too big number"))?
+                };
+
+                let inner = if value.is_sign_negative() {
+                    // Negation of non-negative `i128` never overflows.
+                    i128::try_from(abs)
+                        .ok()
+                        .and_then(|abs| $layout::try_from(-abs).ok())
+                } else {
+                    $layout::try_from(abs).ok()
+                };
+
+                inner
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new("too big number"))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+        impl<P: Precision> TryFrom<FixedPoint<$layout, P>> for Decimal {
+            type Error = ConvertError;
+
+            /// Converts without any loss of precision.
+            /// Fails if the value doesn't fit or has more than 28 significant fractional digits.
+            fn try_from(value: FixedPoint<$layout, P>) -> Result<Self, Self::Error> {
+                let mut mantissa =
+                    i128::try_from(value.inner).map_err(|_| ConvertError::new("too big number"))?;
+                let mut scale = FixedPoint::<$layout, P>::PRECISION;
+
+                if scale > MAX_SCALE {
+                    let divisor = power_of_10((scale - MAX_SCALE) as u32).unwrap() as i128;
+                    if mantissa % divisor != 0 {
+                        return Err(ConvertError::new("requested precision is too high"));
+                    }
+                    mantissa /= divisor;
+                    scale = MAX_SCALE;
+                }
+
+                Decimal::try_from_i128_with_scale(mantissa, scale as u32)
+                    .map_err(|_| ConvertError::new("too big number"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_rust_decimal!(i16);
+#[cfg(feature = "i32")]
+impl_rust_decimal!(i32);
+#[cfg(feature = "i64")]
+impl_rust_decimal!(i64);
+#[cfg(feature = "i128")]
+impl_rust_decimal!(i128);
+#[cfg(feature = "u64")]
+impl_rust_decimal!(u64);
+#[cfg(feature = "u128")]
+impl_rust_decimal!(u128);
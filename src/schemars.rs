@@ -1,11 +1,15 @@
 use schemars::{
     gen::SchemaGenerator,
-    schema::{InstanceType, Schema, SchemaObject},
+    schema::{InstanceType, Schema, SchemaObject, StringValidation},
     JsonSchema,
 };
 
 use crate::FixedPoint;
 
+// Matches strings produced by `Display`, e.g. `-12.34`.
+const PATTERN: &str = r"^-?[0-9]+(\.[0-9]+)?$";
+
+/// Describes the default string representation.
 impl<I, P> JsonSchema for FixedPoint<I, P> {
     fn is_referenceable() -> bool {
         false
@@ -18,8 +22,31 @@ impl<I, P> JsonSchema for FixedPoint<I, P> {
     fn json_schema(_: &mut SchemaGenerator) -> Schema {
         SchemaObject {
             instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(PATTERN.to_owned()),
+                ..Default::default()
+            })),
             ..Default::default()
         }
         .into()
     }
 }
+
+#[cfg(feature = "serde")]
+pub(crate) fn float() -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Number.into()),
+        format: Some("double".to_owned()),
+        ..Default::default()
+    }
+    .into()
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn repr() -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Integer.into()),
+        ..Default::default()
+    }
+    .into()
+}
@@ -6,23 +6,30 @@
 //! and `repr` for binary ones.
 //!
 //! By default, `FixedPoint` is deserialized from strings, floats and integers for human readable
-//! formats and `repr` for binary ones.
+//! formats and `repr` for binary ones. Strings can be written in exponent notation (`"1.5e3"`).
+//! Use [`str_flexible`] to accept all of them regardless of the format.
 
-#[cfg(feature = "quick-xml")]
+#[cfg(any(feature = "quick-xml", feature = "serde-arbitrary-precision"))]
 extern crate alloc;
 
-#[cfg(feature = "quick-xml")]
+#[cfg(any(feature = "quick-xml", feature = "serde-arbitrary-precision"))]
 use alloc::string::String;
 use core::{fmt, marker::PhantomData, str::FromStr};
 
-#[cfg(feature = "quick-xml")]
+#[cfg(any(feature = "quick-xml", feature = "serde-arbitrary-precision"))]
 use serde::de::MapAccess;
 use serde::{
     de::{self, Error as _},
-    Deserialize, Deserializer, Serialize, Serializer,
+    ser, Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::{string::Stringify, FixedPoint};
+#[cfg(primitive_layout)]
+use crate::Precision;
+use crate::{
+    ops::RoundMode,
+    string::{Parse, Stringify},
+    FixedPoint,
+};
 
 impl<I, P> Serialize for FixedPoint<I, P>
 where
@@ -53,7 +60,7 @@ where
         D: Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
-            deserializer.deserialize_any(FixedPointVisitor(PhantomData))
+            deserializer.deserialize_any(FixedPointVisitor::<I, P>(PhantomData))
         } else {
             repr::deserialize(deserializer)
         }
@@ -116,8 +123,7 @@ where
         })
     }
 
-    // Support for `quick-xml` tags: `<tag>42.42</tag>`
-    #[cfg(feature = "quick-xml")]
+    #[cfg(any(feature = "quick-xml", feature = "serde-arbitrary-precision"))]
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
@@ -128,8 +134,14 @@ where
             .next_key::<String>()
             .map_err(|_| A::Error::invalid_type(de::Unexpected::Map, &self))?;
 
-        if key.as_deref() != Some("$value") {
-            return Err(A::Error::invalid_type(de::Unexpected::Map, &self));
+        match key.as_deref() {
+            // Support for `quick-xml` tags: `<tag>42.42</tag>`
+            #[cfg(feature = "quick-xml")]
+            Some("$value") => {}
+            // Numbers of `serde_json` with `arbitrary_precision` keeping all digits.
+            #[cfg(feature = "serde-arbitrary-precision")]
+            Some(SERDE_JSON_NUMBER_TOKEN) => {}
+            _ => return Err(A::Error::invalid_type(de::Unexpected::Map, &self)),
         }
 
         // We use `String` here to support `quick-xml v0.22`. In an actual one it's already fixed.
@@ -141,10 +153,12 @@ where
             .parse()
             .map_err(|_| A::Error::invalid_value(de::Unexpected::Str(&value), &self))
     }
-
-    // TODO: support serde_json/arbitrary_precision.
 }
 
+/// The name of the field representing `serde_json::Number` with `arbitrary_precision`.
+#[cfg(feature = "serde-arbitrary-precision")]
+const SERDE_JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
 /// (De)serializes `FixedPoint` as inner representation.
 pub mod repr {
     use super::*;
@@ -172,6 +186,14 @@ pub mod repr {
             .map(FixedPoint::from_bits)
             .map(F::from)
     }
+
+    /// Describes the inner representation as an integer,
+    /// use it as `#[schemars(schema_with = "fixnum::serde::repr::json_schema")]`.
+    #[cfg(feature = "schemars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+    pub fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::schemars::repr()
+    }
 }
 
 /// (De)serializes `Option<FixedPoint>` as inner representation.
@@ -238,6 +260,204 @@ pub mod str {
     }
 }
 
+/// (De)serializes `FixedPoint` as a string, but deserializes leniently.
+///
+/// Accepts strings, integers and floats, strings can be written in exponent notation (`"1.5e3"`).
+/// Strings are parsed without intermediate floats, so prefer them to keep all digits.
+/// Excess fractional digits are rounded to the nearest value like `FromStr` does.
+pub mod str_flexible {
+    use super::*;
+
+    pub use super::str::serialize;
+
+    /// Deserializes from a string, an integer or a float.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>:
+            FromStr + TryFrom<f64> + TryFrom<u64> + TryFrom<i64> + TryFrom<i128> + TryFrom<u128>,
+    {
+        deserializer
+            .deserialize_any(FixedPointVisitor::<I, P>(PhantomData))
+            .map(F::from)
+    }
+}
+
+/// (De)serializes `FixedPoint` as a string, but fails on excess fractional digits.
+///
+/// Unlike [`str`], which rounds them to the nearest value, the error describes the problem.
+pub mod str_strict {
+    use super::*;
+
+    pub use super::str::serialize;
+
+    /// Deserializes from a string without rounding.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: Parse,
+    {
+        deserialize_str(deserializer, None)
+    }
+}
+
+/// (De)serializes `FixedPoint` as a string, whole numbers are serialized without `.0`.
+///
+/// Trailing fractional zeros are never written, so the output of equal values is always the same
+/// and can be compared as strings, e.g. `"42"` and `"10.042"` rather than `"42.0"` and `"10.042"`.
+/// Deserializes like [`str`].
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U9};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+///     #[serde(with = "fixnum::serde::str_compact")]
+///     amount: FixedPoint<i64, U9>,
+/// }
+///
+/// let order: Order = serde_json::from_str(r#"{"amount":"42.000"}"#)?;
+/// assert_eq!(serde_json::to_string(&order)?, r#"{"amount":"42"}"#);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub mod str_compact {
+    use super::*;
+
+    pub use super::str::deserialize;
+
+    /// Serializes to a string without trailing fractional zeros.
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        S: Serializer,
+        FixedPoint<I, P>: Stringify,
+    {
+        let mut buf = Default::default();
+        fp.clone().into().stringify(&mut buf);
+
+        // `Stringify` trims trailing zeros, but keeps one for whole numbers.
+        let s = buf.as_str();
+        serializer.serialize_str(s.strip_suffix(".0").unwrap_or(s))
+    }
+}
+
+/// (De)serializes `FixedPoint` as a string, excess fractional digits are rounded
+/// according to the chosen submodule, e.g. `#[serde(with = "fixnum::serde::str_rounding::floor")]`.
+pub mod str_rounding {
+    macro_rules! impl_mode {
+        ($module:ident, $mode:ident) => {
+            #[doc = concat!("Rounds according to [`RoundMode::", stringify!($mode), "`][crate::ops::RoundMode::", stringify!($mode), "].")]
+            pub mod $module {
+                use super::super::*;
+
+                pub use super::super::str::serialize;
+
+                /// Deserializes from a string with rounding.
+                pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+                where
+                    F: From<FixedPoint<I, P>>,
+                    D: Deserializer<'de>,
+                    FixedPoint<I, P>: Parse,
+                {
+                    deserialize_str(deserializer, Some(RoundMode::$mode))
+                }
+            }
+        };
+    }
+
+    impl_mode!(floor, Floor);
+    impl_mode!(ceil, Ceil);
+    impl_mode!(nearest, Nearest);
+    impl_mode!(half_even, HalfEven);
+}
+
+fn deserialize_str<'de, F, I, P, D>(deserializer: D, mode: Option<RoundMode>) -> Result<F, D::Error>
+where
+    F: From<FixedPoint<I, P>>,
+    D: Deserializer<'de>,
+    FixedPoint<I, P>: Parse,
+{
+    let s = <&str>::deserialize(deserializer)?;
+    FixedPoint::<I, P>::parse(s, mode)
+        .map(F::from)
+        .map_err(|err| {
+            D::Error::custom(format_args!("invalid fixed-point number {:?}: {}", s, err))
+        })
+}
+
+/// (De)serializes a fraction as a string in percents, e.g. `0.025` ⇄ `"2.5%"`.
+///
+/// Deserialization requires the `%` suffix and fails if the fraction isn't representable exactly.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U9};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Fee {
+///     #[serde(with = "fixnum::serde::percent")]
+///     rate: FixedPoint<i64, U9>,
+/// }
+///
+/// let fee: Fee = serde_json::from_str(r#"{"rate":"2.5%"}"#)?;
+/// assert_eq!(fee.rate, "0.025".parse()?);
+/// assert_eq!(serde_json::to_string(&fee)?, r#"{"rate":"2.5%"}"#);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub mod percent {
+    use super::*;
+
+    /// Serializes to a string in percents.
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        S: Serializer,
+        FixedPoint<I, P>: Stringify + Percent,
+    {
+        let percent = fp
+            .clone()
+            .into()
+            .to_percent()
+            .ok_or_else(|| ser::Error::custom("too big number to represent in percents"))?;
+
+        let mut buf = Default::default();
+        percent.stringify(&mut buf);
+        serializer.collect_str(&format_args!("{}%", buf.as_str()))
+    }
+
+    /// Deserializes from a string in percents.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: Parse + Percent,
+    {
+        let s = <&str>::deserialize(deserializer)?;
+        let invalid =
+            |reason| D::Error::custom(format_args!("invalid percents {:?}: {}", s, reason));
+
+        let percent = s
+            .strip_suffix('%')
+            .ok_or_else(|| invalid("no `%` suffix"))?;
+        FixedPoint::<I, P>::parse(percent, None)
+            .map_err(|err| invalid(err.as_str()))?
+            .percent_to_fraction()
+            .map(F::from)
+            .ok_or_else(|| invalid("requested precision is too high"))
+    }
+}
+
 /// (De)serializes `Option<FixedPoint>` as an optional string.
 pub mod str_option {
     use super::*;
@@ -279,6 +499,10 @@ pub mod str_option {
 }
 
 /// (De)serializes `FixedPoint` as `f64`.
+///
+/// With the `serde-arbitrary-precision` feature, (de)serializes as `serde_json::Number` instead,
+/// so JSON numbers keep all digits rather than ones fitting in `f64`. Then deserialization
+/// requires a self-describing format.
 pub mod float {
     use super::*;
 
@@ -288,9 +512,19 @@ pub mod float {
     where
         F: Into<FixedPoint<I, P>> + Clone,
         I: Serialize,
-        FixedPoint<I, P>: Into<f64>,
+        FixedPoint<I, P>: Into<f64> + Stringify,
         S: Serializer,
     {
+        #[cfg(feature = "serde-arbitrary-precision")]
+        {
+            let mut buf = Default::default();
+            fp.clone().into().stringify(&mut buf);
+            // `Display` always produces a valid JSON number.
+            let number: serde_json::Number = buf.as_str().parse().map_err(ser::Error::custom)?;
+            number.serialize(serializer)
+        }
+
+        #[cfg(not(feature = "serde-arbitrary-precision"))]
         serializer.serialize_f64(fp.clone().into().into())
     }
 
@@ -300,16 +534,38 @@ pub mod float {
     where
         F: From<FixedPoint<I, P>>,
         I: Deserialize<'de>,
-        FixedPoint<I, P>: TryFrom<f64>,
+        FixedPoint<I, P>: TryFrom<f64> + FromStr,
         D: Deserializer<'de>,
     {
-        let f = f64::deserialize(deserializer)?;
-        FixedPoint::<I, P>::try_from(f).map(F::from).map_err(|_| {
-            D::Error::invalid_value(
-                de::Unexpected::Float(f),
-                &"float containing a fixed-point number",
-            )
-        })
+        #[cfg(feature = "serde-arbitrary-precision")]
+        {
+            let number = serde_json::Number::deserialize(deserializer)?;
+            number.as_str().parse().map(F::from).map_err(|_| {
+                D::Error::invalid_value(
+                    de::Unexpected::Other(number.as_str()),
+                    &"number containing a fixed-point number",
+                )
+            })
+        }
+
+        #[cfg(not(feature = "serde-arbitrary-precision"))]
+        {
+            let f = f64::deserialize(deserializer)?;
+            FixedPoint::<I, P>::try_from(f).map(F::from).map_err(|_| {
+                D::Error::invalid_value(
+                    de::Unexpected::Float(f),
+                    &"float containing a fixed-point number",
+                )
+            })
+        }
+    }
+
+    /// Describes `f64` representation as a number,
+    /// use it as `#[schemars(schema_with = "fixnum::serde::float::json_schema")]`.
+    #[cfg(feature = "schemars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+    pub fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::schemars::float()
     }
 }
 
@@ -323,11 +579,11 @@ pub mod float_option {
     where
         F: Into<FixedPoint<I, P>> + Clone,
         I: Serialize,
-        FixedPoint<I, P>: Into<f64>,
+        FixedPoint<I, P>: Into<f64> + Stringify,
         S: Serializer,
     {
         if let Some(fp) = fp {
-            serializer.serialize_some(&fp.clone().into().into())
+            serializer.serialize_some(&SerializeElement::<F, AsFloat<I, P>>(fp, PhantomData))
         } else {
             serializer.serialize_none()
         }
@@ -339,18 +595,766 @@ pub mod float_option {
     where
         F: From<FixedPoint<I, P>>,
         I: Deserialize<'de>,
-        FixedPoint<I, P>: TryFrom<f64>,
+        FixedPoint<I, P>: TryFrom<f64> + FromStr,
         D: Deserializer<'de>,
     {
-        let f = Option::<f64>::deserialize(deserializer)?;
-        f.map(|f| {
-            FixedPoint::<I, P>::try_from(f).map(F::from).map_err(|_| {
-                D::Error::invalid_value(
-                    de::Unexpected::Float(f),
-                    &"float containing a fixed-point number",
-                )
-            })
-        })
-        .transpose()
+        Option::<DeserializeElement<F, AsFloat<I, P>>>::deserialize(deserializer)
+            .map(|element| element.map(|element| element.0))
+    }
+}
+
+/// (De)serializes `Vec<FixedPoint>`, `[FixedPoint; N]` or `Option<Vec<FixedPoint>>`
+/// as a sequence of inner representations. Vectors require the `std` feature.
+pub mod repr_vec {
+    use super::*;
+
+    /// Serializes to a sequence of inner representations.
+    #[inline]
+    pub fn serialize<C, F, I, P, S>(values: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Sequence<Item = F>,
+        F: Into<FixedPoint<I, P>> + Clone,
+        I: Serialize,
+        S: Serializer,
+    {
+        values.serialize_as::<AsRepr<I, P>, S>(serializer)
+    }
+
+    /// Deserializes from a sequence of inner representations.
+    #[inline]
+    pub fn deserialize<'de, C, F, I, P, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: Sequence<Item = F>,
+        F: From<FixedPoint<I, P>>,
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        C::deserialize_as::<AsRepr<I, P>, D>(deserializer)
+    }
+}
+
+/// (De)serializes `Vec<FixedPoint>`, `[FixedPoint; N]` or `Option<Vec<FixedPoint>>`
+/// as a sequence of strings. Vectors require the `std` feature.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U2};
+/// use serde::{Deserialize, Serialize};
+///
+/// type Amount = FixedPoint<i64, U2>;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Quote {
+///     #[serde(with = "fixnum::serde::str_vec")]
+///     bids: Vec<Amount>,
+///     #[serde(with = "fixnum::serde::str_vec")]
+///     spread: [Amount; 2],
+/// }
+///
+/// let quote: Quote = serde_json::from_str(r#"{"bids":["1.5","1.25"],"spread":["0.1","0.2"]}"#)?;
+/// assert_eq!(quote.bids, ["1.5".parse::<Amount>()?, "1.25".parse()?]);
+/// assert_eq!(serde_json::to_string(&quote)?, r#"{"bids":["1.5","1.25"],"spread":["0.1","0.2"]}"#);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub mod str_vec {
+    use super::*;
+
+    /// Serializes to a sequence of strings.
+    #[inline]
+    pub fn serialize<C, F, I, P, S>(values: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Sequence<Item = F>,
+        F: Into<FixedPoint<I, P>> + Clone,
+        S: Serializer,
+        FixedPoint<I, P>: Stringify,
+    {
+        values.serialize_as::<AsStr<I, P>, S>(serializer)
+    }
+
+    /// Deserializes from a sequence of strings.
+    #[inline]
+    pub fn deserialize<'de, C, F, I, P, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: Sequence<Item = F>,
+        F: From<FixedPoint<I, P>>,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: FromStr,
+    {
+        C::deserialize_as::<AsStr<I, P>, D>(deserializer)
+    }
+}
+
+/// (De)serializes `Vec<FixedPoint>`, `[FixedPoint; N]` or `Option<Vec<FixedPoint>>`
+/// as a sequence of `f64`. Vectors require the `std` feature.
+pub mod float_vec {
+    use super::*;
+
+    /// Serializes to a sequence of `f64`.
+    #[inline]
+    pub fn serialize<C, F, I, P, S>(values: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: Sequence<Item = F>,
+        F: Into<FixedPoint<I, P>> + Clone,
+        I: Serialize,
+        FixedPoint<I, P>: Into<f64> + Stringify,
+        S: Serializer,
+    {
+        values.serialize_as::<AsFloat<I, P>, S>(serializer)
+    }
+
+    /// Deserializes from a sequence of `f64`.
+    #[inline]
+    pub fn deserialize<'de, C, F, I, P, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: Sequence<Item = F>,
+        F: From<FixedPoint<I, P>>,
+        I: Deserialize<'de>,
+        FixedPoint<I, P>: TryFrom<f64> + FromStr,
+        D: Deserializer<'de>,
+    {
+        C::deserialize_as::<AsFloat<I, P>, D>(deserializer)
+    }
+}
+
+/// (De)serializes values of `HashMap<K, FixedPoint>` or `BTreeMap<K, FixedPoint>`
+/// as inner representations, keys are (de)serialized as usual.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod repr_map {
+    use super::*;
+
+    /// Serializes values to inner representations.
+    #[inline]
+    pub fn serialize<M, K, F, I, P, S>(values: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        M: Map<Key = K, Value = F>,
+        K: Serialize,
+        F: Into<FixedPoint<I, P>> + Clone,
+        I: Serialize,
+        S: Serializer,
+    {
+        values.serialize_as::<AsRepr<I, P>, S>(serializer)
+    }
+
+    /// Deserializes values from inner representations.
+    #[inline]
+    pub fn deserialize<'de, M, K, F, I, P, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: Map<Key = K, Value = F>,
+        K: Deserialize<'de>,
+        F: From<FixedPoint<I, P>>,
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        M::deserialize_as::<AsRepr<I, P>, D>(deserializer)
+    }
+}
+
+/// (De)serializes values of `HashMap<K, FixedPoint>` or `BTreeMap<K, FixedPoint>`
+/// as strings, keys are (de)serialized as usual.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::collections::BTreeMap;
+/// use fixnum::{FixedPoint, typenum::U2};
+/// use serde::{Deserialize, Serialize};
+///
+/// type Price = FixedPoint<i64, U2>;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Prices {
+///     #[serde(with = "fixnum::serde::str_map")]
+///     last: BTreeMap<String, Price>,
+/// }
+///
+/// let prices: Prices = serde_json::from_str(r#"{"last":{"AAPL":"189.5","MSFT":"402.12"}}"#)?;
+/// assert_eq!(prices.last["AAPL"], "189.5".parse()?);
+/// assert_eq!(serde_json::to_string(&prices)?, r#"{"last":{"AAPL":"189.5","MSFT":"402.12"}}"#);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod str_map {
+    use super::*;
+
+    /// Serializes values to strings.
+    #[inline]
+    pub fn serialize<M, K, F, I, P, S>(values: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        M: Map<Key = K, Value = F>,
+        K: Serialize,
+        F: Into<FixedPoint<I, P>> + Clone,
+        FixedPoint<I, P>: Stringify,
+        S: Serializer,
+    {
+        values.serialize_as::<AsStr<I, P>, S>(serializer)
+    }
+
+    /// Deserializes values from strings.
+    #[inline]
+    pub fn deserialize<'de, M, K, F, I, P, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: Map<Key = K, Value = F>,
+        K: Deserialize<'de>,
+        F: From<FixedPoint<I, P>>,
+        FixedPoint<I, P>: FromStr,
+        D: Deserializer<'de>,
+    {
+        M::deserialize_as::<AsStr<I, P>, D>(deserializer)
+    }
+}
+
+/// (De)serializes values of `HashMap<K, FixedPoint>` or `BTreeMap<K, FixedPoint>`
+/// as `f64`, keys are (de)serialized as usual.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod float_map {
+    use super::*;
+
+    /// Serializes values to `f64`.
+    #[inline]
+    pub fn serialize<M, K, F, I, P, S>(values: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        M: Map<Key = K, Value = F>,
+        K: Serialize,
+        F: Into<FixedPoint<I, P>> + Clone,
+        I: Serialize,
+        FixedPoint<I, P>: Into<f64> + Stringify,
+        S: Serializer,
+    {
+        values.serialize_as::<AsFloat<I, P>, S>(serializer)
+    }
+
+    /// Deserializes values from `f64`.
+    #[inline]
+    pub fn deserialize<'de, M, K, F, I, P, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: Map<Key = K, Value = F>,
+        K: Deserialize<'de>,
+        F: From<FixedPoint<I, P>>,
+        I: Deserialize<'de>,
+        FixedPoint<I, P>: TryFrom<f64> + FromStr,
+        D: Deserializer<'de>,
+    {
+        M::deserialize_as::<AsFloat<I, P>, D>(deserializer)
+    }
+}
+
+/// (De)serializes `FixedPoint` as an integer number of minor units with `S` fractional digits,
+/// e.g. cents for `S = U2`: `12.34` ⇄ `1234`.
+///
+/// Fails if the value can't be converted without loss of precision.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::{U2, U9}};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Payment {
+///     #[serde(with = "fixnum::serde::Units::<U2>")]
+///     amount: FixedPoint<i64, U9>,
+/// }
+///
+/// let payment: Payment = serde_json::from_str(r#"{"amount":1234}"#)?;
+/// assert_eq!(payment.amount, "12.34".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub struct Units<S>(PhantomData<S>);
+
+impl<S> Units<S> {
+    /// Serializes to an integer number of minor units.
+    pub fn serialize<F, I, P, Ser>(fp: &F, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        I: Serialize,
+        FixedPoint<I, P>: RescaleExact<S, Output = FixedPoint<I, S>>,
+        Ser: Serializer,
+    {
+        fp.clone()
+            .into()
+            .rescale_exact()
+            .ok_or_else(|| ser::Error::custom("can't be represented in minor units exactly"))?
+            .into_bits()
+            .serialize(serializer)
+    }
+
+    /// Deserializes from an integer number of minor units.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        I: Deserialize<'de>,
+        FixedPoint<I, S>: RescaleExact<P, Output = FixedPoint<I, P>>,
+        D: Deserializer<'de>,
+    {
+        FixedPoint::<I, S>::from_bits(I::deserialize(deserializer)?)
+            .rescale_exact()
+            .map(F::from)
+            .ok_or_else(|| D::Error::custom("minor units can't be represented exactly"))
+    }
+}
+
+mod private {
+    #[cfg(feature = "std")]
+    use super::{Deserialize, Serialize};
+    use super::{Deserializer, Serializer};
+
+    /// Allows to use `rescale` in generic code.
+    #[allow(unreachable_pub)]
+    pub trait RescaleExact<Q> {
+        type Output;
+
+        /// Returns `None` on overflow or loss of precision.
+        fn rescale_exact(self) -> Option<Self::Output>;
+    }
+
+    /// Allows to convert percents in generic code.
+    #[allow(unreachable_pub)]
+    pub trait Percent: Sized {
+        /// Returns `None` on overflow.
+        fn to_percent(self) -> Option<Self>;
+
+        /// Returns `None` on loss of precision.
+        fn percent_to_fraction(self) -> Option<Self>;
+    }
+
+    /// Allows to (de)serialize elements of a sequence by a chosen representation.
+    #[allow(unreachable_pub)]
+    pub trait Sequence: Sized {
+        type Item;
+
+        fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            W: SerializeAs<Self::Item>,
+            S: Serializer;
+
+        fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            W: DeserializeAs<'de, Self::Item>,
+            D: Deserializer<'de>;
+    }
+
+    /// Allows to (de)serialize values of a map by a chosen representation.
+    #[cfg(feature = "std")]
+    #[allow(unreachable_pub)]
+    pub trait Map: Sized {
+        type Key;
+        type Value;
+
+        fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            Self::Key: Serialize,
+            W: SerializeAs<Self::Value>,
+            S: Serializer;
+
+        fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            Self::Key: Deserialize<'de>,
+            W: DeserializeAs<'de, Self::Value>,
+            D: Deserializer<'de>;
+    }
+
+    /// A representation of elements, e.g. strings.
+    #[allow(unreachable_pub)]
+    pub trait SerializeAs<F> {
+        fn serialize<S: Serializer>(value: &F, serializer: S) -> Result<S::Ok, S::Error>;
+    }
+
+    /// A representation of elements, e.g. strings.
+    #[allow(unreachable_pub)]
+    pub trait DeserializeAs<'de, F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<F, D::Error>;
+    }
+}
+
+#[cfg(feature = "std")]
+use self::private::Map;
+use self::private::{DeserializeAs, Percent, RescaleExact, Sequence, SerializeAs};
+
+/// Elements represented by [`repr`].
+struct AsRepr<I, P>(PhantomData<(I, P)>);
+
+impl<F, I, P> SerializeAs<F> for AsRepr<I, P>
+where
+    F: Into<FixedPoint<I, P>> + Clone,
+    I: Serialize,
+{
+    fn serialize<S: Serializer>(value: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        repr::serialize(value, serializer)
+    }
+}
+
+impl<'de, F, I, P> DeserializeAs<'de, F> for AsRepr<I, P>
+where
+    F: From<FixedPoint<I, P>>,
+    I: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<F, D::Error> {
+        repr::deserialize(deserializer)
+    }
+}
+
+/// Elements represented by [`str`](mod@str).
+struct AsStr<I, P>(PhantomData<(I, P)>);
+
+impl<F, I, P> SerializeAs<F> for AsStr<I, P>
+where
+    F: Into<FixedPoint<I, P>> + Clone,
+    FixedPoint<I, P>: Stringify,
+{
+    fn serialize<S: Serializer>(value: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        str::serialize(value, serializer)
+    }
+}
+
+impl<'de, F, I, P> DeserializeAs<'de, F> for AsStr<I, P>
+where
+    F: From<FixedPoint<I, P>>,
+    FixedPoint<I, P>: FromStr,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<F, D::Error> {
+        str::deserialize(deserializer)
+    }
+}
+
+/// Elements represented by [`float`].
+struct AsFloat<I, P>(PhantomData<(I, P)>);
+
+impl<F, I, P> SerializeAs<F> for AsFloat<I, P>
+where
+    F: Into<FixedPoint<I, P>> + Clone,
+    I: Serialize,
+    FixedPoint<I, P>: Into<f64> + Stringify,
+{
+    fn serialize<S: Serializer>(value: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        float::serialize(value, serializer)
+    }
+}
+
+impl<'de, F, I, P> DeserializeAs<'de, F> for AsFloat<I, P>
+where
+    F: From<FixedPoint<I, P>>,
+    I: Deserialize<'de>,
+    FixedPoint<I, P>: TryFrom<f64> + FromStr,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<F, D::Error> {
+        float::deserialize(deserializer)
+    }
+}
+
+/// Serializes an element by `W`.
+struct SerializeElement<'a, F, W>(&'a F, PhantomData<W>);
+
+impl<F, W: SerializeAs<F>> Serialize for SerializeElement<'_, F, W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        W::serialize(self.0, serializer)
     }
 }
+
+/// Deserializes an element by `W`.
+struct DeserializeElement<F, W>(F, PhantomData<W>);
+
+impl<'de, F, W: DeserializeAs<'de, F>> Deserialize<'de> for DeserializeElement<F, W> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        W::deserialize(deserializer).map(|value| Self(value, PhantomData))
+    }
+}
+
+impl<F, const N: usize> Sequence for [F; N] {
+    type Item = F;
+
+    fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        W: SerializeAs<F>,
+        S: Serializer,
+    {
+        use ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for value in self {
+            tuple.serialize_element(&SerializeElement::<F, W>(value, PhantomData))?;
+        }
+        tuple.end()
+    }
+
+    fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        W: DeserializeAs<'de, F>,
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<F, W, const N: usize>(PhantomData<(F, W)>);
+
+        impl<'de, F, W, const N: usize> de::Visitor<'de> for ArrayVisitor<F, W, N>
+        where
+            W: DeserializeAs<'de, F>,
+        {
+            type Value = [F; N];
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "an array of {} fixed-point numbers", N)
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<[F; N], A::Error> {
+                let mut values: [Option<F>; N] = core::array::from_fn(|_| None);
+
+                for (index, value) in values.iter_mut().enumerate() {
+                    let element = seq.next_element::<DeserializeElement<F, W>>()?;
+                    *value = Some(
+                        element
+                            .ok_or_else(|| A::Error::invalid_length(index, &self))?
+                            .0,
+                    );
+                }
+
+                // All elements are set above.
+                Ok(values.map(|value| value.unwrap()))
+            }
+        }
+
+        deserializer.deserialize_tuple(N, ArrayVisitor::<F, W, N>(PhantomData))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> Sequence for Vec<F> {
+    type Item = F;
+
+    fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        W: SerializeAs<F>,
+        S: Serializer,
+    {
+        serializer.collect_seq(
+            self.iter()
+                .map(|value| SerializeElement::<F, W>(value, PhantomData)),
+        )
+    }
+
+    fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        W: DeserializeAs<'de, F>,
+        D: Deserializer<'de>,
+    {
+        struct VecVisitor<F, W>(PhantomData<(F, W)>);
+
+        impl<'de, F, W> de::Visitor<'de> for VecVisitor<F, W>
+        where
+            W: DeserializeAs<'de, F>,
+        {
+            type Value = Vec<F>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a sequence of fixed-point numbers")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<F>, A::Error> {
+                // Don't trust the hint too much, it comes from the input.
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+                while let Some(element) = seq.next_element::<DeserializeElement<F, W>>()? {
+                    values.push(element.0);
+                }
+
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor::<F, W>(PhantomData))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> Sequence for Option<Vec<F>> {
+    type Item = F;
+
+    fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        W: SerializeAs<F>,
+        S: Serializer,
+    {
+        struct SerializeVec<'a, F, W>(&'a Vec<F>, PhantomData<W>);
+
+        impl<F, W: SerializeAs<F>> Serialize for SerializeVec<'_, F, W> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize_as::<W, S>(serializer)
+            }
+        }
+
+        match self {
+            Some(values) => serializer.serialize_some(&SerializeVec::<F, W>(values, PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        W: DeserializeAs<'de, F>,
+        D: Deserializer<'de>,
+    {
+        struct OptionVisitor<F, W>(PhantomData<(F, W)>);
+
+        impl<'de, F, W> de::Visitor<'de> for OptionVisitor<F, W>
+        where
+            W: DeserializeAs<'de, F>,
+        {
+            type Value = Option<Vec<F>>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an optional sequence of fixed-point numbers")
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                Vec::deserialize_as::<W, D>(deserializer).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionVisitor::<F, W>(PhantomData))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, F, H> Map for std::collections::HashMap<K, F, H>
+where
+    K: Eq + core::hash::Hash,
+    H: core::hash::BuildHasher + Default,
+{
+    type Key = K;
+    type Value = F;
+
+    fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        W: SerializeAs<F>,
+        S: Serializer,
+    {
+        serializer.collect_map(
+            self.iter()
+                .map(|(key, value)| (key, SerializeElement::<F, W>(value, PhantomData))),
+        )
+    }
+
+    fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        K: Deserialize<'de>,
+        W: DeserializeAs<'de, F>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor::<Self, W>(PhantomData))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Ord, F> Map for std::collections::BTreeMap<K, F> {
+    type Key = K;
+    type Value = F;
+
+    fn serialize_as<W, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        W: SerializeAs<F>,
+        S: Serializer,
+    {
+        serializer.collect_map(
+            self.iter()
+                .map(|(key, value)| (key, SerializeElement::<F, W>(value, PhantomData))),
+        )
+    }
+
+    fn deserialize_as<'de, W, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        K: Deserialize<'de>,
+        W: DeserializeAs<'de, F>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor::<Self, W>(PhantomData))
+    }
+}
+
+/// Collects entries into `M` deserializing values by `W`.
+#[cfg(feature = "std")]
+struct MapVisitor<M, W>(PhantomData<(M, W)>);
+
+#[cfg(feature = "std")]
+impl<'de, M, W> de::Visitor<'de> for MapVisitor<M, W>
+where
+    M: Map + Default + Extend<(M::Key, M::Value)>,
+    M::Key: Deserialize<'de>,
+    W: DeserializeAs<'de, M::Value>,
+{
+    type Value = M;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map with fixed-point values")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<M, A::Error> {
+        let mut values = M::default();
+
+        while let Some((key, value)) =
+            map.next_entry::<M::Key, DeserializeElement<M::Value, W>>()?
+        {
+            values.extend(Some((key, value.0)));
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(primitive_layout)]
+macro_rules! impl_rescale_exact {
+    ($layout:tt) => {
+        impl<P: Precision, Q: Precision> RescaleExact<Q> for FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, Q>;
+
+            fn rescale_exact(self) -> Option<Self::Output> {
+                let rescaled = self.rescale::<Q>(RoundMode::Floor).ok()?;
+                // Narrowing back can't overflow.
+                let restored = rescaled.rescale::<P>(RoundMode::Floor).ok()?;
+                (restored.inner == self.inner).then_some(rescaled)
+            }
+        }
+
+        impl<P: Precision> Percent for FixedPoint<$layout, P> {
+            fn to_percent(self) -> Option<Self> {
+                self.as_percent().ok()
+            }
+
+            fn percent_to_fraction(self) -> Option<Self> {
+                (self.inner % 100 == 0).then(|| Self::from_bits(self.inner / 100))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_rescale_exact!(i16);
+#[cfg(feature = "i32")]
+impl_rescale_exact!(i32);
+#[cfg(feature = "i64")]
+impl_rescale_exact!(i64);
+#[cfg(feature = "i128")]
+impl_rescale_exact!(i128);
+#[cfg(feature = "u64")]
+impl_rescale_exact!(u64);
+#[cfg(feature = "u128")]
+impl_rescale_exact!(u128);
@@ -0,0 +1,347 @@
+//! Vectorized operations over slices of `FixedPoint`.
+//!
+//! Additions and subtractions of 16, 32 and 64-bit layouts use SSE2 instructions on `x86_64`
+//! and detect overflows without branches, reporting them once for the whole slice. Other targets
+//! and 128-bit layouts process values one by one.
+//!
+//! Products are computed in the promoted layout like in [`rmul`](crate::ops::RoundingMul::rmul)
+//! and aren't vectorized because of rounding, [`dot`] rounds only once for the whole sum.
+//!
+//! All functions panic if lengths of slices differ. On `Err` the content of `out` is unspecified.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{ops::RoundMode::*, simd, typenum::U9, FixedPoint};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! let prices: [Amount; 3] = ["1.5".parse()?, "2.25".parse()?, "10".parse()?];
+//! let sizes: [Amount; 3] = ["2".parse()?, "0.1".parse()?, "0.003".parse()?];
+//! let mut out = [Amount::ZERO; 3];
+//!
+//! simd::add_slices(&prices, &sizes, &mut out)?;
+//! assert_eq!(out, ["3.5".parse()?, "2.35".parse()?, "10.003".parse()?]);
+//!
+//! simd::scale_slice(&prices, "1.1".parse()?, Floor, &mut out)?;
+//! assert_eq!(out, ["1.65".parse()?, "2.475".parse()?, "11".parse()?]);
+//!
+//! assert_eq!(simd::dot(&prices, &sizes, Nearest)?, "3.255".parse()?);
+//! assert!(simd::add_slices(&[Amount::MAX], &[Amount::EPSILON], &mut out[..1]).is_err());
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+#[cfg(feature = "i128")]
+use crate::i256::I256;
+#[cfg(feature = "u128")]
+use crate::i256::U256;
+use crate::ops::RoundMode;
+use crate::ArithmeticError;
+use crate::{FixedPoint, Precision};
+
+use self::private::SliceOps;
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// Writes `lhs[i] + rhs[i]` to `out[i]`, returns `Err` if any of them overflows.
+#[inline]
+pub fn add_slices<F: SliceOps>(lhs: &[F], rhs: &[F], out: &mut [F]) -> Result<()> {
+    F::add_slices(lhs, rhs, out)
+}
+
+/// Writes `lhs[i] - rhs[i]` to `out[i]`, returns `Err` if any of them overflows.
+#[inline]
+pub fn sub_slices<F: SliceOps>(lhs: &[F], rhs: &[F], out: &mut [F]) -> Result<()> {
+    F::sub_slices(lhs, rhs, out)
+}
+
+/// Writes `values[i] * factor` [rounded][RoundMode] to `out[i]`, returns `Err` on overflow.
+#[inline]
+pub fn scale_slice<F: SliceOps>(
+    values: &[F],
+    factor: F,
+    mode: RoundMode,
+    out: &mut [F],
+) -> Result<()> {
+    F::scale_slice(values, factor, mode, out)
+}
+
+/// Returns the sum of `lhs[i] * rhs[i]` [rounded][RoundMode] once, returns `Err` on overflow.
+#[inline]
+pub fn dot<F: SliceOps>(lhs: &[F], rhs: &[F], mode: RoundMode) -> Result<F> {
+    F::dot(lhs, rhs, mode)
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to use batch operations in generic code.
+    #[allow(unreachable_pub)]
+    pub trait SliceOps: Sized {
+        fn add_slices(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> Result<()>;
+        fn sub_slices(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> Result<()>;
+        fn scale_slice(
+            values: &[Self],
+            factor: Self,
+            mode: RoundMode,
+            out: &mut [Self],
+        ) -> Result<()>;
+        fn dot(lhs: &[Self], rhs: &[Self], mode: RoundMode) -> Result<Self>;
+    }
+}
+
+/// Reinterprets numbers as their layout.
+fn bits<I, P>(values: &[FixedPoint<I, P>]) -> &[I] {
+    // SAFETY: `FixedPoint` is `repr(transparent)` over the layout.
+    unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+}
+
+/// Reinterprets numbers as their layout.
+fn bits_mut<I, P>(values: &mut [FixedPoint<I, P>]) -> &mut [I] {
+    // SAFETY: `FixedPoint` is `repr(transparent)` over the layout.
+    unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+}
+
+/// Wrapping operations over slices of the same length reporting any overflow.
+trait Lanes: Sized {
+    fn add_lanes(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> bool;
+    fn sub_lanes(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> bool;
+}
+
+macro_rules! impl_lanes {
+    ($int:ty) => {
+        impl Lanes for $int {
+            fn add_lanes(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> bool {
+                impl_lanes!(@scalar overflowing_add, lhs, rhs, out, 0, false)
+            }
+
+            fn sub_lanes(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> bool {
+                impl_lanes!(@scalar overflowing_sub, lhs, rhs, out, 0, false)
+            }
+        }
+    };
+    ($int:ty, $signs:literal, $add:ident, $add_overflow:ident, $sub:ident, $sub_overflow:ident) => {
+        impl Lanes for $int {
+            fn add_lanes(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> bool {
+                #[cfg(target_arch = "x86_64")]
+                // SAFETY: SSE2 is always available on `x86_64`, lengths are checked by callers.
+                let (len, is_overflow) = unsafe {
+                    sse2::apply(lhs, rhs, out, $signs, |a, b| {
+                        let result = core::arch::x86_64::$add(a, b);
+                        (result, sse2::$add_overflow(a, b, result))
+                    })
+                };
+                #[cfg(not(target_arch = "x86_64"))]
+                let (len, is_overflow) = (0, false);
+
+                impl_lanes!(@scalar overflowing_add, lhs, rhs, out, len, is_overflow)
+            }
+
+            fn sub_lanes(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> bool {
+                #[cfg(target_arch = "x86_64")]
+                // SAFETY: SSE2 is always available on `x86_64`, lengths are checked by callers.
+                let (len, is_overflow) = unsafe {
+                    sse2::apply(lhs, rhs, out, $signs, |a, b| {
+                        let result = core::arch::x86_64::$sub(a, b);
+                        (result, sse2::$sub_overflow(a, b, result))
+                    })
+                };
+                #[cfg(not(target_arch = "x86_64"))]
+                let (len, is_overflow) = (0, false);
+
+                impl_lanes!(@scalar overflowing_sub, lhs, rhs, out, len, is_overflow)
+            }
+        }
+    };
+    // Processes the rest starting from `$start`.
+    (@scalar $op:ident, $lhs:ident, $rhs:ident, $out:ident, $start:expr, $is_overflow:expr) => {{
+        let mut is_overflow = $is_overflow;
+        let rest = $lhs[$start..].iter().zip(&$rhs[$start..]);
+        for ((lhs, rhs), out) in rest.zip(&mut $out[$start..]) {
+            let (value, overflow) = lhs.$op(*rhs);
+            *out = value;
+            is_overflow |= overflow;
+        }
+        is_overflow
+    }};
+}
+
+// Masks select bytes of `_mm_movemask_epi8` holding signs of lanes.
+#[cfg(feature = "i16")]
+impl_lanes!(
+    i16,
+    0xaaaa,
+    _mm_add_epi16,
+    signed_add_overflow,
+    _mm_sub_epi16,
+    signed_sub_overflow
+);
+#[cfg(feature = "i32")]
+impl_lanes!(
+    i32,
+    0x8888,
+    _mm_add_epi32,
+    signed_add_overflow,
+    _mm_sub_epi32,
+    signed_sub_overflow
+);
+#[cfg(feature = "i64")]
+impl_lanes!(
+    i64,
+    0x8080,
+    _mm_add_epi64,
+    signed_add_overflow,
+    _mm_sub_epi64,
+    signed_sub_overflow
+);
+#[cfg(feature = "i128")]
+impl_lanes!(i128);
+#[cfg(feature = "u64")]
+impl_lanes!(
+    u64,
+    0x8080,
+    _mm_add_epi64,
+    unsigned_add_overflow,
+    _mm_sub_epi64,
+    unsigned_sub_overflow
+);
+#[cfg(feature = "u128")]
+impl_lanes!(u128);
+
+#[cfg(all(
+    target_arch = "x86_64",
+    any(feature = "i16", feature = "i32", feature = "i64", feature = "u64")
+))]
+mod sse2 {
+    use core::arch::x86_64::*;
+    use core::mem::size_of;
+
+    /// Applies `op` returning results and overflows in sign bits to whole vectors of lanes.
+    /// Returns the number of processed lanes and whether any of them overflows.
+    ///
+    /// # Safety
+    ///
+    /// Slices must have the same length.
+    pub(super) unsafe fn apply<T>(
+        lhs: &[T],
+        rhs: &[T],
+        out: &mut [T],
+        signs: i32,
+        op: impl Fn(__m128i, __m128i) -> (__m128i, __m128i),
+    ) -> (usize, bool) {
+        let lanes = size_of::<__m128i>() / size_of::<T>();
+        let len = lhs.len() - lhs.len() % lanes;
+
+        let mut overflow = _mm_setzero_si128();
+        for i in (0..len).step_by(lanes) {
+            let a = _mm_loadu_si128(lhs.as_ptr().add(i).cast());
+            let b = _mm_loadu_si128(rhs.as_ptr().add(i).cast());
+            let (result, lane_overflow) = op(a, b);
+            _mm_storeu_si128(out.as_mut_ptr().add(i).cast(), result);
+            overflow = _mm_or_si128(overflow, lane_overflow);
+        }
+
+        (len, _mm_movemask_epi8(overflow) & signs != 0)
+    }
+
+    /// Operands with the same sign produce a result with the other one.
+    #[cfg(any(feature = "i16", feature = "i32", feature = "i64"))]
+    pub(super) unsafe fn signed_add_overflow(a: __m128i, b: __m128i, r: __m128i) -> __m128i {
+        _mm_and_si128(_mm_xor_si128(a, r), _mm_xor_si128(b, r))
+    }
+
+    /// Operands with different signs produce a result with the sign of `b`.
+    #[cfg(any(feature = "i16", feature = "i32", feature = "i64"))]
+    pub(super) unsafe fn signed_sub_overflow(a: __m128i, b: __m128i, r: __m128i) -> __m128i {
+        _mm_and_si128(_mm_xor_si128(a, b), _mm_xor_si128(a, r))
+    }
+
+    /// The carry out of the highest bit.
+    #[cfg(feature = "u64")]
+    pub(super) unsafe fn unsigned_add_overflow(a: __m128i, b: __m128i, r: __m128i) -> __m128i {
+        _mm_or_si128(_mm_and_si128(a, b), _mm_andnot_si128(r, _mm_or_si128(a, b)))
+    }
+
+    /// The borrow into the highest bit.
+    #[cfg(feature = "u64")]
+    pub(super) unsafe fn unsigned_sub_overflow(a: __m128i, b: __m128i, r: __m128i) -> __m128i {
+        _mm_or_si128(
+            _mm_andnot_si128(a, b),
+            _mm_andnot_si128(_mm_xor_si128(a, b), r),
+        )
+    }
+}
+
+macro_rules! impl_slice_ops {
+    ($layout:tt, $promotion:tt) => {
+        impl<P: Precision> SliceOps for FixedPoint<$layout, P> {
+            fn add_slices(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> Result<()> {
+                assert_eq!(lhs.len(), rhs.len(), "lengths of slices differ");
+                assert_eq!(lhs.len(), out.len(), "lengths of slices differ");
+
+                if $layout::add_lanes(bits(lhs), bits(rhs), bits_mut(out)) {
+                    Err(ArithmeticError::Overflow)
+                } else {
+                    Ok(())
+                }
+            }
+
+            fn sub_slices(lhs: &[Self], rhs: &[Self], out: &mut [Self]) -> Result<()> {
+                assert_eq!(lhs.len(), rhs.len(), "lengths of slices differ");
+                assert_eq!(lhs.len(), out.len(), "lengths of slices differ");
+
+                if $layout::sub_lanes(bits(lhs), bits(rhs), bits_mut(out)) {
+                    Err(ArithmeticError::Overflow)
+                } else {
+                    Ok(())
+                }
+            }
+
+            fn scale_slice(
+                values: &[Self],
+                factor: Self,
+                mode: RoundMode,
+                out: &mut [Self],
+            ) -> Result<()> {
+                assert_eq!(values.len(), out.len(), "lengths of slices differ");
+
+                let factor = $promotion::from(factor.inner);
+                for (value, out) in values.iter().zip(out) {
+                    *out = Self::from_promoted($promotion::from(value.inner) * factor, mode)?;
+                }
+
+                Ok(())
+            }
+
+            fn dot(lhs: &[Self], rhs: &[Self], mode: RoundMode) -> Result<Self> {
+                assert_eq!(lhs.len(), rhs.len(), "lengths of slices differ");
+
+                // Products are exact in the promoted layout.
+                let sum = lhs.iter().zip(rhs).try_fold(
+                    $promotion::from(Self::ZERO.inner),
+                    |sum, (lhs, rhs)| {
+                        sum.checked_add($promotion::from(lhs.inner) * $promotion::from(rhs.inner))
+                            .ok_or(ArithmeticError::Overflow)
+                    },
+                )?;
+
+                Self::from_promoted(sum, mode)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_slice_ops!(i16, i32);
+#[cfg(feature = "i32")]
+impl_slice_ops!(i32, i64);
+#[cfg(feature = "i64")]
+impl_slice_ops!(i64, i128);
+#[cfg(feature = "i128")]
+impl_slice_ops!(i128, I256);
+#[cfg(feature = "u64")]
+impl_slice_ops!(u64, u128);
+#[cfg(feature = "u128")]
+impl_slice_ops!(u128, U256);
@@ -0,0 +1,72 @@
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{
+        types::Oid, PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef,
+    },
+    Decode, Encode, Postgres, Type,
+};
+
+use crate::numeric::{self, Numeric};
+use crate::{FixedPoint, Precision};
+
+// Built-in types have fixed OIDs.
+const NUMERIC_OID: u32 = 1700;
+const NUMERIC_ARRAY_OID: u32 = 1231;
+
+macro_rules! impl_sqlx {
+    ($layout:tt) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<P: Precision> Type<Postgres> for FixedPoint<$layout, P> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::with_oid(Oid(NUMERIC_OID))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<P: Precision> PgHasArrayType for FixedPoint<$layout, P> {
+            fn array_type_info() -> PgTypeInfo {
+                PgTypeInfo::with_oid(Oid(NUMERIC_ARRAY_OID))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<P: Precision> Encode<'_, Postgres> for FixedPoint<$layout, P> {
+            /// Encodes with the display scale equal to `PRECISION`.
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                self.to_numeric().encode(buf);
+                Ok(IsNull::No)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+        impl<'r, P: Precision> Decode<'r, Postgres> for FixedPoint<$layout, P> {
+            /// Decodes without any loss of precision.
+            /// Fails if the value doesn't fit or has more significant fractional digits than
+            /// `PRECISION`, on `NaN` and infinities.
+            fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+                Ok(match value.format() {
+                    PgValueFormat::Binary => {
+                        Self::from_numeric(&Numeric::decode(value.as_bytes()?)?)?
+                    }
+                    PgValueFormat::Text => {
+                        Self::from_str_exact(numeric::trim_scale(value.as_str()?))?
+                    }
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_sqlx!(i16);
+#[cfg(feature = "i32")]
+impl_sqlx!(i32);
+#[cfg(feature = "i64")]
+impl_sqlx!(i64);
+#[cfg(feature = "i128")]
+impl_sqlx!(i128);
+#[cfg(feature = "u64")]
+impl_sqlx!(u64);
+#[cfg(feature = "u128")]
+impl_sqlx!(u128);
@@ -0,0 +1,198 @@
+//! Descriptive statistics over slices of `FixedPoint`.
+//!
+//! Sums are calculated exactly in a wider integer (see [`Accumulator`]), every division is
+//! [rounded][RoundMode] with the provided mode. Functions return `Err` on overflow and
+//! [`ArithmeticError::DivisionByZero`] for empty slices.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{ops::RoundMode::*, stats, typenum::U9, FixedPoint};
+//!
+//! type Price = FixedPoint<i64, U9>;
+//!
+//! let prices: Vec<Price> = ["1", "2", "4", "10"].iter().map(|s| s.parse()).collect::<Result<_, _>>()?;
+//! assert_eq!(stats::min(&prices), Some(prices[0]));
+//! assert_eq!(stats::max(&prices), Some(prices[3]));
+//! assert_eq!(stats::mean(&prices, Nearest)?, "4.25".parse()?);
+//! assert_eq!(stats::median(&prices, Nearest)?, "3".parse()?);
+//! assert_eq!(stats::variance(&prices, Nearest)?, "12.1875".parse()?);
+//!
+//! let volumes: Vec<Price> = ["3", "1", "0", "0"].iter().map(|s| s.parse()).collect::<Result<_, _>>()?;
+//! assert_eq!(stats::weighted_mean(&prices, &volumes, Nearest)?, "1.25".parse()?);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+#[cfg(feature = "i128")]
+use crate::i256::I256;
+#[cfg(feature = "u128")]
+use crate::i256::U256;
+use crate::ops::RoundMode;
+use crate::ArithmeticError;
+use crate::{
+    ops::{CheckedSub, RoundingDiv},
+    Accumulator, FixedPoint, Precision,
+};
+
+use self::private::Stats;
+
+type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
+
+/// Returns the smallest value, `None` for an empty slice.
+#[inline]
+pub fn min<F: Ord + Copy>(values: &[F]) -> Option<F> {
+    values.iter().min().copied()
+}
+
+/// Returns the largest value, `None` for an empty slice.
+#[inline]
+pub fn max<F: Ord + Copy>(values: &[F]) -> Option<F> {
+    values.iter().max().copied()
+}
+
+/// Returns the arithmetic mean.
+///
+/// The sum is calculated exactly, so it never overflows unless the length doesn't fit in the
+/// layout.
+#[inline]
+pub fn mean<F: Stats>(values: &[F], mode: RoundMode) -> Result<F> {
+    F::mean(values, mode)
+}
+
+/// Returns the middle value, or the mean of two middle values for slices of even length.
+#[inline]
+pub fn median<F: Stats>(values: &[F], mode: RoundMode) -> Result<F> {
+    F::median(values, mode)
+}
+
+/// Returns the population variance: the mean of squared deviations from the [`mean`].
+///
+/// The sum of squares is calculated exactly, then it's rounded and divided by the length.
+#[inline]
+pub fn variance<F: Stats>(values: &[F], mode: RoundMode) -> Result<F> {
+    F::variance(values, mode)
+}
+
+/// Returns `sum(values[i] * weights[i]) / sum(weights)` rounded once.
+///
+/// Panics if lengths of slices differ. Returns [`ArithmeticError::DivisionByZero`] if weights sum
+/// to zero.
+#[inline]
+pub fn weighted_mean<F: Stats>(values: &[F], weights: &[F], mode: RoundMode) -> Result<F> {
+    F::weighted_mean(values, weights, mode)
+}
+
+mod private {
+    use super::*;
+
+    /// Allows to use statistics in generic code.
+    #[allow(unreachable_pub)]
+    pub trait Stats: Sized {
+        fn mean(values: &[Self], mode: RoundMode) -> Result<Self>;
+        fn median(values: &[Self], mode: RoundMode) -> Result<Self>;
+        fn variance(values: &[Self], mode: RoundMode) -> Result<Self>;
+        fn weighted_mean(values: &[Self], weights: &[Self], mode: RoundMode) -> Result<Self>;
+    }
+}
+
+macro_rules! impl_stats {
+    ($layout:tt, $promotion:tt) => {
+        impl<P: Precision> Stats for FixedPoint<$layout, P> {
+            fn mean(values: &[Self], mode: RoundMode) -> Result<Self> {
+                if values.is_empty() {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let len = $layout::try_from(values.len()).map_err(|_| ArithmeticError::Overflow)?;
+                let sum =
+                    values
+                        .iter()
+                        .try_fold($promotion::from(Self::ZERO.inner), |sum, value| {
+                            sum.checked_add($promotion::from(value.inner))
+                                .ok_or(ArithmeticError::Overflow)
+                        })?;
+
+                Self::div_promoted(sum, len, mode)
+            }
+
+            fn median(values: &[Self], mode: RoundMode) -> Result<Self> {
+                if values.is_empty() {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let mut values = values.to_vec();
+                let is_even = values.len() % 2 == 0;
+                let middle = values.len() / 2;
+                let (lower, &mut upper, _) = values.select_nth_unstable_by_key(middle, |v| v.inner);
+
+                if !is_even {
+                    return Ok(upper);
+                }
+
+                // `lower` holds the first half, so it isn't empty here.
+                let lower = lower.iter().map(|v| v.inner).max().map(Self::from_bits);
+                Ok(Self::half_sum(lower.unwrap(), upper, mode))
+            }
+
+            fn variance(values: &[Self], mode: RoundMode) -> Result<Self> {
+                let mean = Self::mean(values, mode)?;
+                let len = $layout::try_from(values.len()).map_err(|_| ArithmeticError::Overflow)?;
+
+                let squares =
+                    values
+                        .iter()
+                        .try_fold($promotion::from(Self::ZERO.inner), |sum, &value| {
+                            // Calculated as `|value - mean|` to support unsigned layouts.
+                            let deviation = if value.inner > mean.inner {
+                                value.csub(mean)?
+                            } else {
+                                mean.csub(value)?
+                            };
+                            let deviation = $promotion::from(deviation.inner);
+
+                            sum.checked_add(deviation * deviation)
+                                .ok_or(ArithmeticError::Overflow)
+                        })?;
+
+                Self::from_promoted(squares, mode)?.rdiv(len, mode)
+            }
+
+            fn weighted_mean(values: &[Self], weights: &[Self], mode: RoundMode) -> Result<Self> {
+                assert_eq!(values.len(), weights.len(), "lengths of slices differ");
+
+                let total = weights.iter().collect::<Accumulator<Self>>().finish()?;
+                if total.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `sum(v * COEF * w * COEF) / sum(w * COEF) = sum(v * w) / sum(w) * COEF`
+                let numerator = values.iter().zip(weights).try_fold(
+                    $promotion::from(Self::ZERO.inner),
+                    |sum, (value, weight)| {
+                        sum.checked_add(
+                            $promotion::from(value.inner) * $promotion::from(weight.inner),
+                        )
+                        .ok_or(ArithmeticError::Overflow)
+                    },
+                )?;
+
+                Self::div_promoted(numerator, total.inner, mode)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_stats!(i16, i32);
+#[cfg(feature = "i32")]
+impl_stats!(i32, i64);
+#[cfg(feature = "i64")]
+impl_stats!(i64, i128);
+#[cfg(feature = "i128")]
+impl_stats!(i128, I256);
+#[cfg(feature = "u64")]
+impl_stats!(u64, u128);
+#[cfg(feature = "u128")]
+impl_stats!(u128, U256);
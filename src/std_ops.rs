@@ -0,0 +1,175 @@
+//! Operators of `core::ops` panicking on overflow like primitive integers do.
+//!
+//! Available with the `panicking-ops` feature, which is also enabled by `num-traits`.
+//!
+//! Multiplication and division are rounded to the nearest value (see [`RoundMode::Nearest`])
+//! unless another default mode is [configured][RoundMode],
+//! use [`RoundingMul`] and [`RoundingDiv`] to choose another mode.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::ops::*;
+use crate::{ArithmeticError, FixedPoint, Precision};
+
+#[track_caller]
+fn overflow(op: &str, error: ArithmeticError) -> ! {
+    match error {
+        ArithmeticError::DivisionByZero => panic!("attempt to {} by zero", op),
+        _ => panic!("attempt to {} with overflow", op),
+    }
+}
+
+macro_rules! impl_std_ops {
+    ($layout:tt) => {
+        impl<P: Precision> Add for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn add(self, rhs: Self) -> Self {
+                self.cadd(rhs).unwrap_or_else(|err| overflow("add", err))
+            }
+        }
+
+        impl<P: Precision> Sub for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn sub(self, rhs: Self) -> Self {
+                self.csub(rhs)
+                    .unwrap_or_else(|err| overflow("subtract", err))
+            }
+        }
+
+        impl<P: Precision> Mul for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn mul(self, rhs: Self) -> Self {
+                self.rmul(rhs, RoundMode::Nearest.or_configured())
+                    .unwrap_or_else(|err| overflow("multiply", err))
+            }
+        }
+
+        impl<P: Precision> Div for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: Self) -> Self {
+                self.rdiv(rhs, RoundMode::Nearest.or_configured())
+                    .unwrap_or_else(|err| overflow("divide", err))
+            }
+        }
+
+        impl<P: Precision> Rem for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            /// Calculates the exact remainder of the truncated division, like `%` of integers.
+            #[inline]
+            #[track_caller]
+            fn rem(self, rhs: Self) -> Self {
+                self.crem(rhs)
+                    .unwrap_or_else(|err| overflow("calculate the remainder", err))
+            }
+        }
+
+        impl_std_ops!(@by_ref $layout, Add::add);
+        impl_std_ops!(@by_ref $layout, Sub::sub);
+        impl_std_ops!(@by_ref $layout, Mul::mul);
+        impl_std_ops!(@by_ref $layout, Div::div);
+        impl_std_ops!(@by_ref $layout, Rem::rem);
+
+        impl_std_ops!(@assign $layout, AddAssign::add_assign => add);
+        impl_std_ops!(@assign $layout, SubAssign::sub_assign => sub);
+        impl_std_ops!(@assign $layout, MulAssign::mul_assign => mul);
+        impl_std_ops!(@assign $layout, DivAssign::div_assign => div);
+        impl_std_ops!(@assign $layout, RemAssign::rem_assign => rem);
+    };
+    (@assign $layout:tt, $trait:ident::$method:ident => $op:ident) => {
+        impl<P: Precision> $trait for FixedPoint<$layout, P> {
+            #[inline]
+            #[track_caller]
+            fn $method(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        impl<'a, P: Precision> $trait<&'a FixedPoint<$layout, P>> for FixedPoint<$layout, P> {
+            #[inline]
+            #[track_caller]
+            fn $method(&mut self, rhs: &'a Self) {
+                *self = self.$op(*rhs);
+            }
+        }
+    };
+    (@by_ref $layout:tt, $trait:ident::$method:ident) => {
+        impl<'a, P: Precision> $trait<&'a FixedPoint<$layout, P>> for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &'a Self) -> Self {
+                $trait::$method(self, *rhs)
+            }
+        }
+
+        impl<'a, P: Precision> $trait<FixedPoint<$layout, P>> for &'a FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: FixedPoint<$layout, P>) -> Self::Output {
+                $trait::$method(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, P: Precision> $trait<&'b FixedPoint<$layout, P>> for &'a FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &'b FixedPoint<$layout, P>) -> Self::Output {
+                $trait::$method(*self, *rhs)
+            }
+        }
+    };
+    (@signed $layout:tt) => {
+        impl_std_ops!($layout);
+
+        impl<P: Precision> core::ops::Neg for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> Self {
+                self.cneg().unwrap_or_else(|err| overflow("negate", err))
+            }
+        }
+
+        impl<'a, P: Precision> core::ops::Neg for &'a FixedPoint<$layout, P> {
+            type Output = FixedPoint<$layout, P>;
+
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_std_ops!(@signed i16);
+#[cfg(feature = "i32")]
+impl_std_ops!(@signed i32);
+#[cfg(feature = "i64")]
+impl_std_ops!(@signed i64);
+#[cfg(feature = "i128")]
+impl_std_ops!(@signed i128);
+#[cfg(feature = "u64")]
+impl_std_ops!(u64);
+#[cfg(feature = "u128")]
+impl_std_ops!(u128);
@@ -1,12 +1,28 @@
-use core::str::{self, FromStr};
-
-use crate::{ConvertError, FixedPoint, Precision};
+use core::cmp::Ordering;
+use core::fmt::{self, Alignment, Write};
+use core::str;
+#[cfg(primitive_layout)]
+use core::str::FromStr;
+
+#[cfg(any(feature = "u64", feature = "u128"))]
+use crate::ops::sign::Sign;
+#[cfg(primitive_layout)]
+use crate::Precision;
+use crate::{formatting::FormatOptions, ops::RoundMode, ConvertError, FixedPoint, ParseErrorKind};
 
 #[allow(unreachable_pub)]
 pub trait Stringify {
     fn stringify(&self, buf: &mut StrBuf);
 }
 
+/// Allows to choose the parsing policy in generic code.
+#[allow(unreachable_pub)]
+pub trait Parse: Sized {
+    /// Parses without rounding if `mode` is `None`.
+    fn parse(str: &str, mode: Option<RoundMode>) -> Result<Self, ConvertError>;
+}
+
+#[cfg(primitive_layout)]
 macro_rules! impl_for {
     ($layout:tt) => {
         impl<P: Precision> FromStr for FixedPoint<$layout, P> {
@@ -14,88 +30,139 @@ macro_rules! impl_for {
 
             /// Parses a string slice into a fixed point.
             /// If the value cannot be represented, it will be rounded to the nearest value.
+            /// Half-way cases are rounded away from zero (see [`RoundMode::Nearest`])
+            /// unless another default mode is [configured][RoundMode].
+            /// Exponent notation is accepted, e.g. `1.5e-3` or `2E6`.
             ///
             /// Use `from_str_exact` to parse without rounding.
             fn from_str(str: &str) -> Result<Self, Self::Err> {
-                Self::parse_str::<false>(str)
+                Self::parse_str::<false>(str, RoundMode::Nearest.or_configured())
             }
         }
 
         impl<P: Precision> FixedPoint<$layout, P> {
+            /// The maximum length of strings produced by [`write_to`](Self::write_to)
+            /// and `Display` without flags.
+            pub const MAX_STR_LEN: usize =
+                max_str_len($layout::MIN != 0, $layout::MAX.ilog10() + 1, P::U32);
+
             /// Parses a string slice into a fixed point.
             /// If the value cannot be represented then this will return an error.
             ///
             /// Use the `FromStr` instance to parse with rounding.
             pub fn from_str_exact(str: &str) -> Result<Self, ConvertError> {
-                Self::parse_str::<true>(str)
+                Self::parse_str::<true>(str, RoundMode::Nearest)
             }
 
-            fn parse_str<const EXACT: bool>(str: &str) -> Result<Self, ConvertError> {
-                let str = str.trim();
+            /// Parses a string slice. Excess fractional digits are rounded according to `mode`.
+            /// `mode` is ignored if `EXACT` is set.
+            fn parse_str<const EXACT: bool>(
+                str: &str,
+                mode: RoundMode,
+            ) -> Result<Self, ConvertError> {
+                parse_number(str, P::U32, |number| {
+                    Self::parse_plain::<EXACT>(number, mode)
+                })
+            }
 
-                let (integral_str, mut fractional_str) = if let Some(parts) = str.split_once('.') {
-                    parts
-                } else {
-                    return str
+            fn parse_plain<const EXACT: bool>(
+                number: &Number<'_>,
+                mode: RoundMode,
+            ) -> Result<Self, ConvertError> {
+                if !number.has_point {
+                    return number
+                        .signed_integral
                         .parse::<$layout>()
-                        .map_err(|_| ConvertError::new("can't parse integer"))?
-                        .try_into();
-                };
+                        .map_err(|_| number.overflow("can't parse integer"))?
+                        .try_into()
+                        .map_err(|err: ConvertError| {
+                            err.at(ParseErrorKind::Overflow, number.start)
+                        });
+                }
 
-                let integral: $layout = integral_str
+                // Unsigned layouts fail here with a negative number.
+                let integral: $layout = number
+                    .signed_integral
                     .parse()
-                    .map_err(|_| ConvertError::new("can't parse integral part"))?;
-
-                if !fractional_str.chars().all(|c| c.is_digit(10)) {
-                    return Err(ConvertError::new(
-                        "can't parse fractional part: must contain digits only",
-                    ));
-                }
+                    .map_err(|_| number.overflow("can't parse integral part"))?;
 
-                let signum = if str.as_bytes()[0] == b'-' { -1 } else { 1 };
+                let mut fractional_str = number.fractional;
+                let is_negative = number.is_negative;
+                let signum = if is_negative { -1 } else { 1 };
                 let prec = Self::PRECISION as usize; // TODO: negative precision?
 
-                if EXACT {
-                    if fractional_str.len() > Self::PRECISION.abs() as usize {
-                        return Err(ConvertError::new("requested precision is too high"));
-                    }
+                if EXACT && fractional_str.len() > Self::PRECISION.unsigned_abs() as usize {
+                    return Err(number.too_many_fractional_digits(prec));
                 }
 
-                let round = if !EXACT && fractional_str.len() > prec {
-                    let extra = fractional_str.as_bytes()[prec];
-                    fractional_str = &fractional_str[..prec];
-                    Some(signum).filter(|_| extra >= b'5')
+                // Digits that cannot be represented and must be rounded.
+                let dropped = if !EXACT && fractional_str.len() > prec {
+                    let (kept, dropped) = fractional_str.split_at(prec);
+                    fractional_str = kept;
+                    dropped.trim_end_matches('0')
                 } else {
-                    None
+                    ""
                 };
 
                 let ten: $layout = 10;
                 let exp = ten.pow(fractional_str.len() as u32);
 
                 if EXACT && exp > Self::COEF {
-                    return Err(ConvertError::new("requested precision is too high"));
+                    return Err(number.too_many_fractional_digits(prec));
                 }
 
                 debug_assert!(exp <= Self::COEF);
 
-                let fractional: $layout = fractional_str
-                    .parse()
-                    .map_err(|_| ConvertError::new("can't parse fractional part"))?;
+                // Can be empty for zero precision.
+                let fractional: $layout = if fractional_str.is_empty() {
+                    0
+                } else {
+                    fractional_str
+                        .parse()
+                        .map_err(|_| number.overflow("can't parse fractional part"))?
+                };
 
                 let final_integral = integral
                     .checked_mul(Self::COEF)
-                    .ok_or(ConvertError::new("too big integral"))?;
+                    .ok_or_else(|| number.overflow("too big integral"))?;
+
+                let final_fractional = Self::COEF / exp * fractional;
 
-                let mut final_fractional = signum * Self::COEF / exp * fractional;
-                if let Some(round) = round {
+                let mut value = if is_negative {
+                    final_integral.checked_sub(final_fractional)
+                } else {
+                    final_integral.checked_add(final_fractional)
+                }
+                .ok_or_else(|| number.overflow("too big number"))?;
+
+                if !dropped.is_empty() {
                     debug_assert!(!EXACT);
-                    final_fractional += round;
+
+                    // `dropped` has no trailing zeros, so it's a half only if it's exactly "5".
+                    let half = dropped.as_bytes()[0]
+                        .cmp(&b'5')
+                        .then_with(|| dropped.len().cmp(&1));
+
+                    if mode.is_away_from_zero(signum, half, value % 2 != 0) {
+                        value = if is_negative {
+                            value.checked_sub(1)
+                        } else {
+                            value.checked_add(1)
+                        }
+                        .ok_or_else(|| number.overflow("too big number"))?;
+                    }
                 }
 
-                final_integral
-                    .checked_add(final_fractional)
-                    .map(Self::from_bits)
-                    .ok_or_else(|| ConvertError::new("too big number"))
+                Ok(Self::from_bits(value))
+            }
+        }
+
+        impl<P: Precision> Parse for FixedPoint<$layout, P> {
+            fn parse(str: &str, mode: Option<RoundMode>) -> Result<Self, ConvertError> {
+                match mode {
+                    Some(mode) => Self::parse_str::<false>(str, mode),
+                    None => Self::parse_str::<true>(str, RoundMode::Nearest),
+                }
             }
         }
 
@@ -103,8 +170,7 @@ macro_rules! impl_for {
             fn stringify(&self, buf: &mut StrBuf) {
                 let mut fmt = itoa::Buffer::new();
 
-                let sign = self.inner.signum();
-                if sign < 0 {
+                if self.inner.is_negative() {
                     let _ = buf.push('-');
                 }
 
@@ -127,9 +193,31 @@ macro_rules! impl_for {
 }
 
 // Serialize as a string in case of human readable formats.
-// The maximum length can be calculated as `len(str(-2**bits)) + 1`,
-// where `1` is reserved for `.` after integral part.
-const MAX_LEN: usize = if cfg!(feature = "i128") { 41 } else { 21 };
+// The maximum length is the longest `max_str_len` over enabled layouts,
+// which is reached with zero precision because of the reserved `.0`.
+pub(crate) const MAX_LEN: usize = {
+    const LAYOUTS: [(bool, bool, u32); 7] = [
+        (cfg!(feature = "i16"), true, i16::MAX.ilog10() + 1),
+        (cfg!(feature = "i32"), true, i32::MAX.ilog10() + 1),
+        (cfg!(feature = "i64"), true, i64::MAX.ilog10() + 1),
+        (cfg!(feature = "i128"), true, i128::MAX.ilog10() + 1),
+        (cfg!(feature = "u64"), false, u64::MAX.ilog10() + 1),
+        (cfg!(feature = "u128"), false, u128::MAX.ilog10() + 1),
+        (cfg!(feature = "i256"), true, 77),
+    ];
+
+    let mut max = 0;
+    let mut i = 0;
+    while i < LAYOUTS.len() {
+        let (enabled, is_signed, digits) = LAYOUTS[i];
+        let len = max_str_len(is_signed, digits, 0);
+        if enabled && len > max {
+            max = len;
+        }
+        i += 1;
+    }
+    max
+};
 
 // TODO: try `staticvec` after stabilization.
 // Now it works faster than `arrayvec`.
@@ -150,7 +238,7 @@ impl Default for StrBuf {
 
 impl StrBuf {
     #[inline]
-    fn push(&mut self, c: char) {
+    pub(crate) fn push(&mut self, c: char) {
         debug_assert!(self.len < MAX_LEN);
         debug_assert!(c.is_ascii());
 
@@ -159,7 +247,7 @@ impl StrBuf {
     }
 
     #[inline]
-    fn push_str(&mut self, s: &str) {
+    pub(crate) fn push_str(&mut self, s: &str) {
         debug_assert!(self.len + s.len() <= MAX_LEN);
 
         let s = s.as_bytes();
@@ -177,6 +265,577 @@ impl StrBuf {
     }
 }
 
+impl<I, P> FixedPoint<I, P>
+where
+    Self: Stringify,
+{
+    /// Writes the canonical decimal representation, the same as `Display` without flags,
+    /// into `buf` and returns the number of written bytes. Doesn't use `core::fmt`.
+    ///
+    /// Fails if `buf` is shorter than the representation,
+    /// buffers of `MAX_STR_LEN` bytes are always enough.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{typenum::U9, FixedPoint};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let mut buf = [0; Amount::MAX_STR_LEN];
+    /// let len = Amount::MIN.write_to(&mut buf)?;
+    /// assert_eq!(&buf[..len], b"-9223372036.854775808");
+    ///
+    /// let amount: Amount = "-12.5".parse()?;
+    /// assert!(amount.write_to(&mut [0; 4]).is_err());
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, ConvertError> {
+        let mut str_buf = StrBuf::default();
+        self.stringify(&mut str_buf);
+
+        let bytes = str_buf.as_str().as_bytes();
+        buf.get_mut(..bytes.len())
+            .ok_or(ConvertError::new("buffer is too small"))?
+            .copy_from_slice(bytes);
+
+        Ok(bytes.len())
+    }
+}
+
+/// The length of the sign, the integral part (at least `0`), the point
+/// and the fractional part (at least `0`).
+pub(crate) const fn max_str_len(is_signed: bool, digits: u32, precision: u32) -> usize {
+    let integral = if digits > precision {
+        digits - precision
+    } else {
+        1
+    };
+    let fractional = if precision > 0 { precision } else { 1 };
+    is_signed as usize + integral as usize + 1 + fractional as usize
+}
+
+/// Writes the output of `Stringify` respecting precision, width, fill, alignment and sign flags.
+/// Excess fractional digits are rounded half to even unless another default mode is configured.
+/// The alternate flag without an explicit precision writes exactly `type_precision` digits.
+pub(crate) fn pad_number(
+    str: &str,
+    type_precision: u32,
+    options: &FormatOptions,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let precision = match f.precision() {
+        None if f.alternate() => Some(type_precision as usize),
+        precision => precision,
+    };
+
+    let (is_negative, str) = match str.strip_prefix('-') {
+        Some(str) => (true, str),
+        None => (false, str),
+    };
+
+    let (integral, fractional) = str.split_once('.').unwrap_or((str, ""));
+    let kept = precision.map_or(fractional.len(), |p| p.min(fractional.len()));
+    let (fractional, dropped) = fractional.split_at(kept);
+    let zeros = precision.map_or(0, |p| p - kept);
+
+    // The first digit is reserved for the carry.
+    let mut digits = [b'0'; MAX_LEN + 1];
+    let len = 1 + integral.len() + fractional.len();
+    digits[1..=integral.len()].copy_from_slice(integral.as_bytes());
+    digits[1 + integral.len()..len].copy_from_slice(fractional.as_bytes());
+    round_digits(&mut digits[..len], dropped.as_bytes(), is_negative);
+
+    let start = if digits[0] == b'0' { 1 } else { 0 };
+    // Only ASCII digits are written.
+    let digits = str::from_utf8(&digits[start..len]).unwrap();
+    let (integral, fractional) = digits.split_at(integral.len() + 1 - start);
+
+    let has_point = !fractional.is_empty() || zeros > 0;
+    let separators = options.separators_count(integral.len());
+    let len = integral.len() + separators + usize::from(has_point) + fractional.len() + zeros;
+
+    write_padded(f, is_negative, len, |f| {
+        for (i, digit) in integral.chars().enumerate() {
+            if options.is_group_start(integral.len() - i, integral.len()) {
+                f.write_char(options.grouping_separator)?;
+            }
+
+            f.write_char(digit)?;
+        }
+
+        if has_point {
+            f.write_char(options.decimal_separator)?;
+            f.write_str(fractional)?;
+
+            for _ in 0..zeros {
+                f.write_char('0')?;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Writes the output of `Stringify` in normalized scientific notation, e.g. `1.5e-3`,
+/// respecting precision, width, fill, alignment and sign flags like `pad_number`.
+pub(crate) fn pad_exponent(str: &str, is_upper: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (is_negative, str) = match str.strip_prefix('-') {
+        Some(str) => (true, str),
+        None => (false, str),
+    };
+
+    let (integral, fractional) = str.split_once('.').unwrap_or((str, ""));
+
+    // Significant digits with the carry slot, the exponent of the first one.
+    let mut digits = [b'0'; MAX_LEN + 1];
+    let mut len = 1;
+    let mut exponent = integral.len() as i32 - 1;
+    for &digit in integral.as_bytes().iter().chain(fractional.as_bytes()) {
+        if len == 1 && digit == b'0' {
+            exponent -= 1;
+            continue;
+        }
+        digits[len] = digit;
+        len += 1;
+    }
+
+    // Zero has no significant digits.
+    if len == 1 {
+        len = 2;
+        exponent = 0;
+    }
+
+    while len > 2 && digits[len - 1] == b'0' {
+        len -= 1;
+    }
+
+    let kept = f.precision().map_or(len - 2, |p| p.min(len - 2));
+    let zeros = f.precision().map_or(0, |p| p - kept);
+    let dropped = &digits[2 + kept..len];
+    let mut rounded = [b'0'; MAX_LEN + 1];
+    rounded[..2 + kept].copy_from_slice(&digits[..2 + kept]);
+    round_digits(&mut rounded[..2 + kept], dropped, is_negative);
+
+    // `9.9` is rounded to `10.0`, the last zero is dropped to keep the number of digits.
+    let (mantissa, exponent) = if rounded[0] == b'0' {
+        (&rounded[1..2 + kept], exponent)
+    } else {
+        (&rounded[..1 + kept], exponent + 1)
+    };
+
+    // Only ASCII digits are written.
+    let mantissa = str::from_utf8(mantissa).unwrap();
+    let (first, rest) = mantissa.split_at(1);
+    let mut fmt = itoa::Buffer::new();
+    let exponent = fmt.format(exponent);
+
+    let has_point = !rest.is_empty() || zeros > 0;
+    let len = 1 + usize::from(has_point) + rest.len() + zeros + 1 + exponent.len();
+
+    write_padded(f, is_negative, len, |f| {
+        f.write_str(first)?;
+
+        if has_point {
+            f.write_char('.')?;
+            f.write_str(rest)?;
+
+            for _ in 0..zeros {
+                f.write_char('0')?;
+            }
+        }
+
+        f.write_char(if is_upper { 'E' } else { 'e' })?;
+        f.write_str(exponent)
+    })
+}
+
+/// Writes the output of `Stringify` with the type and the raw representation,
+/// e.g. `FixedPoint<i64, U9>(10.042 = 10042000000)`.
+pub(crate) fn debug_number(
+    str: &str,
+    layout: &str,
+    precision: u32,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    write!(f, "FixedPoint<{layout}, U{precision}>({str} = ")?;
+
+    let (is_negative, abs) = match str.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, str),
+    };
+
+    // The raw representation is the number without the point, padded to the precision.
+    let (integral, fractional) = abs.split_once('.').unwrap_or((abs, ""));
+    let precision = precision as usize;
+    let fractional = &fractional[..fractional.len().min(precision)];
+    let zeros = precision - fractional.len();
+
+    let digits = integral.chars().chain(fractional.chars());
+    let mut significant = digits.skip_while(|&c| c == '0').peekable();
+
+    if significant.peek().is_none() {
+        // All digits are zeros, so are the padding ones.
+        f.write_char('0')?;
+    } else {
+        if is_negative {
+            f.write_char('-')?;
+        }
+        for digit in significant {
+            f.write_char(digit)?;
+        }
+        for _ in 0..zeros {
+            f.write_char('0')?;
+        }
+    }
+
+    f.write_char(')')
+}
+
+/// Rounds `digits`, the first of which is reserved for the carry, according to `dropped` ones
+/// half to even unless another default mode is configured.
+fn round_digits(digits: &mut [u8], dropped: &[u8], is_negative: bool) {
+    let Some((&first, rest)) = dropped.split_first() else {
+        return;
+    };
+
+    let is_rest_zero = rest.iter().all(|&c| c == b'0');
+    let half = match first.cmp(&b'5') {
+        Ordering::Equal if !is_rest_zero => Ordering::Greater,
+        half => half,
+    };
+    let sign = if is_negative { -1 } else { 1 };
+    let is_odd = (digits[digits.len() - 1] - b'0') % 2 == 1;
+    let is_exact = first == b'0' && is_rest_zero;
+
+    if !is_exact
+        && RoundMode::HalfEven
+            .or_configured()
+            .is_away_from_zero(sign, half, is_odd)
+    {
+        for digit in digits.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                break;
+            }
+        }
+    }
+}
+
+/// Writes the sign and `body` of `len` characters respecting width, fill, alignment and sign flags.
+fn write_padded(
+    f: &mut fmt::Formatter<'_>,
+    is_negative: bool,
+    len: usize,
+    body: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let sign = if is_negative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(sign.len() + len));
+
+    let (before, after) = if f.sign_aware_zero_pad() {
+        (0, 0)
+    } else {
+        match f.align().unwrap_or(Alignment::Right) {
+            Alignment::Left => (0, padding),
+            Alignment::Right => (padding, 0),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+        }
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+
+    f.write_str(sign)?;
+
+    if f.sign_aware_zero_pad() {
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+    }
+
+    body(f)?;
+
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// Parses `str` in plain or exponent notation by `parse_plain`, which gets validated parts
+/// of the number in plain notation. Failures are located in `str`, `precision` is used to find
+/// the first excess fractional digit of numbers in exponent notation.
+pub(crate) fn parse_number<T>(
+    str: &str,
+    precision: u32,
+    parse_plain: impl FnOnce(&Number<'_>) -> Result<T, ConvertError>,
+) -> Result<T, ConvertError> {
+    let start = str.len() - str.trim_start().len();
+    let str = str.trim();
+
+    let expanded = match Expanded::new(str, start)? {
+        Some(expanded) => expanded,
+        None => return parse_plain(&Number::new(str, start)?),
+    };
+
+    // The expanded string is always valid, only values can be rejected.
+    parse_plain(&Number::new(expanded.as_str(), 0)?).map_err(|err| match err.kind() {
+        Some(ParseErrorKind::TooManyFractionalDigits) => err.at(
+            ParseErrorKind::TooManyFractionalDigits,
+            expanded.excess_position(precision),
+        ),
+        _ => err.at(ParseErrorKind::Overflow, start),
+    })
+}
+
+/// A validated number in plain notation, e.g. `-12.5`.
+// `I256` is parsed without the sign and doesn't need the point.
+#[cfg_attr(not(primitive_layout), allow(dead_code))]
+pub(crate) struct Number<'a> {
+    /// The integral part with the sign.
+    pub(crate) signed_integral: &'a str,
+    /// Digits of the integral part, never empty.
+    pub(crate) integral: &'a str,
+    /// Digits of the fractional part, empty if there are none or no point.
+    pub(crate) fractional: &'a str,
+    pub(crate) is_negative: bool,
+    pub(crate) has_point: bool,
+    /// Offsets in the parsed string.
+    pub(crate) start: usize,
+    pub(crate) integral_start: usize,
+    pub(crate) fractional_start: usize,
+}
+
+impl<'a> Number<'a> {
+    /// Splits a trimmed number located at `offset` of the parsed string.
+    fn new(str: &'a str, offset: usize) -> Result<Self, ConvertError> {
+        let sign_len = usize::from(matches!(str.as_bytes().first(), Some(b'-' | b'+')));
+        let (signed_integral, fractional) = str.split_once('.').unwrap_or((str, ""));
+        let integral = &signed_integral[sign_len..];
+        let fractional_start = signed_integral.len() + 1;
+
+        let invalid = |reason, position| {
+            ConvertError::new(reason).at(ParseErrorKind::InvalidCharacter, offset + position)
+        };
+
+        if let Some(i) = integral.bytes().position(|b| !b.is_ascii_digit()) {
+            return Err(invalid("can't parse integral part", sign_len + i));
+        }
+
+        if let Some(i) = fractional.bytes().position(|b| !b.is_ascii_digit()) {
+            return Err(invalid(
+                "can't parse fractional part: must contain digits only",
+                fractional_start + i,
+            ));
+        }
+
+        if integral.is_empty() {
+            // The integral part is required, e.g. `.5` is rejected.
+            return Err(if fractional.is_empty() {
+                ConvertError::new("can't parse integral part")
+                    .at(ParseErrorKind::Empty, offset + str.len())
+            } else {
+                invalid("can't parse integral part", signed_integral.len())
+            });
+        }
+
+        if fractional.is_empty() && signed_integral.len() < str.len() {
+            // Digits after the point are required, e.g. `42.` is rejected.
+            return Err(invalid("can't parse fractional part", str.len()));
+        }
+
+        Ok(Self {
+            signed_integral,
+            integral,
+            fractional,
+            is_negative: sign_len > 0 && str.as_bytes()[0] == b'-',
+            has_point: signed_integral.len() < str.len(),
+            start: offset,
+            integral_start: offset + sign_len,
+            fractional_start: offset + fractional_start,
+        })
+    }
+
+    /// Returns an error of the value out of range.
+    pub(crate) fn overflow(&self, reason: &'static str) -> ConvertError {
+        ConvertError::new(reason).at(ParseErrorKind::Overflow, self.start)
+    }
+
+    /// Returns an error of more fractional digits than `precision`.
+    pub(crate) fn too_many_fractional_digits(&self, precision: usize) -> ConvertError {
+        ConvertError::new("requested precision is too high").at(
+            ParseErrorKind::TooManyFractionalDigits,
+            self.fractional_start + precision,
+        )
+    }
+}
+
+// Enough for any representable number, longer strings are rejected.
+const EXPANDED_LEN: usize = 256;
+
+/// Plain representation of a number written in exponent notation, e.g. `1500` for `1.5e3`.
+pub(crate) struct Expanded {
+    buffer: [u8; EXPANDED_LEN],
+    len: usize,
+    /// The position of the point among all digits of the mantissa after shifting.
+    point: i64,
+    digits_count: usize,
+    /// Offsets of the mantissa's parts in the parsed string.
+    integral_len: usize,
+    integral_start: usize,
+    fractional_start: usize,
+}
+
+impl Expanded {
+    /// Expands a trimmed number located at `offset` of the parsed string.
+    /// Returns `None` if the string doesn't contain an exponent.
+    fn new(str: &str, offset: usize) -> Result<Option<Self>, ConvertError> {
+        let (mantissa, exponent) = match str.split_once(['e', 'E']) {
+            Some(parts) => parts,
+            None => return Ok(None),
+        };
+
+        // Unlike plain numbers, a missing mantissa is followed by the exponent.
+        let mantissa = Number::new(mantissa, offset).map_err(|err| match err.position() {
+            Some(position) if err.kind() == Some(ParseErrorKind::Empty) => {
+                err.at(ParseErrorKind::InvalidCharacter, position)
+            }
+            _ => err,
+        })?;
+
+        let exponent_start = offset + str.len() - exponent.len();
+        let sign_len = usize::from(matches!(exponent.as_bytes().first(), Some(b'-' | b'+')));
+        let invalid = |position| {
+            ConvertError::new("can't parse exponent").at(ParseErrorKind::InvalidCharacter, position)
+        };
+
+        if let Some(i) = exponent
+            .bytes()
+            .skip(sign_len)
+            .position(|b| !b.is_ascii_digit())
+        {
+            return Err(invalid(exponent_start + sign_len + i));
+        }
+
+        if exponent.len() == sign_len {
+            return Err(invalid(offset + str.len()));
+        }
+
+        let exponent: i32 = exponent.parse().map_err(|_| {
+            ConvertError::new("too big exponent").at(ParseErrorKind::Overflow, offset)
+        })?;
+
+        let is_negative = mantissa.is_negative;
+        let (integral, fractional) = (mantissa.integral, mantissa.fractional);
+        let digits_count = integral.len() + fractional.len();
+
+        // The position of the point among all digits after shifting.
+        let point = integral.len() as i64 + i64::from(exponent);
+
+        let mut expanded = Self {
+            buffer: [b'0'; EXPANDED_LEN],
+            len: 0,
+            point,
+            digits_count,
+            integral_len: integral.len(),
+            integral_start: mantissa.integral_start,
+            fractional_start: mantissa.fractional_start,
+        };
+
+        // Such tiny numbers are rounded to zero or `EPSILON` for any supported precision,
+        // so only the sign and whether they are zero matter.
+        if point < -(EXPANDED_LEN as i64 / 2) {
+            if integral
+                .bytes()
+                .chain(fractional.bytes())
+                .all(|b| b == b'0')
+            {
+                expanded.len = 1;
+            } else {
+                expanded.len = EXPANDED_LEN / 2 + 2 + is_negative as usize;
+                expanded.buffer[..is_negative as usize].fill(b'-');
+                expanded.buffer[expanded.len - EXPANDED_LEN / 2 - 1] = b'.';
+                expanded.buffer[expanded.len - 1] = b'1';
+            }
+            return Ok(Some(expanded));
+        }
+
+        let len = is_negative as i64
+            + if point <= 0 {
+                // "0." + zeros + digits
+                2 - point + digits_count as i64
+            } else if point >= digits_count as i64 {
+                // digits + zeros
+                point
+            } else {
+                // digits + "." + digits
+                digits_count as i64 + 1
+            };
+
+        if len > EXPANDED_LEN as i64 {
+            return Err(ConvertError::new("too long number").at(ParseErrorKind::Overflow, offset));
+        }
+
+        let mut push = |byte: u8| {
+            expanded.buffer[expanded.len] = byte;
+            expanded.len += 1;
+        };
+
+        if is_negative {
+            push(b'-');
+        }
+
+        if point <= 0 {
+            push(b'0');
+            push(b'.');
+            (0..-point).for_each(|_| push(b'0'));
+        }
+
+        for (i, digit) in integral.bytes().chain(fractional.bytes()).enumerate() {
+            if point > 0 && i as i64 == point {
+                push(b'.');
+            }
+            push(digit);
+        }
+
+        (digits_count as i64..point).for_each(|_| push(b'0'));
+
+        Ok(Some(expanded))
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only ASCII bytes are written.
+        str::from_utf8(&self.buffer[..self.len]).unwrap()
+    }
+
+    /// Returns the offset of the first mantissa's digit beyond `precision` after shifting.
+    fn excess_position(&self, precision: u32) -> usize {
+        let excess = (self.point + i64::from(precision)).clamp(0, self.digits_count as i64 - 1);
+        let excess = excess as usize;
+
+        if excess < self.integral_len {
+            self.integral_start + excess
+        } else {
+            self.fractional_start + excess - self.integral_len
+        }
+    }
+}
+
 // TODO: pass attrs to doc.
 #[cfg(feature = "i16")]
 impl_for!(i16);
@@ -186,3 +845,7 @@ impl_for!(i32);
 impl_for!(i64);
 #[cfg(feature = "i128")]
 impl_for!(i128);
+#[cfg(feature = "u64")]
+impl_for!(u64);
+#[cfg(feature = "u128")]
+impl_for!(u128);
@@ -0,0 +1,76 @@
+#![cfg(any(feature = "i64", feature = "i128"))]
+
+use anyhow::Result;
+use fixnum::{ops::CheckedSub, Accumulator, ArithmeticError};
+
+#[test]
+fn sum() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            let mut acc = Accumulator::new();
+            for &value in values {
+                acc.add(value);
+            }
+            assert_eq!(acc.finish()?, expected);
+
+            assert_eq!(values.iter().collect::<Accumulator<FixedPoint>>().finish()?, expected);
+            assert_eq!(values.iter().copied().collect::<Accumulator<FixedPoint>>().finish()?, expected);
+        },
+        all {
+            (&[], fp!(0));
+            (&[fp!(0.1), fp!(0.2), fp!(0.3)], fp!(0.6));
+            (&[fp!(1.5), fp!(-2.25)], fp!(-0.75));
+            (&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MIN], FixedPoint::from_bits(-2));
+            (&[FixedPoint::MAX, fp!(1), fp!(-1)], FixedPoint::MAX);
+            (&[FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX], FixedPoint::MAX.csub(FixedPoint::from_bits(2))?);
+        },
+    };
+    test_fixed_point! {
+        case (values: &[FixedPoint]) => {
+            let acc = values.iter().collect::<Accumulator<FixedPoint>>();
+            assert_eq!(acc.finish(), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (&[FixedPoint::MAX, FixedPoint::EPSILON]);
+            (&[FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MAX]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sub() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let mut acc = Accumulator::default();
+            acc.add(fp!(1.5));
+            acc.sub(fp!(2));
+            assert_eq!(acc.finish()?, fp!(0.5).cneg()?);
+
+            let snapshot = acc;
+            acc.extend([FixedPoint::MAX, FixedPoint::MAX]);
+            assert!(acc.finish().is_err());
+            acc.sub(FixedPoint::MAX);
+            acc.sub(FixedPoint::MAX);
+            assert_eq!(acc.finish()?, snapshot.finish()?);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn many_values() -> Result<()> {
+    use core::iter::{once, repeat_n};
+
+    type Amount = fixnum::FixedPoint<i64, typenum::U9>;
+
+    let values = once(Amount::MAX)
+        .chain(repeat_n(Amount::EPSILON, 1_000_000))
+        .chain(once(Amount::MAX.cneg()?));
+    assert_eq!(
+        values.collect::<Accumulator<_>>().finish()?,
+        "0.001".parse()?
+    );
+    Ok(())
+}
@@ -0,0 +1,12 @@
+// Ensure that amounts of different currencies can't be added.
+
+use fixnum::{amount::Amount, ops::CheckedAdd, typenum::U9};
+
+enum Usd {}
+enum Eur {}
+
+fn main() {
+    let a = Amount::<Usd, i64, U9>::default();
+    let b = Amount::<Eur, i64, U9>::default();
+    let _ = a.cadd(b);
+}
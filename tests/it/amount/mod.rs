@@ -0,0 +1,73 @@
+#![cfg(feature = "i64")]
+
+use anyhow::Result;
+use fixnum::{amount::Amount, ops::*, typenum::U9, ArithmeticError, FixedPoint};
+
+enum Usd {}
+
+type Rate = FixedPoint<i64, U9>;
+type Usds = Amount<Usd, i64, U9>;
+
+fn usd(str: &str) -> Usds {
+    str.parse().unwrap()
+}
+
+fn rate(str: &str) -> Rate {
+    str.parse().unwrap()
+}
+
+#[test]
+fn ops() -> Result<()> {
+    assert_eq!(usd("1.5").cadd(usd("2.25"))?, usd("3.75"));
+    assert_eq!(usd("1.5").csub(usd("2.25"))?, usd("-0.75"));
+    assert_eq!(usd("1.5").cmul(3)?, usd("4.5"));
+    assert_eq!(usd("10").rmul(rate("0.015"), RoundMode::Ceil)?, usd("0.15"));
+    assert_eq!(usd("10").rdiv(rate("4"), RoundMode::Floor)?, usd("2.5"));
+    assert_eq!(usd("10").rdiv(3, RoundMode::Floor)?, usd("3.333333333"));
+    assert_eq!(usd("3").rdiv(usd("4"), RoundMode::Floor)?, rate("0.75"));
+
+    assert_eq!(Usds::MAX.cadd(usd("1")), Err(ArithmeticError::Overflow));
+    assert_eq!(Usds::MAX.saturating_add(usd("1")), Usds::MAX);
+    assert_eq!(Usds::MIN.saturating_sub(usd("1")), Usds::MIN);
+    assert_eq!(Usds::MAX.saturating_mul(2), Usds::MAX);
+    assert_eq!(
+        Usds::MAX.saturating_rmul(rate("2"), RoundMode::Floor),
+        Usds::MAX
+    );
+    assert_eq!(
+        usd("1").rdiv(Rate::ZERO, RoundMode::Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+
+    let total = [usd("0.1"), usd("0.2")].into_iter().checked_sum()?;
+    assert_eq!(total, usd("0.3"));
+    Ok(())
+}
+
+#[test]
+fn conversions() -> Result<()> {
+    let amount = usd("-12.34");
+    assert_eq!(amount.to_string(), "-12.34");
+    assert_eq!(format!("{:?}", amount), "-12.34");
+    assert_eq!(amount.value(), rate("-12.34"));
+    assert_eq!(*amount.as_value(), rate("-12.34"));
+    assert_eq!(Usds::new(rate("-12.34")), amount);
+    assert_eq!(Usds::default(), Usds::ZERO);
+    assert!(usd("1") < usd("1.5"));
+    assert!("abc".parse::<Usds>().is_err());
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() -> Result<()> {
+    assert_eq!(serde_json::to_string(&usd("12.34"))?, "\"12.34\"");
+    assert_eq!(serde_json::from_str::<Usds>("\"12.34\"")?, usd("12.34"));
+    Ok(())
+}
+
+#[test]
+fn mixed_currencies() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.compile_fail("tests/it/amount/mixed_currencies.rs");
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "arbitrary")]
+
+use anyhow::Result;
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Deterministic pseudo-random input for `Unstructured`.
+fn input(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn boundaries() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let data = input(64 * 1024);
+            let mut u = Unstructured::new(&data);
+            let values = (0..1000)
+                .map(|_| FixedPoint::arbitrary(&mut u))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for boundary in [
+                FixedPoint::MIN,
+                FixedPoint::MAX,
+                FixedPoint::ZERO,
+                FixedPoint::EPSILON,
+                FixedPoint::EPSILON.cneg()?,
+                FixedPoint::ONE,
+                FixedPoint::ONE.cneg()?,
+            ] {
+                assert!(values.contains(&boundary), "{} isn't generated", boundary);
+            }
+
+            // Regular values are generated as well.
+            let one = fp!(1);
+            let regular = values.iter().filter(|value| value.abs().map_or(false, |abs| abs > one));
+            assert!(regular.count() > 500);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn exhausted_input() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let mut u = Unstructured::new(&[]);
+            assert_eq!(FixedPoint::arbitrary(&mut u)?, FixedPoint::ZERO);
+
+            let (min, _) = FixedPoint::size_hint(0);
+            assert_eq!(min, 1 + core::mem::size_of::<Layout>());
+        },
+    };
+    Ok(())
+}
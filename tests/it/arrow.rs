@@ -0,0 +1,53 @@
+#![cfg(all(feature = "arrow", feature = "i128"))]
+
+use anyhow::Result;
+use arrow_array::{Array, Decimal128Array};
+use fixnum::{
+    arrow::{from_array, from_value, to_array, to_nullable_array},
+    typenum::{U18, U9},
+    FixedPoint,
+};
+
+type Amount = FixedPoint<i128, U9>;
+
+#[test]
+fn array_roundtrip() -> Result<()> {
+    let values: Vec<Amount> = vec!["0".parse()?, "1.5".parse()?, "-0.000000001".parse()?];
+    let array = to_array(&values);
+    assert_eq!((array.precision(), array.scale()), (38, 9));
+    assert_eq!(array.values()[1], 1_500_000_000);
+
+    let decoded = from_array::<U9>(&array).collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(decoded, values.into_iter().map(Some).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn nulls() -> Result<()> {
+    let values = vec![Some(Amount::ONE), None, Some(Amount::MAX)];
+    let array = to_nullable_array(values.clone());
+    assert_eq!(array.null_count(), 1);
+
+    let decoded = from_array::<U9>(&array).collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(decoded, values);
+    Ok(())
+}
+
+#[test]
+fn rescale() -> Result<()> {
+    assert_eq!(from_value::<U9>(15, 1)?, "1.5".parse()?);
+    assert_eq!(from_value::<U9>(15, -2)?, "1500".parse()?);
+    assert_eq!(from_value::<U9>(15_000_000_000, 10)?, "1.5".parse()?);
+    assert!(from_value::<U9>(15, 10).is_err());
+    assert!(from_value::<U9>(1, 40).is_err());
+    assert!(from_value::<U9>(i128::MAX, -1).is_err());
+
+    let array =
+        Decimal128Array::from(vec![Some(1), None, Some(25)]).with_precision_and_scale(38, 1)?;
+    let decoded = from_array::<U18>(&array).collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(decoded, [Some("0.1".parse()?), None, Some("2.5".parse()?)]);
+
+    let array = Decimal128Array::from(vec![1]).with_precision_and_scale(38, 20)?;
+    assert!(from_array::<U18>(&array).next().unwrap().is_err());
+    Ok(())
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "async-graphql")]
+
+use anyhow::Result;
+use async_graphql::{
+    value, EmptyMutation, EmptySubscription, Number, Object, ScalarType, Schema, Value,
+};
+
+#[test]
+fn parse_to_value() -> Result<()> {
+    test_fixed_point! {
+        case (input: Value, expected: FixedPoint) => {
+            assert!(<FixedPoint as ScalarType>::is_valid(&input));
+            assert_eq!(<FixedPoint as ScalarType>::parse(input).ok(), Some(expected));
+            assert_eq!(expected.to_value(), Value::String(expected.to_string()));
+        },
+        all {
+            (Value::from("1.5"), fp!(1.5));
+            (Value::from("-0.1e1"), fp!(-1));
+            (Value::from(42), fp!(42));
+            (Value::from(-7), fp!(-7));
+            (Value::Number(Number::from_f64(0.1).unwrap()), fp!(0.1));
+            (Value::Number(Number::from_f64(-1e-7).unwrap()), fp!(-0.0000001));
+            (Value::Number(Number::from_f64(1234.5678).unwrap()), fp!(1234.5678));
+        },
+    };
+    test_fixed_point! {
+        case (input: Value) => {
+            assert!(<FixedPoint as ScalarType>::parse(input).is_err());
+        },
+        all {
+            (Value::from("abc"));
+            (Value::from(true));
+            (Value::Null);
+            (Value::Number(Number::from_f64(1e30).unwrap()));
+        },
+    };
+    Ok(())
+}
+
+#[cfg(all(feature = "i64", feature = "i128"))]
+#[test]
+fn schema() {
+    use fixnum::{
+        typenum::{U18, U9},
+        FixedPoint,
+    };
+
+    type Price = FixedPoint<i64, U9>;
+    type Amount = FixedPoint<i128, U18>;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn price(&self, price: Price) -> Price {
+            price
+        }
+
+        async fn amount(&self, amount: Amount) -> Amount {
+            amount
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+    assert!(sdl.contains("scalar FixedPoint_i64_9"));
+    assert!(sdl.contains("scalar FixedPoint_i128_18"));
+
+    let query = r#"{ price(price: "1.25") fromNumber: price(price: 0.5) amount(amount: 1) }"#;
+    let response = futures_lite::future::block_on(schema.execute(query));
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "price": "1.25", "fromNumber": "0.5", "amount": "1.0" })
+    );
+
+    let response = futures_lite::future::block_on(schema.execute("{ price(price: true) }"));
+    assert_eq!(response.errors.len(), 1);
+}
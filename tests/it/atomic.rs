@@ -0,0 +1,86 @@
+#![cfg(feature = "i64")]
+
+use std::sync::atomic::Ordering::*;
+
+use anyhow::Result;
+use fixnum::{typenum::U9, ArithmeticError, AtomicFixedPoint, FixedPoint};
+
+type Amount = FixedPoint<i64, U9>;
+
+fn fp(str: &str) -> Amount {
+    str.parse().unwrap()
+}
+
+#[test]
+fn load_store() {
+    let atomic = AtomicFixedPoint::<i64, U9>::default();
+    assert_eq!(atomic.load(SeqCst), Amount::ZERO);
+
+    atomic.store(fp("1.5"), Release);
+    assert_eq!(atomic.load(Acquire), fp("1.5"));
+    assert_eq!(atomic.swap(fp("-2"), AcqRel), fp("1.5"));
+    assert_eq!(format!("{:?}", atomic), format!("{:?}", fp("-2")));
+
+    assert_eq!(
+        atomic.compare_exchange(fp("1"), fp("3"), SeqCst, SeqCst),
+        Err(fp("-2"))
+    );
+    assert_eq!(
+        atomic.compare_exchange(fp("-2"), fp("3"), SeqCst, SeqCst),
+        Ok(fp("-2"))
+    );
+    assert_eq!(
+        AtomicFixedPoint::from(fp("3")).into_inner(),
+        atomic.into_inner()
+    );
+}
+
+#[test]
+fn fetch_checked() -> Result<()> {
+    let atomic = AtomicFixedPoint::new(fp("1.5"));
+    assert_eq!(atomic.fetch_add_checked(fp("0.25"), AcqRel)?, fp("1.5"));
+    assert_eq!(atomic.fetch_sub_checked(fp("2"), Release)?, fp("1.75"));
+    assert_eq!(atomic.load(Relaxed), fp("-0.25"));
+
+    atomic.store(Amount::MAX, SeqCst);
+    assert_eq!(
+        atomic.fetch_add_checked(Amount::EPSILON, SeqCst),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(atomic.load(SeqCst), Amount::MAX);
+
+    atomic.store(Amount::MIN, SeqCst);
+    assert_eq!(
+        atomic.fetch_sub_checked(Amount::EPSILON, SeqCst),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(atomic.load(SeqCst), Amount::MIN);
+    Ok(())
+}
+
+#[test]
+fn fetch_max_min() {
+    let atomic = AtomicFixedPoint::new(fp("1.5"));
+    assert_eq!(atomic.fetch_max(fp("1"), SeqCst), fp("1.5"));
+    assert_eq!(atomic.fetch_max(fp("2"), SeqCst), fp("1.5"));
+    assert_eq!(atomic.fetch_min(fp("-0.5"), SeqCst), fp("2"));
+    assert_eq!(atomic.fetch_min(fp("0"), SeqCst), fp("-0.5"));
+    assert_eq!(atomic.load(SeqCst), fp("-0.5"));
+}
+
+#[test]
+fn concurrent_add() {
+    let atomic = AtomicFixedPoint::new(Amount::ZERO);
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..1000 {
+                    atomic.fetch_add_checked(fp("0.001"), AcqRel).unwrap();
+                }
+            });
+        }
+    });
+
+    assert_eq!(atomic.into_inner(), fp("4"));
+}
@@ -0,0 +1,134 @@
+#![cfg(any(feature = "i64", feature = "i128"))]
+
+use anyhow::Result;
+use fixnum::{ops::RoundMode::*, ArithmeticError, Ema, RunningMean, Vwap};
+
+#[test]
+fn ema() -> Result<()> {
+    test_fixed_point! {
+        case (alpha: FixedPoint, values: &[FixedPoint], expected: FixedPoint) => {
+            let mut ema = Ema::new(alpha)?;
+            assert_eq!(ema.alpha(), alpha);
+            assert_eq!(ema.value(), None);
+
+            let mut last = None;
+            for &value in values {
+                last = Some(ema.update(value, Nearest)?);
+            }
+            assert_eq!(last, Some(expected));
+            assert_eq!(ema.value(), Some(expected));
+        },
+        all {
+            (fp!(0.5), &[fp!(3)], fp!(3));
+            (fp!(1), &[fp!(3), fp!(7), fp!(-2)], fp!(-2));
+            (fp!(0.5), &[fp!(10), fp!(20), fp!(0)], fp!(7.5));
+            (fp!(0.25), &[fp!(10), fp!(14), fp!(12)], fp!(11.25));
+            (fp!(0.5), &[FixedPoint::MAX, FixedPoint::MAX], FixedPoint::MAX);
+            (fp!(0.5), &[FixedPoint::MIN, FixedPoint::MAX], FixedPoint::EPSILON.cneg()?);
+        },
+        fp64 {
+            (fp!(0.5), &[fp!(0), fp!(0.000000001)], fp!(0.000000001));
+            (fp!(0.1), &[fp!(1), fp!(0.000000004)], fp!(0.9));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let values = [fp!(0), fp!(0.5).cneg()?];
+            let mut floor = Ema::new(FixedPoint::EPSILON)?;
+            let mut ceil = floor;
+            for value in values {
+                floor.update(value, Floor)?;
+                ceil.update(value, Ceil)?;
+            }
+            assert_eq!(floor.value(), Some(FixedPoint::EPSILON.cneg()?));
+            assert_eq!(ceil.value(), Some(FixedPoint::ZERO));
+        },
+    };
+    test_fixed_point! {
+        case (alpha: FixedPoint) => {
+            assert_eq!(Ema::new(alpha).err(), Some(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(0));
+            (fp!(1.000000001));
+            (FixedPoint::EPSILON.cneg()?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn running_mean() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            let mut mean = RunningMean::new();
+            for &value in values {
+                mean.add(value)?;
+            }
+            assert_eq!(mean.count(), values.len() as u64);
+            assert_eq!(mean.mean(Nearest)?, expected);
+        },
+        all {
+            (&[fp!(1)], fp!(1));
+            (&[fp!(1), fp!(2), fp!(4), fp!(10)], fp!(4.25));
+            (&[fp!(1.5), fp!(-2.5)], fp!(-0.5));
+            (&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX], FixedPoint::MAX);
+            (&[FixedPoint::MIN, FixedPoint::MIN], FixedPoint::MIN);
+        },
+        fp64 {
+            (&[fp!(1), fp!(1), fp!(2)], fp!(1.333333333));
+        },
+        fp128 {
+            (&[fp!(1), fp!(1), fp!(2)], fp!(1.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let mut mean = RunningMean::<FixedPoint>::default();
+            assert_eq!(mean.mean(Nearest), Err(ArithmeticError::DivisionByZero));
+
+            mean.add(fp!(1))?;
+            mean.add(fp!(2))?;
+            assert_eq!(mean.mean(Floor)?, fp!(1.5));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn vwap() -> Result<()> {
+    test_fixed_point! {
+        case (trades: &[(FixedPoint, FixedPoint)], volume: FixedPoint, expected: FixedPoint) => {
+            let mut vwap = Vwap::new();
+            for &(price, volume) in trades {
+                vwap.add(price, volume)?;
+            }
+            assert_eq!(vwap.volume()?, volume);
+            assert_eq!(vwap.vwap(Nearest)?, expected);
+        },
+        all {
+            (&[(fp!(100), fp!(1))], fp!(1), fp!(100));
+            (&[(fp!(100), fp!(1)), (fp!(101), fp!(3))], fp!(4), fp!(100.75));
+            (&[(fp!(10), fp!(0.5)), (fp!(20), fp!(0)), (fp!(40), fp!(0.5))], fp!(1), fp!(25));
+            (&[(FixedPoint::MAX, fp!(2)), (FixedPoint::MAX, fp!(3))], fp!(5), FixedPoint::MAX);
+        },
+        fp64 {
+            (&[(fp!(0), fp!(2)), (fp!(1), fp!(1))], fp!(3), fp!(0.333333333));
+        },
+        fp128 {
+            (&[(fp!(0), fp!(2)), (fp!(1), fp!(1))], fp!(3), fp!(0.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let mut vwap = Vwap::<FixedPoint>::default();
+            assert_eq!(vwap.vwap(Nearest), Err(ArithmeticError::DivisionByZero));
+
+            vwap.add(fp!(1), FixedPoint::MAX)?;
+            vwap.add(fp!(3), FixedPoint::MAX)?;
+            assert_eq!(vwap.volume(), Err(ArithmeticError::Overflow));
+            assert_eq!(vwap.vwap(Nearest)?, fp!(2));
+        },
+    };
+    Ok(())
+}
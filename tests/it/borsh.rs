@@ -0,0 +1,29 @@
+#![cfg(feature = "borsh")]
+
+use anyhow::Result;
+
+#[test]
+fn roundtrip() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let bytes = borsh::to_vec(&x)?;
+            assert_eq!(bytes, borsh::to_vec(x.as_bits())?);
+            assert_eq!(borsh::from_slice::<FixedPoint>(&bytes)?, x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-1.5));
+            (FixedPoint::MIN);
+            (FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let bytes = borsh::to_vec(&fp!(1))?;
+            assert_eq!(bytes.len(), core::mem::size_of::<Layout>());
+            assert!(borsh::from_slice::<FixedPoint>(&bytes[1..]).is_err());
+        },
+    };
+    Ok(())
+}
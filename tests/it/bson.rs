@@ -0,0 +1,130 @@
+#![cfg(all(feature = "bson", any(feature = "i64", feature = "i128")))]
+
+use core::convert::TryFrom;
+
+use anyhow::Result;
+use bson::{doc, Bson, Decimal128};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn from_to_decimal128() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: &str) => {
+            let decimal = Decimal128::from(x);
+            assert_eq!(decimal.to_string(), expected);
+            assert_eq!(FixedPoint::try_from(decimal)?, x);
+        },
+        fp64 {
+            (fp!(0), "0E-9");
+            (fp!(1.5), "1.500000000");
+            (fp!(-1.5), "-1.500000000");
+            (FixedPoint::EPSILON, "1E-9");
+            (FixedPoint::MIN, "-9223372036.854775808");
+            (FixedPoint::MAX, "9223372036.854775807");
+        },
+        fp128 {
+            (fp!(0), "0E-18");
+            (fp!(1.5), "1.500000000000000000");
+            (fp!(-0.000000000000000001), "-1E-18");
+            (fp!(1234567890123456.123456789012345678), "1234567890123456.123456789012345678");
+        },
+    };
+    test_fixed_point! {
+        case (input: &str, expected: FixedPoint) => {
+            let decimal: Decimal128 = input.parse()?;
+            assert_eq!(FixedPoint::try_from(decimal)?, expected);
+        },
+        all {
+            ("0", fp!(0));
+            ("-0", fp!(0));
+            ("0E+6000", fp!(0));
+            ("0E-6000", fp!(0));
+            ("1.50000000000000000000000000000", fp!(1.5));
+            ("15E-1", fp!(1.5));
+            ("-1.5E+3", fp!(-1500));
+            ("1E-9", fp!(0.000000001));
+        },
+    };
+    test_fixed_point! {
+        case (input: &str) => {
+            let decimal: Decimal128 = input.parse()?;
+            assert!(FixedPoint::try_from(decimal).is_err());
+        },
+        all {
+            ("NaN", );
+            ("Infinity", );
+            ("-Infinity", );
+            ("1E-19", );
+            ("1E+21", );
+            ("1E+6000", );
+            ("1.000000000000000000000000000000001", );
+        },
+        fp64 {
+            ("1E-10", );
+            ("9223372036.854775808", );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rounding() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: &str) => {
+            assert_eq!(Decimal128::from(x).to_string(), expected);
+        },
+        fp128 {
+            (FixedPoint::MAX, "170141183460469231731.6873037158841");
+            (FixedPoint::MIN, "-170141183460469231731.6873037158841");
+            (fp!(12345678901234567890.123456789012345678), "12345678901234567890.12345678901235");
+            (fp!(99999999999999999999.999999999999999999), "100000000000000000000.0000000000000");
+            (fp!(10000000000000000000.000000000000005000), "10000000000000000000.00000000000000");
+            (fp!(10000000000000000000.000000000000015000), "10000000000000000000.00000000000002");
+            (fp!(10000000000000000000.000000000000005001), "10000000000000000000.00000000000001");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn serde_with() -> Result<()> {
+    test_fixed_point! {
+        case (amount: FixedPoint, fee: Option<FixedPoint>) => {
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            struct Order {
+                #[serde(with = "fixnum::bson::decimal128")]
+                amount: FixedPoint,
+                #[serde(with = "fixnum::bson::decimal128_option")]
+                fee: Option<FixedPoint>,
+            }
+
+            let order = Order { amount, fee };
+            let document = bson::to_document(&order)?;
+            assert_eq!(document.get("amount"), Some(&Bson::Decimal128(amount.into())));
+            assert_eq!(
+                document.get("fee"),
+                Some(&fee.map_or(Bson::Null, |fee| Bson::Decimal128(fee.into())))
+            );
+            assert_eq!(bson::from_document::<Order>(document)?, order);
+        },
+        all {
+            (fp!(1.5), Some(fp!(0.001)));
+            (fp!(-42), None);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            #[derive(Debug, Deserialize)]
+            struct Order {
+                #[serde(with = "fixnum::bson::decimal128")]
+                #[allow(dead_code)]
+                amount: FixedPoint,
+            }
+
+            let nan: Decimal128 = "NaN".parse()?;
+            assert!(bson::from_document::<Order>(doc! { "amount": nan }).is_err());
+            assert!(bson::from_document::<Order>(doc! { "amount": "1.5" }).is_err());
+        },
+    };
+    Ok(())
+}
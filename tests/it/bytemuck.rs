@@ -0,0 +1,28 @@
+#![cfg(feature = "bytemuck")]
+
+use anyhow::Result;
+use bytemuck::{TransparentWrapper, Zeroable};
+
+#[test]
+fn cast_slice() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let raw: Vec<Layout> = vec![0, 1, FixedPoint::ONE.into_bits(), Layout::MIN, Layout::MAX];
+            let values: &[FixedPoint] = bytemuck::cast_slice(&raw);
+            assert_eq!(
+                values,
+                [FixedPoint::ZERO, FixedPoint::EPSILON, FixedPoint::ONE, FixedPoint::MIN, FixedPoint::MAX]
+            );
+            assert_eq!(FixedPoint::wrap_slice(&raw), values);
+            assert_eq!(FixedPoint::peel_slice(values), &raw[..]);
+
+            let bytes: &[u8] = bytemuck::cast_slice(values);
+            assert_eq!(bytes.len(), raw.len() * core::mem::size_of::<Layout>());
+            assert_eq!(bytemuck::cast_slice::<u8, FixedPoint>(bytes), values);
+
+            assert_eq!(FixedPoint::zeroed(), FixedPoint::ZERO);
+            assert_eq!(FixedPoint::wrap(Layout::MAX), FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
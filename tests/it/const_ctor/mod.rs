@@ -12,10 +12,97 @@ fn valid() {
 
     const SAMPLE2: F64p9 = fixnum_const!(42., 9);
     assert_eq!(SAMPLE2, F64p9::from_decimal(42, 0).unwrap());
+
+    const SAMPLE3: F64p9 = fixnum_const!(1.5e-3, 9);
+    assert_eq!(SAMPLE3, F64p9::from_decimal(15, -4).unwrap());
+
+    const SAMPLE4: F64p9 = fixnum_const!(-2E6, 9);
+    assert_eq!(SAMPLE4, F64p9::from_decimal(-2, 6).unwrap());
+
+    const SAMPLE5: F64p9 = fixnum_const!(1.000e-9, 9);
+    assert_eq!(SAMPLE5, F64p9::EPSILON);
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn consts() {
+    use fixnum::{fixnum_const, FixedPoint};
+    type F64p9 = FixedPoint<i64, typenum::U9>;
+
+    static ZERO: F64p9 = F64p9::ZERO;
+    static ONE: F64p9 = F64p9::ONE;
+    static EPSILON: F64p9 = F64p9::EPSILON;
+    static MIN: F64p9 = F64p9::MIN;
+    static MAX: F64p9 = F64p9::MAX;
+
+    assert_eq!(ZERO, fixnum_const!(0, 9));
+    assert_eq!(ONE, fixnum_const!(1, 9));
+    assert_eq!(EPSILON, fixnum_const!(0.000000001, 9));
+    assert_eq!(MIN, F64p9::from_bits(i64::MIN));
+    assert_eq!(MAX, F64p9::from_bits(i64::MAX));
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn const_arithmetic() {
+    use fixnum::{fixnum_const, ArithmeticError, FixedPoint};
+    type F64p9 = FixedPoint<i64, typenum::U9>;
+
+    const fn unwrap(result: Result<F64p9, ArithmeticError>) -> F64p9 {
+        match result {
+            Ok(value) => value,
+            Err(_) => panic!("overflow"),
+        }
+    }
+
+    const BASE: F64p9 = match F64p9::from_decimal(15, -1) {
+        Ok(value) => value,
+        Err(_) => panic!("invalid decimal"),
+    };
+
+    static TICKS: [F64p9; 3] = [
+        BASE,
+        unwrap(BASE.const_cadd(F64p9::ONE)),
+        unwrap(unwrap(BASE.const_cmul(3)).const_csub(F64p9::EPSILON)),
+    ];
+
+    assert_eq!(BASE, fixnum_const!(1.5, 9));
+    assert_eq!(TICKS[1], fixnum_const!(2.5, 9));
+    assert_eq!(TICKS[2], fixnum_const!(4.499999999, 9));
+
+    assert_eq!(
+        F64p9::MAX.const_cadd(F64p9::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        F64p9::MIN.const_csub(F64p9::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(F64p9::MAX.const_cmul(2), Err(ArithmeticError::Overflow));
+    assert!(F64p9::from_decimal(i64::MAX, 0).is_err());
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn const_clamp() {
+    use fixnum::{fixnum_const, FixedPoint};
+    type F64p9 = FixedPoint<i64, typenum::U9>;
+
+    const LO: F64p9 = fixnum_const!(0.5, 9);
+    const HI: F64p9 = fixnum_const!(2, 9);
+    const LIMITS: [F64p9; 4] = [
+        F64p9::MIN.clamp(LO, HI),
+        F64p9::MAX.clamp(LO, HI),
+        LO.min(HI),
+        LO.max(HI),
+    ];
+
+    assert_eq!(LIMITS, [LO, HI, LO, HI]);
 }
 
 #[test]
 fn too_long_fractional() {
     let test_cases = trybuild::TestCases::new();
     test_cases.compile_fail("tests/it/const_ctor/too_long_fractional.rs");
+    test_cases.compile_fail("tests/it/const_ctor/too_long_fractional_exponent.rs");
 }
@@ -0,0 +1,9 @@
+// Ensure that `fixnum_const` function fails compilation with first argument of incorrect format:
+// with a number in exponent notation, which can't be represented exactly.
+
+use fixnum::{fixnum_const, typenum::U9, FixedPoint};
+
+#[allow(dead_code)]
+const VALUE: FixedPoint<i64, U9> = fixnum_const!(1.5e-9, 9);
+
+fn main() {}
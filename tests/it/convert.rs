@@ -2,8 +2,6 @@ use anyhow::Result;
 #[cfg(feature = "i128")]
 use proptest::prelude::*;
 
-use fixnum::ops::Bounded;
-
 #[test]
 fn from_decimal() -> Result<()> {
     test_fixed_point! {
@@ -55,6 +53,110 @@ fn to_decimal() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn try_into_int() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: Option<i64>) => {
+            assert_eq!(x.try_into_int::<i64>().ok(), expected);
+            if let Some(expected) = expected {
+                assert_eq!(FixedPoint::try_from_int(expected)?, x);
+            }
+        },
+        all {
+            (fp!(0), Some(0));
+            (fp!(42), Some(42));
+            (fp!(-42), Some(-42));
+            (fp!(0.5), None);
+            (fp!(-1.000000001), None);
+            (FixedPoint::EPSILON, None);
+        },
+        fp128 {
+            (fp!(10000000000000000000), None);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(255).try_into_int::<u8>()?, 255);
+            assert!(fp!(256).try_into_int::<u8>().is_err());
+            assert!(fp!(1).cneg()?.try_into_int::<u64>().is_err());
+            assert!(FixedPoint::try_from_int(u128::MAX).is_err());
+            assert!(FixedPoint::try_from_int(Layout::MAX).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn le_be_bytes() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let bits = x.into_bits();
+            assert_eq!(x.to_le_bytes(), bits.to_le_bytes());
+            assert_eq!(x.to_be_bytes(), bits.to_be_bytes());
+            assert_eq!(FixedPoint::from_le_bytes(x.to_le_bytes()), x);
+            assert_eq!(FixedPoint::from_be_bytes(x.to_be_bytes()), x);
+        },
+        all {
+            (FixedPoint::MIN);
+            (fp!(-1.5));
+            (FixedPoint::ZERO);
+            (fp!(1000.25));
+            (FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let mut expected = [0; core::mem::size_of::<Layout>()];
+            expected[0] = 1;
+            assert_eq!(FixedPoint::EPSILON.to_le_bytes(), expected);
+            expected.reverse();
+            assert_eq!(FixedPoint::EPSILON.to_be_bytes(), expected);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sortable_bytes() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint]) => {
+            for pair in values.windows(2) {
+                assert!(pair[0] < pair[1]);
+                assert!(pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes());
+            }
+            for &x in values {
+                let bytes = x.to_sortable_bytes();
+                assert_eq!(bytes.len(), core::mem::size_of::<Layout>());
+                assert_eq!(FixedPoint::from_sortable_bytes(bytes), x);
+            }
+        },
+        all {
+            (&[
+                FixedPoint::MIN,
+                fp!(-1000.5),
+                fp!(-1),
+                FixedPoint::EPSILON.cneg()?,
+                FixedPoint::ZERO,
+                FixedPoint::EPSILON,
+                fp!(0.5),
+                fp!(1),
+                fp!(1000.5),
+                FixedPoint::MAX,
+            ]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let bytes = FixedPoint::ZERO.to_sortable_bytes();
+            assert_eq!(bytes[0], 0x80);
+            assert!(bytes[1..].iter().all(|&byte| byte == 0));
+            assert!(FixedPoint::MIN.to_sortable_bytes().iter().all(|&byte| byte == 0));
+            assert!(FixedPoint::MAX.to_sortable_bytes().iter().all(|&byte| byte == 0xff));
+        },
+    };
+    Ok(())
+}
+
 #[cfg(feature = "i128")]
 proptest! {
     #[test]
@@ -76,3 +178,356 @@ proptest! {
         }
     }
 }
+
+#[test]
+fn rescale() -> Result<()> {
+    use fixnum::ops::RoundMode::{self, *};
+
+    test_fixed_point! {
+        case (x: FixedPoint, mode: RoundMode, expected: &str) => {
+            let narrowed = x.rescale::<typenum::U2>(mode)?;
+            assert_eq!(narrowed, expected.parse()?);
+
+            let widened = narrowed.rescale::<typenum::U9>(mode)?;
+            assert_eq!(widened.to_string(), narrowed.to_string());
+            assert_eq!(widened.rescale::<typenum::U2>(Floor)?, narrowed);
+        },
+        all {
+            (fp!(0), Floor, "0");
+            (fp!(12.34), Floor, "12.34");
+            (fp!(12.345), Floor, "12.34");
+            (fp!(12.345), Ceil, "12.35");
+            (fp!(12.345), Nearest, "12.35");
+            (fp!(12.345), HalfEven, "12.34");
+            (fp!(12.355), HalfEven, "12.36");
+            (fp!(-12.345), Floor, "-12.35");
+            (fp!(-12.345), Ceil, "-12.34");
+            (fp!(-12.345), Nearest, "-12.35");
+            (fp!(0.001), Ceil, "0.01");
+            (fp!(-0.001), Ceil, "0");
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(x.rescale::<typenum::U0>(Floor)?.rescale(Floor)?, x);
+        },
+        all {
+            (FixedPoint::MAX.floor());
+            (FixedPoint::MIN.ceil());
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let result = x.rescale::<typenum::U18>(Floor);
+            assert_eq!(result, Err(fixnum::ArithmeticError::Overflow));
+        },
+        fp64 {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+            (fp!(10));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn convert_currency() -> Result<()> {
+    use fixnum::ops::{
+        RoundMode::{self, *},
+        RoundingMul,
+    };
+
+    test_fixed_point! {
+        case (amount: FixedPoint, rate: FixedPoint, mode: RoundMode, expected: &str) => {
+            let quote = amount.convert::<_, typenum::U2>(rate, mode)?;
+            assert_eq!(quote, expected.parse()?);
+
+            let same: FixedPoint = amount.convert(rate, mode)?;
+            assert_eq!(same, amount.rmul(rate, mode)?);
+        },
+        all {
+            (fp!(0), fp!(1.5), Floor, "0");
+            (fp!(1234.56), fp!(1.084321), Floor, "1338.65");
+            (fp!(1234.56), fp!(1.084321), Ceil, "1338.66");
+            (fp!(1234.56), fp!(1.084321), Nearest, "1338.66");
+            (fp!(-1234.56), fp!(1.084321), Floor, "-1338.66");
+            (fp!(-1234.56), fp!(1.084321), Ceil, "-1338.65");
+            (fp!(0.05), fp!(0.1), Nearest, "0.01");
+            (fp!(0.05), fp!(0.1), HalfEven, "0");
+            (fp!(0.000000001), fp!(0.000000001), Ceil, "0.01");
+        },
+    };
+    test_fixed_point! {
+        case (amount: FixedPoint, rate: FixedPoint, mode: RoundMode, expected: &str) => {
+            let amount = amount.rescale::<typenum::U2>(Floor)?;
+            let rate = rate.rescale::<typenum::U4>(Floor)?;
+            assert_eq!(amount.convert::<_, typenum::U0>(rate, mode)?, expected.parse()?);
+            assert_eq!(
+                amount.convert::<_, typenum::U9>(rate, mode)?,
+                amount.rescale::<typenum::U9>(mode)?.rmul(rate.rescale(mode)?, mode)?,
+            );
+        },
+        all {
+            (fp!(1234.56), fp!(162.3456), Floor, "200425");
+            (fp!(1234.56), fp!(162.3456), Ceil, "200426");
+            (fp!(0.5), fp!(1), Nearest, "1");
+            (fp!(0.5), fp!(1), HalfEven, "0");
+        },
+    };
+    test_fixed_point! {
+        case (amount: FixedPoint, rate: FixedPoint) => {
+            let result = amount.convert::<_, typenum::U18>(rate, Floor);
+            assert_eq!(result, Err(fixnum::ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MAX, fp!(2));
+            (FixedPoint::MIN, fp!(1.5));
+        },
+        fp64 {
+            (FixedPoint::MAX, fp!(1));
+            (fp!(10), fp!(1));
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn rust_decimal() -> Result<()> {
+    use rust_decimal::Decimal;
+
+    test_fixed_point! {
+        case (decimal: &str, expected: FixedPoint) => {
+            let decimal: Decimal = decimal.parse()?;
+            assert_eq!(FixedPoint::try_from(decimal)?, expected);
+            assert_eq!(Decimal::try_from(expected)?, decimal);
+        },
+        all {
+            ("0", fp!(0));
+            ("1.5", fp!(1.5));
+            ("-1.5", fp!(-1.5));
+            ("0.000000001", fp!(0.000000001));
+            ("-9223372036.854775807", fp!(-9223372036.854775807));
+        },
+        fp128 {
+            ("0.000000000000000001", fp!(0.000000000000000001));
+            ("-79228162514.264337593543950335", fp!(-79228162514.264337593543950335));
+        },
+    };
+    test_fixed_point! {
+        case (decimal: &str, expected: FixedPoint) => {
+            // Trailing zeros beyond the precision are dropped.
+            let decimal: Decimal = decimal.parse()?;
+            assert_eq!(FixedPoint::try_from(decimal)?, expected);
+        },
+        all {
+            ("1.5000000000000000000000000000", fp!(1.5));
+            ("-42.000000000000000000", fp!(-42));
+        },
+    };
+    test_fixed_point! {
+        case (decimal: &str, error: &str) => {
+            let decimal: Decimal = decimal.parse()?;
+            let result = FixedPoint::try_from(decimal);
+            assert_eq!(result.map_err(|err| err.as_str()), Err(error));
+        },
+        all {
+            ("0.0000000000000000001", "requested precision is too high");
+            ("79228162514264337593543950335", "too big number");
+        },
+        fp64 {
+            ("0.0000000001", "requested precision is too high");
+            ("9223372036.854775808", "too big number");
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert!(Decimal::try_from(x).is_err());
+        },
+        fp128 {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn bigdecimal() -> Result<()> {
+    use bigdecimal::BigDecimal;
+    use fixnum::ops::RoundMode::{self, *};
+
+    test_fixed_point! {
+        case (decimal: &str, expected: FixedPoint) => {
+            let decimal: BigDecimal = decimal.parse()?;
+            assert_eq!(FixedPoint::try_from(decimal.clone())?, expected);
+            assert_eq!(BigDecimal::from(expected), decimal);
+        },
+        all {
+            ("0", fp!(0));
+            ("1.5", fp!(1.5));
+            ("-1.5", fp!(-1.5));
+            ("0.000000001", fp!(0.000000001));
+            ("1.5000000000000000000000000000", fp!(1.5));
+            ("-42e3", fp!(-42000));
+            ("9223372036.854775807", fp!(9223372036.854775807));
+            ("-9223372036.854775808", fp!(-9223372036.854775808));
+        },
+        fp128 {
+            ("0.000000000000000001", fp!(0.000000000000000001));
+            ("170141183460469231731.687303715884105727", fp!(170141183460469231731.687303715884105727));
+            ("-170141183460469231731.687303715884105728", fp!(-170141183460469231731.687303715884105728));
+        },
+    };
+    test_fixed_point! {
+        case (decimal: &str, error: &str) => {
+            let decimal: BigDecimal = decimal.parse()?;
+            let result = FixedPoint::try_from(decimal);
+            assert_eq!(result.map_err(|err| err.as_str()), Err(error));
+        },
+        all {
+            ("0.0000000000000000001", "requested precision is too high");
+            ("170141183460469231732", "too big number");
+            ("1e1000000000", "too big number");
+        },
+        fp64 {
+            ("0.0000000001", "requested precision is too high");
+            ("9223372036.854775808", "too big number");
+        },
+    };
+    test_fixed_point! {
+        case (decimal: &str, mode: RoundMode, expected: FixedPoint) => {
+            let decimal: BigDecimal = decimal.parse()?;
+            assert_eq!(FixedPoint::from_big_decimal(&decimal, mode)?, expected);
+        },
+        fp64 {
+            ("0.0000000015", Ceil, fp!(0.000000002));
+            ("0.0000000015", Floor, fp!(0.000000001));
+            ("0.0000000015", Nearest, fp!(0.000000002));
+            ("0.0000000015", HalfEven, fp!(0.000000002));
+            ("0.0000000025", HalfEven, fp!(0.000000002));
+            ("-0.0000000015", Ceil, fp!(-0.000000001));
+            ("-0.0000000015", Floor, fp!(-0.000000002));
+            ("-0.0000000015", Nearest, fp!(-0.000000002));
+            ("-0.0000000025", HalfEven, fp!(-0.000000002));
+            ("0.00000000049", Nearest, fp!(0));
+            ("1e-1000000000", Ceil, fp!(0.000000001));
+        },
+        fp128 {
+            ("0.0000000000000000015", Ceil, fp!(0.000000000000000002));
+            ("0.0000000000000000015", Floor, fp!(0.000000000000000001));
+            ("-0.0000000000000000025", HalfEven, fp!(-0.000000000000000002));
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn num_rational() -> Result<()> {
+    use fixnum::ops::RoundMode::{self, *};
+    use num_rational::Ratio;
+
+    test_fixed_point! {
+        case (numer: i128, denom: i128, expected: FixedPoint) => {
+            let ratio = Ratio::new(numer, denom);
+            assert_eq!(FixedPoint::try_from(ratio)?, expected);
+            assert_eq!(Ratio::from(expected), ratio);
+        },
+        all {
+            (0, 1, fp!(0));
+            (3, 2, fp!(1.5));
+            (-1, 4, fp!(-0.25));
+            (1, 1_000_000_000, fp!(0.000000001));
+            (-10, -5, fp!(2));
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(FixedPoint::try_from(Ratio::from(x))?, x);
+        },
+        all {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+            (FixedPoint::EPSILON);
+        },
+    };
+    test_fixed_point! {
+        case (numer: i128, denom: i128, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_ratio(&Ratio::new(numer, denom), mode)?, expected);
+        },
+        all {
+            (1, i128::MAX, Floor, fp!(0));
+            (1, i128::MAX, Ceil, FixedPoint::EPSILON);
+            (-1, i128::MAX, Ceil, fp!(0));
+            (-1, i128::MAX, Floor, FixedPoint::EPSILON.cneg()?);
+            (7, 2, Nearest, fp!(3.5));
+        },
+        fp64 {
+            (1, 3, Floor, fp!(0.333333333));
+            (1, 3, Ceil, fp!(0.333333334));
+            (-2, 3, Nearest, fp!(-0.666666667));
+        },
+        fp128 {
+            (1, 3, Floor, fp!(0.333333333333333333));
+            (1, 3, Ceil, fp!(0.333333333333333334));
+            (-2, 3, Nearest, fp!(-0.666666666666666667));
+        },
+    };
+    test_fixed_point! {
+        case (ratio: Ratio<i128>, error: &str) => {
+            let result = FixedPoint::try_from(ratio);
+            assert_eq!(result.map_err(|err| err.as_str()), Err(error));
+        },
+        all {
+            (Ratio::new(1, 3), "requires rounding");
+            (Ratio::new_raw(1, 0), "zero denominator");
+            (Ratio::from_integer(i128::MAX), "too big number");
+            (Ratio::from_integer(i128::MIN), "too big number");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "i128"))]
+fn between_layouts() -> Result<()> {
+    use fixnum::{
+        ops::{CheckedAdd, CheckedSub},
+        typenum::U9,
+        FixedPoint,
+    };
+
+    type Fp64 = FixedPoint<i64, U9>;
+    type Fp128 = FixedPoint<i128, U9>;
+
+    for value in [
+        Fp64::MIN,
+        "-1.5".parse()?,
+        Fp64::ZERO,
+        "0.000000001".parse()?,
+        Fp64::MAX,
+    ] {
+        let wide: Fp128 = value.into_layout();
+        assert_eq!(wide.into_bits(), i128::from(value.into_bits()));
+        assert_eq!(wide.try_into_layout::<i64>()?, value);
+    }
+
+    let too_big = Fp64::MAX.into_layout::<i128>().cadd(Fp128::EPSILON)?;
+    assert!(too_big.try_into_layout::<i64>().is_err());
+    let too_small = Fp64::MIN.into_layout::<i128>().csub(Fp128::EPSILON)?;
+    assert!(too_small.try_into_layout::<i64>().is_err());
+
+    #[cfg(all(feature = "i16", feature = "i32"))]
+    {
+        type Fp16 = FixedPoint<i16, fixnum::typenum::U2>;
+        type Fp32 = FixedPoint<i32, fixnum::typenum::U2>;
+
+        let value: Fp16 = "-12.34".parse()?;
+        assert_eq!(value.into_layout::<i32>(), "-12.34".parse::<Fp32>()?);
+        assert_eq!(value.into_layout::<i32>().try_into_layout::<i16>()?, value);
+        assert!("1000".parse::<Fp32>()?.try_into_layout::<i16>().is_err());
+    }
+    Ok(())
+}
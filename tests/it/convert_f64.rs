@@ -161,3 +161,160 @@ proptest! {
         prop_assert_eq!(actual, expected);
     }
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn try_to_f64_exact() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: Option<f64>) => {
+            assert_eq!(x.try_to_f64_exact().ok(), expected);
+            assert_eq!(x.cneg()?.try_to_f64_exact().ok(), expected.map(|x| -x));
+            assert_eq!(x.to_f64_lossy(), f64::from(x));
+        },
+        all {
+            (fp!(0), Some(0.0));
+            (fp!(1), Some(1.0));
+            (fp!(0.5), Some(0.5));
+            (fp!(1234.125), Some(1234.125));
+            (fp!(0.000000001), None);
+            (fp!(0.1), None);
+            (fp!(0.3), None);
+            (fp!(9007199.254740993), None);
+        },
+        fp128 {
+            (fp!(0.000000000000000001), None);
+            (fp!(9007199254740992), Some(9007199254740992.0));
+            (fp!(9007199254740993), None);
+            (fp!(18014398509481984), Some(18014398509481984.0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_f64_lossy() -> Result<()> {
+    use fixnum::ops::RoundMode::{self, *};
+
+    test_fixed_point! {
+        case (x: f64, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_f64_lossy(x, mode)?, expected);
+        },
+        all {
+            (0.0, Floor, fp!(0));
+            (-0.0, Ceil, fp!(0));
+            (0.1, Ceil, fp!(0.1));
+            (1.5, Floor, fp!(1.5));
+            (-1.5, Ceil, fp!(1.5).cneg()?);
+            (1e-30, Floor, fp!(0));
+            (1e-30, Ceil, FixedPoint::EPSILON);
+            (-1e-30, Floor, FixedPoint::EPSILON.cneg()?);
+            (-1e-30, Ceil, fp!(0));
+        },
+        fp64 {
+            (0.1234567891, Floor, fp!(0.123456789));
+            (0.1234567891, Ceil, fp!(0.12345679));
+            (-0.1234567891, Floor, fp!(0.12345679).cneg()?);
+            (0.1234567895, Nearest, fp!(0.12345679));
+            (0.1234567885, HalfEven, fp!(0.123456788));
+        },
+        fp128 {
+            (0.1234567891, Floor, fp!(0.1234567891));
+            (0.1234567891, Ceil, fp!(0.1234567891));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::from_f64_lossy(f64::NAN, Floor), Err(FloatConvertError::NaN));
+            assert_eq!(FixedPoint::from_f64_lossy(f64::INFINITY, Floor), Err(FloatConvertError::Infinite));
+            assert_eq!(FixedPoint::from_f64_lossy(f64::NEG_INFINITY, Floor), Err(FloatConvertError::Infinite));
+            assert_eq!(FixedPoint::from_f64_lossy(1e300, Floor), Err(FloatConvertError::OutOfRange));
+            assert_eq!(FixedPoint::from_f64_lossy(-1e300, Floor), Err(FloatConvertError::OutOfRange));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn try_from_f64_exact() -> Result<()> {
+    test_fixed_point! {
+        case (x: f64, expected: Result<FixedPoint, FloatConvertError>) => {
+            assert_eq!(FixedPoint::try_from_f64_exact(x), expected);
+            assert_eq!(FixedPoint::try_from_f64_exact(-x), expected.and_then(|x| Ok(x.cneg().unwrap())));
+        },
+        all {
+            (0.0, Ok(fp!(0)));
+            (1.0, Ok(fp!(1)));
+            (0.5, Ok(fp!(0.5)));
+            (1234.125, Ok(fp!(1234.125)));
+            (0.001953125, Ok(fp!(0.001953125))); // 2^-9
+            (0.1, Err(FloatConvertError::PrecisionLoss));
+            (1e300, Err(FloatConvertError::OutOfRange));
+            (f64::NAN, Err(FloatConvertError::NaN));
+            (f64::INFINITY, Err(FloatConvertError::Infinite));
+            (f64::MIN_POSITIVE, Err(FloatConvertError::PrecisionLoss));
+            (4294967296.0, Ok(fp!(4294967296)));
+        },
+        fp64 {
+            (0.0009765625, Err(FloatConvertError::PrecisionLoss)); // 2^-10
+            (17179869184.0, Err(FloatConvertError::OutOfRange));
+        },
+        fp128 {
+            (0.000003814697265625, Ok(fp!(0.000003814697265625))); // 2^-18
+            (1e20, Ok(fp!(100000000000000000000)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn to_f32() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: f32) => {
+            assert_eq!(f32::from(x), expected);
+            assert_eq!(f32::from(x.cneg()?), -expected);
+        },
+        all {
+            (fp!(0), 0.0);
+            (fp!(0.1), 0.1);
+            (fp!(1.5), 1.5);
+            (fp!(14.14), 14.14);
+            (fp!(42.123456789), 42.123_455);
+            (fp!(8003332421.536753168), 8_003_332_500.0);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_f32() -> Result<()> {
+    test_fixed_point! {
+        case (x: f32, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::try_from(x)?, expected);
+            assert_eq!(FixedPoint::try_from(-x)?, expected.cneg()?);
+        },
+        all {
+            (0.0, fp!(0));
+            (0.1, fp!(0.1));
+            (1.5, fp!(1.5));
+            (42.12345, fp!(42.12345));
+            (0.3333333, fp!(0.3333333));
+            (16777216.0, fp!(16777216));
+        },
+        fp64 {
+            (1e-10, fp!(0));
+        },
+        fp128 {
+            (1e-10, fp!(0.0000000001));
+            (1e15, fp!(999999986991104));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert!(FixedPoint::try_from(f32::NAN).is_err());
+            assert!(FixedPoint::try_from(f32::INFINITY).is_err());
+            assert!(FixedPoint::try_from(f32::MAX).is_err());
+        },
+    };
+    Ok(())
+}
@@ -4,6 +4,7 @@ extern crate alloc;
 use alloc::format;
 
 use anyhow::Result;
+use fixnum::{ops::RoundMode, ParseErrorKind, ParseOptions};
 #[cfg(feature = "i128")]
 use proptest::prelude::*;
 
@@ -60,6 +61,10 @@ fn from_good_str_exact() -> Result<()> {
 }
 
 #[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
 #[allow(overflowing_literals)]
 fn from_good_str_inexact() -> Result<()> {
     test_fixed_point! {
@@ -111,6 +116,90 @@ fn from_good_str_inexact() -> Result<()> {
 }
 
 #[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
+fn from_str_with_exponent() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_str_exact(input)?, expected);
+            assert_eq!(input.parse::<FixedPoint>()?, expected);
+        },
+        all {
+            ("7.02e5", fp!(702000));
+            ("2E6", fp!(2000000));
+            ("-1.5e-3", fp!(-0.0015));
+            ("+15E+1", fp!(150));
+            ("0.00001234567891e5", fp!(1.234567891));
+            ("1234e-9", fp!(0.000001234));
+            ("0e-300", fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (input: &str, expected: FixedPoint) => {
+            assert!(FixedPoint::from_str_exact(input).is_err());
+            assert_eq!(input.parse::<FixedPoint>()?, expected);
+        },
+        all {
+            ("1.5e-300", fp!(0));
+        },
+        fp64 {
+            ("5e-10", fp!(0.000000001));
+            ("-1.2345678915e-1", fp!(-0.123456789));
+        },
+        fp128 {
+            ("5e-19", fp!(0.000000000000000001));
+            ("-1.23456789012345678915e-1", fp!(-0.123456789012345679));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let ceil = RoundMode::Ceil;
+            assert_eq!(FixedPoint::from_str_with("1e-300", ceil)?, FixedPoint::EPSILON);
+            assert_eq!(FixedPoint::from_str_with("-1e-300", RoundMode::Floor)?, FixedPoint::EPSILON.cneg()?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
+fn from_str_with_round_mode() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, expected: [FixedPoint; 4]) => {
+            assert_eq!(FixedPoint::from_str_with(input, RoundMode::Floor)?, expected[0]);
+            assert_eq!(FixedPoint::from_str_with(input, RoundMode::Ceil)?, expected[1]);
+            assert_eq!(FixedPoint::from_str_with(input, RoundMode::Nearest)?, expected[2]);
+            assert_eq!(FixedPoint::from_str_with(input, RoundMode::HalfEven)?, expected[3]);
+            assert_eq!(input.parse::<FixedPoint>()?, expected[2]);
+        },
+        all {
+            ("1.5", [fp!(1.5), fp!(1.5), fp!(1.5), fp!(1.5)]);
+        },
+        fp64 {
+            ("0.123456789987", [fp!(0.123456789), fp!(0.12345679), fp!(0.12345679), fp!(0.12345679)]);
+            ("-0.123456789987", [fp!(-0.12345679), fp!(-0.123456789), fp!(-0.12345679), fp!(-0.12345679)]);
+            ("0.0000000025", [fp!(0.000000002), fp!(0.000000003), fp!(0.000000003), fp!(0.000000002)]);
+            ("-0.0000000035", [fp!(-0.000000004), fp!(-0.000000003), fp!(-0.000000004), fp!(-0.000000004)]);
+            ("2.5e-9", [fp!(0.000000002), fp!(0.000000003), fp!(0.000000003), fp!(0.000000002)]);
+        },
+        fp128 {
+            ("0.0000000000000000025", [fp!(0.000000000000000002), fp!(0.000000000000000003), fp!(0.000000000000000003), fp!(0.000000000000000002)]);
+            ("-0.1234567890123456789", [fp!(-0.123456789012345679), fp!(-0.123456789012345678), fp!(-0.123456789012345679), fp!(-0.123456789012345679)]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
 fn from_bad_str() -> Result<()> {
     test_fixed_point! {
         case (bad_str: &str) => {
@@ -124,7 +213,14 @@ fn from_bad_str() -> Result<()> {
         },
         all {
             ("");
-            ("7.02e5");
+            ("1.");
+            ("-1.e5");
+            (".5");
+            (".5e1");
+            ("-.5e1");
+            ("7.02e");
+            ("7.02e5.5");
+            ("1e25");
             ("a.12");
             ("12.a");
             ("100000000000000000000000");
@@ -144,6 +240,54 @@ fn from_bad_str() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_bad_str_location() -> Result<()> {
+    use ParseErrorKind::*;
+
+    test_fixed_point! {
+        case (input: &str, kind: ParseErrorKind, position: usize) => {
+            let error = FixedPoint::from_str_exact(input).unwrap_err();
+            assert_eq!(error.kind(), Some(kind), "{}", input);
+            assert_eq!(error.position(), Some(position), "{}", input);
+            assert!(error.to_string().ends_with(&format!(" at byte {}", position)));
+
+            if kind != TooManyFractionalDigits {
+                let inexact = input.parse::<FixedPoint>().unwrap_err();
+                assert_eq!((inexact.kind(), inexact.position()), (Some(kind), Some(position)));
+            }
+        },
+        all {
+            ("", Empty, 0);
+            ("   ", Empty, 3);
+            (" -", Empty, 2);
+            (" 42.", InvalidCharacter, 4);
+            ("12.a", InvalidCharacter, 3);
+            (" 1 000", InvalidCharacter, 2);
+            ("1.5.3", InvalidCharacter, 3);
+            ("--1", InvalidCharacter, 1);
+            (".5", InvalidCharacter, 0);
+            (".5e1", InvalidCharacter, 0);
+            ("-.5E-1", InvalidCharacter, 1);
+            ("1.5\u{20ac}", InvalidCharacter, 3);
+            ("7.02e", InvalidCharacter, 5);
+            ("7.02e5.5", InvalidCharacter, 6);
+            ("1e25", Overflow, 0);
+            ("  100000000000000000000000", Overflow, 2);
+            ("-170141183460469231731.687303715884105729", Overflow, 0);
+        },
+        fp64 {
+            ("0.1234567891", TooManyFractionalDigits, 11);
+            (" 1.5e-9", TooManyFractionalDigits, 3);
+            ("9223372036.854775808", Overflow, 0);
+        },
+        fp128 {
+            ("0.1234567891234567891", TooManyFractionalDigits, 20);
+            (" 1.5e-18", TooManyFractionalDigits, 3);
+        },
+    };
+    Ok(())
+}
+
 #[cfg(feature = "i128")]
 proptest! {
     #[test]
@@ -160,3 +304,407 @@ proptest! {
         prop_assert_eq!(exact, expected);
     }
 }
+
+#[test]
+#[cfg_attr(
+    all(
+        feature = "default-round-nearest",
+        not(feature = "default-round-half-even")
+    ),
+    ignore = "relies on historical rounding modes"
+)]
+fn display_flags() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: [&str; 8]) => {
+            assert_eq!(format!("{}", x), expected[0]);
+            assert_eq!(format!("{:.2}", x), expected[1]);
+            assert_eq!(format!("{:.0}", x), expected[2]);
+            assert_eq!(format!("{:+.3}", x), expected[3]);
+            assert_eq!(format!("{:>10.1}", x), expected[4]);
+            assert_eq!(format!("{:*<10}", x), expected[5]);
+            assert_eq!(format!("{:^11.2}", x), expected[6]);
+            assert_eq!(format!("{:010.2}", x), expected[7]);
+        },
+        all {
+            (fp!(0), ["0.0", "0.00", "0", "+0.000", "       0.0", "0.0*******", "   0.00    ", "0000000.00"]);
+            (fp!(1.5), ["1.5", "1.50", "2", "+1.500", "       1.5", "1.5*******", "   1.50    ", "0000001.50"]);
+            (fp!(2.5), ["2.5", "2.50", "2", "+2.500", "       2.5", "2.5*******", "   2.50    ", "0000002.50"]);
+            (fp!(-12.345), ["-12.345", "-12.34", "-12", "-12.345", "     -12.3", "-12.345***", "  -12.34   ", "-000012.34"]);
+            (fp!(12.355), ["12.355", "12.36", "12", "+12.355", "      12.4", "12.355****", "   12.36   ", "0000012.36"]);
+            (fp!(9.999), ["9.999", "10.00", "10", "+9.999", "      10.0", "9.999*****", "   10.00   ", "0000010.00"]);
+            (fp!(-0.001), ["-0.001", "-0.00", "-0", "-0.001", "      -0.0", "-0.001****", "   -0.00   ", "-000000.00"]);
+            (fp!(0.125001), ["0.125001", "0.13", "0", "+0.125", "       0.1", "0.125001**", "   0.13    ", "0000000.13"]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(format!("{:.12}", fp!(1.5)), "1.500000000000");
+            assert_eq!(format!("{:12}", fp!(1.5)), "         1.5");
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint, expected: &str) => {
+            assert_eq!(format!("{:#}", x), expected);
+            assert_eq!(format!("{:#.2}", x), format!("{:.2}", x));
+            assert_eq!(format!("{:>#25}", x), format!("{:>25}", expected));
+        },
+        fp64 {
+            (fp!(0), "0.000000000");
+            (fp!(42), "42.000000000");
+            (fp!(-12.345), "-12.345000000");
+            (fp!(0.000000001), "0.000000001");
+            (FixedPoint::MIN, "-9223372036.854775808");
+        },
+        fp128 {
+            (fp!(0), "0.000000000000000000");
+            (fp!(42), "42.000000000000000000");
+            (fp!(-12.345), "-12.345000000000000000");
+            (fp!(0.000000000000000001), "0.000000000000000001");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    all(
+        feature = "default-round-nearest",
+        not(feature = "default-round-half-even")
+    ),
+    ignore = "relies on historical rounding modes"
+)]
+fn display_exponent() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: [&str; 6]) => {
+            assert_eq!(format!("{:e}", x), expected[0]);
+            assert_eq!(format!("{:E}", x), expected[1]);
+            assert_eq!(format!("{:.2e}", x), expected[2]);
+            assert_eq!(format!("{:.0e}", x), expected[3]);
+            assert_eq!(format!("{:+>10e}", x), expected[4]);
+            assert_eq!(format!("{:010.1e}", x), expected[5]);
+        },
+        all {
+            (fp!(0), ["0e0", "0E0", "0.00e0", "0e0", "+++++++0e0", "000000.0e0"]);
+            (fp!(1), ["1e0", "1E0", "1.00e0", "1e0", "+++++++1e0", "000001.0e0"]);
+            (fp!(1234.5), ["1.2345e3", "1.2345E3", "1.23e3", "1e3", "++1.2345e3", "000001.2e3"]);
+            (fp!(-0.0015), ["-1.5e-3", "-1.5E-3", "-1.50e-3", "-2e-3", "+++-1.5e-3", "-0001.5e-3"]);
+            (fp!(0.0025), ["2.5e-3", "2.5E-3", "2.50e-3", "2e-3", "++++2.5e-3", "00002.5e-3"]);
+            (fp!(9.999), ["9.999e0", "9.999E0", "1.00e1", "1e1", "+++9.999e0", "000001.0e1"]);
+            (fp!(100), ["1e2", "1E2", "1.00e2", "1e2", "+++++++1e2", "000001.0e2"]);
+            (fp!(0.000000001), ["1e-9", "1E-9", "1.00e-9", "1e-9", "++++++1e-9", "00001.0e-9"]);
+        },
+        fp64 {
+            (FixedPoint::MIN, ["-9.223372036854775808e9", "-9.223372036854775808E9", "-9.22e9", "-9e9", "-9.223372036854775808e9", "-00009.2e9"]);
+        },
+        fp128 {
+            (fp!(0.000000000000000001), ["1e-18", "1E-18", "1.00e-18", "1e-18", "+++++1e-18", "0001.0e-18"]);
+            (fp!(0.000000000000001234), ["1.234e-15", "1.234E-15", "1.23e-15", "1e-15", "+1.234e-15", "0001.2e-15"]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(format!("{:+e}", fp!(1.5)), "+1.5e0");
+            assert_eq!(format!("{:<8e}|", fp!(1.5)), "1.5e0   |");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn debug_alternate() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: &str) => {
+            assert_eq!(format!("{:?}", x), x.to_string());
+            assert_eq!(format!("{:#?}", x), expected);
+        },
+        fp64 {
+            (fp!(0), "FixedPoint<i64, U9>(0.0 = 0)");
+            (fp!(10.042), "FixedPoint<i64, U9>(10.042 = 10042000000)");
+            (fp!(-0.000000001), "FixedPoint<i64, U9>(-0.000000001 = -1)");
+            (fp!(100), "FixedPoint<i64, U9>(100.0 = 100000000000)");
+            (FixedPoint::MIN, "FixedPoint<i64, U9>(-9223372036.854775808 = -9223372036854775808)");
+        },
+        fp128 {
+            (fp!(0), "FixedPoint<i128, U18>(0.0 = 0)");
+            (fp!(10.042), "FixedPoint<i128, U18>(10.042 = 10042000000000000000)");
+            (fp!(-0.5), "FixedPoint<i128, U18>(-0.5 = -500000000000000000)");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn display_bits() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let bits = x.into_bits();
+            assert_eq!(format!("{:x}", x), format!("{:x}", bits));
+            assert_eq!(format!("{:#X}", x), format!("{:#X}", bits));
+            assert_eq!(format!("{:#018b}", x), format!("{:#018b}", bits));
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-1.5));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint, hex: &str, binary: &str) => {
+            assert_eq!(format!("{:X}", x), hex);
+            assert_eq!(format!("{:b}", x), binary);
+        },
+        all {
+            (FixedPoint::EPSILON, "1", "1");
+        },
+        fp64 {
+            (fp!(1), "3B9ACA00", "111011100110101100101000000000");
+            (fp!(-0.000000001), "FFFFFFFFFFFFFFFF", "1111111111111111111111111111111111111111111111111111111111111111");
+        },
+        fp128 {
+            (fp!(0.000000000000000005), "5", "101");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    all(
+        feature = "default-round-nearest",
+        not(feature = "default-round-half-even")
+    ),
+    ignore = "relies on historical rounding modes"
+)]
+fn format_options() -> Result<()> {
+    use fixnum::FormatOptions;
+
+    const THOUSANDS: FormatOptions = FormatOptions::new().grouping(',', 3, 3);
+    const GERMAN: FormatOptions = FormatOptions::new()
+        .decimal_separator(',')
+        .grouping('.', 3, 3);
+    const INDIAN: FormatOptions = FormatOptions::new().grouping(',', 3, 2);
+    const SPACES: FormatOptions = FormatOptions::new().grouping('\u{202f}', 3, 3);
+
+    test_fixed_point! {
+        case (x: FixedPoint, expected: [&str; 6]) => {
+            assert_eq!(format!("{}", x.format(&FormatOptions::DEFAULT)), format!("{}", x));
+            assert_eq!(format!("{}", x.format(&THOUSANDS)), expected[0]);
+            assert_eq!(format!("{:.2}", x.format(&GERMAN)), expected[1]);
+            assert_eq!(format!("{}", x.format(&INDIAN)), expected[2]);
+            assert_eq!(format!("{:+.0}", x.format(&INDIAN)), expected[3]);
+            assert_eq!(format!("{:*>16.1}", x.format(&THOUSANDS)), expected[4]);
+            assert_eq!(format!("{:.0}", x.format(&SPACES)), expected[5]);
+        },
+        all {
+            (fp!(0), ["0.0", "0,00", "0.0", "+0", "*************0.0", "0"]);
+            (fp!(999.5), ["999.5", "999,50", "999.5", "+1,000", "***********999.5", "1\u{202f}000"]);
+            (fp!(-1000), ["-1,000.0", "-1.000,00", "-1,000.0", "-1,000", "********-1,000.0", "-1\u{202f}000"]);
+            (fp!(123456.789), ["123,456.789", "123.456,79", "1,23,456.789", "+1,23,457", "*******123,456.8", "123\u{202f}457"]);
+            (fp!(-1234567890.25), ["-1,234,567,890.25", "-1.234.567.890,25", "-1,23,45,67,890.25", "-1,23,45,67,890", "-1,234,567,890.2", "-1\u{202f}234\u{202f}567\u{202f}890"]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn format_options_zero_group_size() -> Result<()> {
+    use fixnum::FormatOptions;
+
+    const FIRST_ONLY: FormatOptions = FormatOptions::new().grouping(',', 3, 0);
+    const DISABLED: FormatOptions = FormatOptions::new().grouping(',', 0, 3);
+
+    test_fixed_point! {
+        case (x: FixedPoint, expected: &str) => {
+            assert_eq!(format!("{}", x.format(&FIRST_ONLY)), expected);
+            assert_eq!(format!("{}", x.format(&DISABLED)), format!("{}", x));
+        },
+        all {
+            (fp!(0), "0.0");
+            (fp!(-123), "-123.0");
+            (fp!(1234567), "1234,567.0");
+            (fp!(-1234567.5), "-1234,567.5");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn parse_options() -> Result<()> {
+    const THOUSANDS: ParseOptions = ParseOptions::new().grouping_separator(',');
+    const GERMAN: ParseOptions = ParseOptions::new()
+        .decimal_separator(',')
+        .grouping_separator('.');
+    const FRENCH: ParseOptions = ParseOptions::new()
+        .decimal_separator(',')
+        .grouping_separator('\u{202f}');
+    const UNDERSCORES: ParseOptions = ParseOptions::new().underscores(true);
+    const AMBIGUOUS: ParseOptions = ParseOptions::new().grouping_separator('.');
+    const AMBIGUOUS_UNDERSCORES: ParseOptions = UNDERSCORES.decimal_separator('_');
+
+    test_fixed_point! {
+        case (options: ParseOptions, input: &str, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_str_with(input, options)?, expected);
+        },
+        all {
+            (ParseOptions::DEFAULT, " +1.5\t", fp!(1.5));
+            (ParseOptions::DEFAULT, "-0042", fp!(-42));
+            (ParseOptions::DEFAULT, "000.000", fp!(0));
+            (ParseOptions::DEFAULT, "-0.25", fp!(-0.25));
+            (THOUSANDS, "1,234,567.891", fp!(1234567.891));
+            (THOUSANDS, "-12,34,567", fp!(-1234567));
+            (GERMAN, "-1.234.567,891", fp!(-1234567.891));
+            (GERMAN, "0,5", fp!(0.5));
+            (GERMAN, "1.000", fp!(1000));
+            (FRENCH, " 1\u{202f}000,25 ", fp!(1000.25));
+            (UNDERSCORES, "+1_000.000_1", fp!(1000.0001));
+        },
+    };
+    test_fixed_point! {
+        case (options: ParseOptions, input: &str) => {
+            assert!(FixedPoint::from_str_with(input, options).is_err());
+        },
+        all {
+            (ParseOptions::DEFAULT, "1,5");
+            (ParseOptions::DEFAULT, "1_000");
+            (ParseOptions::DEFAULT, ".5");
+            (ParseOptions::DEFAULT, "+-1");
+            (ParseOptions::DEFAULT, "");
+            (THOUSANDS, ",100");
+            (THOUSANDS, "100,");
+            (THOUSANDS, "1,,000");
+            (THOUSANDS, "1,000.000,1");
+            (GERMAN, "1.000.000,5,5");
+            (GERMAN, "1,5.0");
+            (UNDERSCORES, "1_.5");
+            (UNDERSCORES, "1._5");
+            (UNDERSCORES, "1 000");
+            (AMBIGUOUS, "1.5");
+            (AMBIGUOUS, "1.000");
+            (AMBIGUOUS_UNDERSCORES, "1_5");
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // Excess digits are rounded like `FromStr` or according to the mode.
+            let long = format!("1.{}", "9".repeat(200));
+            assert_eq!(FixedPoint::from_str_with(&long, ParseOptions::DEFAULT)?, fp!(2));
+            let half = format!("0.{}5{}", "0".repeat(100), "0".repeat(100));
+            let floor = ParseOptions::new().rounding(RoundMode::Floor);
+            assert_eq!(FixedPoint::from_str_with(&long, floor)?, FixedPoint::from_bits(FixedPoint::ONE.into_bits() * 2 - 1));
+            assert_eq!(FixedPoint::from_str_with(&half, ParseOptions::DEFAULT)?, fp!(0));
+            let ceil = ParseOptions::new().rounding(RoundMode::Ceil);
+            assert_eq!(FixedPoint::from_str_with(&half, ceil)?, FixedPoint::EPSILON);
+            assert!(FixedPoint::from_str_with(&long, ParseOptions::new().exact()).is_err());
+            assert_eq!(FixedPoint::from_str_with("1,5", GERMAN.exact())?, fp!(1.5));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn parse_options_location() -> Result<()> {
+    use ParseErrorKind::*;
+
+    const THOUSANDS: ParseOptions = ParseOptions::new().grouping_separator(',');
+    const GERMAN: ParseOptions = ParseOptions::new()
+        .decimal_separator(',')
+        .grouping_separator('.');
+    const UNDERSCORES: ParseOptions = ParseOptions::new().underscores(true).exact();
+
+    test_fixed_point! {
+        case (options: ParseOptions, input: &str, kind: ParseErrorKind, position: usize) => {
+            let error = FixedPoint::from_str_with(input, options).unwrap_err();
+            assert_eq!(error.kind(), Some(kind), "{}", input);
+            assert_eq!(error.position(), Some(position), "{}", input);
+        },
+        all {
+            (ParseOptions::DEFAULT, "   1e5x", InvalidCharacter, 6);
+            (ParseOptions::DEFAULT, "+1e5x", InvalidCharacter, 4);
+            (ParseOptions::DEFAULT, " +1.5a", InvalidCharacter, 5);
+            (ParseOptions::DEFAULT, "  ", Empty, 2);
+            (ParseOptions::DEFAULT, " -", Empty, 2);
+            (ParseOptions::DEFAULT, " 1e", InvalidCharacter, 3);
+            (THOUSANDS, "1,000,000e5x", InvalidCharacter, 11);
+            (THOUSANDS, " 1,,000", InvalidCharacter, 2);
+            (THOUSANDS, " +1,000e30", Overflow, 1);
+            (GERMAN, "-0.001,5x", InvalidCharacter, 8);
+            (GERMAN, "1.000,5.", InvalidCharacter, 7);
+        },
+        fp64 {
+            (UNDERSCORES, " +0.000_000_000_1", TooManyFractionalDigits, 16);
+            (UNDERSCORES, " +1_0.5e-9", TooManyFractionalDigits, 6);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn parse_partial() -> Result<()> {
+    test_fixed_point! {
+        case (input: &[u8], expected: FixedPoint, expected_len: usize) => {
+            assert_eq!(FixedPoint::parse_partial(input)?, (expected, expected_len));
+        },
+        all {
+            (b"42", fp!(42), 2);
+            (b"-1.25|", fp!(-1.25), 5);
+            (b"+7.\x01", fp!(7), 2);
+            (b"7.e5", fp!(7), 1);
+            (b"0.5,0.6", fp!(0.5), 3);
+            (b"1.5e3;", fp!(1500), 5);
+            (b"1.5E-1x", fp!(0.15), 6);
+            (b"1.5e", fp!(1.5), 3);
+            (b"1.5e+", fp!(1.5), 3);
+            (b"12 34", fp!(12), 2);
+            (b"1.2.3", fp!(1.2), 3);
+        },
+        fp64 {
+            (b"0.1234567891 ", fp!(0.123456789), 12);
+        },
+    };
+    test_fixed_point! {
+        case (input: &[u8]) => {
+            assert!(FixedPoint::parse_partial(input).is_err());
+        },
+        all {
+            (b"");
+            (b"-");
+            (b" 1");
+            (b".5");
+            (b"-.5");
+            (b".5e1");
+            (b"abc");
+            (b"1000000000000000000000000");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn write_to() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let mut buf = [0; FixedPoint::MAX_STR_LEN];
+            let len = x.write_to(&mut buf)?;
+            assert_eq!(&buf[..len], format!("{}", x).as_bytes());
+            assert!(x.write_to(&mut buf[..len - 1]).is_err());
+            assert_eq!(x.write_to(&mut [0; 128])?, len);
+        },
+        all {
+            (fp!(0));
+            (fp!(-0.5));
+            (fp!(12.34));
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+            (FixedPoint::EPSILON.cneg()?);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let mut buf = [0; FixedPoint::MAX_STR_LEN];
+            assert_eq!(FixedPoint::MIN.write_to(&mut buf)?, FixedPoint::MAX_STR_LEN);
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "currency")]
+
+use anyhow::Result;
+use fixnum::currency::{format_currency, minor_units, parse_currency, Currency, CurrencyFormat};
+
+#[test]
+fn currencies() {
+    assert_eq!(minor_units("USD"), Some(2));
+    assert_eq!(minor_units("usd"), Some(2));
+    assert_eq!(minor_units("JPY"), Some(0));
+    assert_eq!(minor_units("KWD"), Some(3));
+    assert_eq!(minor_units("CLF"), Some(4));
+    assert_eq!(minor_units("XAU"), None);
+    assert_eq!(minor_units("US"), None);
+
+    let eur = Currency::from_code("eur").unwrap();
+    assert_eq!(eur.code(), "EUR");
+    assert_eq!(eur.symbol(), "€");
+    assert_eq!(Currency::from_code("CHF").unwrap().symbol(), "CHF");
+}
+
+const DE: CurrencyFormat = CurrencyFormat::new()
+    .decimal_separator(',')
+    .grouping('.', 3, 3)
+    .symbol_after(true)
+    .symbol_spacing(true);
+
+const CH: CurrencyFormat = CurrencyFormat::new()
+    .grouping('\'', 3, 3)
+    .symbol_spacing(true)
+    .code(true);
+
+#[test]
+fn format_and_parse() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, code: &str, format: CurrencyFormat, expected: &str) => {
+            assert_eq!(format_currency(&x, code, &format)?.to_string(), expected);
+            assert_eq!(parse_currency::<Layout, _>(expected, code, &format)?, x);
+        },
+        all {
+            (fp!(0), "USD", CurrencyFormat::new(), "$0.00");
+            (fp!(1234.56), "USD", CurrencyFormat::new(), "$1,234.56");
+            (fp!(-1234.56), "USD", CurrencyFormat::new(), "-$1,234.56");
+            (fp!(1234567), "JPY", CurrencyFormat::new(), "¥1,234,567");
+            (fp!(1234.56), "EUR", DE, "1.234,56 €");
+            (fp!(-0.5), "EUR", DE, "-0,50 €");
+            (fp!(1234.567), "KWD", CurrencyFormat::new(), "KWD1,234.567");
+            (fp!(1234.5), "CHF", CH, "CHF 1'234.50");
+            (fp!(9223372036.85), "USD", CurrencyFormat::new(), "$9,223,372,036.85");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn format_rounding() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, code: &str, expected: &str) => {
+            assert_eq!(format_currency(&x, code, &CurrencyFormat::new())?.to_string(), expected);
+        },
+        all {
+            (fp!(1.005), "USD", "$1.00");
+            (fp!(1.015), "USD", "$1.02");
+            (fp!(1.0051), "USD", "$1.01");
+            (fp!(-999.995), "USD", "-$1,000.00");
+            (fp!(2.5), "JPY", "¥2");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn parse_non_canonical() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, code: &str, format: CurrencyFormat, expected: FixedPoint) => {
+            assert_eq!(parse_currency::<Layout, _>(input, code, &format)?, expected);
+        },
+        all {
+            ("1,234.5", "USD", CurrencyFormat::new(), fp!(1234.5));
+            (" $ 1234 ", "USD", CurrencyFormat::new(), fp!(1234));
+            ("$-1.5", "USD", CurrencyFormat::new(), fp!(-1.5));
+            ("1.5 USD", "USD", CurrencyFormat::new(), fp!(1.5));
+            ("- EUR 1.234,5", "EUR", DE, fp!(-1234.5));
+            ("1.234€", "EUR", DE, fp!(1234));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn parse_bad_currency() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, code: &str, format: CurrencyFormat) => {
+            let result: Result<FixedPoint, _> = parse_currency(input, code, &format);
+            assert!(result.is_err());
+        },
+        all {
+            ("", "USD", CurrencyFormat::new());
+            ("$", "USD", CurrencyFormat::new());
+            ("$1.001", "USD", CurrencyFormat::new());
+            ("¥1.5", "JPY", CurrencyFormat::new());
+            ("€1", "USD", CurrencyFormat::new());
+            ("$1", "ABC", CurrencyFormat::new());
+            ("$+1", "USD", CurrencyFormat::new());
+            ("--$1", "USD", CurrencyFormat::new());
+            ("$1e3", "USD", CurrencyFormat::new());
+            ("$1.2.", "USD", CurrencyFormat::new());
+            ("$1,,234", "USD", CurrencyFormat::new());
+        },
+        fp64 {
+            ("$100,000,000,000", "USD", CurrencyFormat::new());
+            ("$9,223,372,036.86", "USD", CurrencyFormat::new());
+        },
+    };
+    Ok(())
+}
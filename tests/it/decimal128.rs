@@ -0,0 +1,127 @@
+#![cfg(any(feature = "i64", feature = "i128"))]
+
+use anyhow::Result;
+
+use fixnum::Decimal128;
+
+const ONE_BID: u128 = 0x3040_0000_0000_0000_0000_0000_0000_0001;
+const ONE_DPD: u128 = 0x2208_0000_0000_0000_0000_0000_0000_0001;
+const NAN: u128 = 0x7c00_0000_0000_0000_0000_0000_0000_0000;
+const INFINITY: u128 = 0x7800_0000_0000_0000_0000_0000_0000_0000;
+
+#[test]
+fn from_decimal128() -> Result<()> {
+    test_fixed_point! {
+        case (decimal: Decimal128, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::try_from(decimal)?, expected);
+        },
+        all {
+            (Decimal128::from_bid(ONE_BID), fp!(1));
+            (Decimal128::from_dpd(ONE_DPD), fp!(1));
+            // `-750 * 10^-2`
+            (Decimal128::from_bid(0xb03c_0000_0000_0000_0000_0000_0000_02ee), fp!(-7.5));
+            (Decimal128::from_dpd(0xa207_8000_0000_0000_0000_0000_0000_03d0), fp!(-7.5));
+            // `15 * 10^-9`
+            (Decimal128::from_bid(0x302e_0000_0000_0000_0000_0000_0000_000f), fp!(0.000000015));
+            // `2 * 10^3`
+            (Decimal128::from_bid(0x3046_0000_0000_0000_0000_0000_0000_0002), fp!(2000));
+            // Zeros with any exponent.
+            (Decimal128::from_bid(0x0000_0000_0000_0000_0000_0000_0000_0000), fp!(0));
+            (Decimal128::from_bid(0xdffe_0000_0000_0000_0000_0000_0000_0000), fp!(0));
+            // Non-canonical coefficients are zeros.
+            (Decimal128::from_bid(0x6000_0000_0000_0000_0000_0000_0000_0001), fp!(0));
+            (Decimal128::from_bid(0x3041_ed09_bead_87c0_378d_8e64_0000_0000), fp!(0));
+        },
+        fp128 {
+            // `15 * 10^-10`
+            (Decimal128::from_bid(0x302c_0000_0000_0000_0000_0000_0000_000f), fp!(0.0000000015));
+        },
+    };
+    test_fixed_point! {
+        case (decimal: Decimal128, expected: &str) => {
+            let result = FixedPoint::try_from(decimal);
+            assert_eq!(result.unwrap_err().as_str(), expected);
+        },
+        all {
+            (Decimal128::from_bid(NAN), "not finite");
+            (Decimal128::from_bid(INFINITY), "not finite");
+            (Decimal128::from_dpd(NAN | 1 << 127), "not finite");
+            // `15 * 10^-20`
+            (Decimal128::from_bid(0x3018_0000_0000_0000_0000_0000_0000_000f), "requested precision is too high");
+            // `1 * 10^100`
+            (Decimal128::from_bid(0x3108_0000_0000_0000_0000_0000_0000_0001), "too big number");
+        },
+        fp64 {
+            // `1 * 10^-10`
+            (Decimal128::from_bid(0x302c_0000_0000_0000_0000_0000_0000_0001), "requested precision is too high");
+            // `1 * 10^11`
+            (Decimal128::from_bid(0x3056_0000_0000_0000_0000_0000_0000_0001), "too big number");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn to_decimal128() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let decimal = Decimal128::try_from(x)?;
+            assert_eq!(FixedPoint::try_from(decimal)?, x);
+            assert_eq!(FixedPoint::try_from(Decimal128::from_bid(decimal.to_bid()))?, x);
+            assert_eq!(FixedPoint::try_from(Decimal128::from_dpd(decimal.to_dpd()))?, x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1));
+            (fp!(-7.5));
+            (fp!(123456789.987654321));
+            (FixedPoint::EPSILON);
+            (FixedPoint::MAX.floor());
+            (FixedPoint::MIN.ceil());
+        },
+        fp64 {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let decimal = Decimal128::try_from(fp!(1.5))?;
+            let expected = (6176 - FixedPoint::PRECISION as u128) << 113 | 15 * 10u128.pow(FixedPoint::PRECISION as u32 - 1);
+            assert_eq!(decimal.to_bid(), expected);
+            assert!(decimal.is_finite());
+            assert!(!Decimal128::from_dpd(INFINITY).is_finite());
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let result = Decimal128::try_from(x);
+            assert_eq!(result.unwrap_err().as_str(), "too big number");
+        },
+        fp128 {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn all_declets() {
+    for coefficient in 0..1000 {
+        let decimal = Decimal128::from_bid(ONE_BID - 1 + coefficient);
+        let dpd = decimal.to_dpd();
+        assert_eq!(Decimal128::from_dpd(dpd).to_bid(), decimal.to_bid());
+    }
+
+    // Redundant encodings of `8xx`, `9xx` etc. are decoded as well.
+    for declet in 0..1024 {
+        let decimal = Decimal128::from_dpd((ONE_DPD - 1) | declet);
+        let coefficient = decimal.to_bid() - (ONE_BID - 1);
+        assert!(coefficient < 1000);
+        assert_eq!(
+            Decimal128::from_dpd(decimal.to_dpd()).to_bid(),
+            decimal.to_bid()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(any(feature = "default-round-nearest", feature = "default-round-half-even"))]
+
+use anyhow::Result;
+
+use fixnum::*;
+
+/// Picks the expected value for the configured default mode.
+fn configured<T>(nearest: T, half_even: T) -> T {
+    if cfg!(feature = "default-round-half-even") {
+        half_even
+    } else {
+        nearest
+    }
+}
+
+#[test]
+fn parsing() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, nearest: FixedPoint, half_even: FixedPoint) => {
+            assert_eq!(input.parse::<FixedPoint>()?, configured(nearest, half_even));
+        },
+        all {
+            ("1.5", fp!(1.5), fp!(1.5));
+        },
+        fp64 {
+            ("0.0000000025", fp!(0.000000003), fp!(0.000000002));
+            ("-0.0000000025", fp!(-0.000000003), fp!(-0.000000002));
+            ("0.0000000035", fp!(0.000000004), fp!(0.000000004));
+            ("2.5e-9", fp!(0.000000003), fp!(0.000000002));
+        },
+        fp128 {
+            ("0.0000000000000000025", fp!(0.000000000000000003), fp!(0.000000000000000002));
+            ("-0.0000000000000000025", fp!(-0.000000000000000003), fp!(-0.000000000000000002));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn float_conversion() -> Result<()> {
+    test_fixed_point! {
+        case (input: f64, nearest: FixedPoint, half_even: FixedPoint) => {
+            assert_eq!(FixedPoint::try_from(input)?, configured(nearest, half_even));
+        },
+        fp64 {
+            (2.5e-9, fp!(0.000000003), fp!(0.000000002));
+            (-2.5e-9, fp!(-0.000000003), fp!(-0.000000002));
+            (1.0000000015, fp!(1.000000002), fp!(1.000000002));
+        },
+        fp128 {
+            (2.5e-18, fp!(0.000000000000000003), fp!(0.000000000000000002));
+            (-2.5e-18, fp!(-0.000000000000000003), fp!(-0.000000000000000002));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn display() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, nearest: [&str; 2], half_even: [&str; 2]) => {
+            let expected = configured(nearest, half_even);
+            assert_eq!(format!("{:.0}", x), expected[0]);
+            assert_eq!(format!("{:.2}", x), expected[1]);
+        },
+        all {
+            (fp!(2.5), ["3", "2.50"], ["2", "2.50"]);
+            (fp!(-2.5), ["-3", "-2.50"], ["-2", "-2.50"]);
+            (fp!(1.125), ["1", "1.13"], ["1", "1.12"]);
+            (fp!(-0.135), ["-0", "-0.14"], ["-0", "-0.14"]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn product() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], nearest: FixedPoint, half_even: FixedPoint) => {
+            let product = values.iter().product::<Result<FixedPoint, _>>()?;
+            assert_eq!(product, configured(nearest, half_even));
+        },
+        fp64 {
+            (&[fp!(0.05), fp!(0.00000005)], fp!(0.000000003), fp!(0.000000002));
+        },
+        fp128 {
+            (&[fp!(0.05), fp!(0.00000000000000005)], fp!(0.000000000000000003), fp!(0.000000000000000002));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "defmt")]
+
+use anyhow::Result;
+use fixnum::{ArithmeticError, ConvertError};
+
+fn assert_format<T: defmt::Format>() {}
+
+// `defmt` needs a global logger to write, so only implementations are checked here.
+#[test]
+fn format() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_format::<FixedPoint>();
+        },
+    };
+    assert_format::<ArithmeticError>();
+    assert_format::<ConvertError>();
+    Ok(())
+}
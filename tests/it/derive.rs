@@ -0,0 +1,69 @@
+#![cfg(all(feature = "derive", feature = "i64"))]
+
+use anyhow::Result;
+use fixnum::{ops::*, typenum::U9, ArithmeticError, FixedPoint, FixedPointOps};
+
+type Fp64 = FixedPoint<i64, U9>;
+
+#[derive(Debug, Clone, Copy, PartialEq, FixedPointOps)]
+struct Price(Fp64);
+
+fn fp(str: &str) -> Fp64 {
+    str.parse().unwrap()
+}
+
+fn price(str: &str) -> Price {
+    str.parse().unwrap()
+}
+
+#[test]
+fn ops() -> Result<()> {
+    assert_eq!(price("1.5").cadd(price("0.25"))?, price("1.75"));
+    assert_eq!(price("1.5").csub(price("0.25"))?, price("1.25"));
+    assert_eq!(
+        price("1.5").rmul(fp("0.1"), RoundMode::Floor)?,
+        price("0.15")
+    );
+    assert_eq!(
+        price("1").rdiv(fp("3"), RoundMode::Ceil)?,
+        price("0.333333334")
+    );
+    assert_eq!(price("1").rdiv(price("4"), RoundMode::Floor)?, fp("0.25"));
+
+    let max = Price(Fp64::MAX);
+    assert_eq!(max.cadd(price("1")), Err(ArithmeticError::Overflow));
+    assert_eq!(
+        price("1").rdiv(price("0"), RoundMode::Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    Ok(())
+}
+
+#[test]
+fn display_and_from_str() -> Result<()> {
+    assert_eq!(price("-12.34").to_string(), "-12.34");
+    assert_eq!("12.34".parse::<Price>()?, Price(fp("12.34")));
+    assert!("abc".parse::<Price>().is_err());
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() -> Result<()> {
+    #[derive(Debug, PartialEq, FixedPointOps)]
+    #[fixnum(serde)]
+    struct Default(Fp64);
+
+    #[derive(Debug, PartialEq, FixedPointOps)]
+    #[fixnum(serde = "float")]
+    struct Float(Fp64);
+
+    assert_eq!(serde_json::to_string(&Default(fp("1.5")))?, "\"1.5\"");
+    assert_eq!(
+        serde_json::from_str::<Default>("\"1.5\"")?,
+        Default(fp("1.5"))
+    );
+    assert_eq!(serde_json::to_string(&Float(fp("1.5")))?, "1.5");
+    assert_eq!(serde_json::from_str::<Float>("1.5")?, Float(fp("1.5")));
+    Ok(())
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "std")]
+
+use std::error::Error;
+
+use anyhow::Result;
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, DetailedError, WithContext,
+};
+
+#[test]
+fn context() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, kind: ArithmeticError, message: &str) => {
+            let error = a.rdiv(b, Floor).context("rdiv", &[&a, &b]).unwrap_err();
+
+            assert_eq!(error.kind(), kind);
+            assert_eq!(error.operation(), Some("rdiv"));
+            assert_eq!(error.operands(), [a.to_string(), b.to_string()]);
+            assert_eq!(error.to_string(), message);
+            assert_eq!(error.source().unwrap().to_string(), kind.to_string());
+        },
+        all {
+            (fp!(1.5), fp!(0), ArithmeticError::DivisionByZero, "division by zero in rdiv(1.5, 0.0)");
+        },
+        fp64 {
+            (FixedPoint::MAX, fp!(0.5), ArithmeticError::Overflow, "overflow in rdiv(9223372036.854775807, 0.5)");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn success() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.cadd(b).context("cadd", &[&a, &b])?, expected);
+        },
+        all {
+            (fp!(1.5), fp!(2), fp!(3.5));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn conversions() {
+    let error = DetailedError::from(ArithmeticError::Overflow);
+    assert_eq!(error.operation(), None);
+    assert!(error.operands().is_empty());
+    assert_eq!(error.to_string(), "overflow");
+    assert_eq!(ArithmeticError::from(error), ArithmeticError::Overflow);
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "diesel")]
+
+use std::num::NonZeroU32;
+
+use anyhow::Result;
+use diesel::{
+    deserialize::{self, FromSql},
+    mysql::{Mysql, MysqlType, MysqlValue},
+    pg::{data_types::PgNumeric, Pg, PgMetadataLookup, PgTypeMetadata, PgValue},
+    query_builder::bind_collector::{BindCollector, RawBytesBindCollector},
+    serialize::ToSql,
+    sql_types::Numeric,
+};
+
+struct NoLookup;
+
+impl PgMetadataLookup for NoLookup {
+    fn lookup_type(&mut self, _type_name: &str, _schema: Option<&str>) -> PgTypeMetadata {
+        unreachable!("NUMERIC is a built-in type")
+    }
+}
+
+fn to_pg<T: ToSql<Numeric, Pg>>(value: &T) -> Vec<u8> {
+    let mut collector = RawBytesBindCollector::<Pg>::new();
+    collector
+        .push_bound_value::<Numeric, _>(value, &mut NoLookup)
+        .unwrap();
+    collector.binds.remove(0).unwrap()
+}
+
+fn from_pg<T: FromSql<Numeric, Pg>>(bytes: &[u8]) -> deserialize::Result<T> {
+    let oid = NonZeroU32::new(1700).unwrap();
+    T::from_sql(PgValue::new(bytes, &oid))
+}
+
+fn positive(weight: i16, scale: u16, digits: &[i16]) -> PgNumeric {
+    let digits = digits.to_vec();
+    PgNumeric::Positive {
+        weight,
+        scale,
+        digits,
+    }
+}
+
+#[test]
+fn pg_round_trip() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, weight: i16, is_negative: bool, digits: &[i16]) => {
+            let scale = FixedPoint::PRECISION as u16;
+            let digits = digits.to_vec();
+            let expected = match is_negative {
+                false => PgNumeric::Positive { weight, scale, digits },
+                true => PgNumeric::Negative { weight, scale, digits },
+            };
+
+            assert_eq!(from_pg::<PgNumeric>(&to_pg(&x))?, expected);
+            assert_eq!(from_pg::<FixedPoint>(&to_pg(&expected))?, x);
+        },
+        all {
+            (fp!(0), 0, false, &[]);
+            (fp!(42), 0, false, &[42]);
+            (fp!(10000), 1, false, &[1]);
+            (fp!(12.34), 0, false, &[12, 3400]);
+            (fp!(-12.34), 0, true, &[12, 3400]);
+            (fp!(-0.000000001), -3, true, &[1000]);
+            (fp!(9223372036.854775807), 2, false, &[92, 2337, 2036, 8547, 7580, 7000]);
+        },
+        fp128 {
+            (
+                fp!(-170141183460469231731.687303715884105728), 5, true,
+                &[1, 7014, 1183, 4604, 6923, 1731, 6873, 371, 5884, 1057, 2800],
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_pg_other_scale() -> Result<()> {
+    test_fixed_point! {
+        case (numeric: PgNumeric, expected: FixedPoint) => {
+            assert_eq!(from_pg::<FixedPoint>(&to_pg(&numeric))?, expected);
+        },
+        all {
+            (positive(0, 0, &[12]), fp!(12));
+            (positive(0, 30, &[12, 3400]), fp!(12.34));
+            (positive(-3, 0, &[1000]), fp!(0.000000001));
+            (PgNumeric::Negative { weight: 1, scale: 2, digits: vec![1] }, fp!(-10000));
+        },
+        fp128 {
+            (positive(-5, 18, &[100]), fp!(0.000000000000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_pg_fails() -> Result<()> {
+    test_fixed_point! {
+        case (numeric: PgNumeric, error: &str) => {
+            let result = from_pg::<FixedPoint>(&to_pg(&numeric));
+            assert_eq!(result.unwrap_err().to_string(), error);
+        },
+        all {
+            (PgNumeric::NaN, "NaN can't be represented");
+            (positive(-6, 24, &[1]), "requested precision is too high");
+            (positive(10, 0, &[1]), "too big number");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mysql() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, decimal: &str) => {
+            let mut collector = RawBytesBindCollector::<Mysql>::new();
+            collector.push_bound_value::<Numeric, _>(&x, &mut ())?;
+            assert_eq!(collector.binds[0].as_deref(), Some(x.to_string().as_bytes()));
+
+            let value = MysqlValue::new(decimal.as_bytes(), MysqlType::Numeric);
+            assert_eq!(<FixedPoint as FromSql<Numeric, Mysql>>::from_sql(value)?, x);
+        },
+        all {
+            (fp!(0), "0");
+            (fp!(42), "42");
+            (fp!(12.34), "12.3400");
+            (fp!(-0.000000001), "-0.000000001000");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "i64")]
+fn insertable() {
+    use diesel::{debug_query, prelude::*};
+
+    type Amount = fixnum::FixedPoint<i64, typenum::U9>;
+
+    diesel::table! {
+        payments (id) {
+            id -> Integer,
+            amount -> Numeric,
+        }
+    }
+
+    #[derive(Queryable, Insertable)]
+    #[diesel(table_name = payments)]
+    struct Payment {
+        id: i32,
+        amount: Amount,
+    }
+
+    let payment = Payment {
+        id: 1,
+        amount: Amount::from_bits(1_500_000_000),
+    };
+    let query = diesel::insert_into(payments::table).values(&payment);
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(sql.contains("1.5"), "{}", sql);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "i128")]
+
+use anyhow::Result;
+use fixnum::{
+    ops::{RoundMode::*, *},
+    typenum::{U18, U2},
+    ArithmeticError, DynFixedPoint, FixedPoint,
+};
+
+fn dfp(str: &str) -> DynFixedPoint {
+    str.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() -> Result<()> {
+    for (str, repr, scale, displayed) in [
+        ("0", 0, 0, "0"),
+        ("1.50", 150, 2, "1.50"),
+        ("-0.05", -5, 2, "-0.05"),
+        ("+12.", 12, 0, "12"),
+        (".5", 5, 1, "0.5"),
+        (
+            "-170141183460469231731687303715884105728",
+            i128::MIN,
+            0,
+            "-170141183460469231731687303715884105728",
+        ),
+    ] {
+        let value = dfp(str);
+        assert_eq!((value.repr(), value.scale()), (repr, scale), "{}", str);
+        assert_eq!(value.to_string(), displayed);
+    }
+
+    for str in [
+        "",
+        "-",
+        ".",
+        "1.2.3",
+        "1e5",
+        "170141183460469231731687303715884105728",
+    ] {
+        assert!(str.parse::<DynFixedPoint>().is_err(), "{}", str);
+    }
+    assert!(format!("0.{}", "1".repeat(39))
+        .parse::<DynFixedPoint>()
+        .is_err());
+    assert!(DynFixedPoint::new(1, 39).is_err());
+    Ok(())
+}
+
+#[test]
+fn compare() {
+    assert_eq!(dfp("1.5"), dfp("1.500"));
+    assert!(dfp("1.49") < dfp("1.5"));
+    assert!(dfp("-2") < dfp("-1.999"));
+    assert_eq!(dfp("1.5").max(dfp("1.50")).scale(), 2);
+}
+
+#[test]
+fn rescale() -> Result<()> {
+    assert_eq!(dfp("1.25").rescale(4, Floor)?.repr(), 12500);
+    assert_eq!(dfp("1.25").rescale(1, Floor)?.repr(), 12);
+    assert_eq!(dfp("1.25").rescale(1, Ceil)?.repr(), 13);
+    assert_eq!(dfp("-1.25").rescale(1, HalfEven)?.repr(), -12);
+    assert_eq!(
+        dfp("1.5").rescale(39, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        DynFixedPoint::new(i128::MAX, 0)?.rescale(1, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
+
+#[test]
+fn arithmetic() -> Result<()> {
+    let sum = dfp("1.5").cadd(dfp("0.25"))?;
+    assert_eq!((sum, sum.scale()), (dfp("1.75"), 2));
+    assert_eq!(dfp("1.5").csub(dfp("0.25"))?, dfp("1.25"));
+
+    let product = dfp("1.5").rmul(dfp("0.25"), Floor)?;
+    assert_eq!((product, product.scale()), (dfp("0.37"), 2));
+    assert_eq!(dfp("1.5").rmul(dfp("0.25"), Ceil)?, dfp("0.38"));
+    assert_eq!(dfp("-1.5").rmul(dfp("0.25"), Floor)?, dfp("-0.38"));
+
+    let quotient = dfp("1").rdiv(dfp("3.00"), Floor)?;
+    assert_eq!((quotient, quotient.scale()), (dfp("0.33"), 2));
+    assert_eq!(dfp("1").rdiv(dfp("3.00"), Ceil)?, dfp("0.34"));
+    assert_eq!(dfp("-7.5").rdiv(dfp("2"), Nearest)?, dfp("-3.8"));
+    assert_eq!(dfp("0.5").cneg()?, dfp("-0.5"));
+
+    let max = DynFixedPoint::new(i128::MAX, 0)?;
+    assert_eq!(max.cadd(dfp("1")), Err(ArithmeticError::Overflow));
+    assert_eq!(max.cadd(dfp("0.1")), Err(ArithmeticError::Overflow));
+    assert_eq!(max.rmul(dfp("2"), Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(max.rdiv(dfp("0.5"), Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(
+        dfp("1").rdiv(dfp("0.00"), Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(
+        DynFixedPoint::new(i128::MIN, 0)?.cneg(),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
+
+#[test]
+fn convert() -> Result<()> {
+    let fp: FixedPoint<i128, U18> = "-1.25".parse()?;
+    let value = DynFixedPoint::from(fp);
+    assert_eq!((value.repr(), value.scale()), (fp.into_bits(), 18));
+    assert_eq!(FixedPoint::<i128, U18>::try_from(value)?, fp);
+
+    assert_eq!(
+        FixedPoint::<i128, U2>::try_from(dfp("1.2"))?,
+        "1.2".parse()?
+    );
+    assert_eq!(
+        FixedPoint::<i128, U2>::try_from(dfp("1.2000"))?,
+        "1.2".parse()?
+    );
+    assert!(FixedPoint::<i128, U2>::try_from(dfp("1.234")).is_err());
+    assert!(FixedPoint::<i128, U18>::try_from(DynFixedPoint::new(i128::MAX, 0)?).is_err());
+
+    #[cfg(feature = "i64")]
+    assert!(FixedPoint::<i64, U18>::try_from(dfp("10")).is_err());
+    Ok(())
+}
@@ -0,0 +1,112 @@
+#![cfg(all(feature = "finance", any(feature = "i64", feature = "i128")))]
+
+use anyhow::Result;
+use fixnum::{
+    finance,
+    ops::{RoundMode, RoundMode::*},
+    ArithmeticError,
+};
+
+#[test]
+fn compound() -> Result<()> {
+    test_fixed_point! {
+        case (principal: FixedPoint, rate: FixedPoint, periods: u32, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(finance::compound(principal, rate, periods, mode)?, expected);
+        },
+        all {
+            (fp!(100), fp!(0.1), 0, Floor, fp!(100));
+            (fp!(100), fp!(0.1), 2, Floor, fp!(121));
+            (fp!(100), fp!(0), 1000, Floor, fp!(100));
+            (fp!(100), fp!(-1), 3, Floor, fp!(0));
+            (fp!(-100), fp!(0.5), 2, Floor, fp!(-225));
+        },
+        fp64 {
+            (fp!(1000), fp!(0.05), 10, Floor, fp!(1628.894626775));
+            (fp!(1000), fp!(0.05), 10, Ceil, fp!(1628.894626779));
+            (fp!(1000), fp!(0.05), 10, Nearest, fp!(1628.894626777));
+        },
+        fp128 {
+            (fp!(1000), fp!(0.05), 10, Floor, fp!(1628.89462677744140625));
+            (fp!(1000), fp!(0.05), 10, Ceil, fp!(1628.89462677744140625));
+        },
+    };
+    test_fixed_point! {
+        case (principal: FixedPoint, rate: FixedPoint, error: ArithmeticError) => {
+            assert_eq!(finance::compound(principal, rate, 1, Floor), Err(error));
+        },
+        all {
+            (fp!(100), fp!(-1.5), ArithmeticError::DomainViolation);
+            (FixedPoint::MAX, fp!(0.1), ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn discount_factor() -> Result<()> {
+    test_fixed_point! {
+        case (rate: FixedPoint, periods: u32, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(finance::discount_factor(rate, periods, mode)?, expected);
+        },
+        all {
+            (fp!(0.05), 0, Floor, fp!(1));
+            (fp!(1), 3, Floor, fp!(0.125));
+            (fp!(-0.5), 2, Ceil, fp!(4));
+        },
+        fp64 {
+            (fp!(0.05), 10, Floor, fp!(0.613913253));
+            (fp!(0.05), 10, Ceil, fp!(0.613913255));
+            (fp!(0.05), 10, Nearest, fp!(0.613913253));
+        },
+        fp128 {
+            (fp!(0.05), 10, Floor, fp!(0.613913253540759374));
+            (fp!(0.05), 10, Ceil, fp!(0.613913253540759375));
+        },
+    };
+    test_fixed_point! {
+        case (rate: FixedPoint, error: ArithmeticError) => {
+            assert_eq!(finance::discount_factor(rate, 2, Floor), Err(error));
+        },
+        all {
+            (fp!(-1), ArithmeticError::DivisionByZero);
+            (fp!(-2), ArithmeticError::DomainViolation);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn npv() -> Result<()> {
+    test_fixed_point! {
+        case (rate: FixedPoint, cashflows: &[FixedPoint], mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(finance::npv(rate, cashflows, mode)?, expected);
+        },
+        all {
+            (fp!(0.1), &[], Floor, fp!(0));
+            (fp!(0.1), &[fp!(-5)], Floor, fp!(-5));
+            (fp!(0), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Floor, fp!(200));
+            (fp!(1), &[fp!(1), fp!(2), fp!(4)], Floor, fp!(3));
+        },
+        fp64 {
+            (fp!(0.1), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Floor, fp!(-21.036814427));
+            (fp!(0.1), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Ceil, fp!(-21.036814424));
+            (fp!(0.1), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Nearest, fp!(-21.036814425));
+        },
+        fp128 {
+            (fp!(0.1), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Floor, fp!(-21.036814425244177311));
+            (fp!(0.1), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Ceil, fp!(-21.036814425244177309));
+            (fp!(0.1), &[fp!(-1000), fp!(300), fp!(400), fp!(500)], Nearest, fp!(-21.03681442524417731));
+        },
+    };
+    test_fixed_point! {
+        case (rate: FixedPoint, cashflows: &[FixedPoint], error: ArithmeticError) => {
+            assert_eq!(finance::npv(rate, cashflows, Floor), Err(error));
+        },
+        all {
+            (fp!(-1), &[fp!(1), fp!(1)], ArithmeticError::DivisionByZero);
+            (fp!(-1.5), &[], ArithmeticError::DomainViolation);
+            (FixedPoint::MAX, &[FixedPoint::MAX], ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
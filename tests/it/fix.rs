@@ -0,0 +1,76 @@
+#![cfg(feature = "fix")]
+
+use anyhow::Result;
+use fixnum::fix::{from_fix_str, to_fix_str};
+
+#[test]
+fn to_and_from_fix_str() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, decimals: u32, expected: &str) => {
+            assert_eq!(to_fix_str(&x, decimals, expected.len())?.as_str(), expected);
+            assert!(to_fix_str(&x, decimals, expected.len() - 1).is_err());
+            assert_eq!(from_fix_str::<Layout, _>(expected.as_bytes())?, x);
+        },
+        all {
+            (fp!(0), 0, "0");
+            (fp!(0), 2, "0.00");
+            (fp!(1), 0, "1");
+            (fp!(101.5), 0, "101.5");
+            (fp!(101.5), 2, "101.50");
+            (fp!(101.125), 2, "101.125");
+            (fp!(-101.5), 4, "-101.5000");
+            (fp!(-0.000000001), 0, "-0.000000001");
+            (fp!(9223372036.854775807), 9, "9223372036.854775807");
+            (fp!(-9223372036.854775808), 0, "-9223372036.854775808");
+            (fp!(1), 20, "1.00000000000000000000");
+        },
+        fp128 {
+            (fp!(0.000000000000000001), 18, "0.000000000000000001");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_fix_str_non_canonical() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, expected: FixedPoint) => {
+            assert_eq!(from_fix_str::<Layout, _>(input.as_bytes())?, expected);
+        },
+        all {
+            ("007.50", fp!(7.5));
+            ("-0.0", fp!(0));
+            ("1.", fp!(1));
+            ("1.2500000000000000000000000", fp!(1.25));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_bad_fix_str() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str) => {
+            let result: Result<FixedPoint, _> = from_fix_str(input.as_bytes());
+            assert!(result.is_err());
+        },
+        all {
+            ("");
+            ("-");
+            (".5");
+            ("+1");
+            (" 1");
+            ("1 ");
+            ("1.5e2");
+            ("1.2.3");
+            ("--1");
+            ("1,5");
+            ("0.0000000000000000001");
+            ("100000000000000000000000000000000000000000");
+        },
+        fp64 {
+            ("0.0000000001");
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "derive")]
+
+use anyhow::Result;
+use fixnum::{
+    fixnum_expr,
+    ops::{RoundMode::*, *},
+    ArithmeticError,
+};
+
+#[test]
+fn arithmetic() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint) => {
+            assert_eq!(fixnum_expr!(Floor, a + b * c)?, a.cadd(b.rmul(c, Floor)?)?);
+            assert_eq!(fixnum_expr!(Floor, (a + b) * c)?, a.cadd(b)?.rmul(c, Floor)?);
+            assert_eq!(fixnum_expr!(Ceil, a - b / c)?, a.csub(b.rdiv(c, Ceil)?)?);
+            assert_eq!(fixnum_expr!(Ceil, a * b / c,)?, a.rmul(b, Ceil)?.rdiv(c, Ceil)?);
+            assert_eq!(fixnum_expr!(Floor, a % c)?, a.crem(c)?);
+            assert_eq!(fixnum_expr!(Floor, -a * 2)?, a.cneg()?.rmul(2, Floor)?);
+            assert_eq!(fixnum_expr!(Floor, a / -2)?, a.rdiv(-2, Floor)?);
+        },
+        all {
+            (fp!(1.5), fp!(2), fp!(3));
+            (fp!(-7.25), fp!(0.1), fp!(-0.3));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rounding() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, floor: FixedPoint, ceil: FixedPoint) => {
+            assert_eq!(fixnum_expr!(Floor, a / b)?, floor);
+            assert_eq!(fixnum_expr!(Ceil, a / b)?, ceil);
+        },
+        fp64 {
+            (fp!(1), fp!(3), fp!(0.333333333), fp!(0.333333334));
+        },
+        fp128 {
+            (fp!(1), fp!(3), fp!(0.333333333333333333), fp!(0.333333333333333334));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint) => {
+            let zero = FixedPoint::ZERO;
+            assert_eq!(fixnum_expr!(Floor, FixedPoint::MAX + a), Err(ArithmeticError::Overflow));
+            assert_eq!(fixnum_expr!(Floor, a / zero + a), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(fixnum_expr!(Floor, -FixedPoint::MIN), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(1));
+        },
+    };
+    Ok(())
+}
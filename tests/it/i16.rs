@@ -0,0 +1,110 @@
+#![cfg(feature = "i16")]
+
+use anyhow::Result;
+use fixnum::{
+    fixnum,
+    ops::{RoundMode::*, *},
+    typenum::U2,
+    ArithmeticError, FixedPoint,
+};
+
+type Fp16 = FixedPoint<i16, U2>;
+
+fn fp(str: &str) -> Fp16 {
+    str.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() -> Result<()> {
+    assert_eq!(Fp16::MAX.to_string(), "327.67");
+    assert_eq!(Fp16::MIN.to_string(), "-327.68");
+    assert_eq!(fp("-1.5").to_string(), "-1.5");
+    assert_eq!(fp("1.05").into_bits(), 105);
+    assert_eq!(format!("{:.1}", fp("1.25")), "1.2");
+    assert!("327.68".parse::<Fp16>().is_err());
+    assert!(Fp16::from_str_exact("0.001").is_err());
+
+    let value: Fp16 = fixnum!(12.34, 2);
+    assert_eq!(value, fp("12.34"));
+    Ok(())
+}
+
+#[test]
+fn ops() -> Result<()> {
+    assert_eq!(fp("1.5").cadd(fp("2.25"))?, fp("3.75"));
+    assert_eq!(fp("1.5").csub(fp("2.25"))?, fp("-0.75"));
+    assert_eq!(fp("1.5").rmul(fp("0.25"), Floor)?, fp("0.37"));
+    assert_eq!(fp("1.5").rmul(fp("0.25"), Ceil)?, fp("0.38"));
+    assert_eq!(fp("1").rdiv(fp("3"), Floor)?, fp("0.33"));
+    assert_eq!(fp("1").rdiv(fp("3"), Ceil)?, fp("0.34"));
+    assert_eq!(fp("10").rmul(7, Floor)?, fp("70"));
+    assert_eq!(fp("2").rsqrt(Floor)?, fp("1.41"));
+    assert_eq!(fp("-7.5").integral(Nearest), -8);
+
+    assert_eq!(
+        Fp16::MAX.cadd(Fp16::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        fp("200").rmul(fp("2"), Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(fp("1").rdiv(fp("0.01"), Floor)?, fp("100"));
+    assert_eq!(
+        fp("4").rdiv(fp("0.01"), Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        fp("1").rdiv(Fp16::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    Ok(())
+}
+
+#[test]
+fn convert() -> Result<()> {
+    assert_eq!(Fp16::try_from(3)?, fp("3"));
+    assert!(Fp16::try_from(328).is_err());
+    assert_eq!(Fp16::try_from(1.25f64)?, fp("1.25"));
+    assert_eq!(Fp16::try_from(1.25f32)?, fp("1.25"));
+    assert_eq!(f64::from(fp("-1.25")), -1.25);
+    assert_eq!(fp("5").try_into_int::<u8>()?, 5);
+    assert_eq!(Fp16::from_le_bytes(fp("1").to_le_bytes()), fp("1"));
+    Ok(())
+}
+
+#[test]
+fn simd_add_sub_slices() {
+    // Longer than a vector of lanes to cover the scalar rest too.
+    let lhs = (0..19)
+        .map(|i| fp("300").csub(Fp16::from_bits(i)).unwrap())
+        .collect::<Vec<_>>();
+    let rhs = vec![fp("27"); 19];
+    let mut out = vec![Fp16::ZERO; 19];
+
+    assert_eq!(fixnum::simd::add_slices(&lhs, &rhs, &mut out), Ok(()));
+    assert!(out
+        .iter()
+        .zip(&lhs)
+        .all(|(out, lhs)| *out == lhs.cadd(fp("27")).unwrap()));
+    assert_eq!(
+        fixnum::simd::sub_slices(&out, &rhs, &mut out.clone()),
+        Ok(())
+    );
+
+    for i in [0, 7, 8, 15, 16, 18] {
+        let mut rhs = rhs.clone();
+        rhs[i] = fp("28");
+        assert_eq!(
+            fixnum::simd::add_slices(&lhs, &rhs, &mut out),
+            Err(ArithmeticError::Overflow)
+        );
+
+        let mut lhs = lhs.clone();
+        lhs[i] = Fp16::MIN;
+        assert_eq!(
+            fixnum::simd::sub_slices(&lhs, &[fp("0.01"); 19], &mut out),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "i256")]
+
+use anyhow::Result;
+use fixnum::ops::{RoundMode::*, *};
+use fixnum::{typenum::U18, ArithmeticError, ParseErrorKind, I256};
+
+type FixedPoint = fixnum::FixedPoint<I256, U18>;
+
+const MAX: &str = "57896044618658097711785492504343953926634992332820282019728.792003956564819967";
+const MIN: &str = "-57896044618658097711785492504343953926634992332820282019728.792003956564819968";
+
+fn fp(s: &str) -> FixedPoint {
+    FixedPoint::from_str_exact(s).unwrap()
+}
+
+#[test]
+fn parse_and_display() -> Result<()> {
+    for s in [
+        "0.0",
+        "1.0",
+        "-42.5",
+        "0.000000000000000001",
+        "1000000000000000000000000000000000000000.000000000000000001",
+        "-123456789012345678901234567890123456789012.5",
+        MAX,
+        MIN,
+    ] {
+        assert_eq!(fp(s).to_string(), s);
+    }
+    assert_eq!(fp(MAX), FixedPoint::MAX);
+    assert_eq!(fp(MIN), FixedPoint::MIN);
+    assert_eq!(format!("{:?}", fp("-1.5")), "-1.5");
+    assert_eq!(
+        format!("{:#?}", fp(MIN)),
+        format!("FixedPoint<I256, U18>({} = {})", MIN, MIN.replace('.', ""))
+    );
+    assert_eq!(format!("{:+08.2}", fp("1.125")), "+0001.12");
+    assert_eq!(format!("{:>8.0}", fp("-2.5")), "      -2");
+    assert_eq!(format!("{:e}", fp("-0.0015")), "-1.5e-3");
+    assert_eq!(format!("{:.1E}", fp("12345")), "1.2E4");
+
+    let mut buf = [0; FixedPoint::MAX_STR_LEN];
+    let len = FixedPoint::MIN.write_to(&mut buf)?;
+    assert_eq!(&buf[..len], MIN.as_bytes());
+    assert_eq!(len, FixedPoint::MAX_STR_LEN);
+    let mut buf = [0; fixnum::FixedPoint::<I256, fixnum::typenum::U0>::MAX_STR_LEN];
+    let min = fixnum::FixedPoint::<I256, fixnum::typenum::U0>::MIN;
+    assert_eq!(min.write_to(&mut buf)?, buf.len());
+    assert_eq!(
+        &buf[..],
+        b"-57896044618658097711785492504343953926634992332820282019728792003956564819968.0"
+    );
+
+    assert_eq!(
+        "1.0000000000000000015".parse::<FixedPoint>()?,
+        fp("1.000000000000000002")
+    );
+    assert_eq!(
+        "-1.0000000000000000015".parse::<FixedPoint>()?,
+        fp("-1.000000000000000002")
+    );
+    assert_eq!(
+        "1.00000000000000000149".parse::<FixedPoint>()?,
+        fp("1.000000000000000001")
+    );
+    assert!(FixedPoint::from_str_exact("1.0000000000000000015").is_err());
+    assert!(
+        "57896044618658097711785492504343953926634992332820282019729"
+            .parse::<FixedPoint>()
+            .is_err()
+    );
+    assert_eq!("1e5".parse::<FixedPoint>()?, fp("100000"));
+    assert_eq!(
+        "-1.5E-18".parse::<FixedPoint>()?,
+        fp("-0.000000000000000002")
+    );
+    assert!(FixedPoint::from_str_exact("1.5e-18").is_err());
+    assert!("".parse::<FixedPoint>().is_err());
+
+    let error = FixedPoint::from_str_exact("1.0000000000000000015").unwrap_err();
+    assert_eq!(error.kind(), Some(ParseErrorKind::TooManyFractionalDigits));
+    assert_eq!(error.position(), Some(20));
+    let error = " 12x".parse::<FixedPoint>().unwrap_err();
+    assert_eq!(error.kind(), Some(ParseErrorKind::InvalidCharacter));
+    assert_eq!(error.position(), Some(3));
+    let error = MIN
+        .replacen('-', "-9", 1)
+        .parse::<FixedPoint>()
+        .unwrap_err();
+    assert_eq!(error.kind(), Some(ParseErrorKind::Overflow));
+    assert_eq!(error.position(), Some(0));
+    Ok(())
+}
+
+#[test]
+fn convert() -> Result<()> {
+    assert_eq!(FixedPoint::try_from(2.5f64)?, fp("2.5"));
+    assert_eq!(FixedPoint::try_from(-0.125f64)?, fp("-0.125"));
+    assert_eq!(
+        FixedPoint::try_from(1e30)?,
+        fp("1000000000000000019884624838656")
+    );
+    assert_eq!(f64::from(FixedPoint::try_from(1e40)?), 1e40);
+    assert!(FixedPoint::try_from(1e60).is_err());
+    assert!(FixedPoint::try_from(f64::NAN).is_err());
+    assert_eq!(f64::from(fp("-2.5")), -2.5);
+    assert_eq!(FixedPoint::try_from(0.1f32)?, fp("0.1"));
+    assert!(FixedPoint::try_from(f32::INFINITY).is_err());
+    assert_eq!(f32::from(fp("-2.5")), -2.5);
+    assert_eq!(
+        f64::from(fp("1000000000000000000000000000000000000000")),
+        1e39
+    );
+
+    assert_eq!(FixedPoint::try_from(-3i8)?, fp("-3"));
+    assert_eq!(FixedPoint::try_from(u64::MAX)?, fp("18446744073709551615"));
+    assert_eq!(
+        FixedPoint::try_from(u128::MAX)?,
+        fp("340282366920938463463374607431768211455")
+    );
+    assert_eq!(
+        FixedPoint::from_bits(I256::from(5i128)),
+        FixedPoint::EPSILON.cmul(I256::from(5i128))?
+    );
+    Ok(())
+}
+
+#[test]
+fn ops() -> Result<()> {
+    // 10^40 tokens with 18 decimals don't fit in `i128`.
+    let a = fp("10000000000000000000000000000000000000000.5");
+    let b = fp("-2");
+
+    assert_eq!(a.cadd(b)?, fp("9999999999999999999999999999999999999998.5"));
+    assert_eq!(
+        a.csub(b)?,
+        fp("10000000000000000000000000000000000000002.5")
+    );
+    assert_eq!(
+        FixedPoint::MAX.cadd(FixedPoint::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(FixedPoint::MAX.saturating_add(a), FixedPoint::MAX);
+    assert_eq!(FixedPoint::MIN.saturating_sub(a), FixedPoint::MIN);
+    assert_eq!(
+        a.cmul(I256::from(-3i128))?,
+        fp("-30000000000000000000000000000000000000001.5")
+    );
+    assert_eq!(
+        FixedPoint::MAX.saturating_mul(I256::from(-2i128)),
+        FixedPoint::MIN
+    );
+
+    assert_eq!(
+        a.rmul(b, Floor)?,
+        fp("-20000000000000000000000000000000000000001")
+    );
+    assert_eq!(a.rmul(a, Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(a.saturating_rmul(a, Floor), FixedPoint::MAX);
+    assert_eq!(
+        a.saturating_rmul(b.cmul(a.integral(Floor))?, Floor),
+        FixedPoint::MIN
+    );
+
+    let eps = FixedPoint::EPSILON;
+    assert_eq!(eps.rmul(fp("0.5"), Floor)?, FixedPoint::ZERO);
+    assert_eq!(eps.rmul(fp("0.5"), Ceil)?, eps);
+    assert_eq!(eps.rmul(fp("0.5"), Nearest)?, eps);
+    assert_eq!(eps.rmul(fp("0.5"), HalfEven)?, FixedPoint::ZERO);
+    assert_eq!(eps.rmul(fp("-0.5"), Floor)?, eps.cneg()?);
+    assert_eq!(eps.rmul(fp("-0.5"), Ceil)?, FixedPoint::ZERO);
+
+    let third = FixedPoint::ONE.rdiv(fp("3"), Floor)?;
+    assert_eq!(third, fp("0.333333333333333333"));
+    assert_eq!(FixedPoint::ONE.rdiv(fp("3"), Ceil)?, third.cadd(eps)?);
+    assert_eq!(
+        FixedPoint::ONE.rdiv(fp("-3"), Floor)?,
+        fp("-0.333333333333333334")
+    );
+    assert_eq!(
+        a.rdiv(b, Floor)?,
+        fp("-5000000000000000000000000000000000000000.25")
+    );
+    assert_eq!(
+        a.rdiv(I256::from(2i128), Floor)?,
+        fp("5000000000000000000000000000000000000000.25")
+    );
+    assert_eq!(I256::from(1i128).rdiv(fp("0.5"), Floor)?, fp("2"));
+    assert_eq!(
+        a.rdiv(FixedPoint::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(
+        a.rdiv(I256::from(0i128), Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(fp("4").recip(Floor)?, fp("0.25"));
+    Ok(())
+}
+
+#[test]
+fn rounding() -> Result<()> {
+    let a = fp("-7.5");
+
+    assert_eq!(a.integral(Floor), I256::from(-8i128));
+    assert_eq!(a.integral(Ceil), I256::from(-7i128));
+    assert_eq!(a.integral(Nearest), I256::from(-8i128));
+    assert_eq!(a.integral(HalfEven), I256::from(-8i128));
+    assert_eq!(fp("6.5").integral(HalfEven), I256::from(6i128));
+    assert_eq!(a.floor(), fp("-8"));
+    assert_eq!(a.ceil(), fp("-7"));
+    assert_eq!(a.round(), fp("-8"));
+    assert_eq!(
+        FixedPoint::MAX.integral(Floor),
+        I256::MAX / I256::from(1_000_000_000_000_000_000i128)
+    );
+
+    assert_eq!(a.signum(), fp("-1"));
+    assert_eq!(FixedPoint::ZERO.signum(), FixedPoint::ZERO);
+    assert_eq!(a.abs()?, fp("7.5"));
+    assert_eq!(FixedPoint::MIN.abs(), Err(ArithmeticError::Overflow));
+    assert_eq!(
+        FixedPoint::MIN.checked_abs(),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        FixedPoint::MIN.checked_neg(),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(fp("7.5").copysign(a)?, a);
+    assert_eq!(a.copysign(FixedPoint::ZERO)?, fp("7.5"));
+    assert_eq!(FixedPoint::MIN.copysign(a)?, FixedPoint::MIN);
+    assert_eq!(
+        FixedPoint::MIN.copysign(FixedPoint::ONE),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(FixedPoint::MIN.cneg(), Err(ArithmeticError::Overflow));
+    assert_eq!(
+        FixedPoint::MAX.cneg()?,
+        FixedPoint::MIN.cadd(FixedPoint::EPSILON)?
+    );
+
+    assert_eq!(FixedPoint::half_sum(a, fp("2"), Floor), fp("-2.75"));
+    assert_eq!(
+        FixedPoint::half_sum(FixedPoint::MAX, FixedPoint::MAX, Floor),
+        FixedPoint::MAX
+    );
+    assert_eq!(
+        FixedPoint::half_sum(FixedPoint::MIN, FixedPoint::MAX, Floor),
+        eps_neg()
+    );
+    assert_eq!(
+        FixedPoint::half_sum(FixedPoint::MIN, FixedPoint::MAX, Ceil),
+        FixedPoint::ZERO
+    );
+
+    assert_eq!(fp("81").rsqrt(Floor)?, fp("9"));
+    assert_eq!(fp("2").rsqrt(Floor)?, fp("1.414213562373095048"));
+    assert_eq!(fp("2").rsqrt(Ceil)?, fp("1.414213562373095049"));
+    assert_eq!(fp("2").rsqrt(Nearest)?, fp("1.414213562373095049"));
+    assert_eq!(
+        fp("100000000000000000000000000000000000000000000000000").rsqrt(Floor)?,
+        fp("10000000000000000000000000")
+    );
+    assert!(FixedPoint::MAX.rsqrt(Ceil).is_ok());
+    assert_eq!(a.rsqrt(Floor), Err(ArithmeticError::DomainViolation));
+    Ok(())
+}
+
+fn eps_neg() -> FixedPoint {
+    FixedPoint::EPSILON.cneg().unwrap()
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() -> Result<()> {
+    let x = fp("-10000000000000000000000000000000000000000.042");
+    let json = serde_json::to_string(&x)?;
+    assert_eq!(json, "\"-10000000000000000000000000000000000000000.042\"");
+    assert_eq!(serde_json::from_str::<FixedPoint>(&json)?, x);
+    Ok(())
+}
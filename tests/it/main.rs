@@ -179,9 +179,50 @@ mod r#impl {
 }
 
 // Tests
+mod accumulator;
+mod amount;
+mod arbitrary;
+mod arrow;
+mod async_graphql;
+mod atomic;
+mod average;
+mod borsh;
+mod bson;
+mod bytemuck;
 mod const_ctor;
 mod convert;
 mod convert_f64;
 mod convert_str;
+mod currency;
+mod decimal128;
+mod default_round;
+mod defmt;
+mod derive;
+mod detailed_error;
+mod diesel;
+mod dynamic;
+mod finance;
+mod fix;
+mod fixnum_expr;
+mod i16;
+mod i256;
+mod math;
+mod money_proto;
+mod num_traits;
 mod ops;
+mod overflow_tracing;
+mod panicking_ops;
+mod parity;
+mod pg_numeric;
+mod proptest;
+mod rand;
+mod ratio;
+mod rkyv;
+mod schemars;
 mod serde;
+mod simd;
+mod sqlx;
+mod stats;
+mod trig;
+mod unsigned;
+mod zerocopy;
@@ -0,0 +1,258 @@
+use anyhow::Result;
+
+use fixnum::{ops::RoundMode::*, ArithmeticError};
+
+#[test]
+fn ln_log2_log10_exact() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, ln: FixedPoint, log2: FixedPoint, log10: FixedPoint) => {
+            assert_eq!(x.ln()?, ln, "ln");
+            assert_eq!(x.log2()?, log2, "log2");
+            assert_eq!(x.log10()?, log10, "log10");
+        },
+        all {
+            (fp!(1), fp!(0), fp!(0), fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.log2()?, expected);
+        },
+        all {
+            (fp!(2), fp!(1));
+            (fp!(1024), fp!(10));
+            (fp!(0.5), fp!(-1));
+            (fp!(0.0625), fp!(-4));
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.log10()?, expected);
+        },
+        all {
+            (fp!(10), fp!(1));
+            (fp!(1000000), fp!(6));
+            (fp!(0.001), fp!(-3));
+        },
+        fp64 {
+            (FixedPoint::EPSILON, fp!(-9));
+        },
+        fp128 {
+            (FixedPoint::EPSILON, fp!(-18));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn ln() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.ln()?, expected);
+        },
+        fp64 {
+            (fp!(2), fp!(0.693147181));
+            (fp!(0.5), fp!(-0.693147181));
+            (fp!(10), fp!(2.302585093));
+            (fp!(0.05), fp!(-2.995732274));
+            (fp!(1.05), fp!(0.048790164));
+            (fp!(123456.789), fp!(11.723646487));
+            (fp!(0.000000001), fp!(-20.723265837));
+        },
+        fp128 {
+            (fp!(2), fp!(0.693147180559945309));
+            (fp!(0.5), fp!(-0.693147180559945309));
+            (fp!(10), fp!(2.302585092994045684));
+            (fp!(0.05), fp!(-2.995732273553990993));
+            (fp!(1.05), fp!(0.048790164169432003));
+            (fp!(123456.789), fp!(11.723646487185880981));
+            (fp!(0.000000001), fp!(-20.723265836946411156));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn log2() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.log2()?, expected);
+        },
+        fp64 {
+            (fp!(3), fp!(1.584962501));
+            (fp!(0.1), fp!(-3.321928095));
+            (fp!(1000000), fp!(19.931568569));
+        },
+        fp128 {
+            (fp!(3), fp!(1.584962500721156181));
+            (fp!(0.1), fp!(-3.321928094887362348));
+            (fp!(1000000), fp!(19.931568569324174087));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn log10() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.log10()?, expected);
+        },
+        fp64 {
+            (fp!(2), fp!(0.301029996));
+            (fp!(0.5), fp!(-0.301029996));
+            (fp!(123.456), fp!(2.091512202));
+        },
+        fp128 {
+            (fp!(2), fp!(0.301029995663981195));
+            (fp!(0.5), fp!(-0.301029995663981195));
+            (fp!(123.456), fp!(2.091512201627771681));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn log_domain_violation() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(x.ln(), Err(ArithmeticError::DomainViolation));
+            assert_eq!(x.log2(), Err(ArithmeticError::DomainViolation));
+            assert_eq!(x.log10(), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (FixedPoint::ZERO);
+            (fp!(-1));
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn exp() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.exp()?, expected);
+        },
+        all {
+            (FixedPoint::ZERO, FixedPoint::ONE);
+            (fp!(-100), FixedPoint::ZERO);
+            (FixedPoint::MIN, FixedPoint::ZERO);
+        },
+        fp64 {
+            (fp!(1), fp!(2.718281828));
+            (fp!(-1), fp!(0.367879441));
+            (fp!(0.05), fp!(1.051271096));
+            (fp!(-0.05), fp!(0.951229425));
+            (fp!(10), fp!(22026.465794807));
+            (fp!(-10), fp!(0.0000454));
+            (fp!(0.000000001), fp!(1.000000001));
+        },
+        fp128 {
+            (fp!(1), fp!(2.718281828459045235));
+            (fp!(-1), fp!(0.367879441171442322));
+            (fp!(0.05), fp!(1.05127109637602404));
+            (fp!(-0.05), fp!(0.951229424500714009));
+            (fp!(10), fp!(22026.465794806716516958));
+            (fp!(-10), fp!(0.000045399929762485));
+            (fp!(0.000000001), fp!(1.000000001000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn exp_overflow() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(x.exp(), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (fp!(100));
+            (FixedPoint::MAX);
+        },
+        fp64 {
+            (fp!(23));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn nth_root_exact() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, n: u32, expected: FixedPoint) => {
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(x.nth_root(n, mode)?, expected, "{:?}", mode);
+                if n == 3 {
+                    assert_eq!(x.cbrt(mode)?, expected, "cbrt, {:?}", mode);
+                }
+            }
+        },
+        all {
+            (fp!(0), 3, fp!(0));
+            (fp!(1), 7, fp!(1));
+            (fp!(8), 3, fp!(2));
+            (fp!(-27), 3, fp!(-3));
+            (fp!(0.001), 3, fp!(0.1));
+            (fp!(0.000000001), 3, fp!(0.001));
+            (fp!(16), 4, fp!(2));
+            (fp!(-32), 5, fp!(-2));
+            (fp!(1.21), 2, fp!(1.1));
+            (fp!(1024), 10, fp!(2));
+            (fp!(1.5), 1, fp!(1.5));
+            (FixedPoint::EPSILON, 1, FixedPoint::EPSILON);
+            (fp!(0.000001), 6, fp!(0.1));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), 6, fp!(0.001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn nth_root() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, n: u32, floor: FixedPoint, ceil: FixedPoint, nearest: FixedPoint) => {
+            assert_eq!(x.nth_root(n, Floor)?, floor, "floor");
+            assert_eq!(x.nth_root(n, Ceil)?, ceil, "ceil");
+            assert_eq!(x.nth_root(n, Nearest)?, nearest, "nearest");
+        },
+        fp64 {
+            (fp!(2), 3, fp!(1.259921049), fp!(1.25992105), fp!(1.25992105));
+            (fp!(10), 5, fp!(1.584893192), fp!(1.584893193), fp!(1.584893192));
+            (fp!(0.5), 2, fp!(0.707106781), fp!(0.707106782), fp!(0.707106781));
+            (fp!(-5), 3, fp!(-1.709975947), fp!(-1.709975946), fp!(-1.709975947));
+            (fp!(1000000), 7, fp!(7.19685673), fp!(7.196856731), fp!(7.19685673));
+            (FixedPoint::MAX, 3, fp!(2097.151999999), fp!(2097.152), fp!(2097.152));
+        },
+        fp128 {
+            (fp!(2), 3, fp!(1.259921049894873164), fp!(1.259921049894873165), fp!(1.259921049894873165));
+            (fp!(10), 5, fp!(1.584893192461113485), fp!(1.584893192461113486), fp!(1.584893192461113485));
+            (fp!(0.5), 2, fp!(0.707106781186547524), fp!(0.707106781186547525), fp!(0.707106781186547524));
+            (fp!(-5), 3, fp!(-1.70997594667669699), fp!(-1.709975946676696989), fp!(-1.709975946676696989));
+            (fp!(1000000), 7, fp!(7.196856730011520199), fp!(7.1968567300115202), fp!(7.196856730011520199));
+            (FixedPoint::MAX, 3, fp!(5541191.377756635628281025), fp!(5541191.377756635628281026), fp!(5541191.377756635628281026));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn nth_root_domain_violation() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, n: u32) => {
+            assert_eq!(x.nth_root(n, Floor), Err(ArithmeticError::DomainViolation));
+        },
+        all {
+            (fp!(2), 0);
+            (fp!(0), 0);
+            (fp!(-1), 2);
+            (fp!(-16), 4);
+            (FixedPoint::MIN, 2);
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,69 @@
+#![cfg(all(feature = "money-proto", any(feature = "i64", feature = "i128")))]
+
+use anyhow::Result;
+
+use fixnum::money_proto;
+
+#[test]
+fn to_and_from_units_nanos() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, units: i64, nanos: i32) => {
+            assert_eq!(money_proto::to_units_nanos(x)?, (units, nanos));
+            assert_eq!(money_proto::from_units_nanos::<FixedPoint>(units, nanos)?, x);
+        },
+        all {
+            (fp!(0), 0, 0);
+            (fp!(1), 1, 0);
+            (fp!(-1), -1, 0);
+            (fp!(1.75), 1, 750_000_000);
+            (fp!(-1.75), -1, -750_000_000);
+            (fp!(-0.75), 0, -750_000_000);
+            (fp!(0.000000001), 0, 1);
+            (fp!(-0.000000001), 0, -1);
+            (fp!(9223372036.854775807), 9223372036, 854775807);
+            (fp!(-9223372036.854775808), -9223372036, -854775808);
+        },
+        fp128 {
+            (fp!(9223372036854775807.999999999), 9223372036854775807, 999999999);
+            (fp!(-9223372036854775808.999999999), -9223372036854775808, -999999999);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn invalid_units_nanos() -> Result<()> {
+    test_fixed_point! {
+        case (units: i64, nanos: i32) => {
+            assert!(money_proto::from_units_nanos::<FixedPoint>(units, nanos).is_err());
+        },
+        all {
+            (0, 1_000_000_000);
+            (0, -1_000_000_000);
+            (1, -1);
+            (-1, 1);
+        },
+        fp64 {
+            (i64::MAX, 0);
+            (i64::MIN, -1);
+            (9223372037, 0);
+            (-9223372036, -854775809);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn inexact_units_nanos() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert!(money_proto::to_units_nanos(x).is_err());
+        },
+        fp128 {
+            (fp!(0.0000000001));
+            (fp!(-1.000000000000000001));
+            (fp!(9223372036854775808));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,223 @@
+#![cfg(feature = "num-traits")]
+
+use anyhow::Result;
+use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+#[cfg(feature = "panicking-ops")]
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Num, One, Signed, Zero};
+
+#[cfg(feature = "panicking-ops")]
+fn lerp<T: Num + Copy>(a: T, b: T, t: T) -> T {
+    a + (b - a) * t
+}
+
+#[cfg(feature = "panicking-ops")]
+#[test]
+fn generic() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, t: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(lerp(a, b, t), expected);
+        },
+        all {
+            (fp!(1), fp!(3), fp!(0.5), fp!(2));
+            (fp!(-1.5), fp!(1.5), fp!(0.25), fp!(-0.75));
+            (fp!(0), fp!(1), fp!(0.333333333), fp!(0.333333333));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::zero(), fp!(0));
+            assert!(fp!(0).is_zero());
+            assert!(!fp!(0.000000001).is_zero());
+            assert_eq!(FixedPoint::one(), fp!(1));
+            assert!(fp!(1).is_one());
+            assert_eq!(FixedPoint::from_str_radix("-1.5", 10)?, fp!(1.5).cneg()?);
+            assert!(FixedPoint::from_str_radix("1.5", 16).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "panicking-ops")]
+#[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
+fn operators() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: (FixedPoint, FixedPoint, FixedPoint, FixedPoint, FixedPoint)) => {
+            assert_eq!(a + b, expected.0);
+            assert_eq!(a - b, expected.1);
+            assert_eq!(a * b, expected.2);
+            assert_eq!(a / b, expected.3);
+            assert_eq!(a % b, expected.4);
+
+            assert_eq!(&a + &b, expected.0);
+            assert_eq!(a - &b, expected.1);
+            assert_eq!(&a * b, expected.2);
+            assert_eq!(&a / &b, expected.3);
+            assert_eq!(&a % b, expected.4);
+        },
+        all {
+            (fp!(7.5), fp!(2), (fp!(9.5), fp!(5.5), fp!(15), fp!(3.75), fp!(1.5)));
+            (fp!(-7.5), fp!(2), (fp!(-5.5), fp!(-9.5), fp!(-15), fp!(-3.75), fp!(-1.5)));
+        },
+        fp64 {
+            (fp!(1), fp!(3), (fp!(4), fp!(-2), fp!(3), fp!(0.333333333), fp!(1)));
+            (fp!(2), fp!(3), (fp!(5), fp!(-1), fp!(6), fp!(0.666666667), fp!(2)));
+            (fp!(0.000000001), fp!(0.5), (fp!(0.500000001), fp!(-0.499999999), fp!(0.000000001), fp!(0.000000002), fp!(0.000000001)));
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint) => {
+            assert_eq!(-a, a.cneg()?);
+            assert_eq!(-(-a), a);
+            assert_eq!(-&a, -a);
+        },
+        all {
+            (fp!(0));
+            (fp!(42.5));
+            (FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "panicking-ops")]
+fn sum_of_ratios<'a, T>(values: &'a [T], total: &'a T) -> T
+where
+    T: Num + Copy + 'a,
+    &'a T: core::ops::Div<&'a T, Output = T>,
+{
+    values
+        .iter()
+        .fold(T::zero(), |acc, value| acc + value / total)
+}
+
+#[cfg(feature = "panicking-ops")]
+#[test]
+fn by_ref() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], total: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(sum_of_ratios(values, &total), expected);
+            assert_eq!(values.iter().fold(FixedPoint::zero(), |acc, x| acc + x), total);
+        },
+        all {
+            (&[fp!(1), fp!(3)], fp!(4), fp!(1));
+            (&[fp!(0.5), fp!(1.5), fp!(-1)], fp!(1), fp!(1));
+        },
+    };
+    Ok(())
+}
+
+#[cfg(all(feature = "i64", feature = "panicking-ops"))]
+type FixedPoint64 = fixnum::FixedPoint<i64, typenum::U9>;
+
+#[cfg(all(feature = "i64", feature = "panicking-ops"))]
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_overflow() {
+    let _ = FixedPoint64::MAX + FixedPoint64::EPSILON;
+}
+
+#[cfg(all(feature = "i64", feature = "panicking-ops"))]
+#[test]
+#[should_panic(expected = "attempt to negate with overflow")]
+fn neg_overflow() {
+    let _ = -FixedPoint64::MIN;
+}
+
+#[cfg(all(feature = "i64", feature = "panicking-ops"))]
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_by_zero() {
+    let _ = FixedPoint64::ONE / FixedPoint64::ZERO;
+}
+
+#[cfg(all(feature = "i64", feature = "panicking-ops"))]
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder by zero")]
+fn rem_by_zero() {
+    let _ = FixedPoint64::ONE % FixedPoint64::ZERO;
+}
+
+#[cfg(feature = "panicking-ops")]
+#[test]
+fn checked() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(1).checked_add(&fp!(2)), Some(fp!(3)));
+            assert_eq!(FixedPoint::MAX.checked_add(&FixedPoint::EPSILON), None);
+            assert_eq!(fp!(1).checked_sub(&fp!(2)), Some(fp!(1).cneg()?));
+            assert_eq!(FixedPoint::MIN.checked_sub(&FixedPoint::EPSILON), None);
+            assert_eq!(fp!(1.5).checked_mul(&fp!(2)), Some(fp!(3)));
+            assert_eq!(FixedPoint::MAX.checked_mul(&fp!(2)), None);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "panicking-ops")]
+#[test]
+fn signed() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, abs: FixedPoint, signum: FixedPoint) => {
+            assert_eq!(Signed::abs(&x), abs);
+            assert_eq!(Signed::signum(&x), signum);
+            assert_eq!(x.is_positive(), signum == fp!(1));
+            assert_eq!(x.is_negative(), signum == fp!(1).cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(0.000000001), fp!(0.000000001), fp!(1));
+            (fp!(-42.5), fp!(42.5), fp!(-1));
+            (FixedPoint::MAX, FixedPoint::MAX, fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.abs_sub(&b), expected);
+        },
+        all {
+            (fp!(3), fp!(1), fp!(2));
+            (fp!(1), fp!(3), fp!(0));
+            (fp!(-1), fp!(-1), fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn bounded() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(<FixedPoint as Bounded>::min_value(), FixedPoint::MIN);
+            assert_eq!(<FixedPoint as Bounded>::max_value(), FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::from_i64(-5), Some(fp!(5).cneg()?));
+            assert_eq!(FixedPoint::from_u128(u128::MAX), None);
+            assert_eq!(FixedPoint::from_i128(i128::MIN), None);
+            assert_eq!(FixedPoint::from_u64(5), Some(fp!(5)));
+            assert_eq!(FixedPoint::from_f64(-2.5), Some(fp!(2.5).cneg()?));
+            assert_eq!(FixedPoint::from_f32(0.25), Some(fp!(0.25)));
+            assert_eq!(FixedPoint::from_f64(f64::NAN), None);
+
+            assert_eq!(fp!(2.9).to_i64(), Some(2));
+            assert_eq!(fp!(2.9).cneg()?.to_i64(), Some(-2));
+            assert_eq!(fp!(2.9).cneg()?.to_u64(), None);
+            assert_eq!(fp!(0.9).cneg()?.to_u64(), Some(0));
+            assert_eq!(fp!(42).to_u8(), Some(42));
+            assert_eq!(fp!(256).to_u8(), None);
+            assert_eq!(fp!(2.5).cneg()?.to_f64(), Some(-2.5));
+        },
+    };
+    Ok(())
+}
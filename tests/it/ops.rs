@@ -140,6 +140,37 @@ fn rmul_overflow() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rmul_half_even() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected_nearest: FixedPoint, expected_half_even: FixedPoint) => {
+            assert_eq!(a.rmul(b, Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(a.rmul(b, HalfEven)?, expected_half_even, "HalfEven");
+            assert_eq!(b.rmul(a, HalfEven)?, expected_half_even, "HalfEven, commutative");
+            assert_eq!(a.cneg()?.rmul(b, HalfEven)?, expected_half_even.cneg()?, "HalfEven, negation");
+        },
+        all {
+            (fp!(0.5), fp!(4), fp!(2), fp!(2));
+            (fp!(1.3), fp!(0.1), fp!(0.13), fp!(0.13));
+        },
+        fp64 {
+            (fp!(0.5), fp!(0.000000001), fp!(0.000000001), fp!(0));
+            (fp!(1.5), fp!(0.000000001), fp!(0.000000002), fp!(0.000000002));
+            (fp!(2.5), fp!(0.000000001), fp!(0.000000003), fp!(0.000000002));
+            (fp!(0.51), fp!(0.000000001), fp!(0.000000001), fp!(0.000000001));
+            (fp!(0.49), fp!(0.000000001), fp!(0), fp!(0));
+        },
+        fp128 {
+            (fp!(0.5), fp!(0.000000000000000001), fp!(0.000000000000000001), fp!(0));
+            (fp!(1.5), fp!(0.000000000000000001), fp!(0.000000000000000002), fp!(0.000000000000000002));
+            (fp!(2.5), fp!(0.000000000000000001), fp!(0.000000000000000003), fp!(0.000000000000000002));
+            (fp!(0.51), fp!(0.000000000000000001), fp!(0.000000000000000001), fp!(0.000000000000000001));
+            (fp!(0.49), fp!(0.000000000000000001), fp!(0), fp!(0));
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn rdiv_exact() -> Result<()> {
     test_fixed_point! {
@@ -277,6 +308,51 @@ fn rdiv_round() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rdiv_half_even() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected_nearest: FixedPoint, expected_half_even: FixedPoint) => {
+            assert_eq!(a.rdiv(b, Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(a.rdiv(b, HalfEven)?, expected_half_even, "HalfEven");
+            assert_eq!(a.cneg()?.rdiv(b, HalfEven)?, expected_half_even.cneg()?, "HalfEven, negation");
+            assert_eq!(a.rdiv(b.cneg()?, HalfEven)?, expected_half_even.cneg()?, "HalfEven, negation");
+            assert_eq!(a.cneg()?.rdiv(b.cneg()?, HalfEven)?, expected_half_even, "HalfEven, negation");
+        },
+        all {
+            (fp!(5), fp!(2), fp!(2.5), fp!(2.5));
+            (FixedPoint::MAX, FixedPoint::MAX, fp!(1), fp!(1));
+        },
+        fp64 {
+            (fp!(0.000000001), fp!(2), fp!(0.000000001), fp!(0));
+            (fp!(0.000000003), fp!(2), fp!(0.000000002), fp!(0.000000002));
+            (fp!(0.000000005), fp!(2), fp!(0.000000003), fp!(0.000000002));
+            (fp!(0.000000005), fp!(3), fp!(0.000000002), fp!(0.000000002));
+            (fp!(0.000000007), fp!(3), fp!(0.000000002), fp!(0.000000002));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), fp!(2), fp!(0.000000000000000001), fp!(0));
+            (fp!(0.000000000000000003), fp!(2), fp!(0.000000000000000002), fp!(0.000000000000000002));
+            (fp!(0.000000000000000005), fp!(2), fp!(0.000000000000000003), fp!(0.000000000000000002));
+        },
+    };
+    test_fixed_point! {
+        case (a: Layout, b: Layout, expected_nearest: Layout, expected_half_even: Layout) => {
+            assert_eq!(a.rdiv(b, Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(a.rdiv(b, HalfEven)?, expected_half_even, "HalfEven");
+            assert_eq!((-a).rdiv(b, HalfEven)?, -expected_half_even, "HalfEven, negation");
+        },
+        all {
+            (5, 2, 3, 2);
+            (7, 2, 4, 4);
+            (9, 4, 2, 2);
+            (10, 4, 3, 2);
+            (Layout::MAX, Layout::MIN, -1, -1);
+            (Layout::MIN / 2, Layout::MIN, 1, 0);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn rdiv_layout() -> Result<()> {
     test_fixed_point! {
@@ -303,6 +379,158 @@ fn rdiv_layout() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rdiv_by_int() -> Result<()> {
+    use core::num::NonZero;
+
+    test_fixed_point! {
+        case (a: FixedPoint, b: i32, expected_floor: FixedPoint, expected_ceil: FixedPoint) => {
+            assert_eq!(a.rdiv(b, Floor)?, expected_floor);
+            assert_eq!(a.rdiv(b, Ceil)?, expected_ceil);
+            assert_eq!(a.rdiv(i128::from(b), Floor)?, expected_floor);
+            assert_eq!(a.rdiv(i8::try_from(b)?, Ceil)?, expected_ceil);
+            assert_eq!(a.rdiv(NonZero::new(b).unwrap(), Floor)?, expected_floor);
+
+            if let Ok(b) = u32::try_from(b) {
+                assert_eq!(a.rdiv(b, Floor)?, expected_floor);
+                assert_eq!(a.rdiv(u64::from(b), Ceil)?, expected_ceil);
+                assert_eq!(a.rdiv(NonZero::new(b).unwrap(), Ceil)?, expected_ceil);
+            }
+        },
+        all {
+            (fp!(2.4), 2, fp!(1.2), fp!(1.2));
+            (fp!(0), 5, fp!(0), fp!(0));
+            (fp!(-2.4), -2, fp!(1.2), fp!(1.2));
+        },
+        fp64 {
+            (fp!(7), 3, fp!(2.333333333), fp!(2.333333334));
+            (fp!(7), -3, fp!(-2.333333334), fp!(-2.333333333));
+        },
+        fp128 {
+            (fp!(7), 3, fp!(2.333333333333333333), fp!(2.333333333333333334));
+            (fp!(7), -3, fp!(-2.333333333333333334), fp!(-2.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // Divisors beyond the layout.
+            assert_eq!(fp!(1).rdiv(u64::MAX, Floor)?, FixedPoint::ZERO);
+            assert_eq!(fp!(1).rdiv(u64::MAX, Ceil)?, FixedPoint::EPSILON);
+            assert_eq!(fp!(1).rdiv(i128::MAX, Floor)?, FixedPoint::ZERO);
+            assert_eq!(fp!(1).rdiv(i128::MAX, Ceil)?, FixedPoint::EPSILON);
+            assert_eq!(fp!(1).rdiv(i128::MIN, Floor)?, FixedPoint::EPSILON.cneg()?);
+            assert_eq!(fp!(1).rdiv(0u32, Floor), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(FixedPoint::MIN.rdiv(-1i8, Floor), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rmul_by_int() -> Result<()> {
+    use core::num::NonZero;
+
+    test_fixed_point! {
+        case (a: FixedPoint, b: i32, expected: FixedPoint) => {
+            assert_eq!(a.rmul(b, Floor)?, expected);
+            assert_eq!(a.rmul(Layout::from(b), Ceil)?, expected);
+            assert_eq!(a.rmul(i128::from(b), Floor)?, expected);
+            assert_eq!(a.rmul(NonZero::new(b).unwrap(), Ceil)?, expected);
+            if let Ok(b) = u64::try_from(b) {
+                assert_eq!(a.rmul(b, Floor)?, expected);
+            }
+        },
+        all {
+            (fp!(1.2), 2, fp!(2.4));
+            (fp!(0.000000001), -3, fp!(-0.000000003));
+            (fp!(-1.5), -4, fp!(6));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.rmul(2u8, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(fp!(0).rmul(u64::MAX, Floor)?, fp!(0));
+            assert_eq!(fp!(1).rmul(i128::MAX, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.rmul(-1i16, Floor), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rounding_by_ref() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint) => {
+            assert_eq!((&a).rmul(&b, Nearest)?, a.rmul(b, Nearest)?);
+            assert_eq!((&a).rmul(b, Nearest)?, a.rmul(b, Nearest)?);
+            assert_eq!(a.rmul(&b, Nearest)?, a.rmul(b, Nearest)?);
+            assert_eq!((&a).rdiv(&b, Nearest)?, a.rdiv(b, Nearest)?);
+            assert_eq!((&a).rdiv(b, Nearest)?, a.rdiv(b, Nearest)?);
+            assert_eq!(a.rdiv(&b, Nearest)?, a.rdiv(b, Nearest)?);
+
+            let int: Layout = 3;
+            assert_eq!((&a).rdiv(&int, Floor)?, a.rdiv(int, Floor)?);
+            assert_eq!(a.rmul(&int, Floor)?, a.rmul(int, Floor)?);
+
+            assert_eq!((&a).cadd(&b)?, a.cadd(b)?);
+            assert_eq!(a.cadd(&b)?, a.cadd(b)?);
+            assert_eq!((&a).csub(b)?, a.csub(b)?);
+            assert_eq!((&a).csub(&b)?, a.csub(b)?);
+            assert_eq!(a.csub(&b)?, a.csub(b)?);
+            assert_eq!((&a).cmul(&int)?, a.cmul(int)?);
+            assert_eq!(a.cmul(&int)?, a.cmul(int)?);
+            assert_eq!((&a).crem(&b)?, a.crem(b)?);
+
+            fn mean<'a, T>(values: &'a [T], total: &'a T) -> Result<T, ArithmeticError>
+            where
+                &'a T: RoundingDiv<&'a T, Output = T, Error = ArithmeticError>,
+            {
+                values[0].rdiv(total, Nearest)
+            }
+            assert_eq!(mean(&[a], &b)?, a.rdiv(b, Nearest)?);
+        },
+        all {
+            (fp!(2.5), fp!(0.5));
+            (fp!(-7), fp!(3));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn checked_recip() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, floor: FixedPoint, ceil: FixedPoint) => {
+            assert_eq!(a.checked_recip(Floor)?, floor);
+            assert_eq!(a.checked_recip(Ceil)?, ceil);
+            assert_eq!(a.recip(Floor)?, floor);
+            assert_eq!(a.cneg()?.checked_recip(Ceil)?, floor.cneg()?);
+        },
+        all {
+            (fp!(1), fp!(1), fp!(1));
+            (fp!(4), fp!(0.25), fp!(0.25));
+            (fp!(0.5), fp!(2), fp!(2));
+            (fp!(0.001), fp!(1000), fp!(1000));
+            (fp!(1000000000), fp!(0.000000001), fp!(0.000000001));
+        },
+        fp64 {
+            (fp!(3), fp!(0.333333333), fp!(0.333333334));
+            (fp!(3000000000), fp!(0), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(3), fp!(0.333333333333333333), fp!(0.333333333333333334));
+            (fp!(3000000000000000000), fp!(0), fp!(0.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::ZERO.checked_recip(Floor), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(FixedPoint::EPSILON.checked_recip(Floor)?.checked_recip(Floor)?, FixedPoint::EPSILON);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn rdiv_division_by_zero() -> Result<()> {
     test_fixed_point! {
@@ -451,6 +679,154 @@ fn half_sum_rounded() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn midpoint() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.midpoint(b), expected);
+            assert_eq!(b.midpoint(a), expected);
+        },
+        all {
+            (fp!(1), fp!(3), fp!(2));
+            (fp!(100.5), fp!(100.75), fp!(100.625));
+            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX);
+            (FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MIN);
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::EPSILON.cneg()?);
+            (FixedPoint::MAX, FixedPoint::MAX.csub(FixedPoint::EPSILON)?, FixedPoint::MAX);
+            (FixedPoint::EPSILON, fp!(0), FixedPoint::EPSILON);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn lerp() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, t: FixedPoint, floor: FixedPoint, ceil: FixedPoint) => {
+            assert_eq!(a.lerp(b, t, Floor)?, floor);
+            assert_eq!(a.lerp(b, t, Ceil)?, ceil);
+            assert_eq!(b.lerp(a, fp!(1).csub(t)?, Floor)?, floor);
+            assert_eq!(a.cneg()?.lerp(b.cneg()?, t, Ceil)?, floor.cneg()?);
+        },
+        all {
+            (fp!(1), fp!(3), fp!(0), fp!(1), fp!(1));
+            (fp!(1), fp!(3), fp!(1), fp!(3), fp!(3));
+            (fp!(1), fp!(3), fp!(0.25), fp!(1.5), fp!(1.5));
+            (fp!(3), fp!(1), fp!(0.25), fp!(2.5), fp!(2.5));
+            (fp!(1), fp!(3), fp!(2), fp!(5), fp!(5));
+            (fp!(1), fp!(3), fp!(0.5).cneg()?, fp!(0), fp!(0));
+            (fp!(1).cneg()?, fp!(1), fp!(0.5), fp!(0), fp!(0));
+            (FixedPoint::ZERO, FixedPoint::EPSILON, fp!(0.5), fp!(0), FixedPoint::EPSILON);
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, t: FixedPoint, floor: FixedPoint, ceil: FixedPoint) => {
+            assert_eq!(a.lerp(b, t, Floor)?, floor);
+            assert_eq!(a.lerp(b, t, Ceil)?, ceil);
+        },
+        all {
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(0), FixedPoint::MIN, FixedPoint::MIN);
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(1), FixedPoint::MAX, FixedPoint::MAX);
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(0.5), FixedPoint::EPSILON.cneg()?, fp!(0));
+            (FixedPoint::MAX, FixedPoint::MIN, fp!(0.5), FixedPoint::EPSILON.cneg()?, fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.lerp(FixedPoint::MAX, fp!(2), Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MAX.lerp(FixedPoint::MIN, FixedPoint::MAX, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.lerp(FixedPoint::MAX, FixedPoint::MIN, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MAX.lerp(FixedPoint::MAX, FixedPoint::MIN, Floor)?, FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn abs_diff() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.abs_diff(b), expected);
+            assert_eq!(b.abs_diff(a), expected);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(1), fp!(3), fp!(2));
+            (fp!(100.5), fp!(-0.25), fp!(100.75));
+            (FixedPoint::MAX, FixedPoint::MAX, fp!(0));
+            (FixedPoint::MIN, fp!(0), FixedPoint::MAX);
+            (FixedPoint::MIN, FixedPoint::EPSILON.cneg()?, FixedPoint::MAX);
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sign() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, signum: FixedPoint) => {
+            assert_eq!(a.signum(), signum);
+            assert_eq!(a.cneg()?.signum(), signum.cneg()?);
+            assert_eq!(a.checked_abs()?, a);
+            assert_eq!(a.cneg()?.checked_abs()?, a);
+            assert_eq!(a.checked_neg()?, a.cneg()?);
+
+            assert_eq!(a.copysign(fp!(1))?, a);
+            assert_eq!(a.copysign(fp!(0))?, a);
+            assert_eq!(a.copysign(fp!(0.5).cneg()?)?, a.cneg()?);
+            assert_eq!(a.cneg()?.copysign(FixedPoint::MAX)?, a);
+            assert_eq!(a.cneg()?.copysign(FixedPoint::MIN)?, a.cneg()?);
+        },
+        all {
+            (fp!(0), fp!(0));
+            (FixedPoint::EPSILON, fp!(1));
+            (fp!(0.5), fp!(1));
+            (fp!(42), fp!(1));
+            (FixedPoint::MAX, fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.signum(), fp!(1).cneg()?);
+            assert_eq!(FixedPoint::MIN.checked_abs(), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.checked_neg(), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.copysign(fp!(1).cneg()?)?, FixedPoint::MIN);
+            assert_eq!(FixedPoint::MIN.copysign(fp!(1)), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn clamp() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, lo: FixedPoint, hi: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.clamp(lo, hi), expected);
+            assert_eq!(a.max(lo).min(hi), expected);
+            assert_eq!(Ord::clamp(a, lo, hi), expected);
+        },
+        all {
+            (fp!(1), fp!(0), fp!(2), fp!(1));
+            (fp!(0), fp!(0), fp!(2), fp!(0));
+            (fp!(2), fp!(0), fp!(2), fp!(2));
+            (fp!(3), fp!(0), fp!(2), fp!(2));
+            (fp!(1).cneg()?, fp!(0), fp!(2), fp!(0));
+            (FixedPoint::MIN, fp!(0.5), fp!(0.5), fp!(0.5));
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "i64")]
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn clamp_invalid_bounds() {
+    type F64p9 = FixedPoint<i64, typenum::U9>;
+    let _ = F64p9::ZERO.clamp(F64p9::ONE, F64p9::ZERO);
+}
+
 #[test]
 fn integral() -> Result<()> {
     test_fixed_point! {
@@ -478,6 +854,32 @@ fn integral() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn integral_half_even() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, expected: Layout) => {
+            assert_eq!(a.integral(HalfEven), expected);
+            assert_eq!(a.cneg()?.integral(HalfEven), -expected);
+        },
+        all {
+            (fp!(0), 0);
+            (fp!(0.4), 0);
+            (fp!(0.5), 0);
+            (fp!(0.6), 1);
+            (fp!(1.5), 2);
+            (fp!(2.5), 2);
+            (fp!(2.500000001), 3);
+            (fp!(3.5), 4);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.integral(Nearest), FixedPoint::MIN.integral(HalfEven));
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn round_towards_zero_by() -> Result<()> {
     test_fixed_point! {
@@ -628,7 +1030,7 @@ fn saturating_mul() -> Result<()> {
     test_fixed_point! {
         case (a: FixedPoint, b: Layout, expected: FixedPoint) => {
             assert_eq!(a.saturating_mul(b), expected);
-            assert_eq!(CheckedMul::saturating_mul(b, a), expected);
+            assert_eq!(SaturatingMul::saturating_mul(b, a), expected);
             assert_eq!(a.cneg()?.saturating_mul(b), expected.cneg()?);
             assert_eq!(a.saturating_mul(-b), expected.cneg()?);
             assert_eq!(a.cneg()?.saturating_mul(-b), expected);
@@ -771,6 +1173,46 @@ fn saturating_sub() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[allow(deprecated)]
+fn deprecated_saturating() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint) => {
+            assert_eq!(CheckedAdd::saturating_add(a, b), a.saturating_add(b));
+            assert_eq!(CheckedSub::saturating_sub(a, b), a.saturating_sub(b));
+            assert_eq!(CheckedMul::saturating_mul(a, 3), a.saturating_mul(3));
+            assert_eq!(RoundingMul::saturating_rmul(a, b, Floor), a.saturating_rmul(b, Floor));
+        },
+        all {
+            (fp!(1.5), fp!(-2));
+            (FixedPoint::MAX, fp!(2));
+            (FixedPoint::MIN, fp!(2));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn saturating_generic() -> Result<()> {
+    fn sum<T: SaturatingAdd<Output = T> + Zero>(values: impl IntoIterator<Item = T>) -> T {
+        values
+            .into_iter()
+            .fold(T::ZERO, SaturatingAdd::saturating_add)
+    }
+
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(sum([a, b, a]), expected);
+        },
+        all {
+            (fp!(1), fp!(2), fp!(4));
+            (FixedPoint::MAX, fp!(-1), FixedPoint::MAX);
+            (FixedPoint::MIN, fp!(1), FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn sqrt_exact() -> Result<()> {
     test_fixed_point! {
@@ -804,6 +1246,7 @@ fn sqrt_approx() -> Result<()> {
         case (x: FixedPoint, expected_floor: FixedPoint, expected_nearest: FixedPoint) => {
             assert_eq!(x.rsqrt(Floor)?, expected_floor, "Floor");
             assert_eq!(x.rsqrt(Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(x.rsqrt(HalfEven)?, expected_nearest, "HalfEven");
             assert_eq!(x.rsqrt(Ceil)?, expected_floor.cadd(FixedPoint::from_bits(1))?, "Ceil");
         },
         fp64 {
@@ -839,3 +1282,872 @@ fn sqrt_negative() -> Result<()> {
     };
     Ok(())
 }
+
+#[test]
+fn hypot() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, floor: FixedPoint, nearest: FixedPoint, ceil: FixedPoint) => {
+            assert_eq!(a.hypot(b, Floor)?, floor, "Floor");
+            assert_eq!(a.hypot(b, Nearest)?, nearest, "Nearest");
+            assert_eq!(a.hypot(b, Ceil)?, ceil, "Ceil");
+            assert_eq!(b.cneg()?.hypot(a, Floor)?, floor, "Floor, swapped");
+            assert_eq!(FixedPoint::l2_norm(&[a, fp!(0), b.cneg()?], Ceil)?, ceil, "l2_norm");
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0), fp!(0), fp!(0));
+            (fp!(3), fp!(4), fp!(5), fp!(5), fp!(5));
+            (fp!(0), fp!(7.5), fp!(7.5), fp!(7.5), fp!(7.5));
+            (FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::from_bits(2));
+            (FixedPoint::MAX, fp!(0), FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX);
+        },
+        fp64 {
+            (fp!(1), fp!(1), fp!(1.414213562), fp!(1.414213562), fp!(1.414213563));
+            (fp!(6000000000), fp!(6000000000), fp!(8485281374.238570292), fp!(8485281374.238570293), fp!(8485281374.238570293));
+        },
+        fp128 {
+            (fp!(1), fp!(1), fp!(1.414213562373095048), fp!(1.414213562373095049), fp!(1.414213562373095049));
+            (fp!(100000000000), fp!(100000000000), fp!(141421356237.309504880168872420), fp!(141421356237.309504880168872421), fp!(141421356237.309504880168872421));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.hypot(FixedPoint::EPSILON, Ceil), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MAX.hypot(FixedPoint::MAX, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.hypot(FixedPoint::MIN, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.hypot(FixedPoint::ZERO, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::l2_norm(&[], Floor)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::l2_norm(&[FixedPoint::MIN; 8], Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::l2_norm(&[fp!(1), fp!(2), fp!(2)], Floor)?, fp!(3));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn checked_powi_exact() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, exp: u32, expected: FixedPoint) => {
+            for mode in [Floor, Nearest, Ceil, HalfEven] {
+                assert_eq!(x.checked_powi(exp, mode)?, expected, "{:?}", mode);
+            }
+        },
+        all {
+            (fp!(0), 0, fp!(1));
+            (fp!(0), 5, fp!(0));
+            (fp!(1), 1000, fp!(1));
+            (fp!(-1), 1000, fp!(1));
+            (fp!(-1), 1001, fp!(-1));
+            (fp!(2), 10, fp!(1024));
+            (fp!(-2), 3, fp!(-8));
+            (fp!(1.5), 2, fp!(2.25));
+            (fp!(-0.1), 5, fp!(-0.00001));
+            (FixedPoint::MAX, 1, FixedPoint::MAX);
+            (FixedPoint::MIN, 1, FixedPoint::MIN);
+            (FixedPoint::MIN, 0, fp!(1));
+        },
+        fp128 {
+            (fp!(2), 64, fp!(18446744073709551616));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn checked_powi_round() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, exp: u32, expected_floor: FixedPoint, expected_ceil: FixedPoint) => {
+            assert_eq!(x.checked_powi(exp, Floor)?, expected_floor, "Floor");
+            assert_eq!(x.checked_powi(exp, Ceil)?, expected_ceil, "Ceil");
+
+            if exp % 2 == 1 {
+                assert_eq!(x.cneg()?.checked_powi(exp, Floor)?, expected_ceil.cneg()?, "Floor, negation");
+                assert_eq!(x.cneg()?.checked_powi(exp, Ceil)?, expected_floor.cneg()?, "Ceil, negation");
+            } else {
+                assert_eq!(x.cneg()?.checked_powi(exp, Floor)?, expected_floor, "Floor, negation");
+                assert_eq!(x.cneg()?.checked_powi(exp, Ceil)?, expected_ceil, "Ceil, negation");
+            }
+        },
+        fp64 {
+            (fp!(0.00001), 2, fp!(0), fp!(0.000000001));
+            (fp!(1.001), 365, fp!(1.440251273), fp!(1.440251524));
+            (fp!(0.5), 31, fp!(0), fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(0.000000001), 3, fp!(0), fp!(0.000000000000000001));
+            (fp!(0.5), 61, fp!(0), fp!(0.000000000000000001));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn checked_powi_overflow() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, exp: u32) => {
+            assert_eq!(x.checked_powi(exp, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(x.checked_powi(exp, Ceil), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MAX, 2);
+            (FixedPoint::MIN, 2);
+            (FixedPoint::MIN, 3);
+            (fp!(2), 128);
+            (fp!(10), 21);
+            (fp!(-10), 21);
+        },
+        fp64 {
+            (fp!(10), 10);
+            (fp!(-10), 11);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mul_div_exact() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint, expected: FixedPoint) => {
+            for mode in [Floor, Nearest, Ceil, HalfEven] {
+                assert_eq!(a.mul_div(b, c, mode)?, expected, "{:?}", mode);
+            }
+        },
+        all {
+            (fp!(0), fp!(5), fp!(3), fp!(0));
+            (fp!(2), fp!(3), fp!(6), fp!(1));
+            (fp!(-2), fp!(3), fp!(6), fp!(-1));
+            (fp!(2), fp!(-3), fp!(-6), fp!(1));
+            (fp!(0.5), fp!(0.5), fp!(0.25), fp!(1));
+            (FixedPoint::MAX, fp!(2), fp!(2), FixedPoint::MAX);
+            (FixedPoint::MIN, fp!(3), fp!(3), FixedPoint::MIN);
+            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX);
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mul_div_round() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint, expected_floor: FixedPoint, expected_ceil: FixedPoint) => {
+            assert_eq!(a.mul_div(b, c, Floor)?, expected_floor, "Floor");
+            assert_eq!(a.mul_div(b, c, Ceil)?, expected_ceil, "Ceil");
+            assert_eq!(a.cneg()?.mul_div(b, c, Floor)?, expected_ceil.cneg()?, "Floor, negation");
+            assert_eq!(a.mul_div(b, c.cneg()?, Ceil)?, expected_floor.cneg()?, "Ceil, negation");
+        },
+        all {
+            (FixedPoint::EPSILON, fp!(1), fp!(3), fp!(0), FixedPoint::EPSILON);
+        },
+        fp64 {
+            (fp!(1000), fp!(7), fp!(3), fp!(2333.333333333), fp!(2333.333333334));
+            (fp!(0.1), fp!(0.1), fp!(0.3), fp!(0.033333333), fp!(0.033333334));
+        },
+        fp128 {
+            (fp!(1000), fp!(7), fp!(3), fp!(2333.333333333333333333), fp!(2333.333333333333333334));
+            (fp!(0.1), fp!(0.1), fp!(0.3), fp!(0.033333333333333333), fp!(0.033333333333333334));
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint, expected_nearest: FixedPoint, expected_half_even: FixedPoint) => {
+            assert_eq!(a.mul_div(b, c, Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(a.mul_div(b, c, HalfEven)?, expected_half_even, "HalfEven");
+            assert_eq!(a.cneg()?.mul_div(b, c, HalfEven)?, expected_half_even.cneg()?, "HalfEven, negation");
+        },
+        all {
+            (FixedPoint::EPSILON, fp!(1), fp!(2), FixedPoint::EPSILON, fp!(0));
+            (FixedPoint::EPSILON, fp!(3), fp!(2), FixedPoint::from_bits(2), FixedPoint::from_bits(2));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mul_div_errors() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint, expected: ArithmeticError) => {
+            assert_eq!(a.mul_div(b, c, Floor), Err(expected.clone()));
+            assert_eq!(a.mul_div(b, c, Ceil), Err(expected));
+        },
+        all {
+            (fp!(1), fp!(1), fp!(0), ArithmeticError::DivisionByZero);
+            (FixedPoint::MAX, fp!(2), fp!(1), ArithmeticError::Overflow);
+            (FixedPoint::MIN, fp!(-1), fp!(1), ArithmeticError::Overflow);
+            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::EPSILON, ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn crem_and_euclid() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, rem: FixedPoint, div_euclid: Layout, rem_euclid: FixedPoint) => {
+            assert_eq!(a.crem(b)?, rem);
+            assert_eq!(a.checked_div_euclid(b)?, div_euclid);
+            assert_eq!(a.checked_rem_euclid(b)?, rem_euclid);
+            assert_eq!(b.cmul(div_euclid)?.cadd(rem_euclid)?, a);
+        },
+        all {
+            (fp!(7.5), fp!(2), fp!(1.5), 3, fp!(1.5));
+            (fp!(-7.5), fp!(2), fp!(-1.5), -4, fp!(0.5));
+            (fp!(7.5), fp!(-2), fp!(1.5), -3, fp!(1.5));
+            (fp!(-7.5), fp!(-2), fp!(-1.5), 4, fp!(0.5));
+            (fp!(6), fp!(1.5), fp!(0), 4, fp!(0));
+            (fp!(0.3), fp!(0.7), fp!(0.3), 0, fp!(0.3));
+            (fp!(-0.3), fp!(0.7), fp!(-0.3), -1, fp!(0.4));
+            (FixedPoint::MAX, FixedPoint::EPSILON, fp!(0), Layout::MAX, fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: ArithmeticError) => {
+            assert_eq!(a.crem(b), Err(expected.clone()));
+            assert_eq!(a.checked_div_euclid(b), Err(expected.clone()));
+            assert_eq!(a.checked_rem_euclid(b), Err(expected));
+        },
+        all {
+            (fp!(1), fp!(0), ArithmeticError::DivisionByZero);
+            (FixedPoint::MIN, FixedPoint::EPSILON.cneg()?, ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_to_step() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, step: FixedPoint, floor: FixedPoint, ceil: FixedPoint, nearest: FixedPoint, half_even: FixedPoint) => {
+            assert_eq!(a.round_to_step(step, Floor)?, floor, "Floor");
+            assert_eq!(a.round_to_step(step, Ceil)?, ceil, "Ceil");
+            assert_eq!(a.round_to_step(step, Nearest)?, nearest, "Nearest");
+            assert_eq!(a.round_to_step(step, HalfEven)?, half_even, "HalfEven");
+
+            assert_eq!(a.round_to_step(step.cneg()?, Floor)?, floor, "Floor, negative step");
+            assert_eq!(a.cneg()?.round_to_step(step, Floor)?, ceil.cneg()?, "Floor, negation");
+            assert_eq!(a.cneg()?.round_to_step(step, Ceil)?, floor.cneg()?, "Ceil, negation");
+            assert_eq!(a.cneg()?.round_to_step(step, HalfEven)?, half_even.cneg()?, "HalfEven, negation");
+        },
+        all {
+            (fp!(0), fp!(0.05), fp!(0), fp!(0), fp!(0), fp!(0));
+            (fp!(1.25), fp!(0.05), fp!(1.25), fp!(1.25), fp!(1.25), fp!(1.25));
+            (fp!(1.234), fp!(0.05), fp!(1.2), fp!(1.25), fp!(1.25), fp!(1.25));
+            (fp!(1.225), fp!(0.05), fp!(1.2), fp!(1.25), fp!(1.25), fp!(1.2));
+            (fp!(1.275), fp!(0.05), fp!(1.25), fp!(1.3), fp!(1.3), fp!(1.3));
+            (fp!(10.1), fp!(0.25), fp!(10), fp!(10.25), fp!(10), fp!(10));
+            (fp!(0.3), fp!(7), fp!(0), fp!(7), fp!(0), fp!(0));
+            (fp!(5.5), fp!(1), fp!(5), fp!(6), fp!(6), fp!(6));
+            (fp!(4.5), fp!(1), fp!(4), fp!(5), fp!(5), fp!(4));
+            (FixedPoint::MAX, FixedPoint::EPSILON, FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, step: FixedPoint, mode: RoundMode, expected: ArithmeticError) => {
+            assert_eq!(a.round_to_step(step, mode), Err(expected));
+        },
+        all {
+            (fp!(1), fp!(0), Floor, ArithmeticError::DivisionByZero);
+            (FixedPoint::MAX, fp!(1), Ceil, ArithmeticError::Overflow);
+            (FixedPoint::MIN, fp!(1), Floor, ArithmeticError::Overflow);
+            (FixedPoint::MIN, FixedPoint::EPSILON.cneg()?, Floor, ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn cash_rounding() -> Result<()> {
+    test_fixed_point! {
+        case (total: FixedPoint, increment: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(total.round_to_step(increment, mode)?, expected);
+        },
+        all {
+            // CHF
+            (fp!(2.42), fp!(0.05), Nearest, fp!(2.4));
+            (fp!(2.43), fp!(0.05), Nearest, fp!(2.45));
+            (fp!(2.475), fp!(0.05), Nearest, fp!(2.5));
+            (fp!(-2.475), fp!(0.05), Nearest, fp!(-2.5));
+            (fp!(2.475), fp!(0.05), HalfEven, fp!(2.5));
+            (fp!(2.425), fp!(0.05), HalfEven, fp!(2.4));
+            // NZD
+            (fp!(3.04), fp!(0.1), Nearest, fp!(3));
+            (fp!(3.05), fp!(0.1), Nearest, fp!(3.1));
+            (fp!(3.05), fp!(0.1), HalfEven, fp!(3));
+            (fp!(3.15), fp!(0.1), HalfEven, fp!(3.2));
+            (fp!(3.06), fp!(0.1), Floor, fp!(3));
+            // SEK
+            (fp!(99.49), fp!(1), Nearest, fp!(99));
+            (fp!(99.5), fp!(1), Nearest, fp!(100));
+            (fp!(-99.5), fp!(1), Ceil, fp!(-99));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_to_precision() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, digits: u8, floor: FixedPoint, ceil: FixedPoint, half_even: FixedPoint) => {
+            assert_eq!(a.round_to_precision(digits, Floor)?, floor, "Floor");
+            assert_eq!(a.round_to_precision(digits, Ceil)?, ceil, "Ceil");
+            assert_eq!(a.round_to_precision(digits, HalfEven)?, half_even, "HalfEven");
+            assert_eq!(a.cneg()?.round_to_precision(digits, Floor)?, ceil.cneg()?, "Floor, negation");
+        },
+        all {
+            (fp!(0), 2, fp!(0), fp!(0), fp!(0));
+            (fp!(1.2345), 2, fp!(1.23), fp!(1.24), fp!(1.23));
+            (fp!(1.235), 2, fp!(1.23), fp!(1.24), fp!(1.24));
+            (fp!(1.245), 2, fp!(1.24), fp!(1.25), fp!(1.24));
+            (fp!(7.5), 0, fp!(7), fp!(8), fp!(8));
+            (fp!(1.000000001), 9, fp!(1.000000001), fp!(1.000000001), fp!(1.000000001));
+            (fp!(1.000000001), 255, fp!(1.000000001), fp!(1.000000001), fp!(1.000000001));
+        },
+        fp128 {
+            (fp!(1.000000000000000001), 17, fp!(1), fp!(1.00000000000000001), fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.round_to_precision(0, Ceil), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.round_to_precision(0, Floor), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_significant() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, figures: u8, floor: FixedPoint, ceil: FixedPoint, half_even: FixedPoint) => {
+            assert_eq!(a.round_significant(figures, Floor)?, floor, "Floor");
+            assert_eq!(a.round_significant(figures, Ceil)?, ceil, "Ceil");
+            assert_eq!(a.round_significant(figures, HalfEven)?, half_even, "HalfEven");
+            assert_eq!(a.cneg()?.round_significant(figures, Floor)?, ceil.cneg()?, "Floor, negation");
+        },
+        all {
+            (fp!(0), 1, fp!(0), fp!(0), fp!(0));
+            (fp!(12345.678), 3, fp!(12300), fp!(12400), fp!(12300));
+            (fp!(12345.678), 8, fp!(12345.678), fp!(12345.678), fp!(12345.678));
+            (fp!(0.0012345), 2, fp!(0.0012), fp!(0.0013), fp!(0.0012));
+            (fp!(0.125), 2, fp!(0.12), fp!(0.13), fp!(0.12));
+            (fp!(999.5), 3, fp!(999), fp!(1000), fp!(1000));
+            (fp!(7), 1, fp!(7), fp!(7), fp!(7));
+            (FixedPoint::EPSILON, 1, FixedPoint::EPSILON, FixedPoint::EPSILON, FixedPoint::EPSILON);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(1).round_significant(0, Floor), Err(ArithmeticError::DomainViolation));
+            assert_eq!(FixedPoint::MAX.round_significant(1, Ceil), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn trunc_fract_into_parts() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, trunc: FixedPoint, fract: FixedPoint) => {
+            assert_eq!(a.trunc(), trunc);
+            assert_eq!(a.fract(), fract);
+            assert_eq!(a.trunc().cadd(a.fract())?, a);
+            assert_eq!(a.cneg()?.trunc(), trunc.cneg()?);
+            assert_eq!(a.cneg()?.fract(), fract.cneg()?);
+
+            let (integral, fractional) = a.into_parts();
+            assert_eq!(FixedPoint::from_bits(integral * fp!(1).into_bits()), trunc);
+            assert_eq!(FixedPoint::from_bits(fractional), fract);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(1), fp!(1), fp!(0));
+            (fp!(12.345), fp!(12), fp!(0.345));
+            (fp!(0.5), fp!(0), fp!(0.5));
+            (FixedPoint::EPSILON, fp!(0), FixedPoint::EPSILON);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.trunc().cadd(FixedPoint::MAX.fract())?, FixedPoint::MAX);
+            assert_eq!(FixedPoint::MIN.trunc().cadd(FixedPoint::MIN.fract())?, FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, integral: Layout, fractional: Layout) => {
+            assert_eq!(a.into_parts(), (integral, fractional));
+            assert_eq!(a.cneg()?.into_parts(), (-integral, -fractional));
+        },
+        fp64 {
+            (fp!(12.34), 12, 340_000_000);
+        },
+        fp128 {
+            (fp!(12.34), 12, 340_000_000_000_000_000);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn split() -> Result<()> {
+    test_fixed_point! {
+        case (amount: FixedPoint, n: usize, expected: &[FixedPoint]) => {
+            let parts = amount.split(n)?;
+            assert_eq!(parts.len(), n);
+            assert_eq!(parts.collect::<Vec<_>>(), expected);
+
+            let negated = amount.cneg()?.split(n)?.map(|part| part.cneg()).collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(negated, expected);
+        },
+        all {
+            (fp!(0), 2, &[fp!(0), fp!(0)]);
+            (fp!(10), 1, &[fp!(10)]);
+            (fp!(10), 4, &[fp!(2.5), fp!(2.5), fp!(2.5), fp!(2.5)]);
+            (FixedPoint::from_bits(5), 3, &[FixedPoint::from_bits(2), FixedPoint::from_bits(2), FixedPoint::from_bits(1)]);
+            (FixedPoint::from_bits(2), 3, &[FixedPoint::EPSILON, FixedPoint::EPSILON, fp!(0)]);
+        },
+        fp64 {
+            (fp!(100), 3, &[fp!(33.333333334), fp!(33.333333333), fp!(33.333333333)]);
+        },
+        fp128 {
+            (fp!(100), 3, &[fp!(33.333333333333333334), fp!(33.333333333333333333), fp!(33.333333333333333333)]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(1).split(0).err(), Some(ArithmeticError::DivisionByZero));
+            assert_eq!(FixedPoint::MAX.split(2)?.fold(Ok(fp!(0)), |sum: Result<FixedPoint, _>, part| sum?.cadd(part))?, FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn allocate() -> Result<()> {
+    test_fixed_point! {
+        case (amount: FixedPoint, ratios: &[FixedPoint], expected: &[FixedPoint]) => {
+            assert_eq!(amount.allocate(ratios)?.collect::<Vec<_>>(), expected);
+
+            let negated = amount.cneg()?.allocate(ratios)?.map(|part| part.cneg()).collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(negated, expected);
+        },
+        all {
+            (fp!(0), &[fp!(1), fp!(2)], &[fp!(0), fp!(0)]);
+            (fp!(10), &[fp!(1), fp!(3)], &[fp!(2.5), fp!(7.5)]);
+            (fp!(10), &[fp!(0.3), fp!(0), fp!(0.7)], &[fp!(3), fp!(0), fp!(7)]);
+            (FixedPoint::from_bits(5), &[fp!(0.3), fp!(0.7)], &[FixedPoint::from_bits(2), FixedPoint::from_bits(3)]);
+            (FixedPoint::from_bits(2), &[fp!(0), fp!(1), fp!(1), fp!(1)], &[fp!(0), FixedPoint::EPSILON, FixedPoint::EPSILON, fp!(0)]);
+            (FixedPoint::MAX, &[fp!(1), fp!(1)], &[FixedPoint::from_bits(FixedPoint::MAX.into_bits() / 2 + 1), FixedPoint::from_bits(FixedPoint::MAX.into_bits() / 2)]);
+        },
+        fp64 {
+            (fp!(100), &[fp!(1), fp!(1), fp!(1)], &[fp!(33.333333334), fp!(33.333333333), fp!(33.333333333)]);
+        },
+        fp128 {
+            (fp!(100), &[fp!(1), fp!(1), fp!(1)], &[fp!(33.333333333333333334), fp!(33.333333333333333333), fp!(33.333333333333333333)]);
+        },
+    };
+    test_fixed_point! {
+        case (ratios: &[FixedPoint], expected: ArithmeticError) => {
+            assert_eq!(fp!(1).allocate(ratios).err(), Some(expected));
+        },
+        all {
+            (&[], ArithmeticError::DivisionByZero);
+            (&[fp!(0), fp!(0)], ArithmeticError::DivisionByZero);
+            (&[fp!(1), FixedPoint::EPSILON.cneg()?], ArithmeticError::DomainViolation);
+            (&[FixedPoint::MAX, fp!(1)], ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn range_step() -> Result<()> {
+    test_fixed_point! {
+        case (start: FixedPoint, end: FixedPoint, step: FixedPoint, expected: &[FixedPoint], inclusive: &[FixedPoint]) => {
+            let range = FixedPoint::range_step(start, end, step)?;
+            assert_eq!(range.size_hint(), (expected.len(), Some(expected.len())));
+            assert_eq!(range.collect::<Vec<_>>(), expected, "exclusive");
+
+            let range = FixedPoint::range_step_inclusive(start, end, step)?;
+            assert_eq!(range.size_hint(), (inclusive.len(), Some(inclusive.len())));
+            assert_eq!(range.collect::<Vec<_>>(), inclusive, "inclusive");
+        },
+        all {
+            (fp!(1), fp!(2), fp!(0.25), &[fp!(1), fp!(1.25), fp!(1.5), fp!(1.75)], &[fp!(1), fp!(1.25), fp!(1.5), fp!(1.75), fp!(2)]);
+            (fp!(1), fp!(2), fp!(0.3), &[fp!(1), fp!(1.3), fp!(1.6), fp!(1.9)], &[fp!(1), fp!(1.3), fp!(1.6), fp!(1.9)]);
+            (fp!(1), fp!(1), fp!(0.5), &[], &[fp!(1)]);
+            (fp!(1), fp!(0), fp!(0.5), &[], &[]);
+            (fp!(1), fp!(2), fp!(5), &[fp!(1)], &[fp!(1)]);
+            (fp!(0.5), fp!(-0.5), fp!(-0.5), &[fp!(0.5), fp!(0)], &[fp!(0.5), fp!(0), fp!(-0.5)]);
+            (fp!(-1), fp!(1), fp!(-0.5), &[], &[]);
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX, &[FixedPoint::MIN, FixedPoint::from_bits(-1), FixedPoint::MAX.csub(FixedPoint::EPSILON)?], &[FixedPoint::MIN, FixedPoint::from_bits(-1), FixedPoint::MAX.csub(FixedPoint::EPSILON)?]);
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MIN, &[FixedPoint::MAX, FixedPoint::from_bits(-1)], &[FixedPoint::MAX, FixedPoint::from_bits(-1)]);
+            (FixedPoint::MAX.csub(FixedPoint::EPSILON)?, FixedPoint::MAX, FixedPoint::EPSILON, &[FixedPoint::MAX.csub(FixedPoint::EPSILON)?], &[FixedPoint::MAX.csub(FixedPoint::EPSILON)?, FixedPoint::MAX]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::range_step(fp!(0), fp!(1), fp!(0)).err(), Some(ArithmeticError::DomainViolation));
+            assert_eq!(FixedPoint::range_step_inclusive(fp!(0), fp!(0), fp!(0)).err(), Some(ArithmeticError::DomainViolation));
+
+            let mut range = FixedPoint::range_step_inclusive(FixedPoint::MIN, FixedPoint::MAX, FixedPoint::EPSILON)?;
+            assert_eq!(range.size_hint().1.is_some(), usize::BITS > Layout::BITS);
+            assert_eq!(range.nth(2), Some(FixedPoint::MIN.cadd(FixedPoint::from_bits(2))?));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn linspace() -> Result<()> {
+    test_fixed_point! {
+        case (start: FixedPoint, end: FixedPoint, n: usize, mode: RoundMode, expected: &[FixedPoint]) => {
+            let grid = FixedPoint::linspace(start, end, n, mode);
+            assert_eq!(grid.len(), n);
+            assert_eq!(grid.collect::<Vec<_>>(), expected);
+        },
+        all {
+            (fp!(1), fp!(2), 0, Floor, &[]);
+            (fp!(1), fp!(2), 1, Floor, &[fp!(1)]);
+            (fp!(1), fp!(2), 2, Floor, &[fp!(1), fp!(2)]);
+            (fp!(1), fp!(2), 5, Floor, &[fp!(1), fp!(1.25), fp!(1.5), fp!(1.75), fp!(2)]);
+            (fp!(-1), fp!(-1), 3, Ceil, &[fp!(-1), fp!(-1), fp!(-1)]);
+            (fp!(1), fp!(-1), 5, Floor, &[fp!(1), fp!(0.5), fp!(0), fp!(-0.5), fp!(-1)]);
+            (FixedPoint::MIN, FixedPoint::MAX, 2, Floor, &[FixedPoint::MIN, FixedPoint::MAX]);
+            (FixedPoint::MAX, FixedPoint::MIN, 3, Floor, &[FixedPoint::MAX, FixedPoint::from_bits(-1), FixedPoint::MIN]);
+            (FixedPoint::MAX, FixedPoint::MIN, 3, Ceil, &[FixedPoint::MAX, fp!(0), FixedPoint::MIN]);
+            (fp!(0), FixedPoint::EPSILON, 3, HalfEven, &[fp!(0), fp!(0), FixedPoint::EPSILON]);
+            (FixedPoint::EPSILON, fp!(0), 3, Nearest, &[FixedPoint::EPSILON, FixedPoint::EPSILON, fp!(0)]);
+            (FixedPoint::EPSILON.cneg()?, fp!(0), 3, Nearest, &[FixedPoint::EPSILON.cneg()?, FixedPoint::EPSILON.cneg()?, fp!(0)]);
+        },
+        fp64 {
+            (fp!(0), fp!(1), 4, Floor, &[fp!(0), fp!(0.333333333), fp!(0.666666666), fp!(1)]);
+            (fp!(0), fp!(-1), 4, Nearest, &[fp!(0), fp!(-0.333333333), fp!(-0.666666667), fp!(-1)]);
+        },
+        fp128 {
+            (fp!(0), fp!(1), 4, Floor, &[fp!(0), fp!(0.333333333333333333), fp!(0.666666666666666666), fp!(1)]);
+            (fp!(0), fp!(-1), 4, Nearest, &[fp!(0), fp!(-0.333333333333333333), fp!(-0.666666666666666667), fp!(-1)]);
+        },
+    };
+    test_fixed_point! {
+        case (start: FixedPoint, end: FixedPoint, n: usize) => {
+            // Compare with exact rational calculations.
+            let distance = Ratio::from(end).csub(Ratio::from(start))?;
+            let intervals = FixedPoint::try_from(n as i32 - 1)?;
+
+            for mode in [Floor, Ceil, Nearest, HalfEven] {
+                for (k, value) in FixedPoint::linspace(start, end, n, mode).enumerate() {
+                    let expected = Ratio::from(start)
+                        .cadd(distance.cmul(Ratio::new(FixedPoint::try_from(k as i32)?, intervals)?)?)?
+                        .resolve(mode)?;
+                    assert_eq!(value, expected, "{:?}, k = {}", mode, k);
+                }
+            }
+        },
+        all {
+            (fp!(0), fp!(0.000000007), 5);
+            (fp!(0.000000007), fp!(0), 5);
+            (fp!(-0.000000003), fp!(0.000000004), 9);
+            (fp!(0.000000003), fp!(-0.000000004), 9);
+            (fp!(-0.000000011), fp!(-0.000000001), 5);
+            (fp!(-0.000000001), fp!(-0.000000011), 5);
+            (fp!(-1.000000001), fp!(2.000000003), 7);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn percent() -> Result<()> {
+    test_fixed_point! {
+        case (fraction: FixedPoint, percent: FixedPoint) => {
+            assert_eq!(fraction.as_percent()?, percent);
+            for mode in [Floor, Ceil, Nearest, HalfEven] {
+                assert_eq!(FixedPoint::from_percent(percent, mode)?, fraction);
+            }
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(0.025), fp!(2.5));
+            (fp!(-1), fp!(-100));
+            (fp!(0.0000001), fp!(0.00001));
+        },
+    };
+    test_fixed_point! {
+        case (percent: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_percent(percent, mode)?, expected);
+        },
+        all {
+            (FixedPoint::EPSILON, Floor, fp!(0));
+            (FixedPoint::EPSILON, Ceil, FixedPoint::EPSILON);
+            (FixedPoint::EPSILON.cneg()?, Floor, FixedPoint::EPSILON.cneg()?);
+            (FixedPoint::from_bits(50), Nearest, FixedPoint::EPSILON);
+            (FixedPoint::from_bits(50), HalfEven, fp!(0));
+            (FixedPoint::MIN, Floor, FixedPoint::from_bits(FixedPoint::MIN.into_bits() / 100 - 1));
+        },
+    };
+    test_fixed_point! {
+        case (percent: FixedPoint, base: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(percent.apply_percent(base, mode)?, expected);
+            assert_eq!(base.apply_percent(percent, mode)?, expected);
+        },
+        all {
+            (fp!(2.5), fp!(10), Floor, fp!(0.25));
+            (fp!(-2.5), fp!(10), Floor, fp!(-0.25));
+            (fp!(100), FixedPoint::MAX, Floor, FixedPoint::MAX);
+            (fp!(50), FixedPoint::MIN, Floor, FixedPoint::from_bits(FixedPoint::MIN.into_bits() / 2));
+            (FixedPoint::EPSILON, fp!(1), Floor, fp!(0));
+            (FixedPoint::EPSILON, fp!(1), Ceil, FixedPoint::EPSILON);
+            (FixedPoint::EPSILON, fp!(-50), Nearest, FixedPoint::EPSILON.cneg()?);
+            (FixedPoint::EPSILON, fp!(-50), HalfEven, fp!(0));
+            (FixedPoint::EPSILON, fp!(-150), HalfEven, FixedPoint::from_bits(-2));
+        },
+        fp64 {
+            (fp!(33.333333333), fp!(3), Nearest, fp!(1));
+            (fp!(33.333333333), fp!(3), Floor, fp!(0.999999999));
+        },
+        fp128 {
+            (fp!(33.333333333333333333), fp!(3), Nearest, fp!(1));
+            (fp!(33.333333333333333333), fp!(3), Floor, fp!(0.999999999999999999));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.as_percent(), Err(ArithmeticError::Overflow));
+            assert_eq!(fp!(101).apply_percent(FixedPoint::MAX, Floor), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn bps() -> Result<()> {
+    test_fixed_point! {
+        case (bps: i64, fraction: FixedPoint) => {
+            assert_eq!(FixedPoint::from_bps(bps)?, fraction);
+            for mode in [Floor, Ceil, Nearest, HalfEven] {
+                assert_eq!(fraction.to_bps(mode)?, bps);
+            }
+        },
+        all {
+            (0, fp!(0));
+            (1, fp!(0.0001));
+            (25, fp!(0.0025));
+            (-150, fp!(-0.015));
+            (10_000, fp!(1));
+            (1_000_000, fp!(100));
+        },
+    };
+    test_fixed_point! {
+        case (fraction: FixedPoint, mode: RoundMode, expected: i64) => {
+            assert_eq!(fraction.to_bps(mode)?, expected);
+        },
+        all {
+            (fp!(0.00015), Floor, 1);
+            (fp!(0.00015), Ceil, 2);
+            (fp!(0.00015), Nearest, 2);
+            (fp!(0.00015), HalfEven, 2);
+            (fp!(0.00025), HalfEven, 2);
+            (fp!(-0.00015), Floor, -2);
+            (fp!(-0.00015), Nearest, -2);
+            (FixedPoint::EPSILON, Floor, 0);
+            (FixedPoint::EPSILON, Ceil, 1);
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint, bps: i64, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(value.apply_bps(bps, mode)?, expected);
+        },
+        all {
+            (fp!(1000), 15, Floor, fp!(1.5));
+            (fp!(-1000), 15, Floor, fp!(-1.5));
+            (fp!(1000), -15, Ceil, fp!(-1.5));
+            (FixedPoint::MAX, 10_000, Floor, FixedPoint::MAX);
+            (FixedPoint::MIN, 5_000, Floor, FixedPoint::from_bits(FixedPoint::MIN.into_bits() / 2));
+            (FixedPoint::EPSILON, 1, Floor, fp!(0));
+            (FixedPoint::EPSILON, 1, Ceil, FixedPoint::EPSILON);
+            (FixedPoint::from_bits(5), 1_000, Nearest, FixedPoint::EPSILON);
+            (FixedPoint::from_bits(5), 1_000, HalfEven, fp!(0));
+        },
+        fp64 {
+            (fp!(0.123456789), 5_000, Floor, fp!(0.061728394));
+            (fp!(0.123456789), 5_000, Nearest, fp!(0.061728395));
+            (fp!(0.123456789), 5_000, HalfEven, fp!(0.061728394));
+        },
+        fp128 {
+            (fp!(0.123456789012345677), 5_000, Floor, fp!(0.061728394506172838));
+            (fp!(0.123456789012345677), 5_000, Nearest, fp!(0.061728394506172839));
+            (fp!(0.123456789012345677), 5_000, HalfEven, fp!(0.061728394506172838));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.apply_bps(10_001, Floor), Err(ArithmeticError::Overflow));
+        },
+    };
+    test_fixed_point! {
+        case (bps: i64) => {
+            assert!(FixedPoint::from_bps(bps).is_err());
+        },
+        fp64 {
+            (i64::MAX);
+            (i64::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint) => {
+            assert_eq!(value.to_bps(Floor), Err(ArithmeticError::Overflow));
+        },
+        fp128 {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn checked_assign() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, sum: Option<FixedPoint>, diff: Option<FixedPoint>) => {
+            let mut value = a;
+            assert_eq!(value.checked_add_assign(b).ok(), sum.map(|_| ()));
+            assert_eq!(value, sum.unwrap_or(a));
+
+            let mut value = a;
+            assert_eq!(value.checked_sub_assign(b).ok(), diff.map(|_| ()));
+            assert_eq!(value, diff.unwrap_or(a));
+        },
+        all {
+            (fp!(1.5), fp!(0.25), Some(fp!(1.75)), Some(fp!(1.25)));
+            (fp!(-1.5), fp!(2), Some(fp!(0.5)), Some(fp!(-3.5)));
+            (FixedPoint::MAX, FixedPoint::EPSILON, None, Some(FixedPoint::MAX.csub(FixedPoint::EPSILON)?));
+            (FixedPoint::MIN, FixedPoint::EPSILON, Some(FixedPoint::MIN.cadd(FixedPoint::EPSILON)?), None);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let (mut value, rate, expected) = (fp!(100), fp!(1.5), fp!(150));
+            value.update_checked(|v| v.rmul(rate, Floor))?;
+            assert_eq!(value, expected);
+
+            let result = value.update_checked(|v| v.rdiv(FixedPoint::ZERO, Floor));
+            assert_eq!(result, Err(ArithmeticError::DivisionByZero));
+            assert_eq!(value, expected);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sum() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(values.iter().copied().checked_sum(), expected);
+            assert_eq!(values.iter().copied().sum::<Result<FixedPoint, _>>(), expected);
+            assert_eq!(values.iter().sum::<Result<FixedPoint, _>>(), expected);
+        },
+        all {
+            (&[], Ok(fp!(0)));
+            (&[fp!(0.1), fp!(0.2)], Ok(fp!(0.3)));
+            (&[fp!(1.5), fp!(-2), fp!(0.000000001)], Ok(fp!(-0.499999999)));
+            (&[FixedPoint::MAX, FixedPoint::EPSILON], Err(ArithmeticError::Overflow));
+            (&[FixedPoint::MAX, FixedPoint::EPSILON, fp!(-1)], Err(ArithmeticError::Overflow));
+            (&[FixedPoint::MIN, fp!(-1)], Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
+fn product() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(values.iter().copied().product::<Result<FixedPoint, _>>(), expected);
+            assert_eq!(values.iter().product::<Result<FixedPoint, _>>(), expected);
+        },
+        all {
+            (&[], Ok(fp!(1)));
+            (&[fp!(1.5), fp!(-2), fp!(0.5)], Ok(fp!(-1.5)));
+            (&[FixedPoint::EPSILON, fp!(0.5)], Ok(FixedPoint::EPSILON));
+            (&[FixedPoint::EPSILON, fp!(0.4)], Ok(fp!(0)));
+            (&[FixedPoint::MAX, fp!(2)], Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn generic_fixed() -> Result<()> {
+    fn total<F: Fixed>(values: &[F]) -> Result<F, ArithmeticError> {
+        values
+            .iter()
+            .try_fold(F::ZERO, |acc, &value| acc.cadd(value))
+    }
+
+    // Operations with integers aren't covered by `Fixed`.
+    fn mean<F>(values: &[F], n: F::Layout) -> Result<F, ArithmeticError>
+    where
+        F: Fixed + RoundingDiv<F::Layout, Output = F, Error = ArithmeticError>,
+    {
+        total(values)?.rdiv(n, RoundMode::Nearest)
+    }
+
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            assert_eq!(<FixedPoint as Fixed>::PRECISION, FixedPoint::PRECISION);
+            assert_eq!(mean(values, values.len().try_into()?)?, expected);
+            assert_eq!(Fixed::cneg(expected)?, expected.cneg()?);
+            assert_eq!(Fixed::integral(expected, Floor), expected.integral(Floor));
+        },
+        all {
+            (&[fp!(1), fp!(2)], fp!(1.5));
+        },
+        fp64 {
+            (&[fp!(0.1), fp!(-0.4), fp!(1)], fp!(0.233333333));
+        },
+        fp128 {
+            (&[fp!(0.1), fp!(-0.4), fp!(1)], fp!(0.233333333333333333));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "i128"))]
+fn full_mul() -> Result<()> {
+    use fixnum::typenum::{U18, U4, U5, U9};
+
+    let a: FixedPoint<i64, U4> = "1.2345".parse()?;
+    let b: FixedPoint<i64, U5> = "-0.00001".parse()?;
+    let c: FixedPoint<i128, U9> = a.full_mul(b);
+    assert_eq!(c, "-0.000012345".parse()?);
+    assert_eq!(c.rescale::<U4>(RoundMode::Floor)?, "-0.0001".parse()?);
+
+    let max = FixedPoint::<i64, U9>::MAX;
+    let min = FixedPoint::<i64, U9>::MIN;
+    let expected = i128::from(i64::MAX) * i128::from(i64::MAX);
+    assert_eq!(
+        max.full_mul(max),
+        FixedPoint::<i128, U18>::from_bits(expected)
+    );
+    let expected = i128::from(i64::MIN) * i128::from(i64::MIN);
+    assert_eq!(
+        min.full_mul(min),
+        FixedPoint::<i128, U18>::from_bits(expected)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "i64")]
+fn errors_implement_core_error() {
+    fn source(error: &dyn core::error::Error) -> Option<&dyn core::error::Error> {
+        error.source()
+    }
+
+    assert!(source(&ArithmeticError::Overflow).is_none());
+    let convert_error = FixedPoint::<i64, typenum::U9>::try_from(u128::MAX).unwrap_err();
+    assert!(source(&convert_error).is_none());
+}
@@ -0,0 +1,111 @@
+#![cfg(all(feature = "overflow-tracing", feature = "i64"))]
+
+use std::{cell::RefCell, sync::Once};
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    overflow_tracing::{self, Event, EventKind},
+    typenum::U2,
+    ArithmeticError, FixedPoint,
+};
+
+type Amount = FixedPoint<i64, U2>;
+
+fn fp(str: &str) -> Amount {
+    str.parse().unwrap()
+}
+
+std::thread_local! {
+    static EVENTS: RefCell<Vec<Event>> = const { RefCell::new(Vec::new()) };
+}
+
+// Tests run in parallel, so events are collected per thread.
+fn take_events(f: impl FnOnce()) -> Vec<(EventKind, &'static str, u32)> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        overflow_tracing::set_hook(Box::new(|event| {
+            EVENTS.with(|events| events.borrow_mut().push(event.clone()));
+        }));
+    });
+
+    f();
+
+    EVENTS
+        .with(|events| events.take())
+        .into_iter()
+        .inspect(|event| assert_eq!(event.location.file(), file!()))
+        .map(|event| (event.kind, event.operation, event.location.line()))
+        .collect()
+}
+
+#[test]
+fn rounding() {
+    let line = line!();
+    let events = take_events(|| {
+        let _ = fp("1.5").rmul(fp("0.5"), Floor);
+        let _ = fp("1.5").rmul(fp("0.25"), Floor);
+        let _ = fp("1").rdiv(fp("3"), Ceil);
+        let _ = fp("1").rdiv(fp("4"), Ceil);
+    });
+
+    assert_eq!(
+        events,
+        [
+            (EventKind::Rounding, "rmul", line + 3),
+            (EventKind::Rounding, "rdiv", line + 4),
+        ]
+    );
+}
+
+#[test]
+fn errors() {
+    let line = line!();
+    let events = take_events(|| {
+        let _ = Amount::MAX.rmul(fp("2"), Floor);
+        let _ = fp("1").rdiv(Amount::ZERO, Floor);
+        let _ = Amount::MAX.cadd(fp("1"));
+        let _ = Amount::MIN.csub(fp("1"));
+        let _ = Amount::MAX.cmul(2);
+        let _ = fp("1").cadd(fp("1"));
+    });
+
+    let overflow = EventKind::Error(ArithmeticError::Overflow);
+    assert_eq!(
+        events,
+        [
+            (overflow.clone(), "rmul", line + 2),
+            (
+                EventKind::Error(ArithmeticError::DivisionByZero),
+                "rdiv",
+                line + 3
+            ),
+            (overflow.clone(), "cadd", line + 4),
+            (overflow.clone(), "csub", line + 5),
+            (overflow, "cmul", line + 6),
+        ]
+    );
+}
+
+#[test]
+fn saturation() {
+    let line = line!();
+    let events = take_events(|| {
+        let _ = Amount::MAX.saturating_add(fp("1"));
+        let _ = Amount::MIN.saturating_sub(fp("1"));
+        let _ = Amount::MAX.saturating_mul(2);
+        let _ = SaturatingMul::saturating_mul(2, Amount::MAX);
+        let _ = Amount::MAX.saturating_rmul(fp("2"), Floor);
+        let _ = fp("1").saturating_add(fp("1"));
+    });
+
+    assert_eq!(
+        events,
+        [
+            (EventKind::Saturation, "saturating_add", line + 2),
+            (EventKind::Saturation, "saturating_sub", line + 3),
+            (EventKind::Saturation, "saturating_mul", line + 4),
+            (EventKind::Saturation, "saturating_mul", line + 5),
+            (EventKind::Saturation, "saturating_rmul", line + 6),
+        ]
+    );
+}
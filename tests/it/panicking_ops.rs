@@ -0,0 +1,65 @@
+#![cfg(feature = "panicking-ops")]
+
+use anyhow::Result;
+
+#[test]
+fn assign() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint) => {
+            let mut x = a;
+            x += b;
+            assert_eq!(x, a + b);
+            x -= &b;
+            assert_eq!(x, a);
+            x *= b;
+            assert_eq!(x, a * b);
+            x /= &b;
+            assert_eq!(x, a * b / b);
+            x %= b;
+            assert_eq!(x, a * b / b % b);
+        },
+        all {
+            (fp!(7.5), fp!(2));
+            (fp!(-7.5), fp!(0.5));
+            (fp!(0), fp!(3));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let mut total = FixedPoint::ZERO;
+            for x in [fp!(0.1), fp!(0.2), fp!(0.3)] {
+                total += x;
+            }
+            assert_eq!(total, fp!(0.6));
+            assert_eq!(fp!(1) / fp!(4) * fp!(2) - fp!(0.5), FixedPoint::ZERO);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "i64")]
+type FixedPoint64 = fixnum::FixedPoint<i64, typenum::U9>;
+
+#[cfg(feature = "i64")]
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn mul_assign_overflow() {
+    let mut x = FixedPoint64::MAX;
+    x *= FixedPoint64::MAX;
+}
+
+#[cfg(feature = "i64")]
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn sub_assign_overflow() {
+    let mut x = FixedPoint64::MIN;
+    x -= FixedPoint64::EPSILON;
+}
+
+#[cfg(feature = "i64")]
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_assign_by_zero() {
+    let mut x = FixedPoint64::ONE;
+    x /= &FixedPoint64::ZERO;
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "parity")]
+
+use anyhow::Result;
+use parity_scale_codec::{Compact, Decode, Encode, MaxEncodedLen};
+use scale_info::{Registry, TypeDef, TypeDefPrimitive, TypeInfo};
+
+#[test]
+fn encode_decode() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            let encoded = x.encode();
+            assert_eq!(encoded.len(), FixedPoint::max_encoded_len());
+            assert_eq!(FixedPoint::decode(&mut &encoded[..])?, x);
+
+            let compact = Compact(x).encode();
+            assert_eq!(FixedPoint::from(Compact::<FixedPoint>::decode(&mut &compact[..])?), x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-1.5));
+            (FixedPoint::MIN);
+            (FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::max_encoded_len(), core::mem::size_of::<Layout>());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn type_info() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let info = FixedPoint::type_info();
+            assert_eq!(info.path.segments, ["fixnum", "FixedPoint"]);
+
+            let TypeDef::Composite(composite) = info.type_def else {
+                panic!("must be a composite");
+            };
+            assert_eq!(composite.fields.len(), 1);
+
+            // The field is the representation, which is actually encoded.
+            let mut registry = Registry::new();
+            let field = registry.register_type(&composite.fields[0].ty);
+            let registry = scale_info::PortableRegistry::from(registry);
+            let expected = match core::mem::size_of::<Layout>() {
+                8 => TypeDefPrimitive::U64,
+                16 => TypeDefPrimitive::U128,
+                _ => unreachable!(),
+            };
+            assert_eq!(registry.resolve(field.id).unwrap().type_def, TypeDef::Primitive(expected));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "pg-numeric")]
+
+use anyhow::Result;
+use fixnum::pg_numeric::{decode, encode};
+
+fn numeric(weight: i16, sign: u16, dscale: u16, digits: &[i16]) -> Vec<u8> {
+    let header = [digits.len() as i16, weight, sign as i16, dscale as i16];
+    header
+        .iter()
+        .chain(digits)
+        .flat_map(|word| word.to_be_bytes())
+        .collect()
+}
+
+#[test]
+fn encode_decode() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, weight: i16, sign: u16, digits: &[i16]) => {
+            let expected = numeric(weight, sign, FixedPoint::PRECISION as u16, digits);
+            assert_eq!(encode(&x).as_bytes(), expected);
+            assert_eq!(decode::<Layout, _>(&expected)?, x);
+        },
+        all {
+            (fp!(0), 0, 0x0000, &[]);
+            (fp!(1), 0, 0x0000, &[1]);
+            (fp!(12.34), 0, 0x0000, &[12, 3400]);
+            (fp!(-12.34), 0, 0x4000, &[12, 3400]);
+            (fp!(10000), 1, 0x0000, &[1]);
+            (fp!(10000.5), 1, 0x0000, &[1, 0, 5000]);
+            (fp!(123456789.0001), 2, 0x0000, &[1, 2345, 6789, 1]);
+            (fp!(0.0001), -1, 0x0000, &[1]);
+            (fp!(0.00001), -2, 0x0000, &[1000]);
+            (fp!(-0.000000001), -3, 0x4000, &[1000]);
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(decode::<Layout, _>(encode(&x).as_bytes())?, x);
+        },
+        all {
+            (FixedPoint::MIN);
+            (FixedPoint::MAX);
+            (FixedPoint::EPSILON);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn decode_non_canonical() -> Result<()> {
+    test_fixed_point! {
+        case (bytes: Vec<u8>, expected: FixedPoint) => {
+            assert_eq!(decode::<Layout, _>(&bytes)?, expected);
+        },
+        all {
+            // Other display scales, leading and trailing zeros.
+            (numeric(0, 0x0000, 0, &[12]), fp!(12));
+            (numeric(0, 0x0000, 40, &[12, 3400, 0, 0]), fp!(12.34));
+            (numeric(1, 0x0000, 0, &[0, 12]), fp!(12));
+            (numeric(5, 0x0000, 0, &[]), fp!(0));
+            (numeric(0, 0x4000, 0, &[0]), fp!(0));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn decode_errors() -> Result<()> {
+    test_fixed_point! {
+        case (bytes: Vec<u8>, expected: &str) => {
+            let result: Result<FixedPoint, _> = decode(&bytes);
+            assert_eq!(result.unwrap_err().as_str(), expected);
+        },
+        all {
+            (vec![], "invalid NUMERIC");
+            (numeric(0, 0x0000, 0, &[1])[..9].to_vec(), "invalid NUMERIC");
+            (numeric(0, 0x0000, 0, &[10000]), "invalid NUMERIC");
+            (numeric(0, 0x0000, 0, &[-1]), "invalid NUMERIC");
+            (numeric(0, 0x1234, 0, &[1]), "invalid NUMERIC");
+            (numeric(0, 0xc000, 0, &[]), "NaN can't be represented");
+            (numeric(0, 0xd000, 0, &[]), "infinity can't be represented");
+            (numeric(0, 0xf000, 0, &[]), "infinity can't be represented");
+            (numeric(100, 0x0000, 0, &[1]), "too big number");
+            (numeric(-10, 0x0000, 0, &[1]), "requested precision is too high");
+            (numeric(-10000, 0x0000, 0, &[1]), "requested precision is too high");
+        },
+    };
+    Ok(())
+}
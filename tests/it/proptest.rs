@@ -0,0 +1,50 @@
+#![cfg(feature = "proptest")]
+
+use anyhow::Result;
+use proptest::{
+    prelude::*,
+    strategy::ValueTree,
+    test_runner::{Config, TestRunner},
+};
+
+#[test]
+fn boundaries() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let mut runner = TestRunner::deterministic();
+            let strategy = any::<FixedPoint>();
+            let values = (0..1000)
+                .map(|_| strategy.new_tree(&mut runner).map(|tree| tree.current()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(anyhow::Error::msg)?;
+
+            for boundary in [
+                FixedPoint::MIN,
+                FixedPoint::MAX,
+                FixedPoint::ZERO,
+                FixedPoint::EPSILON,
+                FixedPoint::EPSILON.cneg()?,
+                FixedPoint::ONE,
+            ] {
+                assert!(values.contains(&boundary), "{} isn't generated", boundary);
+            }
+
+            // Regular values are generated as well.
+            let one = fp!(1);
+            let regular = values.iter().filter(|value| value.abs().map_or(false, |abs| abs > one));
+            assert!(regular.count() > 500);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "i64")]
+proptest! {
+    #![proptest_config(Config::with_cases(1000))]
+
+    #[test]
+    fn arbitrary(x in any::<fixnum::FixedPoint<i64, typenum::U9>>()) {
+        let parsed: fixnum::FixedPoint<i64, typenum::U9> = x.to_string().parse().unwrap();
+        prop_assert_eq!(parsed, x);
+    }
+}
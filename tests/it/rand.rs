@@ -0,0 +1,44 @@
+#![cfg(feature = "rand")]
+
+use anyhow::Result;
+use fixnum::ops::CheckedAdd;
+use rand::{distributions::Uniform, rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn gen_range() -> Result<()> {
+    test_fixed_point! {
+        case (low: FixedPoint, high: FixedPoint) => {
+            let mut rng = StdRng::seed_from_u64(42);
+
+            for _ in 0..1000 {
+                let x = rng.gen_range(low..high);
+                assert!(low <= x && x < high);
+
+                let x = rng.gen_range(low..=high);
+                assert!(low <= x && x <= high);
+            }
+
+            let distribution = Uniform::new(low, high);
+            let values: Vec<FixedPoint> = (&mut rng).sample_iter(distribution).take(1000).collect();
+            assert!(values.iter().all(|x| low <= *x && *x < high));
+            assert!(values.iter().any(|x| x.floor() != *x));
+        },
+        all {
+            (fp!(0), fp!(1));
+            (fp!(-1.5), fp!(2.5));
+            (FixedPoint::MIN, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint) => {
+            let mut rng = StdRng::seed_from_u64(42);
+            assert_eq!(rng.gen_range(value..=value), value);
+            assert_eq!(rng.gen_range(value..value.cadd(FixedPoint::EPSILON)?), value);
+        },
+        all {
+            (fp!(0.5));
+            (FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
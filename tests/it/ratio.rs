@@ -0,0 +1,88 @@
+#![cfg(any(feature = "i64", feature = "i128"))]
+
+use anyhow::Result;
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, Ratio,
+};
+
+#[test]
+fn resolve() -> Result<()> {
+    test_fixed_point! {
+        case (numer: FixedPoint, denom: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(Ratio::new(numer, denom)?.resolve(mode)?, expected);
+            assert_eq!(numer.rdiv(denom, mode)?, expected);
+        },
+        all {
+            (fp!(1), fp!(2), Floor, fp!(0.5));
+            (fp!(-3), fp!(-6), Ceil, fp!(0.5));
+            (fp!(0), fp!(-6), Ceil, fp!(0));
+            (FixedPoint::MAX, FixedPoint::MAX, Floor, fp!(1));
+            (FixedPoint::MIN, fp!(1), Floor, FixedPoint::MIN);
+            (FixedPoint::EPSILON, fp!(2), Floor, fp!(0));
+            (FixedPoint::EPSILON, fp!(2), Ceil, FixedPoint::EPSILON);
+            (FixedPoint::EPSILON, fp!(-2), Floor, FixedPoint::EPSILON.cneg()?);
+        },
+        fp64 {
+            (fp!(1), fp!(3), Floor, fp!(0.333333333));
+            (fp!(1), fp!(3), Ceil, fp!(0.333333334));
+            (fp!(-2), fp!(3), Nearest, fp!(-0.666666667));
+            (fp!(2), fp!(-3), Floor, fp!(-0.666666667));
+        },
+        fp128 {
+            (fp!(1), fp!(3), Floor, fp!(0.333333333333333333));
+            (fp!(1), fp!(3), Ceil, fp!(0.333333333333333334));
+            (fp!(-2), fp!(3), Nearest, fp!(-0.666666666666666667));
+            (fp!(2), fp!(-3), Floor, fp!(-0.666666666666666667));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn exact_chain() -> Result<()> {
+    test_fixed_point! {
+        case (amount: FixedPoint, weights: &[FixedPoint]) => {
+            let total = Ratio::from(weights.iter().copied().checked_sum()?);
+            let mut sum = Ratio::from(FixedPoint::ZERO);
+
+            for &weight in weights {
+                let share = Ratio::from(amount).cmul(Ratio::from(weight))?.cmul(total.recip()?)?;
+                sum = sum.cadd(share)?;
+            }
+
+            assert_eq!(sum.resolve(Floor)?, amount);
+            assert_eq!(sum.csub(Ratio::from(amount))?.resolve(Ceil)?, FixedPoint::ZERO);
+        },
+        all {
+            (fp!(100), &[fp!(1), fp!(1), fp!(1)]);
+            (fp!(-7.5), &[fp!(0.3), fp!(0.7), fp!(2.9)]);
+            (FixedPoint::MAX, &[fp!(3), fp!(7), fp!(11)]);
+            (FixedPoint::EPSILON, &[FixedPoint::EPSILON, FixedPoint::MAX.csub(FixedPoint::EPSILON)?]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(Ratio::new(fp!(1), fp!(0)).err(), Some(ArithmeticError::DivisionByZero));
+            assert_eq!(Ratio::from(fp!(0)).recip().err(), Some(ArithmeticError::DivisionByZero));
+
+            let max = Ratio::from(FixedPoint::MAX);
+            assert_eq!(max.cadd(max)?.resolve(Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(Ratio::from(FixedPoint::MIN).csub(max)?.resolve(Floor), Err(ArithmeticError::Overflow));
+
+            // Coprime denominators grow until they don't fit.
+            let mut product = Ratio::new(fp!(1), FixedPoint::MAX)?;
+            let result = (0..10).try_fold(product, |product, _| product.cmul(product));
+            assert_eq!(result.err(), Some(ArithmeticError::Overflow));
+
+            product = product.cmul(Ratio::from(FixedPoint::MAX))?;
+            assert_eq!(product.resolve(Floor)?, fp!(1));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "rkyv")]
+
+use anyhow::Result;
+use rkyv::{rancor::Error, Archive, Archived, Deserialize, Serialize};
+
+#[test]
+fn archive() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(size_of::<Archived<FixedPoint>>(), size_of::<Layout>());
+
+            let bytes = rkyv::to_bytes::<Error>(&x)?;
+            let archived = rkyv::access::<Archived<FixedPoint>, Error>(&bytes)?;
+            assert_eq!(archived.to_native(), x);
+            assert_eq!(rkyv::deserialize::<FixedPoint, Error>(archived)?, x);
+        },
+        all {
+            (fp!(0));
+            (fp!(1.5));
+            (fp!(-1.5));
+            (FixedPoint::MIN);
+            (FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn archive_slice() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            #[derive(Archive, Serialize, Deserialize)]
+            struct Quotes {
+                prices: Vec<FixedPoint>,
+            }
+
+            let prices = vec![fp!(1.25), fp!(0.5).cneg()?, FixedPoint::MAX];
+            let bytes = rkyv::to_bytes::<Error>(&Quotes { prices: prices.clone() })?;
+
+            // Prices are read in place without deserialization.
+            let archived = rkyv::access::<ArchivedQuotes, Error>(&bytes)?;
+            let archived = archived.prices.iter().map(|p| p.to_native()).collect::<Vec<_>>();
+            assert_eq!(archived, prices);
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,32 @@
+#![cfg(all(feature = "schemars", feature = "serde"))]
+
+use anyhow::Result;
+use schemars::{schema_for, JsonSchema};
+use serde_json::json;
+
+#[test]
+fn json_schema() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            #[derive(JsonSchema)]
+            #[allow(dead_code)]
+            struct Payment {
+                amount: FixedPoint,
+                #[schemars(schema_with = "fixnum::serde::float::json_schema")]
+                float: FixedPoint,
+                #[schemars(schema_with = "fixnum::serde::repr::json_schema")]
+                repr: FixedPoint,
+            }
+
+            let schema = serde_json::to_value(schema_for!(Payment))?;
+            let properties = &schema["properties"];
+            assert_eq!(
+                properties["amount"],
+                json!({ "type": "string", "pattern": r"^-?[0-9]+(\.[0-9]+)?$" })
+            );
+            assert_eq!(properties["float"], json!({ "type": "number", "format": "double" }));
+            assert_eq!(properties["repr"], json!({ "type": "integer" }));
+        },
+    };
+    Ok(())
+}
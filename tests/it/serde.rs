@@ -54,6 +54,287 @@ fn deserialize() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(
+    feature = "default-round-half-even",
+    ignore = "relies on historical rounding modes"
+)]
+fn str_flexible() -> Result<()> {
+    test_fixed_point! {
+        case (json: &str, expected: FixedPoint) => {
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            struct Flexible(#[serde(with = "fixnum::serde::str_flexible")] FixedPoint);
+
+            let actual: Flexible = serde_json::from_str(json)?;
+            assert_eq!(actual.0, expected);
+            assert_eq!(serde_json::to_string(&actual)?, format!("\"{}\"", expected));
+        },
+        all {
+            ("42", fp!(42));
+            ("-42", fp!(-42));
+            ("42.1", fp!(42.1));
+            ("\"42.1\"", fp!(42.1));
+            ("1.5e3", fp!(1500));
+            ("\"1.5e3\"", fp!(1500));
+            ("\"1.5E+3\"", fp!(1500));
+            ("\"-15e-1\"", fp!(-1.5));
+            ("\"+.5e1\"", fp!(5));
+            ("\"5.e0\"", fp!(5));
+            ("\"12.345e-2\"", fp!(0.12345));
+            ("\"12345e-5\"", fp!(0.12345));
+            ("\"1e-9\"", fp!(0.000000001));
+            ("\"1e-300\"", fp!(0));
+            ("\"0.00001234567891e5\"", fp!(1.234567891));
+        },
+        fp64 {
+            ("\"1e-10\"", fp!(0));
+            ("\"5e-10\"", fp!(0.000000001));
+        },
+        fp128 {
+            ("\"1.234567890123456789e2\"", fp!(123.4567890123456789));
+            ("\"1e20\"", fp!(100000000000000000000));
+        },
+    };
+    test_fixed_point! {
+        case (json: &str) => {
+            #[derive(Debug, Deserialize)]
+            struct Flexible(#[serde(with = "fixnum::serde::str_flexible")] #[allow(dead_code)] FixedPoint);
+
+            assert!(serde_json::from_str::<Flexible>(json).is_err());
+        },
+        all {
+            ("\"1e\"");
+            ("\"e5\"");
+            ("\"1.5e3.5\"");
+            ("\"1.5e3e2\"");
+            ("\"--1e2\"");
+            ("\"1e400\"");
+            ("\"1e99999999999\"");
+            ("\"abc\"");
+            ("true");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn units() -> Result<()> {
+    test_fixed_point! {
+        case (value: FixedPoint, cents: &str) => {
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            struct Payment {
+                #[serde(with = "fixnum::serde::Units::<typenum::U2>")]
+                amount: FixedPoint,
+            }
+
+            let json = format!(r#"{{"amount":{}}}"#, cents);
+            let payment = Payment { amount: value };
+            assert_eq!(serde_json::to_string(&payment)?, json);
+            assert_eq!(serde_json::from_str::<Payment>(&json)?, payment);
+        },
+        all {
+            (fp!(0), "0");
+            (fp!(12.34), "1234");
+            (fp!(-12.3), "-1230");
+            (fp!(1000000), "100000000");
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint) => {
+            #[derive(Debug, Serialize)]
+            struct Payment {
+                #[serde(with = "fixnum::serde::Units::<typenum::U2>")]
+                amount: FixedPoint,
+            }
+
+            assert!(serde_json::to_string(&Payment { amount: value }).is_err());
+        },
+        all {
+            (fp!(12.345));
+            (fp!(-0.001));
+        },
+    };
+    test_fixed_point! {
+        case (json: &str) => {
+            #[derive(Debug, Deserialize)]
+            struct Payment {
+                #[serde(with = "fixnum::serde::Units::<typenum::U2>")]
+                #[allow(dead_code)]
+                amount: FixedPoint,
+            }
+
+            assert!(serde_json::from_str::<Payment>(json).is_err());
+        },
+        all {
+            (r#"{"amount":"1"}"#);
+            (r#"{"amount":1.5}"#);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn percent() -> Result<()> {
+    test_fixed_point! {
+        case (value: FixedPoint, percent: &str) => {
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            struct Fee {
+                #[serde(with = "fixnum::serde::percent")]
+                rate: FixedPoint,
+            }
+
+            let json = format!(r#"{{"rate":"{}"}}"#, percent);
+            let fee = Fee { rate: value };
+            assert_eq!(serde_json::to_string(&fee)?, json);
+            assert_eq!(serde_json::from_str::<Fee>(&json)?, fee);
+        },
+        all {
+            (fp!(0), "0.0%");
+            (fp!(0.025), "2.5%");
+            (fp!(-0.0001), "-0.01%");
+            (fp!(1), "100.0%");
+            (fp!(12.5), "1250.0%");
+            (fp!(0.000000001), "0.0000001%");
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint) => {
+            #[derive(Debug, Serialize)]
+            struct Fee {
+                #[serde(with = "fixnum::serde::percent")]
+                rate: FixedPoint,
+            }
+
+            assert!(serde_json::to_string(&Fee { rate: value }).is_err());
+        },
+        all {
+            (FixedPoint::MAX);
+            (FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case (json: &str, error: &str) => {
+            #[derive(Debug, Deserialize)]
+            struct Fee {
+                #[serde(with = "fixnum::serde::percent")]
+                #[allow(dead_code)]
+                rate: FixedPoint,
+            }
+
+            let actual = serde_json::from_str::<Fee>(json).unwrap_err().to_string();
+            assert!(actual.starts_with(error), "{}", actual);
+        },
+        all {
+            (r#"{"rate":"2.5"}"#, r#"invalid percents "2.5": no `%` suffix"#);
+            (r#"{"rate":"%"}"#, r#"invalid percents "%": "#);
+            (r#"{"rate":0.025}"#, "invalid type");
+            (r#"{"rate":"0.00000000000000001%"}"#, r#"invalid percents "0.00000000000000001%": requested precision is too high"#);
+        },
+        fp64 {
+            (r#"{"rate":"0.00000001%"}"#, r#"invalid percents "0.00000001%": requested precision is too high"#);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn str_strict_and_rounding() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, floor: FixedPoint, ceil: FixedPoint, nearest: FixedPoint, half_even: FixedPoint) => {
+            #[derive(Debug, Deserialize)]
+            struct Rounding {
+                #[serde(with = "fixnum::serde::str_rounding::floor")]
+                floor: FixedPoint,
+                #[serde(with = "fixnum::serde::str_rounding::ceil")]
+                ceil: FixedPoint,
+                #[serde(with = "fixnum::serde::str_rounding::nearest")]
+                nearest: FixedPoint,
+                #[serde(with = "fixnum::serde::str_rounding::half_even")]
+                half_even: FixedPoint,
+            }
+
+            let json = format!(
+                r#"{{"floor":"{0}","ceil":"{0}","nearest":"{0}","half_even":"{0}"}}"#,
+                input
+            );
+            let actual: Rounding = serde_json::from_str(&json)?;
+            assert_eq!(actual.floor, floor);
+            assert_eq!(actual.ceil, ceil);
+            assert_eq!(actual.nearest, nearest);
+            assert_eq!(actual.half_even, half_even);
+        },
+        all {
+            ("1.5", fp!(1.5), fp!(1.5), fp!(1.5), fp!(1.5));
+        },
+        fp64 {
+            ("0.0000000015", fp!(0.000000001), fp!(0.000000002), fp!(0.000000002), fp!(0.000000002));
+            ("0.0000000025", fp!(0.000000002), fp!(0.000000003), fp!(0.000000003), fp!(0.000000002));
+            ("-0.0000000015", fp!(-0.000000002), fp!(-0.000000001), fp!(-0.000000002), fp!(-0.000000002));
+        },
+        fp128 {
+            ("0.0000000000000000015", fp!(0.000000000000000001), fp!(0.000000000000000002), fp!(0.000000000000000002), fp!(0.000000000000000002));
+            ("0.0000000000000000025", fp!(0.000000000000000002), fp!(0.000000000000000003), fp!(0.000000000000000003), fp!(0.000000000000000002));
+        },
+    };
+    test_fixed_point! {
+        case (input: &str, expected: FixedPoint) => {
+            #[derive(Debug, Deserialize)]
+            struct Strict(#[serde(with = "fixnum::serde::str_strict")] FixedPoint);
+
+            let actual: Strict = serde_json::from_str(&format!("\"{}\"", input))?;
+            assert_eq!(actual.0, expected);
+        },
+        all {
+            ("42", fp!(42));
+            ("-1.5", fp!(-1.5));
+            ("0.000000001", fp!(0.000000001));
+        },
+    };
+    test_fixed_point! {
+        case (input: &str) => {
+            #[derive(Debug, Deserialize)]
+            struct Strict(#[allow(dead_code)] #[serde(with = "fixnum::serde::str_strict")] FixedPoint);
+
+            let err = serde_json::from_str::<Strict>(&format!("\"{}\"", input)).unwrap_err();
+            assert!(err.to_string().contains(input), "{}", err);
+        },
+        all {
+            ("abc");
+            ("1.2.3");
+            ("0.0000000000000000015");
+        },
+        fp64 {
+            ("0.0000000015");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn str_compact() -> Result<()> {
+    test_fixed_point! {
+        case (value: FixedPoint, expected: &str) => {
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            struct Compact(#[serde(with = "fixnum::serde::str_compact")] FixedPoint);
+
+            let json = format!("\"{}\"", expected);
+            assert_eq!(serde_json::to_string(&Compact(value))?, json);
+            assert_eq!(serde_json::from_str::<Compact>(&json)?, Compact(value));
+        },
+        all {
+            (fp!(0), "0");
+            (fp!(42), "42");
+            (fp!(-42), "-42");
+            (fp!(10.042), "10.042");
+            (fp!(10.0420), "10.042");
+            (fp!(-0.5), "-0.5");
+            (fp!(100), "100");
+            (fp!(0.000000001), "0.000000001");
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn serde_with() -> Result<()> {
     test_fixed_point! {
@@ -205,6 +486,225 @@ fn serde_with_option() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn serde_with_vec() -> Result<()> {
+    test_fixed_point! {
+        case (values: [FixedPoint; 3]) => {
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::repr_vec")]
+                repr: Vec<FixedPoint>,
+                #[serde(with = "fixnum::serde::str_vec")]
+                str: [FixedPoint; 3],
+                #[serde(with = "fixnum::serde::float_vec")]
+                float: Option<Vec<FixedPoint>>,
+            }
+
+            #[derive(Debug, Clone, PartialEq, Eq, Into, From)]
+            struct Amount(FixedPoint);
+
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct WrappedSample {
+                #[serde(with = "fixnum::serde::repr_vec")]
+                repr: Vec<Amount>,
+                #[serde(with = "fixnum::serde::str_vec")]
+                str: [Amount; 3],
+                #[serde(with = "fixnum::serde::float_vec")]
+                float: Option<Vec<Amount>>,
+            }
+
+            #[derive(Debug, PartialEq, Deserialize)]
+            struct Raw {
+                repr: Vec<Layout>,
+                str: [String; 3],
+                float: Option<Vec<f64>>,
+            }
+
+            let sample = Sample {
+                repr: values.to_vec(),
+                str: values,
+                float: Some(values.to_vec()),
+            };
+            let wrapped_sample = WrappedSample {
+                repr: values.map(Into::into).to_vec(),
+                str: values.map(Into::into),
+                float: Some(values.map(Into::into).to_vec()),
+            };
+
+            // Check raw representation.
+            let json = serde_json::to_string(&sample).unwrap();
+            assert_eq!(serde_json::to_string(&wrapped_sample).unwrap(), json);
+
+            let raw: Raw = serde_json::from_str(&json).unwrap();
+            assert_eq!(raw, Raw {
+                repr: values.map(FixedPoint::into_bits).to_vec(),
+                str: values.map(|v| v.to_string()),
+                float: Some(values.map(Into::into).to_vec()),
+            });
+
+            // Check round-trip.
+            let actual: Sample = serde_json::from_str(&json).unwrap();
+            assert_eq!(actual, sample);
+            let actual: WrappedSample = serde_json::from_str(&json).unwrap();
+            assert_eq!(actual, wrapped_sample);
+
+            // Check empty and missing values.
+            let json = r#"{"repr":[],"str":["0","0","0"],"float":null}"#;
+            let actual: Sample = serde_json::from_str(json).unwrap();
+            assert_eq!(actual, Sample { repr: vec![], str: [fp!(0); 3], float: None });
+            assert_eq!(serde_json::to_string(&actual).unwrap(), r#"{"repr":[],"str":["0.0","0.0","0.0"],"float":null}"#);
+
+            // Check errors.
+            let json = r#"{"repr":[],"str":["0","0"],"float":null}"#;
+            let err = serde_json::from_str::<Sample>(json).unwrap_err();
+            assert!(err.to_string().contains("invalid length 2"), "{}", err);
+            let json = r#"{"repr":[],"str":["0","0","a"],"float":null}"#;
+            assert!(serde_json::from_str::<Sample>(json).is_err());
+        },
+        all {
+            ([fp!(0), fp!(1), fp!(-1.02)]);
+            ([fp!(1.1), fp!(0.1234), fp!(-0.1234)]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn serde_with_map() -> Result<()> {
+    use std::collections::{BTreeMap, HashMap};
+
+    test_fixed_point! {
+        case (values: [FixedPoint; 2]) => {
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::repr_map")]
+                repr: BTreeMap<String, FixedPoint>,
+                #[serde(with = "fixnum::serde::str_map")]
+                str: BTreeMap<u32, FixedPoint>,
+                #[serde(with = "fixnum::serde::float_map")]
+                float: HashMap<String, FixedPoint>,
+            }
+
+            #[derive(Debug, Clone, PartialEq, Eq, Into, From)]
+            struct Amount(FixedPoint);
+
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct WrappedSample {
+                #[serde(with = "fixnum::serde::repr_map")]
+                repr: BTreeMap<String, Amount>,
+                #[serde(with = "fixnum::serde::str_map")]
+                str: BTreeMap<u32, Amount>,
+                #[serde(with = "fixnum::serde::float_map")]
+                float: HashMap<String, Amount>,
+            }
+
+            #[derive(Debug, PartialEq, Deserialize)]
+            struct Raw {
+                repr: BTreeMap<String, Layout>,
+                str: BTreeMap<u32, String>,
+                float: HashMap<String, f64>,
+            }
+
+            let keys = ["AAPL".to_string(), "MSFT".to_string()];
+            let sample = Sample {
+                repr: keys.iter().cloned().zip(values).collect(),
+                str: (1..).zip(values).collect(),
+                // A single entry keeps the order of keys stable.
+                float: keys.iter().cloned().zip(values).take(1).collect(),
+            };
+            let wrapped_sample = WrappedSample {
+                repr: keys.iter().cloned().zip(values.map(Into::into)).collect(),
+                str: (1..).zip(values.map(Into::into)).collect(),
+                float: keys.iter().cloned().zip(values.map(Into::into)).take(1).collect(),
+            };
+
+            // Check raw representation.
+            let json = serde_json::to_string(&sample).unwrap();
+            assert_eq!(serde_json::to_string(&wrapped_sample).unwrap(), json);
+
+            let raw: Raw = serde_json::from_str(&json).unwrap();
+            assert_eq!(raw, Raw {
+                repr: keys.iter().cloned().zip(values.map(FixedPoint::into_bits)).collect(),
+                str: (1..).zip(values.map(|v| v.to_string())).collect(),
+                float: keys.iter().cloned().zip(values.map(Into::into)).take(1).collect(),
+            });
+
+            // Check round-trip.
+            let actual: Sample = serde_json::from_str(&json).unwrap();
+            assert_eq!(actual, sample);
+            let actual: WrappedSample = serde_json::from_str(&json).unwrap();
+            assert_eq!(actual, wrapped_sample);
+
+            // Check empty maps and errors.
+            let json = r#"{"repr":{},"str":{},"float":{}}"#;
+            let actual: Sample = serde_json::from_str(json).unwrap();
+            assert_eq!(actual, Sample { repr: BTreeMap::new(), str: BTreeMap::new(), float: HashMap::new() });
+            assert_eq!(serde_json::to_string(&actual).unwrap(), json);
+
+            let json = r#"{"repr":{},"str":{"1":"a"},"float":{}}"#;
+            assert!(serde_json::from_str::<Sample>(json).is_err());
+        },
+        all {
+            ([fp!(0), fp!(-1.02)]);
+            ([fp!(1.1), fp!(0.1234)]);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "serde-arbitrary-precision")]
+#[test]
+fn float_arbitrary_precision() -> Result<()> {
+    test_fixed_point! {
+        case (json: &str, expected: FixedPoint, serialized: &str) => {
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::float")]
+                float: FixedPoint,
+                #[serde(with = "fixnum::serde::float_option")]
+                float_option: Option<FixedPoint>,
+            }
+
+            let input = format!(r#"{{"float":{json},"float_option":{json}}}"#);
+            let actual: Sample = serde_json::from_str(&input).unwrap();
+            assert_eq!(actual, Sample { float: expected, float_option: Some(expected) });
+
+            let output = format!(r#"{{"float":{serialized},"float_option":{serialized}}}"#);
+            assert_eq!(serde_json::to_string(&actual).unwrap(), output);
+
+            // The default implementation accepts numbers with all digits too.
+            assert_eq!(serde_json::from_str::<FixedPoint>(json).unwrap(), expected);
+        },
+        all {
+            ("0", fp!(0), "0.0");
+            ("-1.02", fp!(-1.02), "-1.02");
+            ("1.5e-3", fp!(0.0015), "0.0015");
+            ("9223372036.854775807", fp!(9223372036.854775807), "9223372036.854775807");
+            ("-9223372036.854775808", fp!(-9223372036.854775808), "-9223372036.854775808");
+        },
+        fp64 {
+            ("1.0000000001", fp!(1), "1.0");
+        },
+        fp128 {
+            ("170141183460469231731.687303715884105727", fp!(170141183460469231731.687303715884105727), "170141183460469231731.687303715884105727");
+            ("-0.000000000000000001", fp!(-0.000000000000000001), "-0.000000000000000001");
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            #[derive(Debug, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::float")]
+                _float: FixedPoint,
+            }
+
+            assert!(serde_json::from_str::<Sample>(r#"{"_float":1e100}"#).is_err());
+            assert!(serde_json::from_str::<Sample>(r#"{"_float":"1"}"#).is_err());
+        },
+    };
+    Ok(())
+}
+
 #[cfg(feature = "quick-xml")]
 #[test]
 fn quickxml() -> Result<()> {
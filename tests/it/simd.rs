@@ -0,0 +1,171 @@
+#![cfg(any(feature = "i64", feature = "i128"))]
+
+use anyhow::Result;
+use fixnum::{
+    ops::{CheckedAdd, CheckedSub, RoundMode::*, RoundingMul},
+    simd,
+};
+#[cfg(any(feature = "i64", feature = "u64"))]
+use proptest::prelude::*;
+
+#[test]
+fn add_sub_slices() -> Result<()> {
+    test_fixed_point! {
+        case (lhs: &[FixedPoint], rhs: &[FixedPoint]) => {
+            let mut out = vec![FixedPoint::ZERO; lhs.len()];
+
+            simd::add_slices(lhs, rhs, &mut out)?;
+            for ((lhs, rhs), out) in lhs.iter().zip(rhs).zip(&out) {
+                assert_eq!(lhs.cadd(*rhs)?, *out);
+            }
+
+            simd::sub_slices(lhs, rhs, &mut out)?;
+            for ((lhs, rhs), out) in lhs.iter().zip(rhs).zip(&out) {
+                assert_eq!(lhs.csub(*rhs)?, *out);
+            }
+        },
+        all {
+            (&[], &[]);
+            (&[fp!(1.5), fp!(-2), fp!(0)], &[fp!(0.25), fp!(3.000000001), fp!(-7)]);
+            (&[FixedPoint::MAX, FixedPoint::MIN], &[fp!(0), fp!(0)]);
+        },
+    };
+    test_fixed_point! {
+        case (lhs: &[FixedPoint], rhs: &[FixedPoint], add_fails: bool) => {
+            let mut out = vec![FixedPoint::ZERO; lhs.len()];
+            assert_eq!(simd::add_slices(lhs, rhs, &mut out).is_err(), add_fails);
+            assert_eq!(simd::sub_slices(lhs, rhs, &mut out).is_err(), !add_fails);
+        },
+        all {
+            (&[fp!(1), FixedPoint::MAX, fp!(1)], &[fp!(1), FixedPoint::EPSILON, fp!(1)], true);
+            (&[fp!(1), FixedPoint::MIN], &[fp!(1), FixedPoint::EPSILON], false);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn scale_slice() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], factor: FixedPoint) => {
+            let mut out = vec![FixedPoint::ZERO; values.len()];
+            for mode in [Floor, Ceil, Nearest, HalfEven] {
+                simd::scale_slice(values, factor, mode, &mut out)?;
+                for (value, out) in values.iter().zip(&out) {
+                    assert_eq!(value.rmul(factor, mode)?, *out);
+                }
+            }
+        },
+        all {
+            (&[], fp!(2));
+            (&[fp!(1.5), fp!(-2.25), fp!(0), FixedPoint::EPSILON], fp!(1.1));
+            (&[fp!(1.5), fp!(-0.000000005), fp!(0.000000005)], fp!(-0.3));
+            (&[FixedPoint::MAX, FixedPoint::MIN], fp!(1));
+        },
+    };
+    test_fixed_point! {
+        case (values: &[FixedPoint], factor: FixedPoint) => {
+            let mut out = vec![FixedPoint::ZERO; values.len()];
+            assert!(simd::scale_slice(values, factor, Floor, &mut out).is_err());
+        },
+        all {
+            (&[fp!(1), FixedPoint::MAX], fp!(2));
+            (&[FixedPoint::MIN], fp!(-1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn dot() -> Result<()> {
+    test_fixed_point! {
+        case (lhs: &[FixedPoint], rhs: &[FixedPoint], mode: fixnum::ops::RoundMode, expected: FixedPoint) => {
+            assert_eq!(simd::dot(lhs, rhs, mode)?, expected);
+        },
+        all {
+            (&[], &[], Floor, fp!(0));
+            (&[fp!(1.5), fp!(2.25), fp!(10)], &[fp!(2), fp!(0.1), fp!(0.003)], Nearest, fp!(3.255));
+            (&[fp!(1.5), fp!(-2)], &[fp!(-2), fp!(0.5)], Ceil, fp!(-4));
+            // Intermediate sums may exceed the layout.
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[fp!(1), fp!(-1)], Floor, fp!(0));
+        },
+        fp64 {
+            // Rounded once: 3 * 0.0000000005 = 0.0000000015 -> 0.000000002.
+            (&[fp!(0.00001); 3], &[fp!(0.00005); 3], Nearest, fp!(0.000000002));
+            (&[fp!(0.00001); 3], &[fp!(0.00005); 3], Floor, fp!(0.000000001));
+        },
+        fp128 {
+            (&[fp!(0.000000001); 3], &[fp!(0.0000000005); 3], Nearest, fp!(0.000000000000000002));
+        },
+    };
+    test_fixed_point! {
+        case (lhs: &[FixedPoint], rhs: &[FixedPoint]) => {
+            assert!(simd::dot(lhs, rhs, Floor).is_err());
+        },
+        all {
+            (&[FixedPoint::MAX], &[fp!(2)]);
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[fp!(1), fp!(1)]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "lengths of slices differ")]
+#[cfg(feature = "i64")]
+fn lengths_differ() {
+    type Amount = fixnum::FixedPoint<i64, typenum::U9>;
+    let _ = simd::add_slices(&[Amount::ONE], &[], &mut [Amount::ZERO]);
+}
+
+#[cfg(feature = "i64")]
+proptest! {
+    #[test]
+    fn add_slices_like_cadd(lhs in any::<Vec<i64>>(), rhs in any::<Vec<i64>>()) {
+        type Amount = fixnum::FixedPoint<i64, typenum::U9>;
+
+        let len = lhs.len().min(rhs.len());
+        let lhs = lhs[..len].iter().copied().map(Amount::from_bits).collect::<Vec<_>>();
+        let rhs = rhs[..len].iter().copied().map(Amount::from_bits).collect::<Vec<_>>();
+        let mut out = vec![Amount::ZERO; len];
+
+        let expected = lhs.iter().zip(&rhs).map(|(l, r)| l.cadd(*r)).collect::<Result<Vec<_>, _>>();
+        let actual = simd::add_slices(&lhs, &rhs, &mut out).map(|_| out);
+        prop_assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sub_slices_like_csub(lhs in any::<Vec<i64>>(), rhs in any::<Vec<i64>>()) {
+        type Amount = fixnum::FixedPoint<i64, typenum::U9>;
+
+        let len = lhs.len().min(rhs.len());
+        let lhs = lhs[..len].iter().copied().map(Amount::from_bits).collect::<Vec<_>>();
+        let rhs = rhs[..len].iter().copied().map(Amount::from_bits).collect::<Vec<_>>();
+        let mut out = vec![Amount::ZERO; len];
+
+        let expected = lhs.iter().zip(&rhs).map(|(l, r)| l.csub(*r)).collect::<Result<Vec<_>, _>>();
+        let actual = simd::sub_slices(&lhs, &rhs, &mut out).map(|_| out);
+        prop_assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(feature = "u64")]
+proptest! {
+    #[test]
+    fn unsigned_add_sub_slices_like_checked(lhs in any::<Vec<u64>>(), rhs in any::<Vec<u64>>()) {
+        type Amount = fixnum::FixedPoint<u64, typenum::U9>;
+
+        let len = lhs.len().min(rhs.len());
+        let lhs = lhs[..len].iter().copied().map(Amount::from_bits).collect::<Vec<_>>();
+        let rhs = rhs[..len].iter().copied().map(Amount::from_bits).collect::<Vec<_>>();
+        let mut out = vec![Amount::ZERO; len];
+
+        let expected = lhs.iter().zip(&rhs).map(|(l, r)| l.cadd(*r)).collect::<Result<Vec<_>, _>>();
+        let actual = simd::add_slices(&lhs, &rhs, &mut out).map(|_| out.clone());
+        prop_assert_eq!(actual, expected);
+
+        let expected = lhs.iter().zip(&rhs).map(|(l, r)| l.csub(*r)).collect::<Result<Vec<_>, _>>();
+        let actual = simd::sub_slices(&lhs, &rhs, &mut out).map(|_| out);
+        prop_assert_eq!(actual, expected);
+    }
+}
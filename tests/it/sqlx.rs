@@ -0,0 +1,59 @@
+#![cfg(feature = "sqlx")]
+
+use anyhow::Result;
+use sqlx::{
+    postgres::{types::Oid, PgArgumentBuffer, PgHasArrayType},
+    Encode, Postgres, Type,
+};
+
+#[test]
+fn type_info() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(<FixedPoint as Type<Postgres>>::type_info().oid(), Some(Oid(1700)));
+            assert_eq!(FixedPoint::array_type_info().oid(), Some(Oid(1231)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, weight: i16, is_negative: bool, digits: &[i16]) => {
+            let mut buf = PgArgumentBuffer::default();
+            let _ = Encode::<Postgres>::encode_by_ref(&x, &mut buf).unwrap();
+
+            let sign: u16 = if is_negative { 0x4000 } else { 0 };
+            let mut expected = Vec::new();
+            expected.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+            expected.extend_from_slice(&weight.to_be_bytes());
+            expected.extend_from_slice(&sign.to_be_bytes());
+            expected.extend_from_slice(&(FixedPoint::PRECISION as u16).to_be_bytes());
+            for digit in digits {
+                expected.extend_from_slice(&digit.to_be_bytes());
+            }
+
+            assert_eq!(buf.as_slice(), expected.as_slice());
+        },
+        all {
+            (fp!(0), 0, false, &[]);
+            (fp!(42), 0, false, &[42]);
+            (fp!(10000), 1, false, &[1]);
+            (fp!(12.34), 0, false, &[12, 3400]);
+            (fp!(-12.34), 0, true, &[12, 3400]);
+            (fp!(0.0001), -1, false, &[1]);
+            (fp!(-0.000000001), -3, true, &[1000]);
+            (fp!(123456789.1), 2, false, &[1, 2345, 6789, 1000]);
+            (fp!(9223372036.854775807), 2, false, &[92, 2337, 2036, 8547, 7580, 7000]);
+        },
+        fp128 {
+            (fp!(0.000000000000000001), -5, false, &[100]);
+            (
+                fp!(-170141183460469231731.687303715884105728), 5, true,
+                &[1, 7014, 1183, 4604, 6923, 1731, 6873, 371, 5884, 1057, 2800],
+            );
+        },
+    };
+    Ok(())
+}
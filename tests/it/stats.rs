@@ -0,0 +1,153 @@
+#![cfg(all(feature = "stats", any(feature = "i64", feature = "i128")))]
+
+use anyhow::Result;
+use fixnum::{
+    ops::{CheckedAdd, RoundMode::*},
+    stats, ArithmeticError,
+};
+
+#[test]
+fn min_max() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], min: Option<FixedPoint>, max: Option<FixedPoint>) => {
+            assert_eq!(stats::min(values), min);
+            assert_eq!(stats::max(values), max);
+        },
+        all {
+            (&[], None, None);
+            (&[fp!(1)], Some(fp!(1)), Some(fp!(1)));
+            (&[fp!(0.5), fp!(-3), fp!(2)], Some(fp!(-3)), Some(fp!(2)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mean() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            assert_eq!(stats::mean(values, Nearest)?, expected);
+        },
+        all {
+            (&[fp!(1)], fp!(1));
+            (&[fp!(1), fp!(2), fp!(4), fp!(10)], fp!(4.25));
+            (&[fp!(1.5), fp!(-2.5)], fp!(-0.5));
+            (&[FixedPoint::MAX, FixedPoint::MAX], FixedPoint::MAX);
+            (&[FixedPoint::MAX, FixedPoint::MIN.cadd(FixedPoint::EPSILON)?], fp!(0));
+        },
+        fp64 {
+            (&[fp!(1), fp!(1), fp!(2)], fp!(1.333333333));
+        },
+        fp128 {
+            (&[fp!(1), fp!(1), fp!(2)], fp!(1.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(stats::mean::<FixedPoint>(&[], Nearest), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(
+                stats::mean(&[fp!(1), fp!(1), fp!(2)], Ceil)?,
+                stats::mean(&[fp!(1), fp!(1), fp!(2)], Floor)?.cadd(FixedPoint::EPSILON)?,
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn median() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            assert_eq!(stats::median(values, Nearest)?, expected);
+        },
+        all {
+            (&[fp!(7)], fp!(7));
+            (&[fp!(3), fp!(1), fp!(2)], fp!(2));
+            (&[fp!(10), fp!(1), fp!(4), fp!(2)], fp!(3));
+            (&[fp!(-1), fp!(2)], fp!(0.5));
+            (&[FixedPoint::MAX, FixedPoint::MAX], FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(stats::median::<FixedPoint>(&[], Nearest), Err(ArithmeticError::DivisionByZero));
+            let values = [FixedPoint::ZERO, FixedPoint::EPSILON];
+            assert_eq!(stats::median(&values, Floor)?, FixedPoint::ZERO);
+            assert_eq!(stats::median(&values, Ceil)?, FixedPoint::EPSILON);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn variance() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            assert_eq!(stats::variance(values, Nearest)?, expected);
+        },
+        all {
+            (&[fp!(5)], fp!(0));
+            (&[fp!(2), fp!(4), fp!(4), fp!(4), fp!(5), fp!(5), fp!(7), fp!(9)], fp!(4));
+            (&[fp!(1), fp!(2), fp!(4), fp!(10)], fp!(12.1875));
+            (&[fp!(-0.001), fp!(0.001)], fp!(0.000001));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(stats::variance::<FixedPoint>(&[], Nearest), Err(ArithmeticError::DivisionByZero));
+            let values = [FixedPoint::MAX, FixedPoint::MIN.cadd(FixedPoint::EPSILON)?];
+            assert_eq!(stats::variance(&values, Nearest), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn weighted_mean() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], weights: &[FixedPoint], expected: FixedPoint) => {
+            assert_eq!(stats::weighted_mean(values, weights, Nearest)?, expected);
+        },
+        all {
+            (&[fp!(1), fp!(2)], &[fp!(1), fp!(1)], fp!(1.5));
+            (&[fp!(1), fp!(2), fp!(4), fp!(10)], &[fp!(3), fp!(1), fp!(0), fp!(0)], fp!(1.25));
+            (&[fp!(100), fp!(101)], &[fp!(0.25), fp!(0.75)], fp!(100.75));
+            (&[fp!(-2), fp!(2)], &[fp!(3), fp!(1)], fp!(-1));
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[fp!(0.5), fp!(0.5)], FixedPoint::MAX);
+        },
+        fp64 {
+            (&[fp!(0), fp!(1)], &[fp!(2), fp!(1)], fp!(0.333333333));
+        },
+        fp128 {
+            (&[fp!(0), fp!(1)], &[fp!(2), fp!(1)], fp!(0.333333333333333333));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(
+                stats::weighted_mean(&[fp!(1), fp!(2)], &[fp!(1), fp!(1).cneg()?], Nearest),
+                Err(ArithmeticError::DivisionByZero),
+            );
+            assert_eq!(
+                stats::weighted_mean(&[FixedPoint::MAX], &[fp!(2)], Nearest)?,
+                FixedPoint::MAX,
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "lengths of slices differ")]
+fn weighted_mean_length_mismatch() {
+    #[cfg(feature = "i64")]
+    {
+        type Price = fixnum::FixedPoint<i64, typenum::U9>;
+        let _ = stats::weighted_mean::<Price>(&[Price::ONE], &[], Nearest);
+    }
+    #[cfg(all(feature = "i128", not(feature = "i64")))]
+    {
+        type Price = fixnum::FixedPoint<i128, typenum::U18>;
+        let _ = stats::weighted_mean::<Price>(&[Price::ONE], &[], Nearest);
+    }
+}
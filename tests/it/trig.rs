@@ -0,0 +1,94 @@
+#![cfg(feature = "trig")]
+
+use anyhow::Result;
+
+use fixnum::ArithmeticError;
+
+#[test]
+fn sin_cos() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, sin: FixedPoint, cos: FixedPoint) => {
+            assert_eq!(x.sin()?, sin, "sin");
+            assert_eq!(x.cos()?, cos, "cos");
+            assert_eq!(x.sin_cos()?, (sin, cos), "sin_cos");
+            assert_eq!(x.cneg()?.sin()?, sin.cneg()?, "sin, negation");
+            assert_eq!(x.cneg()?.cos()?, cos, "cos, negation");
+        },
+        all {
+            (fp!(0), fp!(0), fp!(1));
+        },
+        fp64 {
+            (fp!(0.5), fp!(0.479425539), fp!(0.877582562));
+            (fp!(1), fp!(0.841470985), fp!(0.540302306));
+            (fp!(2), fp!(0.909297427), fp!(-0.416146837));
+            (fp!(3), fp!(0.141120008), fp!(-0.989992497));
+            (fp!(100), fp!(-0.506365641), fp!(0.862318872));
+            (fp!(1000000), fp!(-0.349993502), fp!(0.936752128));
+            (fp!(1.570796327), fp!(1), fp!(0));
+            (fp!(3.141592654), fp!(0), fp!(-1));
+            (FixedPoint::MAX, fp!(-0.774505497), fp!(-0.632567178));
+        },
+        fp128 {
+            (fp!(0.5), fp!(0.479425538604203000), fp!(0.877582561890372716));
+            (fp!(1), fp!(0.841470984807896507), fp!(0.540302305868139717));
+            (fp!(2), fp!(0.909297426825681695), fp!(-0.416146836547142387));
+            (fp!(3), fp!(0.141120008059867222), fp!(-0.989992496600445457));
+            (fp!(100), fp!(-0.506365641109758794), fp!(0.862318872287683934));
+            (fp!(1000000), fp!(-0.349993502171292952), fp!(0.936752127533144787));
+            (fp!(3.141592653589793238), fp!(0), fp!(-1));
+            (FixedPoint::MAX, fp!(-0.988229642639096190), fp!(-0.152977689253708616));
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint, sin: FixedPoint, cos: FixedPoint) => {
+            assert_eq!(x.sin_cos()?, (sin, cos));
+        },
+        fp64 {
+            (FixedPoint::MIN, fp!(0.774505498), fp!(-0.632567178));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn atan2() -> Result<()> {
+    test_fixed_point! {
+        case (y: FixedPoint, x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(y.atan2(x)?, expected);
+        },
+        all {
+            (fp!(0), fp!(1), fp!(0));
+            (fp!(0), FixedPoint::EPSILON, fp!(0));
+            (fp!(1), fp!(1000000), fp!(0.000001));
+            (FixedPoint::EPSILON, fp!(1), FixedPoint::EPSILON);
+        },
+        fp64 {
+            (fp!(1), fp!(1), fp!(0.785398163));
+            (fp!(1), fp!(-1), fp!(2.356194490));
+            (fp!(-1), fp!(-1), fp!(-2.356194490));
+            (fp!(0), fp!(-1), fp!(3.141592654));
+            (fp!(1), fp!(0), fp!(1.570796327));
+            (fp!(-1), fp!(0), fp!(-1.570796327));
+            (fp!(3), fp!(4), fp!(0.643501109));
+            (FixedPoint::MAX, FixedPoint::MAX, fp!(0.785398163));
+            (FixedPoint::MIN, FixedPoint::MIN, fp!(-2.356194490));
+        },
+        fp128 {
+            (fp!(1), fp!(1), fp!(0.785398163397448310));
+            (fp!(1), fp!(-1), fp!(2.356194490192344929));
+            (fp!(-1), fp!(-1), fp!(-2.356194490192344929));
+            (fp!(0), fp!(-1), fp!(3.141592653589793238));
+            (fp!(1), fp!(0), fp!(1.570796326794896619));
+            (fp!(-1), fp!(0), fp!(-1.570796326794896619));
+            (fp!(3), fp!(4), fp!(0.643501108793284387));
+            (FixedPoint::MAX, FixedPoint::MAX, fp!(0.785398163397448310));
+            (FixedPoint::MIN, FixedPoint::MIN, fp!(-2.356194490192344929));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::ZERO.atan2(FixedPoint::ZERO), Err(ArithmeticError::DomainViolation));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,252 @@
+#![cfg(any(feature = "u64", feature = "u128"))]
+
+macro_rules! impl_tests {
+    ($feature:literal, $layout:ident, $precision:ident, $digits:literal, $max:literal) => {
+        #[cfg(feature = $feature)]
+        mod $layout {
+            use anyhow::Result;
+            use fixnum::ops::{RoundMode::*, *};
+            use fixnum::{fixnum, typenum::$precision, ArithmeticError};
+
+            type FixedPoint = fixnum::FixedPoint<$layout, $precision>;
+
+            fn fp(s: &str) -> FixedPoint {
+                FixedPoint::from_str_exact(s).unwrap()
+            }
+
+            #[test]
+            fn parse_and_display() -> Result<()> {
+                for s in ["0.0", "1.0", "42.5", "0.000000001", $max] {
+                    assert_eq!(fp(s).to_string(), s);
+                }
+                assert_eq!(fp($max), FixedPoint::MAX);
+                assert_eq!(FixedPoint::MIN, FixedPoint::ZERO);
+
+                assert_eq!("1.25".parse::<FixedPoint>()?, fp("1.25"));
+                assert!("-1".parse::<FixedPoint>().is_err());
+                assert!("-0.5".parse::<FixedPoint>().is_err());
+                assert!(FixedPoint::try_from(-1.5f64).is_err());
+                assert_eq!(FixedPoint::try_from(2.5f64)?, fp("2.5"));
+                assert_eq!(f64::from(fp("2.5")), 2.5);
+                assert!(FixedPoint::try_from(-1i64).is_err());
+                assert_eq!(FixedPoint::try_from(3u8)?, fp("3"));
+
+                let lit: FixedPoint = fixnum!(12.34, $digits);
+                assert_eq!(lit, fp("12.34"));
+                Ok(())
+            }
+
+            #[test]
+            fn display_max_without_precision() {
+                type Integer = fixnum::FixedPoint<$layout, fixnum::typenum::U0>;
+
+                let max = format!("{}.0", $layout::MAX);
+                assert_eq!(Integer::MAX.to_string(), max);
+                assert_eq!(max.len(), Integer::MAX_STR_LEN);
+            }
+
+            #[test]
+            fn ops() -> Result<()> {
+                let a = fp("7.5");
+                let b = fp("2");
+
+                assert_eq!(a.cadd(b)?, fp("9.5"));
+                assert_eq!(a.csub(b)?, fp("5.5"));
+                assert_eq!(b.csub(a), Err(ArithmeticError::Overflow));
+                assert_eq!(b.saturating_sub(a), FixedPoint::ZERO);
+                assert_eq!(FixedPoint::MAX.saturating_add(a), FixedPoint::MAX);
+                assert_eq!(a.cmul(3)?, fp("22.5"));
+                assert_eq!(FixedPoint::MAX.saturating_rmul(b, Floor), FixedPoint::MAX);
+
+                let eps = FixedPoint::EPSILON;
+                assert_eq!(eps.rmul(fp("0.5"), Floor)?, FixedPoint::ZERO);
+                assert_eq!(eps.rmul(fp("0.5"), Ceil)?, eps);
+                assert_eq!(eps.rmul(fp("0.5"), Nearest)?, eps);
+                assert_eq!(eps.rmul(fp("0.5"), HalfEven)?, FixedPoint::ZERO);
+
+                let third = FixedPoint::ONE.rdiv(fp("3"), Floor)?;
+                assert_eq!(FixedPoint::ONE.rdiv(fp("3"), Ceil)?, third.cadd(eps)?);
+                assert_eq!(
+                    a.rdiv(FixedPoint::ZERO, Floor),
+                    Err(ArithmeticError::DivisionByZero)
+                );
+                assert_eq!(a.mul_div(b, fp("3"), Floor)?, fp("5"));
+
+                assert_eq!(a.integral(Floor), 7);
+                assert_eq!(a.integral(Ceil), 8);
+                assert_eq!(a.integral(HalfEven), 8);
+                assert_eq!(a.signum(), FixedPoint::ONE);
+                assert_eq!(FixedPoint::ZERO.signum(), FixedPoint::ZERO);
+                assert_eq!(a.copysign(FixedPoint::ZERO)?, a);
+                assert_eq!(FixedPoint::half_sum(a, b, Floor), fp("4.75"));
+
+                assert_eq!(a.abs()?, a);
+                assert_eq!(FixedPoint::ZERO.cneg()?, FixedPoint::ZERO);
+                assert_eq!(a.cneg(), Err(ArithmeticError::Overflow));
+
+                assert_eq!(fp("81").rsqrt(Floor)?, fp("9"));
+                assert_eq!(b.checked_powi(10, Floor)?, fp("1024"));
+                assert_eq!(fp("1024").log2()?, fp("10"));
+                assert_eq!(FixedPoint::ZERO.ln(), Err(ArithmeticError::DomainViolation));
+                assert_eq!(FixedPoint::ZERO.exp()?, FixedPoint::ONE);
+                assert_eq!(fp("1024").nth_root(10, Floor)?, b);
+                assert_eq!(fp("8").cbrt(Floor)?, b);
+
+                assert_eq!(fp("3").hypot(fp("4"), Nearest)?, fp("5"));
+                assert_eq!(
+                    FixedPoint::MAX.hypot(FixedPoint::ZERO, Nearest)?,
+                    FixedPoint::MAX
+                );
+                assert_eq!(
+                    FixedPoint::MAX.hypot(FixedPoint::MAX, Floor),
+                    Err(ArithmeticError::Overflow)
+                );
+                assert_eq!(b.lerp(a, fp("0.5"), Floor)?, fp("4.75"));
+                assert_eq!(a.lerp(b, fp("0.5"), Floor)?, fp("4.75"));
+                assert_eq!(a.lerp(b, fp("2"), Floor), Err(ArithmeticError::Overflow));
+                assert_eq!(
+                    FixedPoint::MAX.lerp(FixedPoint::ZERO, FixedPoint::ONE, Floor)?,
+                    FixedPoint::ZERO
+                );
+                let range = FixedPoint::range_step_inclusive(FixedPoint::ZERO, b, fp("0.5"))?;
+                assert_eq!(range.last(), Some(b));
+                assert_eq!(FixedPoint::range_step(b, FixedPoint::ZERO, b)?.count(), 0);
+                assert_eq!(
+                    fp("2.5").apply_percent(FixedPoint::MAX, Floor)?,
+                    FixedPoint::MAX.rdiv(fp("40"), Floor)?
+                );
+                assert_eq!(FixedPoint::from_percent(b, Floor)?, fp("0.02"));
+                assert_eq!(FixedPoint::from_bps(25)?, fp("0.0025"));
+                assert_eq!(fp("0.0025").to_bps(Floor)?, 25);
+                assert_eq!(a.apply_bps(-1, Floor), Err(ArithmeticError::Overflow));
+                let mut c = a;
+                assert_eq!(
+                    c.checked_sub_assign(fp("10")),
+                    Err(ArithmeticError::Overflow)
+                );
+                c.checked_sub_assign(b)?;
+                assert_eq!(c, fp("5.5"));
+                assert_eq!(
+                    a.convert::<_, fixnum::typenum::U0>(b, Ceil)?,
+                    fp("15").rescale(Floor)?
+                );
+
+                assert_eq!(fp("42").next_power_of_ten()?, fp("100"));
+                assert_eq!(
+                    FixedPoint::MAX.next_power_of_ten(),
+                    Err(ArithmeticError::Overflow)
+                );
+                Ok(())
+            }
+
+            #[test]
+            fn accumulator() -> Result<()> {
+                let mut acc = fixnum::Accumulator::new();
+                acc.add(fp("1"));
+                acc.sub(fp("2"));
+                assert_eq!(acc.finish(), Err(ArithmeticError::Overflow));
+                acc.add(fp("2.5"));
+                assert_eq!(acc.finish()?, fp("1.5"));
+                Ok(())
+            }
+
+            #[test]
+            fn ratio() -> Result<()> {
+                let a = fixnum::Ratio::new(fp("1"), fp("3"))?;
+                let b = a.csub(fixnum::Ratio::from(fp("1")))?;
+                assert_eq!(b.resolve(Floor), Err(ArithmeticError::Overflow));
+                assert_eq!(
+                    b.cadd(a.cmul(fixnum::Ratio::from(fp("5")))?)?
+                        .resolve(Floor)?,
+                    fp("1")
+                );
+                Ok(())
+            }
+
+            #[test]
+            fn averages() -> Result<()> {
+                let mut ema = fixnum::Ema::new(fp("0.5"))?;
+                ema.update(fp("4"), Nearest)?;
+                assert_eq!(ema.update(fp("1"), Floor)?, fp("2.5"));
+
+                let mut mean = fixnum::RunningMean::new();
+                mean.add(FixedPoint::MAX)?;
+                mean.add(FixedPoint::MAX)?;
+                assert_eq!(mean.mean(Nearest)?, FixedPoint::MAX);
+
+                let mut vwap = fixnum::Vwap::new();
+                vwap.add(fp("1"), fp("1"))?;
+                vwap.add(fp("2"), fp("3"))?;
+                assert_eq!(vwap.vwap(Nearest)?, fp("1.75"));
+                Ok(())
+            }
+
+            #[test]
+            fn sortable_bytes() {
+                let values = [
+                    FixedPoint::ZERO,
+                    fp("0.000000001"),
+                    fp("1"),
+                    FixedPoint::MAX,
+                ];
+                for pair in values.windows(2) {
+                    assert!(pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes());
+                }
+                for x in values {
+                    assert_eq!(FixedPoint::from_sortable_bytes(x.to_sortable_bytes()), x);
+                }
+                assert_eq!(
+                    FixedPoint::ONE.to_sortable_bytes(),
+                    FixedPoint::ONE.into_bits().to_be_bytes()
+                );
+            }
+
+            #[test]
+            fn debug() {
+                assert_eq!(format!("{:?}", fp("1.5")), "1.5");
+                assert_eq!(
+                    format!("{:#?}", fp("1.5")),
+                    format!(
+                        "FixedPoint<{}, U{}>(1.5 = 15{})",
+                        stringify!($layout),
+                        $digits,
+                        "0".repeat($digits - 1)
+                    )
+                );
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde() -> Result<()> {
+                let x = fp("10.042");
+                assert_eq!(serde_json::to_string(&x)?, "\"10.042\"");
+                assert_eq!(serde_json::from_str::<FixedPoint>("\"10.042\"")?, x);
+                assert_eq!(serde_json::from_str::<FixedPoint>("10")?, fp("10"));
+                assert!(serde_json::from_str::<FixedPoint>("\"-10.042\"").is_err());
+                assert!(serde_json::from_str::<FixedPoint>("-10").is_err());
+                Ok(())
+            }
+
+            #[cfg(feature = "money-proto")]
+            #[test]
+            fn money_proto() -> Result<()> {
+                use fixnum::money_proto::*;
+
+                assert_eq!(to_units_nanos(fp("1.75"))?, (1, 750_000_000));
+                assert_eq!(from_units_nanos::<FixedPoint>(1, 750_000_000)?, fp("1.75"));
+                assert!(from_units_nanos::<FixedPoint>(-1, 0).is_err());
+                assert!(from_units_nanos::<FixedPoint>(0, -1).is_err());
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tests!("u64", u64, U9, 9, "18446744073.709551615");
+impl_tests!(
+    "u128",
+    u128,
+    U18,
+    18,
+    "340282366920938463463.374607431768211455"
+);
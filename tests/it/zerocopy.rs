@@ -0,0 +1,26 @@
+#![cfg(feature = "zerocopy")]
+
+use anyhow::Result;
+use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+#[test]
+fn ref_from_bytes() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let raw: Vec<Layout> = vec![0, 1, FixedPoint::ONE.into_bits(), Layout::MIN, Layout::MAX];
+            let bytes = raw.as_bytes();
+
+            let values = <[FixedPoint]>::ref_from_bytes(bytes).map_err(|err| anyhow::anyhow!("{}", err))?;
+            assert_eq!(
+                values,
+                [FixedPoint::ZERO, FixedPoint::EPSILON, FixedPoint::ONE, FixedPoint::MIN, FixedPoint::MAX]
+            );
+            assert_eq!(values.as_bytes(), bytes);
+
+            assert_eq!(FixedPoint::new_zeroed(), FixedPoint::ZERO);
+            assert_eq!(FixedPoint::read_from_bytes(FixedPoint::MAX.as_bytes()).ok(), Some(FixedPoint::MAX));
+            assert!(FixedPoint::read_from_bytes(&bytes[1..]).is_err());
+        },
+    };
+    Ok(())
+}